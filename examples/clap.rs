@@ -0,0 +1,31 @@
+//! Parsing documents from command line arguments with clap.
+//!
+//! ```text
+//! $ cargo run --example clap -- --cpf 984.844.854-39 --cep 01310100
+//! CPF 984.844.854-39, CEP 01310-100 (SP)
+//!
+//! $ cargo run --example clap -- --cpf 984.844.854-00
+//! error: invalid value '984.844.854-00' for '--cpf <CPF>': invalid CPF, the first check digit does not match
+//! ```
+
+use bras::{Cep, Cpf};
+use clap::Parser;
+
+#[derive(Parser)]
+struct Args {
+    #[arg(long, value_parser = clap::value_parser!(Cpf))]
+    cpf: Cpf,
+    #[arg(long, value_parser = clap::value_parser!(Cep))]
+    cep: Option<Cep>,
+}
+
+fn main() {
+    let args = Args::parse();
+    match args.cep {
+        Some(cep) => match cep.uf() {
+            Some(uf) => println!("CPF {}, CEP {} ({})", args.cpf, cep, uf),
+            None => println!("CPF {}, CEP {}", args.cpf, cep),
+        },
+        None => println!("CPF {}", args.cpf),
+    }
+}
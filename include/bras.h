@@ -0,0 +1,50 @@
+/*
+ * C bindings of the bras crate, built with the `ffi` feature.
+ *
+ * Functions return BRAS_OK or a negative error code. Outputs are written as NUL
+ * terminated strings into buffers owned by the caller.
+ */
+
+#ifndef BRAS_H
+#define BRAS_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define BRAS_OK 0
+/* A pointer argument is null. */
+#define BRAS_ERR_NULL -1
+/* The input is not valid UTF-8. */
+#define BRAS_ERR_UTF8 -2
+/* The input is not a valid document. */
+#define BRAS_ERR_INVALID -3
+/* The output buffer can't hold the result and its NUL terminator. */
+#define BRAS_ERR_BUFFER_TOO_SMALL -4
+
+/* Size of a buffer for a formatted CPF, "000.000.000-00", and its NUL terminator. */
+#define BRAS_CPF_LEN 15
+/* Size of a buffer for a formatted CNPJ, "00.000.000/0000-00", and its NUL terminator. */
+#define BRAS_CNPJ_LEN 19
+/* Size of a buffer for a formatted CEP, "00000-000", and its NUL terminator. */
+#define BRAS_CEP_LEN 10
+
+int bras_cpf_validate(const char *s);
+int bras_cpf_format(const char *s, char *out, size_t len);
+int bras_cpf_generate(char *out, size_t len);
+
+int bras_cnpj_validate(const char *s);
+int bras_cnpj_format(const char *s, char *out, size_t len);
+int bras_cnpj_generate(char *out, size_t len);
+
+int bras_cep_validate(const char *s);
+int bras_cep_format(const char *s, char *out, size_t len);
+int bras_cep_generate(char *out, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* BRAS_H */
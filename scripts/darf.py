@@ -0,0 +1,91 @@
+#!/usr/bin/env python3
+"""Regenerates src/darf/codigos.rs from the Receita Federal table of revenue codes.
+
+Usage: python3 scripts/darf.py --input codigos.csv
+
+The input is the table of DARF revenue codes exported as CSV, with the code in the first
+column and its description in the second, separated by ";" or ",". A header row is
+skipped. Codes are grouped by the tax their description starts with, as in
+"IRRF - Rendimentos do trabalho assalariado", and the output is sorted by code, the order
+`CodigoReceita` relies on.
+"""
+
+import argparse
+import csv
+import pathlib
+
+OUTPUT = pathlib.Path(__file__).resolve().parent.parent / "src" / "darf" / "codigos.rs"
+
+HEADER = """\
+//! Receita Federal revenue codes (códigos de receita) for DARF, sorted by code.
+//!
+//! Regenerate with `scripts/darf.py` from the Receita Federal table of revenue codes, do
+//! not edit by hand.
+
+use super::CategoriaReceita::{self, *};
+
+#[rustfmt::skip]
+pub(super) const CODIGOS: &[(u16, CategoriaReceita, &str)] = &[
+"""
+
+# Checked in order, so the withholdings come before the taxes they withhold.
+CATEGORIAS = [
+    ("RETENÇÃO DE", "Csrf"),
+    ("CSRF", "Csrf"),
+    ("IRPF", "Irpf"),
+    ("IRPJ", "Irpj"),
+    ("IRRF", "Irrf"),
+    ("CSLL", "Csll"),
+    ("PIS", "Pis"),
+    ("COFINS", "Cofins"),
+    ("IPI", "Ipi"),
+    ("CONTRIBUIÇÃO PREVIDENCIÁRIA", "Previdencia"),
+]
+
+
+def rust_string(s):
+    return '"' + s.replace("\\", "\\\\").replace('"', '\\"') + '"'
+
+
+def categoria(description):
+    upper = description.upper()
+    for prefix, variant in CATEGORIAS:
+        if upper.startswith(prefix):
+            return variant
+    return "Outros"
+
+
+def read(path):
+    text = path.read_text(encoding="utf-8-sig")
+    dialect = csv.Sniffer().sniff(text.splitlines()[0], delimiters=";,")
+    codigos = {}
+    for row in csv.reader(text.splitlines(), dialect):
+        if len(row) < 2 or not row[0].strip().isdigit():
+            continue
+        code = int(row[0])
+        if code > 9999:
+            raise SystemExit("revenue code with more than 4 digits: {}".format(row[0]))
+        if code in codigos:
+            raise SystemExit("duplicated revenue code in the input: {:04}".format(code))
+        codigos[code] = " ".join(row[1].split())
+    return codigos
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--input", type=pathlib.Path, required=True, help="CSV of the table")
+    args = parser.parse_args()
+
+    codigos = read(args.input)
+    lines = [HEADER]
+    lines.extend(
+        "    ({}, {}, {}),\n".format(code, categoria(description), rust_string(description))
+        for code, description in sorted(codigos.items())
+    )
+    lines.append("];\n")
+    OUTPUT.write_text("".join(lines), encoding="utf-8")
+    print("wrote {} revenue codes to {}".format(len(codigos), OUTPUT))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,55 @@
+#!/usr/bin/env python3
+"""Regenerates src/geo/municipios_data.rs from the IBGE localities API.
+
+Usage: python3 scripts/municipios.py [--input municipios.json]
+
+Without --input the list is downloaded from the API. The output is sorted by code, the
+order `Municipio::find_by_code` relies on.
+"""
+
+import argparse
+import json
+import pathlib
+import urllib.request
+
+URL = "https://servicodados.ibge.gov.br/api/v1/localidades/municipios"
+OUTPUT = pathlib.Path(__file__).resolve().parent.parent / "src" / "geo" / "municipios_data.rs"
+
+HEADER = """\
+//! IBGE municipality table (DTB), sorted by code.
+//!
+//! Generated by `scripts/municipios.py` from the IBGE localities API, do not edit.
+
+pub(super) const MUNICIPIOS: &[(u32, &str)] = &[
+"""
+
+
+def rust_string(s):
+    return '"' + s.replace("\\", "\\\\").replace('"', '\\"') + '"'
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--input", type=pathlib.Path, help="JSON already downloaded from the API")
+    args = parser.parse_args()
+
+    if args.input:
+        data = json.loads(args.input.read_text(encoding="utf-8"))
+    else:
+        with urllib.request.urlopen(URL) as response:
+            data = json.load(response)
+
+    municipios = sorted((int(m["id"]), m["nome"]) for m in data)
+    codes = [code for code, _ in municipios]
+    if len(set(codes)) != len(codes):
+        raise SystemExit("duplicated municipality codes in the input")
+
+    lines = [HEADER]
+    lines.extend("    ({}, {}),\n".format(code, rust_string(name)) for code, name in municipios)
+    lines.append("];\n")
+    OUTPUT.write_text("".join(lines), encoding="utf-8")
+    print("wrote {} municipalities to {}".format(len(municipios), OUTPUT))
+
+
+if __name__ == "__main__":
+    main()
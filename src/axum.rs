@@ -0,0 +1,184 @@
+//! axum extractors whose rejections explain which document failed to parse.
+//!
+//! [`Path`] and [`Query`] behave like the axum extractors of the same name, but a value
+//! that fails to deserialize is rejected with `422 Unprocessable Entity` and a JSON body
+//! with the reason in `error`, such as `invalid value: string "98484485401", expected a CPF`,
+//! and the parameter name in `field` when axum reports it.
+//!
+//! # Examples
+//! ```
+//! use axum::routing::get;
+//! use axum::Router;
+//! use bras::axum::Path;
+//! use bras::Cpf;
+//!
+//! async fn cliente(Path(cpf): Path<Cpf>) -> String {
+//!     format!("cliente {}", cpf)
+//! }
+//!
+//! let app: Router = Router::new().route("/clientes/{cpf}", get(cliente));
+//! ```
+
+use ::axum::extract::path::ErrorKind;
+use ::axum::extract::rejection::{PathRejection, QueryRejection};
+use ::axum::extract::FromRequestParts;
+use ::axum::http::request::Parts;
+use ::axum::http::StatusCode;
+use ::axum::response::{IntoResponse, Response};
+use ::axum::Json;
+use ::serde::de::DeserializeOwned;
+
+/// Path parameters, deserialized like [`axum::extract::Path`].
+#[derive(Clone, Copy, Debug)]
+pub struct Path<T>(pub T);
+
+/// Query string, deserialized like [`axum::extract::Query`].
+#[derive(Clone, Copy, Debug)]
+pub struct Query<T>(pub T);
+
+/// Rejection of [`Path`] and [`Query`].
+#[derive(Clone, Debug)]
+pub struct Rejection {
+    pub status: StatusCode,
+    /// Why the value was rejected.
+    pub error: String,
+    /// Name of the parameter that failed, when known.
+    pub field: Option<String>,
+}
+
+impl From<PathRejection> for Rejection {
+    fn from(rejection: PathRejection) -> Self {
+        match rejection {
+            PathRejection::FailedToDeserializePathParams(err) => {
+                let field = match err.kind() {
+                    ErrorKind::ParseErrorAtKey { key, .. } => Some(key.clone()),
+                    _ => None,
+                };
+                Rejection {
+                    status: StatusCode::UNPROCESSABLE_ENTITY,
+                    error: err.body_text(),
+                    field,
+                }
+            }
+            other => Rejection {
+                status: other.status(),
+                error: other.body_text(),
+                field: None,
+            },
+        }
+    }
+}
+
+impl From<QueryRejection> for Rejection {
+    fn from(rejection: QueryRejection) -> Self {
+        let status = match rejection {
+            QueryRejection::FailedToDeserializeQueryString(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            _ => rejection.status(),
+        };
+        Rejection {
+            status,
+            error: rejection.body_text(),
+            field: None,
+        }
+    }
+}
+
+impl IntoResponse for Rejection {
+    fn into_response(self) -> Response {
+        let body = serde_json::json!({
+            "error": self.error,
+            "field": self.field,
+        });
+        (self.status, Json(body)).into_response()
+    }
+}
+
+impl<T, S> FromRequestParts<S> for Path<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = Rejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let ::axum::extract::Path(value) =
+            ::axum::extract::Path::<T>::from_request_parts(parts, state).await?;
+        Ok(Path(value))
+    }
+}
+
+impl<T, S> FromRequestParts<S> for Query<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = Rejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let ::axum::extract::Query(value) =
+            ::axum::extract::Query::<T>::from_request_parts(parts, state).await?;
+        Ok(Query(value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Cep, Cpf};
+    use ::axum::body::Body;
+    use ::axum::http::Request;
+    use ::axum::routing::get;
+    use ::axum::Router;
+    use tower::ServiceExt;
+
+    #[derive(serde::Deserialize)]
+    struct Busca {
+        cep: Cep,
+    }
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/clientes/{cpf}",
+                get(|Path(cpf): Path<Cpf>| async move { cpf.to_string() }),
+            )
+            .route(
+                "/enderecos",
+                get(|Query(busca): Query<Busca>| async move { busca.cep.to_string() }),
+            )
+    }
+
+    async fn get_body(uri: &str) -> (StatusCode, String) {
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+        let response = app().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = ::axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn valid_documents_are_extracted() {
+        assert_eq!(
+            (StatusCode::OK, "984.844.854-39".to_string()),
+            get_body("/clientes/98484485439").await
+        );
+        assert_eq!(
+            (StatusCode::OK, "01310-100".to_string()),
+            get_body("/enderecos?cep=01310100").await
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_documents_are_unprocessable() {
+        let (status, body) = get_body("/clientes/98484485401").await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, status);
+        let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert!(body["error"].as_str().unwrap().contains("expected a CPF"));
+
+        let (status, body) = get_body("/enderecos?cep=00000-000").await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, status);
+        assert!(body.contains("expected a CEP"));
+    }
+}
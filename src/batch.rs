@@ -0,0 +1,219 @@
+//! Helpers for bulk imports: iterator adapters for validation pipelines and, with the
+//! `rayon` feature, parallel parsing spread over every core.
+//!
+//! # Examples
+//! ```
+//! # #[cfg(feature = "cpf")]
+//! # fn main() {
+//! use bras::batch::ValidateExt;
+//! use bras::ParseCpfError;
+//!
+//! let linhas = ["984.844.854-39", "984.844.854-00", "051.194.390-39"];
+//! let (cpfs, falhas) = linhas.iter().validate_cpfs().partition_valid();
+//! assert_eq!(2, cpfs.len());
+//! assert_eq!(vec![(1, ParseCpfError::InvalidCheckDigit { which: 1 })], falhas);
+//! # }
+//! # #[cfg(not(feature = "cpf"))]
+//! # fn main() {}
+//! ```
+
+use alloc::vec::Vec;
+
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "cpf")]
+use crate::Cpf;
+
+/// Adapters for iterators over raw values, such as the column of a file being imported.
+pub trait ValidateExt: Iterator + Sized {
+    /// Parses each item as a `T`, pairing every error with the position of its item.
+    ///
+    /// ```
+    /// use bras::batch::ValidateExt;
+    /// use bras::CodigoMunicipio;
+    ///
+    /// let mut codigos = ["4106902", "410690"].into_iter().validate::<CodigoMunicipio>();
+    /// assert!(codigos.next().unwrap().is_ok());
+    /// assert_eq!(1, codigos.next().unwrap().unwrap_err().0);
+    /// ```
+    fn validate<T: FromStr>(self) -> Validate<Self, T>
+    where
+        Self::Item: AsRef<str>,
+    {
+        Validate {
+            iter: self,
+            position: 0,
+            parsed: PhantomData,
+        }
+    }
+
+    /// [`ValidateExt::validate`] for CPFs.
+    #[cfg(feature = "cpf")]
+    fn validate_cpfs(self) -> Validate<Self, Cpf>
+    where
+        Self::Item: AsRef<str>,
+    {
+        self.validate()
+    }
+
+    /// Splits the output of [`ValidateExt::validate`] into the parsed values and the
+    /// failures, both in order.
+    fn partition_valid<T, E>(self) -> (Vec<T>, Vec<(usize, E)>)
+    where
+        Self: Iterator<Item = Result<T, (usize, E)>>,
+    {
+        let mut valid = Vec::new();
+        let mut failures = Vec::new();
+        for item in self {
+            match item {
+                Ok(value) => valid.push(value),
+                Err(failure) => failures.push(failure),
+            }
+        }
+        (valid, failures)
+    }
+}
+
+impl<I: Iterator> ValidateExt for I {}
+
+/// Iterator returned by [`ValidateExt::validate`].
+#[derive(Clone, Debug)]
+pub struct Validate<I, T> {
+    iter: I,
+    position: usize,
+    parsed: PhantomData<fn() -> T>,
+}
+
+impl<I, T> Iterator for Validate<I, T>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+    T: FromStr,
+{
+    type Item = Result<T, (usize, T::Err)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let position = self.position;
+        self.position += 1;
+        Some(item.as_ref().parse().map_err(|err| (position, err)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Number of CPFs each task of [`par_validate_cpfs`] checks with [`Cpf::validate_batch`].
+#[cfg(all(feature = "rayon", feature = "cpf"))]
+const CHUNK: usize = 4096;
+
+/// Parses every item in parallel. Returns them all in order when they all parse, or
+/// else the position and error of every failure, in order.
+///
+/// ```
+/// use bras::batch::par_parse_all;
+/// use bras::{Cpf, ParseCpfError};
+///
+/// let cpfs: Vec<Cpf> = par_parse_all(&["984.844.854-39", "051.194.390-39"]).unwrap();
+/// assert_eq!(2, cpfs.len());
+///
+/// let failures = par_parse_all::<Cpf>(&["984.844.854-39", ""]).unwrap_err();
+/// assert_eq!(vec![(1, ParseCpfError::InvalidLength { got: 0 })], failures);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_parse_all<T>(items: &[&str]) -> Result<Vec<T>, Vec<(usize, T::Err)>>
+where
+    T: FromStr + Send,
+    T::Err: Send,
+{
+    let parsed: Vec<Result<T, T::Err>> = items.par_iter().map(|s| s.parse()).collect();
+    if parsed.iter().all(Result::is_ok) {
+        return Ok(parsed.into_iter().flatten().collect());
+    }
+    Err(parsed
+        .into_iter()
+        .enumerate()
+        .filter_map(|(position, result)| result.err().map(|err| (position, err)))
+        .collect())
+}
+
+/// [`Cpf::validate_batch`] over several cores, returning the positions of the invalid
+/// documents.
+///
+/// ```
+/// use bras::batch::par_validate_cpfs;
+///
+/// let documents = ["984.844.854-39", "984.844.854-00", "98484485439"];
+/// assert_eq!(vec![1], par_validate_cpfs(&documents));
+/// ```
+#[cfg(all(feature = "rayon", feature = "cpf"))]
+pub fn par_validate_cpfs(documents: &[&str]) -> Vec<usize> {
+    documents
+        .par_chunks(CHUNK)
+        .enumerate()
+        .flat_map_iter(|(chunk, block)| {
+            Cpf::validate_batch(block)
+                .into_iter()
+                .enumerate()
+                .filter(|(_, valid)| !valid)
+                .map(move |(position, _)| chunk * CHUNK + position)
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "cpf"))]
+mod test {
+    use super::*;
+    #[cfg(all(feature = "rayon", feature = "geo"))]
+    use crate::Cep;
+    #[cfg(all(feature = "rayon", feature = "geo"))]
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn positions_count_every_item() {
+        let linhas = ["", "98484485439", "x", "984.844.854-39"];
+        let (cpfs, falhas) = linhas.iter().validate_cpfs().partition_valid();
+        assert_eq!(cpfs[0], cpfs[1]);
+        assert_eq!(
+            vec![0, 2],
+            falhas
+                .iter()
+                .map(|(position, _)| *position)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn failures_are_reported_in_order_across_chunks() {
+        let mut documents = vec!["984.844.854-39"; 3 * CHUNK];
+        let invalid = [0, CHUNK - 1, CHUNK, 2 * CHUNK + 7, 3 * CHUNK - 1];
+        for position in invalid {
+            documents[position] = "984.844.854-00";
+        }
+        assert_eq!(invalid.to_vec(), par_validate_cpfs(&documents));
+
+        let failures = par_parse_all::<Cpf>(&documents).unwrap_err();
+        assert_eq!(
+            invalid.to_vec(),
+            failures
+                .iter()
+                .map(|(position, _)| *position)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[cfg(all(feature = "rayon", feature = "geo"))]
+    #[test]
+    fn parse_any_document() {
+        let ceps: Vec<Cep> = par_parse_all(&["01310-100", "70040010"]).unwrap();
+        assert_eq!("70040-010", ceps[1].to_string());
+        assert!(par_parse_all::<Cep>(&[]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,181 @@
+//! Command line access to the crate, built with the `cli` feature.
+//!
+//! ```text
+//! $ bras validate cpf 984.844.854-39 984.844.854-00
+//! 984.844.854-00: invalid CPF, the first check digit does not match
+//!
+//! $ cat ceps.txt | bras format cep
+//! 01310-100
+//! 70040-010
+//!
+//! $ bras generate cpf -n 2
+//! 984.844.854-39
+//! 051.194.390-39
+//! ```
+//!
+//! Values are read from the arguments or, when there are none, one per line from stdin.
+//! `validate` and `format` exit with 1 when any value is invalid.
+
+use std::io::BufRead;
+use std::process::ExitCode;
+
+use bras::{Cep, CodigoMunicipio, Cpf};
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(
+    name = "bras",
+    about = "Validates, formats and generates brazilian documents"
+)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Reports the invalid values, quietly accepting the others.
+    Validate { tipo: Tipo, values: Vec<String> },
+    /// Prints each value in its canonical format, ignoring the original punctuation.
+    Format {
+        tipo: Tipo,
+        /// Prints only the digits.
+        #[arg(long)]
+        digits: bool,
+        values: Vec<String>,
+    },
+    /// Prints random valid documents.
+    Generate {
+        tipo: Tipo,
+        #[arg(short, default_value_t = 1)]
+        n: usize,
+    },
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum Tipo {
+    Cpf,
+    Cep,
+    Municipio,
+}
+
+impl Tipo {
+    fn validate(self, value: &str) -> Result<(), String> {
+        match self {
+            Tipo::Cpf => value
+                .parse::<Cpf>()
+                .map(drop)
+                .map_err(|err| err.to_string()),
+            Tipo::Cep => value
+                .parse::<Cep>()
+                .map(drop)
+                .map_err(|err| err.to_string()),
+            Tipo::Municipio => value
+                .parse::<CodigoMunicipio>()
+                .map(drop)
+                .map_err(|err| err.to_string()),
+        }
+    }
+
+    fn format(self, value: &str, digits: bool) -> Result<String, String> {
+        match self {
+            Tipo::Cpf => Cpf::parse_lossy(value)
+                .map(|cpf| {
+                    if digits {
+                        format!("{:#}", cpf)
+                    } else {
+                        cpf.to_string()
+                    }
+                })
+                .map_err(|err| err.to_string()),
+            Tipo::Cep => Cep::parse_lossy(value)
+                .map(|cep| {
+                    if digits {
+                        format!("{:#}", cep)
+                    } else {
+                        cep.to_string()
+                    }
+                })
+                .map_err(|err| err.to_string()),
+            Tipo::Municipio => CodigoMunicipio::parse_lossy(value)
+                .map(|codigo| codigo.to_string())
+                .map_err(|err| err.to_string()),
+        }
+    }
+
+    fn generate(self) -> Option<String> {
+        match self {
+            Tipo::Cpf => Some(Cpf::generate().to_string()),
+            Tipo::Cep => Some(Cep::generate().to_string()),
+            Tipo::Municipio => None,
+        }
+    }
+}
+
+/// The given values, or the lines of stdin when none were given.
+fn values(values: Vec<String>) -> Box<dyn Iterator<Item = std::io::Result<String>>> {
+    if values.is_empty() {
+        Box::new(std::io::stdin().lock().lines())
+    } else {
+        Box::new(values.into_iter().map(Ok))
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let mut status = ExitCode::SUCCESS;
+    match args.command {
+        Command::Validate {
+            tipo,
+            values: given,
+        } => {
+            for value in values(given) {
+                let value = match value {
+                    Ok(value) => value,
+                    Err(err) => {
+                        eprintln!("error: {}", err);
+                        return ExitCode::FAILURE;
+                    }
+                };
+                if let Err(err) = tipo.validate(value.trim()) {
+                    println!("{}: {}", value, err);
+                    status = ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Format {
+            tipo,
+            digits,
+            values: given,
+        } => {
+            for value in values(given) {
+                let value = match value {
+                    Ok(value) => value,
+                    Err(err) => {
+                        eprintln!("error: {}", err);
+                        return ExitCode::FAILURE;
+                    }
+                };
+                match tipo.format(&value, digits) {
+                    Ok(formatted) => println!("{}", formatted),
+                    Err(err) => {
+                        eprintln!("{}: {}", value, err);
+                        status = ExitCode::FAILURE;
+                    }
+                }
+            }
+        }
+        Command::Generate { tipo, n } => {
+            for _ in 0..n {
+                match tipo.generate() {
+                    Some(generated) => println!("{}", generated),
+                    None => {
+                        eprintln!("error: generating IBGE municipality codes is not supported");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+        }
+    }
+    status
+}
@@ -0,0 +1,180 @@
+//! Data quality reports over a column of a CSV file.
+//!
+//! # Examples
+//! ```
+//! use bras::csv::validate_column;
+//! use bras::{Cpf, ParseCpfError};
+//!
+//! let arquivo = "\
+//! nome,cpf
+//! Maria,984.844.854-39
+//! João,984.844.845-39
+//! José,98484485439
+//! ";
+//! let mut reader = csv::Reader::from_reader(arquivo.as_bytes());
+//! let report = validate_column::<Cpf, _>(&mut reader, "cpf")?;
+//! assert_eq!(3, report.rows);
+//!
+//! let failure = &report.failures[0];
+//! assert_eq!(3, failure.row);
+//! assert_eq!("984.844.845-39", failure.value);
+//! assert_eq!(ParseCpfError::InvalidCheckDigit { which: 1 }, failure.error);
+//! assert_eq!("984.844.854-39", failure.suggestion.unwrap().to_string());
+//!
+//! # Ok::<(), bras::csv::ReportError>(())
+//! ```
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::{Cep, CodigoMunicipio, Cpf};
+
+/// A type the report knows how to suggest fixes for.
+pub trait Fixable: FromStr {
+    /// Most likely value meant by an invalid `s`, when there is one.
+    fn suggest_fix(s: &str) -> Option<Self>;
+}
+
+impl Fixable for Cpf {
+    /// The only CPF one typo away, see [`Cpf::suggest`].
+    fn suggest_fix(s: &str) -> Option<Self> {
+        match Cpf::suggest(s).as_slice() {
+            [cpf] => Some(*cpf),
+            _ => None,
+        }
+    }
+}
+
+impl Fixable for Cep {
+    /// The CEP written with other separators, see [`Cep::parse_lossy`].
+    fn suggest_fix(s: &str) -> Option<Self> {
+        Cep::parse_lossy(s).ok()
+    }
+}
+
+impl Fixable for CodigoMunicipio {
+    /// The code written with separators, see [`CodigoMunicipio::parse_lossy`].
+    fn suggest_fix(s: &str) -> Option<Self> {
+        CodigoMunicipio::parse_lossy(s).ok()
+    }
+}
+
+/// Outcome of [`validate_column`].
+#[derive(Clone, Debug)]
+pub struct ValidationReport<T, E> {
+    /// Number of records read, not counting the header.
+    pub rows: usize,
+    pub failures: Vec<Failure<T, E>>,
+}
+
+/// A value of the column that did not parse.
+#[derive(Clone, Debug)]
+pub struct Failure<T, E> {
+    /// Number of the record, the header being row 1, as spreadsheets show it. Blank lines
+    /// are not counted.
+    pub row: usize,
+    pub value: String,
+    pub error: E,
+    pub suggestion: Option<T>,
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ReportError {
+    Csv(csv::Error),
+    MissingColumn(String),
+}
+
+impl Display for ReportError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ReportError::Csv(err) => write!(f, "could not read the CSV: {}", err),
+            ReportError::MissingColumn(column) => write!(f, "no column named {:?}", column),
+        }
+    }
+}
+
+impl core::error::Error for ReportError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ReportError::Csv(err) => Some(err),
+            ReportError::MissingColumn(_) => None,
+        }
+    }
+}
+
+impl From<csv::Error> for ReportError {
+    fn from(err: csv::Error) -> Self {
+        ReportError::Csv(err)
+    }
+}
+
+/// Parses every value of the column named `column` as a `T`, reporting the values that
+/// fail along with a suggested fix. Values are trimmed of surrounding whitespace.
+pub fn validate_column<T, R>(
+    reader: &mut csv::Reader<R>,
+    column: &str,
+) -> Result<ValidationReport<T, T::Err>, ReportError>
+where
+    T: Fixable,
+    R: std::io::Read,
+{
+    let index = reader
+        .headers()?
+        .iter()
+        .position(|header| header.trim() == column)
+        .ok_or_else(|| ReportError::MissingColumn(column.to_string()))?;
+
+    let mut report = ValidationReport {
+        rows: 0,
+        failures: Vec::new(),
+    };
+    let mut record = csv::StringRecord::new();
+    while reader.read_record(&mut record)? {
+        report.rows += 1;
+        let value = record.get(index).unwrap_or_default().trim();
+        if let Err(error) = value.parse::<T>() {
+            report.failures.push(Failure {
+                row: report.rows + 1,
+                value: value.to_string(),
+                error,
+                suggestion: T::suggest_fix(value),
+            });
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ParseCepError;
+
+    #[test]
+    fn report_every_failure() {
+        let arquivo = "cep;cidade\n01310-100;São Paulo\n01.310-100;São Paulo\n\n;Brasília\n";
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(b';')
+            .from_reader(arquivo.as_bytes());
+        let report = validate_column::<Cep, _>(&mut reader, "cep").unwrap();
+        assert_eq!(3, report.rows);
+        assert_eq!(2, report.failures.len());
+
+        assert_eq!(3, report.failures[0].row);
+        assert_eq!(
+            Some("01310-100".parse().unwrap()),
+            report.failures[0].suggestion
+        );
+        assert_eq!(4, report.failures[1].row);
+        assert_eq!("", report.failures[1].value);
+        assert_eq!(ParseCepError::Invalid, report.failures[1].error);
+        assert_eq!(None, report.failures[1].suggestion);
+    }
+
+    #[test]
+    fn missing_column() {
+        let mut reader = csv::Reader::from_reader("nome,cpf\n".as_bytes());
+        let err = validate_column::<Cpf, _>(&mut reader, "cep").unwrap_err();
+        assert_eq!(r#"no column named "cep""#, err.to_string());
+    }
+}
@@ -0,0 +1,39 @@
+//! Receita Federal revenue codes (códigos de receita) for DARF, sorted by code.
+//!
+//! Regenerate with `scripts/darf.py` from the Receita Federal table of revenue codes, do
+//! not edit by hand.
+
+use super::CategoriaReceita::{self, *};
+
+#[rustfmt::skip]
+pub(super) const CODIGOS: &[(u16, CategoriaReceita, &str)] = &[
+    (190, Irpf, "IRPF - Carnê-Leão"),
+    (211, Irpf, "IRPF - Declaração de ajuste anual, quota única ou quotas"),
+    (220, Irpj, "IRPJ - Lucro real, apuração trimestral"),
+    (473, Irrf, "IRRF - Rendimentos de residentes ou domiciliados no exterior"),
+    (561, Irrf, "IRRF - Rendimentos do trabalho assalariado"),
+    (588, Irrf, "IRRF - Rendimentos do trabalho sem vínculo empregatício"),
+    (1708, Irrf, "IRRF - Remuneração de serviços profissionais prestados por pessoa jurídica"),
+    (2089, Irpj, "IRPJ - Lucro presumido"),
+    (2172, Cofins, "COFINS - Faturamento, regime cumulativo"),
+    (2362, Irpj, "IRPJ - Lucro real, estimativa mensal"),
+    (2372, Csll, "CSLL - Lucro presumido"),
+    (2484, Csll, "CSLL - Lucro real, estimativa mensal"),
+    (2985, Previdencia, "Contribuição previdenciária sobre a receita bruta (CPRB)"),
+    (3208, Irrf, "IRRF - Aluguéis e royalties pagos a pessoa física"),
+    (3426, Irrf, "IRRF - Aplicações financeiras de renda fixa"),
+    (4600, Irpf, "IRPF - Ganho de capital na alienação de bens e direitos"),
+    (5123, Ipi, "IPI - Demais produtos"),
+    (5706, Irrf, "IRRF - Juros sobre o capital próprio"),
+    (5856, Cofins, "COFINS - Regime não cumulativo"),
+    (5952, Csrf, "Retenção de CSLL, COFINS e PIS/PASEP sobre pagamentos a pessoa jurídica"),
+    (5960, Csrf, "Retenção de COFINS sobre pagamentos a pessoa jurídica"),
+    (5979, Csrf, "Retenção de PIS/PASEP sobre pagamentos a pessoa jurídica"),
+    (5987, Csrf, "Retenção de CSLL sobre pagamentos a pessoa jurídica"),
+    (6012, Csll, "CSLL - Lucro real, apuração trimestral"),
+    (6015, Irpf, "IRPF - Ganhos líquidos em operações em bolsa"),
+    (6912, Pis, "PIS/PASEP - Regime não cumulativo"),
+    (8045, Irrf, "IRRF - Comissões e corretagens pagas a pessoa jurídica"),
+    (8109, Pis, "PIS/PASEP - Faturamento, regime cumulativo"),
+    (8301, Pis, "PIS/PASEP - Folha de salários"),
+];
@@ -0,0 +1,169 @@
+//! Revenue codes (códigos de receita) of the DARF, the document federal taxes are paid
+//! with, checked against an embedded table of the Receita Federal codes.
+//!
+//! # Examples
+//! ```
+//! use bras::darf::{CategoriaReceita, CodigoReceita};
+//! # use bras::darf::ParseCodigoReceitaError;
+//!
+//! let codigo: CodigoReceita = "0561".parse()?;
+//! assert_eq!(CategoriaReceita::Irrf, codigo.categoria());
+//! assert_eq!("IRRF - Rendimentos do trabalho assalariado", codigo.description());
+//! assert_eq!("0561", codigo.to_string());
+//!
+//! assert_eq!(Ok(codigo), CodigoReceita::try_from(561));
+//! assert_eq!(Err(ParseCodigoReceitaError::Unknown), "9999".parse::<CodigoReceita>());
+//!
+//! # Ok::<(), ParseCodigoReceitaError>(())
+//! ```
+
+mod codigos;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use self::codigos::CODIGOS;
+
+/// A revenue code present in the embedded table.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct CodigoReceita {
+    index: u16,
+}
+
+/// Tax or contribution a revenue code is for.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum CategoriaReceita {
+    /// Imposto de Renda da Pessoa Física.
+    Irpf,
+    /// Imposto de Renda da Pessoa Jurídica.
+    Irpj,
+    /// Imposto de Renda Retido na Fonte.
+    Irrf,
+    /// Contribuição Social sobre o Lucro Líquido.
+    Csll,
+    Pis,
+    Cofins,
+    /// Contribuições sociais retidas na fonte, CSLL, COFINS and PIS/PASEP withheld
+    /// together or separately.
+    Csrf,
+    /// Imposto sobre Produtos Industrializados.
+    Ipi,
+    /// Social security contributions paid by DARF instead of GPS.
+    Previdencia,
+    /// Every other tax, contribution, fine or fee, such as IOF, ITR and CIDE.
+    Outros,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseCodigoReceitaError {
+    /// Not 4 digits.
+    InvalidFormat,
+    /// Not in the embedded table.
+    Unknown,
+}
+
+impl Display for ParseCodigoReceitaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseCodigoReceitaError::InvalidFormat => {
+                f.write_str("invalid DARF revenue code, expected 4 digits")
+            }
+            ParseCodigoReceitaError::Unknown => f.write_str("unknown DARF revenue code"),
+        }
+    }
+}
+
+impl core::error::Error for ParseCodigoReceitaError {}
+
+impl CodigoReceita {
+    pub fn categoria(self) -> CategoriaReceita {
+        CODIGOS[usize::from(self.index)].1
+    }
+
+    /// What the code is for, as the Receita Federal describes it.
+    pub fn description(self) -> &'static str {
+        CODIGOS[usize::from(self.index)].2
+    }
+
+    /// Every code of the embedded table, in ascending order.
+    pub fn all() -> impl Iterator<Item = CodigoReceita> {
+        (0..CODIGOS.len() as u16).map(|index| CodigoReceita { index })
+    }
+}
+
+impl FromStr for CodigoReceita {
+    type Err = ParseCodigoReceitaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 4 || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseCodigoReceitaError::InvalidFormat);
+        }
+        let codigo = s
+            .parse::<u16>()
+            .map_err(|_| ParseCodigoReceitaError::InvalidFormat)?;
+        CodigoReceita::try_from(codigo)
+    }
+}
+
+impl TryFrom<u16> for CodigoReceita {
+    type Error = ParseCodigoReceitaError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        CODIGOS
+            .binary_search_by_key(&value, |&(codigo, _, _)| codigo)
+            .map(|index| CodigoReceita {
+                index: index as u16,
+            })
+            .map_err(|_| ParseCodigoReceitaError::Unknown)
+    }
+}
+
+impl From<CodigoReceita> for u16 {
+    fn from(codigo: CodigoReceita) -> Self {
+        CODIGOS[usize::from(codigo.index)].0
+    }
+}
+
+impl Display for CodigoReceita {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:04}", u16::from(*self))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn embedded_table_is_sorted() {
+        assert!(CODIGOS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for codigo in CodigoReceita::all() {
+            assert_eq!(Ok(codigo), codigo.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn codes_missing_from_the_table_are_rejected() {
+        assert_eq!(
+            Err(ParseCodigoReceitaError::Unknown),
+            "0001".parse::<CodigoReceita>()
+        );
+        assert_eq!(
+            Err(ParseCodigoReceitaError::Unknown),
+            CodigoReceita::try_from(10_000)
+        );
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        for s in ["561", "05610", "05 1", "+561", ""] {
+            assert_eq!(
+                Err(ParseCodigoReceitaError::InvalidFormat),
+                s.parse::<CodigoReceita>()
+            );
+        }
+    }
+}
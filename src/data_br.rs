@@ -0,0 +1,257 @@
+//! Dates the way Brazilians write them: "31/12/2024" and "31 de dezembro de 2024".
+//!
+//! # Examples
+//! ```
+//! use bras::data_br;
+//! use chrono::NaiveDate;
+//! # use bras::data_br::ParseDataError;
+//!
+//! let date = data_br::parse("12 de março de 2024")?;
+//! assert_eq!(NaiveDate::from_ymd_opt(2024, 3, 12).unwrap(), date);
+//! assert_eq!(date, data_br::parse("12/03/2024")?);
+//!
+//! assert_eq!("12/03/2024", data_br::formatar(date));
+//! assert_eq!("12 de março de 2024", data_br::por_extenso(date));
+//!
+//! # Ok::<(), ParseDataError>(())
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::geo::normalize;
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseDataError {
+    Invalid,
+}
+
+impl Display for ParseDataError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseDataError::Invalid => f.write_str("invalid date"),
+        }
+    }
+}
+
+impl core::error::Error for ParseDataError {}
+
+const MESES: [&str; 12] = [
+    "janeiro",
+    "fevereiro",
+    "março",
+    "abril",
+    "maio",
+    "junho",
+    "julho",
+    "agosto",
+    "setembro",
+    "outubro",
+    "novembro",
+    "dezembro",
+];
+
+/// Parses "dd/mm/aaaa" or "d de mês de aaaa", ignoring case and accents in the month name.
+/// The first day of the month may be written as "1º".
+///
+/// ```
+/// use bras::data_br::{parse, ParseDataError};
+/// use chrono::NaiveDate;
+///
+/// assert_eq!(NaiveDate::from_ymd_opt(2024, 5, 1), parse("1º de Maio de 2024").ok());
+/// assert_eq!(NaiveDate::from_ymd_opt(2024, 3, 2), parse("2 de marco de 2024").ok());
+/// assert_eq!(Err(ParseDataError::Invalid), parse("31/02/2024"));
+/// ```
+pub fn parse(s: &str) -> Result<NaiveDate, ParseDataError> {
+    let s = s.trim();
+    if s.contains('/') {
+        parse_numerica(s)
+    } else {
+        parse_extenso(s)
+    }
+}
+
+const MESES_ABREVIADOS: [&str; 12] = [
+    "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez",
+];
+
+/// Month name, lowercase as written in running text ("12 de março"), for months 1 to 12.
+///
+/// ```
+/// use bras::data_br::{mes_abreviado, mes_nome};
+///
+/// assert_eq!(Some("março"), mes_nome(3));
+/// assert_eq!(Some("dez"), mes_abreviado(12));
+/// assert_eq!(None, mes_nome(13));
+/// ```
+pub fn mes_nome(mes: u32) -> Option<&'static str> {
+    MESES.get(mes.checked_sub(1)? as usize).copied()
+}
+
+/// Three letter abbreviation of the month, for months 1 to 12.
+pub fn mes_abreviado(mes: u32) -> Option<&'static str> {
+    MESES_ABREVIADOS.get(mes.checked_sub(1)? as usize).copied()
+}
+
+/// Weekday name, lowercase as written in running text.
+///
+/// ```
+/// use bras::data_br::{dia_semana_abreviado, dia_semana_nome};
+/// use chrono::Weekday;
+///
+/// assert_eq!("terça-feira", dia_semana_nome(Weekday::Tue));
+/// assert_eq!("sáb", dia_semana_abreviado(Weekday::Sat));
+/// ```
+pub fn dia_semana_nome(dia: Weekday) -> &'static str {
+    match dia {
+        Weekday::Mon => "segunda-feira",
+        Weekday::Tue => "terça-feira",
+        Weekday::Wed => "quarta-feira",
+        Weekday::Thu => "quinta-feira",
+        Weekday::Fri => "sexta-feira",
+        Weekday::Sat => "sábado",
+        Weekday::Sun => "domingo",
+    }
+}
+
+/// Three letter abbreviation of the weekday.
+pub fn dia_semana_abreviado(dia: Weekday) -> &'static str {
+    match dia {
+        Weekday::Mon => "seg",
+        Weekday::Tue => "ter",
+        Weekday::Wed => "qua",
+        Weekday::Thu => "qui",
+        Weekday::Fri => "sex",
+        Weekday::Sat => "sáb",
+        Weekday::Sun => "dom",
+    }
+}
+
+fn parse_numerica(s: &str) -> Result<NaiveDate, ParseDataError> {
+    let parts: Vec<&str> = s.split('/').collect();
+    match parts.as_slice() {
+        [day, month, year] if day.len() == 2 && month.len() == 2 && year.len() == 4 => {
+            date(number(year)?, number(month)?, number(day)?)
+        }
+        _ => Err(ParseDataError::Invalid),
+    }
+}
+
+/// "ddmmaaaa", as fiscal files and certificates write dates.
+#[cfg(any(feature = "icp-brasil", feature = "sped"))]
+pub(crate) fn parse_compacta(s: &str) -> Result<NaiveDate, ParseDataError> {
+    if s.len() != 8 || !s.is_ascii() {
+        return Err(ParseDataError::Invalid);
+    }
+    date(number(&s[4..])?, number(&s[2..4])?, number(&s[..2])?)
+}
+
+fn parse_extenso(s: &str) -> Result<NaiveDate, ParseDataError> {
+    let normalized = normalize(s);
+    let parts: Vec<&str> = normalized.split(' ').collect();
+    match parts.as_slice() {
+        [day, "de", month, "de", year] if year.len() == 4 => {
+            let day = match *day {
+                "1º" | "1o" => 1,
+                day if day.len() <= 2 => number(day)?,
+                _ => return Err(ParseDataError::Invalid),
+            };
+            let month = MESES
+                .iter()
+                .position(|name| normalize(name) == *month)
+                .ok_or(ParseDataError::Invalid)?;
+            date(number(year)?, month as u32 + 1, day)
+        }
+        _ => Err(ParseDataError::Invalid),
+    }
+}
+
+fn number<T: FromStr>(s: &str) -> Result<T, ParseDataError> {
+    if !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseDataError::Invalid);
+    }
+    T::from_str(s).map_err(|_| ParseDataError::Invalid)
+}
+
+fn date(year: i32, month: u32, day: u32) -> Result<NaiveDate, ParseDataError> {
+    NaiveDate::from_ymd_opt(year, month, day).ok_or(ParseDataError::Invalid)
+}
+
+/// "dd/mm/aaaa"
+pub fn formatar(date: NaiveDate) -> String {
+    format!("{:02}/{:02}/{:04}", date.day(), date.month(), date.year())
+}
+
+/// "d de mês de aaaa", writing the first day of the month as "1º".
+///
+/// ```
+/// use bras::data_br::por_extenso;
+/// use chrono::NaiveDate;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+/// assert_eq!("1º de maio de 2024", por_extenso(date));
+/// ```
+pub fn por_extenso(date: NaiveDate) -> String {
+    let mes = mes_nome(date.month()).expect("valid month");
+    if date.day() == 1 {
+        format!("1º de {} de {}", mes, date.year())
+    } else {
+        format!("{} de {} de {}", date.day(), mes, date.year())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip_every_day_of_a_year() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        for date in start.iter_days().take(366) {
+            assert_eq!(date, parse(&formatar(date)).unwrap());
+            assert_eq!(date, parse(&por_extenso(date)).unwrap());
+            assert_eq!(date, parse(&por_extenso(date).to_uppercase()).unwrap());
+        }
+    }
+
+    #[test]
+    fn abbreviations_are_prefixes() {
+        for mes in 1..=12 {
+            let nome = normalize(mes_nome(mes).unwrap());
+            assert!(nome.starts_with(&normalize(mes_abreviado(mes).unwrap())));
+        }
+        let mut dia = Weekday::Mon;
+        for _ in 0..7 {
+            assert!(dia_semana_nome(dia).starts_with(dia_semana_abreviado(dia)));
+            dia = dia.succ();
+        }
+        assert_eq!(None, mes_nome(0));
+        assert_eq!(None, mes_abreviado(0));
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let invalid = [
+            "",
+            "1/1/2024",
+            "01/01/24",
+            "01-01-2024",
+            "2024/01/01",
+            "+1/01/2024",
+            "32 de janeiro de 2024",
+            "1 de janeir de 2024",
+            "1 janeiro 2024",
+            "1º de janeiro de 24",
+        ];
+        for date in invalid {
+            assert_eq!(Err(ParseDataError::Invalid), parse(date), "{}", date);
+        }
+    }
+}
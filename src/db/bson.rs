@@ -0,0 +1,78 @@
+//! BSON conversions, for documents stored in MongoDB. Values are stored as their formatted
+//! string, the same representation used by the serde implementations.
+
+use ::bson::Bson;
+
+use crate::{
+    Cep, Cnpj, CodigoMunicipio, Competencia, Cpf, ParseCepError, ParseCnpjError,
+    ParseCodigoMunicipioError, ParseCompetenciaError, ParseCpfError, ParseUfError, Uf,
+};
+
+macro_rules! impl_bson {
+    ($($ty:ty => $err:ident::$variant:ident,)*) => {
+        $(
+            impl From<$ty> for Bson {
+                fn from(value: $ty) -> Self {
+                    Bson::String(value.to_string())
+                }
+            }
+
+            impl TryFrom<Bson> for $ty {
+                type Error = $err;
+
+                fn try_from(value: Bson) -> Result<Self, Self::Error> {
+                    match value {
+                        Bson::String(s) => s.parse(),
+                        _ => Err($err::$variant),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_bson! {
+    Cep => ParseCepError::Invalid,
+    Cnpj => ParseCnpjError::InvalidFormat,
+    CodigoMunicipio => ParseCodigoMunicipioError::Invalid,
+    Competencia => ParseCompetenciaError::Invalid,
+    Cpf => ParseCpfError::InvalidFormat,
+    Uf => ParseUfError::Invalid,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+    struct Cliente {
+        cpf: Cpf,
+        uf: Uf,
+    }
+
+    #[test]
+    fn round_trip_through_document() {
+        let cliente = Cliente {
+            cpf: "984.844.854-39".parse().unwrap(),
+            uf: Uf::SP,
+        };
+        let document = ::bson::to_document(&cliente).unwrap();
+        assert_eq!(Some("984.844.854-39"), document.get_str("cpf").ok());
+        assert_eq!(cliente, ::bson::from_document(document).unwrap());
+    }
+
+    #[test]
+    fn conversions() {
+        let cpf: Cpf = "984.844.854-39".parse().unwrap();
+        assert_eq!(Bson::String("984.844.854-39".into()), Bson::from(cpf));
+        assert_eq!(Ok(cpf), Cpf::try_from(Bson::from(cpf)));
+        let cnpj: Cnpj = "12.ABC.345/01DE-35".parse().unwrap();
+        assert_eq!(Bson::String("12.ABC.345/01DE-35".into()), Bson::from(cnpj));
+        assert_eq!(Ok(cnpj), Cnpj::try_from(Bson::from(cnpj)));
+        assert_eq!(
+            Err(ParseCnpjError::InvalidFormat),
+            Cnpj::try_from(Bson::Int64(11222333000181))
+        );
+        assert_eq!(Err(ParseUfError::Invalid), Uf::try_from(Bson::Int32(35)));
+    }
+}
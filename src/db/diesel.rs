@@ -0,0 +1,152 @@
+//! diesel `ToSql` and `FromSql` for PostgreSQL and MySQL.
+//!
+//! Every type maps to `Text` as its formatted string. The numeric documents also map to an
+//! integer type holding their digits: `BigInt` for [`Cpf`] and [`Cnpj`], `Integer` for
+//! [`Cep`] and [`CodigoMunicipio`]. Writing an alphanumeric CNPJ to `BigInt` fails.
+
+use std::io::Write;
+
+use ::diesel::backend::Backend;
+use ::diesel::deserialize::{self, FromSql};
+use ::diesel::mysql::Mysql;
+use ::diesel::pg::Pg;
+use ::diesel::serialize::{self, IsNull, Output, ToSql};
+use ::diesel::sql_types::{BigInt, Integer, Text};
+
+use crate::{Cep, Cnpj, CodigoMunicipio, Competencia, Cpf, Uf};
+
+fn digits<T: ToString>(value: &T) -> String {
+    value
+        .to_string()
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect()
+}
+
+/// The digits of `value` as a number, failing for documents with letters.
+fn number<T: ToString, N: core::str::FromStr>(
+    value: &T,
+) -> Result<N, Box<dyn std::error::Error + Send + Sync>>
+where
+    N::Err: std::error::Error + Send + Sync + 'static,
+{
+    if value
+        .to_string()
+        .contains(|c: char| c.is_ascii_alphabetic())
+    {
+        return Err("an alphanumeric document does not fit in an integer column".into());
+    }
+    Ok(digits(value).parse()?)
+}
+
+macro_rules! impl_diesel_text {
+    ($($ty:ty => $name:literal,)*) => {
+        $(
+            impl<DB: Backend> FromSql<Text, DB> for $ty
+            where
+                String: FromSql<Text, DB>,
+            {
+                fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+                    <String as FromSql<Text, DB>>::from_sql(bytes)?
+                        .parse()
+                        .map_err(|_| concat!("invalid ", $name).into())
+                }
+            }
+
+            impl ToSql<Text, Pg> for $ty {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+                    out.write_all(self.to_string().as_bytes())?;
+                    Ok(IsNull::No)
+                }
+            }
+
+            impl ToSql<Text, Mysql> for $ty {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+                    out.write_all(self.to_string().as_bytes())?;
+                    Ok(IsNull::No)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_diesel_integer {
+    ($($ty:ty => $sql:ty, $int:ty, $width:literal, $name:literal,)*) => {
+        $(
+            impl<DB: Backend> FromSql<$sql, DB> for $ty
+            where
+                $int: FromSql<$sql, DB>,
+            {
+                fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+                    let number = <$int as FromSql<$sql, DB>>::from_sql(bytes)?;
+                    format!("{:0width$}", number, width = $width)
+                        .parse()
+                        .map_err(|_| concat!("invalid ", $name).into())
+                }
+            }
+
+            impl ToSql<$sql, Pg> for $ty {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+                    let number: $int = number(self)?;
+                    out.write_all(&number.to_be_bytes())?;
+                    Ok(IsNull::No)
+                }
+            }
+
+            impl ToSql<$sql, Mysql> for $ty {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+                    let number: $int = number(self)?;
+                    out.write_all(&number.to_ne_bytes())?;
+                    Ok(IsNull::No)
+                }
+            }
+        )*
+    };
+}
+
+impl_diesel_text! {
+    Cep => "CEP",
+    Cnpj => "CNPJ",
+    CodigoMunicipio => "municipality code",
+    Competencia => "competência",
+    Cpf => "CPF",
+    Uf => "UF",
+}
+
+impl_diesel_integer! {
+    Cep => Integer, i32, 8, "CEP",
+    Cnpj => BigInt, i64, 14, "CNPJ",
+    CodigoMunicipio => Integer, i32, 7, "municipality code",
+    Cpf => BigInt, i64, 11, "CPF",
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::diesel::expression::AsExpression;
+
+    fn assert_column_type<ST, DB: Backend, T>()
+    where
+        T: ToSql<ST, DB> + FromSql<ST, DB> + AsExpression<ST>,
+        ST: ::diesel::sql_types::SqlType + ::diesel::sql_types::SingleValue,
+    {
+    }
+
+    #[test]
+    fn usable_with_postgres_and_mysql() {
+        assert_column_type::<Text, Pg, Cpf>();
+        assert_column_type::<BigInt, Pg, Cpf>();
+        assert_column_type::<BigInt, Mysql, Cpf>();
+        assert_column_type::<Integer, Pg, Cep>();
+        assert_column_type::<Text, Mysql, Uf>();
+        assert_column_type::<Text, Pg, Competencia>();
+        assert_eq!("01678346063", digits(&Cpf::try_from(1678346063).unwrap()));
+
+        assert_column_type::<Text, Pg, Cnpj>();
+        assert_column_type::<BigInt, Mysql, Cnpj>();
+        let numeric: Cnpj = "00.394.460/0058-87".parse().unwrap();
+        assert_eq!(Ok(394460005887), number::<_, i64>(&numeric).map_err(|_| ()));
+        let alphanumeric: Cnpj = "12.ABC.345/01DE-35".parse().unwrap();
+        assert!(number::<_, i64>(&alphanumeric).is_err());
+    }
+}
@@ -0,0 +1,12 @@
+//! Column type support for database libraries.
+
+#[cfg(feature = "bson")]
+mod bson;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "tokio-postgres")]
+mod postgres;
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
+#[cfg(feature = "sqlx")]
+mod sqlx;
@@ -0,0 +1,126 @@
+//! `postgres-types` `ToSql` and `FromSql`, for tokio-postgres and postgres.
+//!
+//! Every type is accepted in TEXT, VARCHAR and BPCHAR columns as its formatted string. The
+//! numeric documents are also accepted in INT4 and INT8 columns holding their digits, which
+//! fails to write an alphanumeric [`Cnpj`].
+
+use std::error::Error;
+
+use bytes::BytesMut;
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::{Cep, Cnpj, CodigoMunicipio, Competencia, Cpf, Uf};
+
+fn is_text(ty: &Type) -> bool {
+    <&str as FromSql>::accepts(ty)
+}
+
+fn is_integer(ty: &Type) -> bool {
+    *ty == Type::INT4 || *ty == Type::INT8
+}
+
+/// Implements the traits for a type with `FromStr` and `Display`. `$width` is the number of
+/// digits when the value may also be stored as an integer, `None` otherwise.
+macro_rules! impl_postgres {
+    ($ty:ty, $name:literal, $width:expr) => {
+        impl ToSql for $ty {
+            fn to_sql(
+                &self,
+                ty: &Type,
+                out: &mut BytesMut,
+            ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+                let text = self.to_string();
+                if !is_integer(ty) {
+                    return text.to_sql(ty, out);
+                }
+                if text.contains(|c: char| c.is_ascii_alphabetic()) {
+                    return Err(concat!("alphanumeric ", $name, " in an integer column").into());
+                }
+                let digits: String = text.chars().filter(char::is_ascii_digit).collect();
+                let number: i64 = digits.parse()?;
+                if *ty == Type::INT4 {
+                    i32::try_from(number)?.to_sql(ty, out)
+                } else {
+                    number.to_sql(ty, out)
+                }
+            }
+
+            fn accepts(ty: &Type) -> bool {
+                let width: Option<usize> = $width;
+                is_text(ty) || (width.is_some() && is_integer(ty))
+            }
+
+            to_sql_checked!();
+        }
+
+        impl<'a> FromSql<'a> for $ty {
+            fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+                let width: Option<usize> = $width;
+                let parsed = match width {
+                    Some(width) if *ty == Type::INT8 => {
+                        let number = i64::from_sql(ty, raw)?;
+                        format!("{:0width$}", number, width = width).parse()
+                    }
+                    Some(width) if *ty == Type::INT4 => {
+                        let number = i32::from_sql(ty, raw)?;
+                        format!("{:0width$}", number, width = width).parse()
+                    }
+                    _ => <&str>::from_sql(ty, raw)?.parse(),
+                };
+                parsed.map_err(|_| concat!("invalid ", $name).into())
+            }
+
+            fn accepts(ty: &Type) -> bool {
+                <Self as ToSql>::accepts(ty)
+            }
+        }
+    };
+}
+
+impl_postgres!(Cep, "CEP", Some(8));
+impl_postgres!(Cnpj, "CNPJ", Some(14));
+impl_postgres!(CodigoMunicipio, "municipality code", Some(7));
+impl_postgres!(Competencia, "competência", None);
+impl_postgres!(Cpf, "CPF", Some(11));
+impl_postgres!(Uf, "UF", None);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn round_trip<T>(value: T, ty: &Type) -> T
+    where
+        T: ToSql + for<'a> FromSql<'a>,
+    {
+        let mut buf = BytesMut::new();
+        value.to_sql_checked(ty, &mut buf).unwrap();
+        T::from_sql(ty, &buf).unwrap()
+    }
+
+    #[test]
+    fn round_trip_through_text_and_integers() {
+        let cpf = Cpf::try_from(1678346063).unwrap();
+        assert_eq!(cpf, round_trip(cpf, &Type::TEXT));
+        assert_eq!(cpf, round_trip(cpf, &Type::INT8));
+
+        let cnpj: Cnpj = "11.222.333/0001-81".parse().unwrap();
+        assert_eq!(cnpj, round_trip(cnpj, &Type::INT8));
+        let cnpj: Cnpj = "12.ABC.345/01DE-35".parse().unwrap();
+        assert_eq!(cnpj, round_trip(cnpj, &Type::BPCHAR));
+
+        let cep: Cep = "01310-100".parse().unwrap();
+        assert_eq!(cep, round_trip(cep, &Type::INT4));
+
+        assert_eq!(Uf::SP, round_trip(Uf::SP, &Type::VARCHAR));
+        assert!(!<Uf as ToSql>::accepts(&Type::INT8));
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        assert!(Cpf::from_sql(&Type::TEXT, b"98484485401").is_err());
+        assert!(Cpf::from_sql(&Type::INT8, &98484485401i64.to_be_bytes()).is_err());
+
+        let cnpj: Cnpj = "12.ABC.345/01DE-35".parse().unwrap();
+        assert!(cnpj.to_sql(&Type::INT8, &mut BytesMut::new()).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+//! SeaORM column support. Values are stored as their formatted string in text columns.
+
+use ::sea_orm::sea_query::{ArrayType, ColumnType, Nullable, StringLen, ValueType, ValueTypeErr};
+use ::sea_orm::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable, Value};
+
+use crate::{Cep, Cnpj, CodigoMunicipio, Competencia, Cpf, Uf};
+
+macro_rules! impl_sea_orm {
+    ($($ty:ident => $name:literal,)*) => {
+        $(
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    Value::String(Some(Box::new(value.to_string())))
+                }
+            }
+
+            impl TryGetable for $ty {
+                fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+                    String::try_get_by(res, index)?.parse().map_err(|_| {
+                        TryGetError::DbErr(DbErr::Type(concat!("invalid ", $name).to_string()))
+                    })
+                }
+            }
+
+            impl ValueType for $ty {
+                fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+                    match v {
+                        Value::String(Some(s)) => s.parse().map_err(|_| ValueTypeErr),
+                        _ => Err(ValueTypeErr),
+                    }
+                }
+
+                fn type_name() -> String {
+                    stringify!($ty).to_string()
+                }
+
+                fn array_type() -> ArrayType {
+                    ArrayType::String
+                }
+
+                fn column_type() -> ColumnType {
+                    ColumnType::String(StringLen::None)
+                }
+            }
+
+            impl Nullable for $ty {
+                fn null() -> Value {
+                    Value::String(None)
+                }
+            }
+        )*
+    };
+}
+
+impl_sea_orm! {
+    Cep => "CEP",
+    Cnpj => "CNPJ",
+    CodigoMunicipio => "municipality code",
+    Competencia => "competência",
+    Cpf => "CPF",
+    Uf => "UF",
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip_through_value() {
+        let cpf: Cpf = "016.783.460-63".parse().unwrap();
+        let value = Value::from(cpf);
+        assert_eq!(
+            Value::String(Some(Box::new("016.783.460-63".into()))),
+            value
+        );
+        assert_eq!(Ok(cpf), <Cpf as ValueType>::try_from(value).map_err(|_| ()));
+
+        let cnpj: Cnpj = "12.ABC.345/01DE-35".parse().unwrap();
+        assert_eq!(
+            Ok(cnpj),
+            <Cnpj as ValueType>::try_from(Value::from(cnpj)).map_err(|_| ())
+        );
+
+        assert!(<Uf as ValueType>::try_from(Value::String(Some(Box::new("XX".into())))).is_err());
+        assert!(<Uf as ValueType>::try_from(Value::Int(Some(35))).is_err());
+        assert_eq!(Value::String(None), <Cep as Nullable>::null());
+    }
+}
@@ -0,0 +1,106 @@
+//! sqlx `Type`, `Encode` and `Decode` for any database with text columns.
+//!
+//! Values are written as their formatted string. Reading also accepts integer columns
+//! for the numeric documents, so a CPF or a numeric CNPJ kept in a BIGINT decodes as well
+//! as one in TEXT. Alphanumeric CNPJs only fit in text columns.
+
+use ::sqlx::decode::Decode;
+use ::sqlx::encode::{Encode, IsNull};
+use ::sqlx::error::BoxDynError;
+use ::sqlx::{Database, Type, ValueRef};
+
+use crate::{Cep, Cnpj, CodigoMunicipio, Competencia, Cpf, Uf};
+
+/// Implements the traits for a type with `FromStr` and `Display`. `$width` is the number of
+/// digits when the value may also be stored as an integer, `None` otherwise.
+macro_rules! impl_sqlx {
+    ($ty:ty, $name:literal, $width:expr) => {
+        impl<DB: Database> Type<DB> for $ty
+        where
+            String: Type<DB>,
+            i64: Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <String as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                let width: Option<usize> = $width;
+                <String as Type<DB>>::compatible(ty)
+                    || (width.is_some() && <i64 as Type<DB>>::compatible(ty))
+            }
+        }
+
+        impl<'q, DB: Database> Encode<'q, DB> for $ty
+        where
+            String: Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+            ) -> Result<IsNull, BoxDynError> {
+                self.to_string().encode_by_ref(buf)
+            }
+        }
+
+        impl<'r, DB: Database> Decode<'r, DB> for $ty
+        where
+            &'r str: Decode<'r, DB>,
+            i64: Decode<'r, DB> + Type<DB>,
+        {
+            fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                let width: Option<usize> = $width;
+                let parsed = match width {
+                    Some(width) if <i64 as Type<DB>>::compatible(&value.type_info()) => {
+                        let number = <i64 as Decode<DB>>::decode(value)?;
+                        format!("{:0width$}", number, width = width).parse()
+                    }
+                    _ => <&str as Decode<DB>>::decode(value)?.parse(),
+                };
+                parsed.map_err(|_| concat!("invalid ", $name).into())
+            }
+        }
+    };
+}
+
+impl_sqlx!(Cep, "CEP", Some(8));
+impl_sqlx!(Cnpj, "CNPJ", Some(14));
+impl_sqlx!(CodigoMunicipio, "municipality code", Some(7));
+impl_sqlx!(Competencia, "competência", None);
+impl_sqlx!(Cpf, "CPF", Some(11));
+impl_sqlx!(Uf, "UF", None);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::sqlx::{MySql, Postgres};
+
+    fn assert_column_type<DB: Database, T>()
+    where
+        T: Type<DB> + for<'q> Encode<'q, DB> + for<'r> Decode<'r, DB>,
+    {
+    }
+
+    #[test]
+    fn usable_with_postgres_and_mysql() {
+        assert_column_type::<Postgres, Cpf>();
+        assert_column_type::<MySql, Cpf>();
+        assert_column_type::<Postgres, Cnpj>();
+        assert_column_type::<MySql, Cnpj>();
+        assert_column_type::<Postgres, Cep>();
+        assert_column_type::<Postgres, Uf>();
+
+        assert!(<Cpf as Type<Postgres>>::compatible(&<i64 as Type<
+            Postgres,
+        >>::type_info(
+        )));
+        assert!(<Cpf as Type<Postgres>>::compatible(&<String as Type<
+            Postgres,
+        >>::type_info(
+        )));
+        assert!(!<Uf as Type<Postgres>>::compatible(&<i64 as Type<
+            Postgres,
+        >>::type_info(
+        )));
+    }
+}
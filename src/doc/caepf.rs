@@ -0,0 +1,415 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::mask::mask;
+use crate::{BrDocument, Cpf, MaskStyle, ParseCpfError};
+
+/// Cadastro de Atividade Econômica da Pessoa Física, which replaced the CEI of rural
+/// producers, self-employed professionals and other employers that are people. The first
+/// 9 digits are the base of the holder CPF, followed by a 3 digit sequential number for
+/// each activity and 2 check digits.
+///
+/// # Examples
+/// ```
+/// use bras::{Caepf, Cpf};
+/// # use bras::ParseCaepfError;
+///
+/// let caepf: Caepf = "984.844.854/001-67".parse()?;
+/// assert_eq!("984.844.854-39", caepf.cpf().to_string());
+/// assert_eq!(1, caepf.sequencial());
+/// assert_eq!(caepf, "98484485400167".parse::<Caepf>()?);
+///
+/// let cpf: Cpf = "984.844.854-39".parse().unwrap();
+/// assert_eq!(caepf, Caepf::from_cpf(cpf, 1)?);
+/// assert_eq!("984.844.854/002-48", Caepf::from_cpf(cpf, 2)?.to_string());
+///
+/// # Ok::<(), ParseCaepfError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Caepf {
+    inner: u64,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseCaepfError {
+    /// Neither 14 digits nor 18 characters formatted as "000.000.000/000-00".
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter { position: usize },
+    /// 18 characters with something other than the `expected` separator of
+    /// "000.000.000/000-00" at the zero based `position`.
+    InvalidSeparator { position: usize, expected: char },
+    /// The first 9 digits are not the base of a valid CPF.
+    InvalidCpf(ParseCpfError),
+    /// The sequential number does not fit in 3 digits.
+    InvalidSequencial,
+    /// The check digits do not match.
+    InvalidCheckDigit,
+}
+
+impl Display for ParseCaepfError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseCaepfError::InvalidLength { got } => write!(
+                f,
+                "invalid CAEPF, expected 14 digits or 18 characters but got {}",
+                got
+            ),
+            ParseCaepfError::InvalidCharacter { position } => write!(
+                f,
+                "invalid CAEPF, character at position {} is not a digit",
+                position
+            ),
+            ParseCaepfError::InvalidSeparator { position, expected } => write!(
+                f,
+                "invalid CAEPF, expected '{}' at position {}",
+                expected, position
+            ),
+            ParseCaepfError::InvalidCpf(err) => write!(f, "invalid CAEPF, {}", err),
+            ParseCaepfError::InvalidSequencial => {
+                f.write_str("invalid CAEPF, the sequential number has more than 3 digits")
+            }
+            ParseCaepfError::InvalidCheckDigit => {
+                f.write_str("invalid CAEPF, the check digits do not match")
+            }
+        }
+    }
+}
+
+impl ParseCaepfError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseCaepfError::InvalidCharacter { position }
+            | ParseCaepfError::InvalidSeparator { position, .. } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseCaepfError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseCaepfError::InvalidCpf(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Positions of the separators in "000.000.000/000-00".
+const SEPARATORS: [(usize, u8); 4] = [(3, b'.'), (7, b'.'), (11, b'/'), (15, b'-')];
+
+const FIRST_DIGIT_WEIGHTS: [u32; 12] = [5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+const SECOND_DIGIT_WEIGHTS: [u32; 13] = [6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+
+impl Caepf {
+    /// The CAEPF of the `sequencial` activity of the holder of `cpf`.
+    pub fn from_cpf(cpf: Cpf, sequencial: u16) -> Result<Self, ParseCaepfError> {
+        if sequencial > 999 {
+            return Err(ParseCaepfError::InvalidSequencial);
+        }
+        let mut digits = [0u8; 14];
+        digits[..9].copy_from_slice(&cpf.digits()[..9]);
+        digits[9] = (sequencial / 100) as u8;
+        digits[10] = (sequencial / 10 % 10) as u8;
+        digits[11] = (sequencial % 10) as u8;
+        let check = Caepf::check_digits(&digits);
+        digits[12] = check / 10;
+        digits[13] = check % 10;
+        Caepf::from_digits(digits)
+    }
+
+    /// Random valid CAEPF, of a random CPF and a sequencial from 1 to 999.
+    ///
+    /// ```
+    /// use bras::Caepf;
+    ///
+    /// let caepf = Caepf::generate();
+    /// assert_eq!(Ok(caepf), caepf.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Caepf::generate`], drawing from the given generator so runs can be
+    /// reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// The CPF of the holder, whose base are the first 9 digits.
+    pub fn cpf(self) -> Cpf {
+        let mut base = [0u8; 9];
+        base.copy_from_slice(&self.digits()[..9]);
+        Cpf::complete(base).expect("validated on construction")
+    }
+
+    /// Sequential number of the activity among those of the same person.
+    pub fn sequencial(self) -> u16 {
+        (self.inner / 100 % 1000) as u16
+    }
+
+    /// The 14 digits, the last two being the check digits.
+    pub fn digits(self) -> [u8; 14] {
+        let mut digits = [0u8; 14];
+        let mut rest = self.inner;
+        for digit in digits.iter_mut().rev() {
+            *digit = (rest % 10) as u8;
+            rest /= 10;
+        }
+        digits
+    }
+
+    /// Formatted with the first three and the check digits hidden, as for a CPF.
+    ///
+    /// ```
+    /// use bras::Caepf;
+    /// # use bras::ParseCaepfError;
+    ///
+    /// let caepf: Caepf = "984.844.854/001-67".parse()?;
+    /// assert_eq!("***.844.854/001-**", caepf.masked());
+    ///
+    /// # Ok::<(), ParseCaepfError>(())
+    /// ```
+    pub fn masked(self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    /// Formatted with the digits chosen by `style` hidden.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        mask(&self.to_string(), style.visible(14, 3..12))
+    }
+
+    /// Whether `s` is a valid CAEPF in a format accepted by [`FromStr`].
+    pub fn is_valid(s: &str) -> bool {
+        Caepf::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`caepf!`](crate::caepf!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseCaepfError> {
+        let formatted = match bytes.len() {
+            14 => false,
+            18 => true,
+            got => return Err(ParseCaepfError::InvalidLength { got }),
+        };
+        if formatted {
+            let mut separator = 0;
+            while separator < SEPARATORS.len() {
+                let (position, expected) = SEPARATORS[separator];
+                if bytes[position] != expected {
+                    return Err(ParseCaepfError::InvalidSeparator {
+                        position,
+                        expected: expected as char,
+                    });
+                }
+                separator += 1;
+            }
+        }
+
+        let mut digits = [0u8; 14];
+        let mut count = 0;
+        let mut position = 0;
+        while position < bytes.len() {
+            if !(formatted && matches!(position, 3 | 7 | 11 | 15)) {
+                if !bytes[position].is_ascii_digit() {
+                    return Err(ParseCaepfError::InvalidCharacter { position });
+                }
+                digits[count] = bytes[position] - b'0';
+                count += 1;
+            }
+            position += 1;
+        }
+        Caepf::from_digits(digits)
+    }
+
+    const fn from_digits(digits: [u8; 14]) -> Result<Self, ParseCaepfError> {
+        let mut base = [0u8; 9];
+        let mut inner = 0u64;
+        let mut position = 0;
+        while position < digits.len() {
+            if position < base.len() {
+                base[position] = digits[position];
+            }
+            inner = inner * 10 + digits[position] as u64;
+            position += 1;
+        }
+        if let Err(err) = Cpf::complete(base) {
+            return Err(ParseCaepfError::InvalidCpf(err));
+        }
+        let check = Caepf::check_digits(&digits);
+        if digits[12] != check / 10 || digits[13] != check % 10 {
+            return Err(ParseCaepfError::InvalidCheckDigit);
+        }
+        Ok(Caepf { inner })
+    }
+
+    /// The two check digits as a number: those a CNPJ with the same first 12 digits would
+    /// have, plus 12, modulo 100.
+    const fn check_digits(digits: &[u8; 14]) -> u8 {
+        let mut digits = *digits;
+        digits[12] = Caepf::verifier_digit(&digits, &FIRST_DIGIT_WEIGHTS);
+        digits[13] = Caepf::verifier_digit(&digits, &SECOND_DIGIT_WEIGHTS);
+        (digits[12] * 10 + digits[13] + 12) % 100
+    }
+
+    const fn verifier_digit(digits: &[u8; 14], weights: &[u32]) -> u8 {
+        let mut sum = 0;
+        let mut position = 0;
+        while position < weights.len() {
+            sum += weights[position] * digits[position] as u32;
+            position += 1;
+        }
+        match sum % 11 {
+            0 | 1 => 0,
+            rest => (11 - rest) as u8,
+        }
+    }
+}
+
+impl FromStr for Caepf {
+    type Err = ParseCaepfError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Caepf::from_bytes(s.as_bytes())
+    }
+}
+
+impl From<Caepf> for u64 {
+    fn from(caepf: Caepf) -> Self {
+        caepf.inner
+    }
+}
+
+impl Display for Caepf {
+    /// "000.000.000/000-00", or only the 14 digits with the alternate flag.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            return write!(f, "{:014}", self.inner);
+        }
+        write!(
+            f,
+            "{:03}.{:03}.{:03}/{:03}-{:02}",
+            self.inner / 100_000_000_000,
+            self.inner / 100_000_000 % 1000,
+            self.inner / 100_000 % 1000,
+            self.inner / 100 % 1000,
+            self.inner % 100
+        )
+    }
+}
+
+impl BrDocument for Caepf {
+    fn kind(&self) -> &'static str {
+        "CAEPF"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        Caepf::digits(*self).to_vec()
+    }
+
+    fn masked(&self) -> String {
+        Caepf::masked(*self)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Caepf::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Caepf> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Caepf {
+        Caepf::from_cpf(rng.gen(), rng.gen_range(1..1000)).expect("sequencial is in range")
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Caepf {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let cpf: Cpf = u.arbitrary()?;
+        Caepf::from_cpf(cpf, u.int_in_range(0..=999)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Caepf {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Caepf>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (proptest::arbitrary::any::<Cpf>(), 0..1000u16)
+            .prop_map(|(cpf, sequencial)| {
+                Caepf::from_cpf(cpf, sequencial).expect("sequencial is in range")
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn every_sequencial_of_a_cpf() {
+        let cpf: Cpf = "051.194.390-39".parse().unwrap();
+        for sequencial in [0, 1, 2, 10, 99, 500, 999] {
+            let caepf = Caepf::from_cpf(cpf, sequencial).unwrap();
+            assert_eq!(cpf, caepf.cpf());
+            assert_eq!(sequencial, caepf.sequencial());
+            assert_eq!(Ok(caepf), caepf.to_string().parse());
+            assert_eq!(Ok(caepf), alloc::format!("{:#}", caepf).parse());
+        }
+        assert_eq!(
+            Err(ParseCaepfError::InvalidSequencial),
+            Caepf::from_cpf(cpf, 1000)
+        );
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let parse = |s: &str| s.parse::<Caepf>();
+        assert_eq!(
+            Err(ParseCaepfError::InvalidCheckDigit),
+            parse("984.844.854/001-68")
+        );
+        assert_eq!(
+            Err(ParseCaepfError::InvalidLength { got: 15 }),
+            parse("984.844.854/001")
+        );
+        assert_eq!(
+            Err(ParseCaepfError::InvalidSeparator {
+                position: 11,
+                expected: '/'
+            }),
+            parse("984.844.854.001-67")
+        );
+        assert_eq!(
+            Err(ParseCaepfError::InvalidCharacter { position: 2 }),
+            parse("98x84485400167")
+        );
+        assert_eq!(
+            Err(ParseCaepfError::InvalidCpf(ParseCpfError::RepeatedDigits)),
+            parse("111.111.111/001-00")
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_caepfs_are_valid() {
+        for _ in 0..1000 {
+            let caepf = Caepf::generate();
+            assert_eq!(Ok(caepf), caepf.to_string().parse());
+        }
+    }
+}
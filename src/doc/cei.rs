@@ -0,0 +1,323 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::cno::read_digits;
+use super::mask::mask;
+use crate::{BrDocument, MaskStyle, ParseCnoError};
+
+/// Cadastro Específico do INSS, the 12 digit registration of employers without a CNPJ and
+/// of construction works, replaced by the CAEPF and the CNO but still found in historical
+/// payroll records.
+///
+/// # Examples
+/// ```
+/// use bras::Cei;
+/// # use bras::ParseCeiError;
+///
+/// let cei: Cei = "51.204.09876/71".parse()?;
+/// assert!(cei.is_obra());
+/// assert_eq!(cei, "512040987671".parse::<Cei>()?);
+/// assert_eq!("512040987671", format!("{:#}", cei));
+///
+/// let cei: Cei = "51.204.09876/01".parse()?;
+/// assert!(!cei.is_obra());
+///
+/// # Ok::<(), ParseCeiError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Cei {
+    inner: u64,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseCeiError {
+    /// Neither 12 digits nor 15 characters formatted as "00.000.00000/00".
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter { position: usize },
+    /// 15 characters with something other than the `expected` separator of
+    /// "00.000.00000/00" at the zero based `position`.
+    InvalidSeparator { position: usize, expected: char },
+    /// The check digit does not match.
+    InvalidCheckDigit,
+}
+
+impl Display for ParseCeiError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseCeiError::InvalidLength { got } => write!(
+                f,
+                "invalid CEI, expected 12 digits or 15 characters but got {}",
+                got
+            ),
+            ParseCeiError::InvalidCharacter { position } => write!(
+                f,
+                "invalid CEI, character at position {} is not a digit",
+                position
+            ),
+            ParseCeiError::InvalidSeparator { position, expected } => write!(
+                f,
+                "invalid CEI, expected '{}' at position {}",
+                expected, position
+            ),
+            ParseCeiError::InvalidCheckDigit => {
+                f.write_str("invalid CEI, the check digit does not match")
+            }
+        }
+    }
+}
+
+impl ParseCeiError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseCeiError::InvalidCharacter { position }
+            | ParseCeiError::InvalidSeparator { position, .. } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseCeiError {}
+
+/// Eleventh digit of the CEI of a construction work.
+const OBRA: u8 = 7;
+
+const WEIGHTS: [u32; 11] = [7, 4, 1, 8, 5, 2, 1, 6, 3, 7, 4];
+
+impl Cei {
+    /// Whether the CEI is of a construction work, which the eleventh digit tells.
+    pub fn is_obra(self) -> bool {
+        self.digits()[10] == OBRA
+    }
+
+    /// The 12 digits, the last one being the check digit.
+    pub fn digits(self) -> [u8; 12] {
+        let mut digits = [0u8; 12];
+        let mut rest = self.inner;
+        for digit in digits.iter_mut().rev() {
+            *digit = (rest % 10) as u8;
+            rest /= 10;
+        }
+        digits
+    }
+
+    /// Formatted with only the middle five digits visible.
+    ///
+    /// ```
+    /// use bras::Cei;
+    /// # use bras::ParseCeiError;
+    ///
+    /// let cei: Cei = "51.204.09876/01".parse()?;
+    /// assert_eq!("**.***.09876/**", cei.masked());
+    ///
+    /// # Ok::<(), ParseCeiError>(())
+    /// ```
+    pub fn masked(self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    /// Formatted with the digits chosen by `style` hidden.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        mask(&self.to_string(), style.visible(12, 5..10))
+    }
+
+    /// Whether `s` is a valid CEI in a format accepted by [`FromStr`].
+    pub fn is_valid(s: &str) -> bool {
+        Cei::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Random valid CEI.
+    ///
+    /// ```
+    /// use bras::Cei;
+    ///
+    /// let cei = Cei::generate();
+    /// assert_eq!(Ok(cei), cei.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Cei::generate`], drawing from the given generator so runs can be reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`cei!`](crate::cei!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseCeiError> {
+        let digits = match read_digits(bytes) {
+            Ok(digits) => digits,
+            Err(ParseCnoError::InvalidLength { got }) => {
+                return Err(ParseCeiError::InvalidLength { got })
+            }
+            Err(ParseCnoError::InvalidCharacter { position }) => {
+                return Err(ParseCeiError::InvalidCharacter { position })
+            }
+            Err(ParseCnoError::InvalidSeparator { position, expected }) => {
+                return Err(ParseCeiError::InvalidSeparator { position, expected })
+            }
+            Err(_) => return Err(ParseCeiError::InvalidCheckDigit),
+        };
+        if digits[11] != Cei::check_digit(&digits) {
+            return Err(ParseCeiError::InvalidCheckDigit);
+        }
+        let mut inner = 0u64;
+        let mut position = 0;
+        while position < digits.len() {
+            inner = inner * 10 + digits[position] as u64;
+            position += 1;
+        }
+        Ok(Cei { inner })
+    }
+
+    /// Builds from the first 11 `digits`, replacing the last with the check digit.
+    #[cfg(any(feature = "rand", feature = "arbitrary"))]
+    fn complete(mut digits: [u8; 12]) -> Self {
+        digits[11] = Cei::check_digit(&digits);
+        Cei {
+            inner: digits
+                .iter()
+                .fold(0, |acc, &digit| acc * 10 + u64::from(digit)),
+        }
+    }
+
+    /// 10 minus the units of the sum of the last two digits of the weighted sum. The CNO
+    /// kept it.
+    pub(super) const fn check_digit(digits: &[u8; 12]) -> u8 {
+        let mut sum = 0;
+        let mut position = 0;
+        while position < WEIGHTS.len() {
+            sum += WEIGHTS[position] * digits[position] as u32;
+            position += 1;
+        }
+        ((10 - (sum % 10 + sum / 10 % 10) % 10) % 10) as u8
+    }
+}
+
+impl FromStr for Cei {
+    type Err = ParseCeiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cei::from_bytes(s.as_bytes())
+    }
+}
+
+impl From<Cei> for u64 {
+    fn from(cei: Cei) -> Self {
+        cei.inner
+    }
+}
+
+impl Display for Cei {
+    /// "00.000.00000/00", or only the 12 digits with the alternate flag.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            return write!(f, "{:012}", self.inner);
+        }
+        write!(
+            f,
+            "{:02}.{:03}.{:05}/{:02}",
+            self.inner / 10_000_000_000,
+            self.inner / 10_000_000 % 1000,
+            self.inner / 100 % 100_000,
+            self.inner % 100
+        )
+    }
+}
+
+impl BrDocument for Cei {
+    fn kind(&self) -> &'static str {
+        "CEI"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        Cei::digits(*self).to_vec()
+    }
+
+    fn masked(&self) -> String {
+        Cei::masked(*self)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Cei::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Cei> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Cei {
+        Cei::complete(core::array::from_fn(|_| rng.gen_range(0..10)))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Cei {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut digits = [0u8; 12];
+        for digit in digits.iter_mut() {
+            *digit = u.int_in_range(0..=9)?;
+        }
+        Ok(Cei::complete(digits))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Cei {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Cei>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::array::uniform12(0u8..10)
+            .prop_map(Cei::complete)
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let parse = |s: &str| s.parse::<Cei>();
+        assert_eq!(
+            Err(ParseCeiError::InvalidCheckDigit),
+            parse("51.204.09876/72")
+        );
+        assert_eq!(
+            Err(ParseCeiError::InvalidSeparator {
+                position: 2,
+                expected: '.'
+            }),
+            parse("51-204.09876/71")
+        );
+        assert_eq!(
+            Err(ParseCeiError::InvalidCharacter { position: 0 }),
+            parse("X12040987671")
+        );
+        assert_eq!(
+            Err(ParseCeiError::InvalidLength { got: 13 }),
+            parse("5120409876710")
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_ceis_are_valid() {
+        for _ in 0..1000 {
+            let cei = Cei::generate();
+            assert_eq!(Ok(cei), cei.to_string().parse());
+        }
+    }
+}
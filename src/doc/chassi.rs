@@ -0,0 +1,372 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::{BrDocument, MaskStyle};
+
+/// Vehicle chassis number, the 17 character VIN (ISO 3779) stamped on the vehicle and
+/// recorded in the CRLV together with the RENAVAM and the license plate.
+///
+/// Letters are read in any case and kept in upper case. The check digit in the ninth
+/// position is mandatory only for vehicles sold in North America, and most vehicles made
+/// in Brazil do not follow it, so it does not stop parsing; see
+/// [`Chassi::check_digit_matches`].
+///
+/// # Examples
+/// ```
+/// use bras::Chassi;
+/// # use bras::ParseChassiError;
+///
+/// let chassi: Chassi = "9bwzzz377vt004251".parse()?;
+/// assert_eq!("9BWZZZ377VT004251", chassi.to_string());
+/// assert_eq!("9BW", chassi.wmi());
+/// assert_eq!("004251", chassi.serie());
+/// assert_eq!([1997, 2027], chassi.anos_modelo());
+/// assert!(!chassi.check_digit_matches());
+///
+/// let chassi: Chassi = "1M8GDM9AXKP042788".parse()?;
+/// assert!(chassi.check_digit_matches());
+///
+/// # Ok::<(), ParseChassiError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Chassi {
+    inner: [u8; 17],
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseChassiError {
+    /// Not 17 characters long.
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is neither a digit nor a letter
+    /// other than I, O and Q, which are left out to avoid confusion with 1 and 0.
+    InvalidCharacter { position: usize },
+    /// The tenth character is not a model year code.
+    InvalidYear,
+}
+
+impl Display for ParseChassiError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseChassiError::InvalidLength { got } => write!(
+                f,
+                "invalid chassis number, expected 17 characters but got {}",
+                got
+            ),
+            ParseChassiError::InvalidCharacter { position } => write!(
+                f,
+                "invalid chassis number, character at position {} is not allowed",
+                position
+            ),
+            ParseChassiError::InvalidYear => {
+                f.write_str("invalid chassis number, invalid model year code")
+            }
+        }
+    }
+}
+
+impl ParseChassiError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseChassiError::InvalidCharacter { position } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseChassiError {}
+
+/// Model year codes in the order of the years of a 30 year cycle starting in 1980.
+const YEAR_CODES: &[u8; 30] = b"ABCDEFGHJKLMNPRSTVWXY123456789";
+
+/// Characters allowed in any position.
+#[cfg(any(feature = "rand", feature = "arbitrary"))]
+const CHARACTERS: &[u8; 33] = b"0123456789ABCDEFGHJKLMNPRSTUVWXYZ";
+
+const WEIGHTS: [u32; 17] = [8, 7, 6, 5, 4, 3, 2, 10, 0, 9, 8, 7, 6, 5, 4, 3, 2];
+
+impl Chassi {
+    /// World manufacturer identifier, the first 3 characters. Those of vehicles made in
+    /// Brazil start with "9B" to "9U".
+    pub fn wmi(&self) -> &str {
+        &self.as_str()[..3]
+    }
+
+    /// Serial number of the vehicle, the last 6 characters.
+    pub fn serie(&self) -> &str {
+        &self.as_str()[11..]
+    }
+
+    /// The two model years the tenth character may stand for, 30 years apart.
+    pub fn anos_modelo(&self) -> [u16; 2] {
+        let index = YEAR_CODES
+            .iter()
+            .position(|&code| code == self.inner[9])
+            .expect("validated on construction") as u16;
+        [1980 + index, 2010 + index]
+    }
+
+    /// Whether the ninth character is the check digit computed from the others.
+    pub fn check_digit_matches(&self) -> bool {
+        self.inner[8] == Chassi::check_digit(&self.inner)
+    }
+
+    /// Random chassis number with a valid model year and a matching check digit.
+    ///
+    /// ```
+    /// use bras::Chassi;
+    ///
+    /// let chassi = Chassi::generate();
+    /// assert!(chassi.check_digit_matches());
+    /// assert_eq!(Ok(chassi), chassi.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Chassi::generate`], drawing from the given generator so runs can be reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.inner).expect("validated on construction")
+    }
+
+    /// With every character but the manufacturer identifier hidden.
+    ///
+    /// ```
+    /// use bras::{Chassi, MaskStyle};
+    /// # use bras::ParseChassiError;
+    ///
+    /// let chassi: Chassi = "9BWZZZ377VT004251".parse()?;
+    /// assert_eq!("9BW**************", chassi.masked());
+    /// assert_eq!("***********004251", chassi.masked_with(MaskStyle::KeepLast(6)));
+    ///
+    /// # Ok::<(), ParseChassiError>(())
+    /// ```
+    pub fn masked(&self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    /// With the characters chosen by `style` hidden. Unlike the other documents, letters
+    /// are hidden too, as they are part of what identifies the vehicle.
+    pub fn masked_with(&self, style: MaskStyle) -> String {
+        let visible = style.visible(17, 0..3);
+        self.inner
+            .iter()
+            .enumerate()
+            .map(|(index, &c)| {
+                if visible.contains(&index) {
+                    char::from(c)
+                } else {
+                    '*'
+                }
+            })
+            .collect()
+    }
+
+    /// Whether `s` is a chassis number accepted by [`FromStr`].
+    pub fn is_valid(s: &str) -> bool {
+        Chassi::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Parses the same format as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`chassi!`](crate::chassi!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseChassiError> {
+        if bytes.len() != 17 {
+            return Err(ParseChassiError::InvalidLength { got: bytes.len() });
+        }
+        let mut inner = [0u8; 17];
+        let mut position = 0;
+        while position < bytes.len() {
+            let byte = bytes[position].to_ascii_uppercase();
+            if !byte.is_ascii_alphanumeric() || matches!(byte, b'I' | b'O' | b'Q') {
+                return Err(ParseChassiError::InvalidCharacter { position });
+            }
+            inner[position] = byte;
+            position += 1;
+        }
+        let mut index = 0;
+        while index < YEAR_CODES.len() && YEAR_CODES[index] != inner[9] {
+            index += 1;
+        }
+        if index == YEAR_CODES.len() {
+            return Err(ParseChassiError::InvalidYear);
+        }
+        Ok(Chassi { inner })
+    }
+
+    /// Check digit expected in the ninth position.
+    fn check_digit(inner: &[u8; 17]) -> u8 {
+        let sum: u32 = inner
+            .iter()
+            .zip(WEIGHTS)
+            .map(|(&c, weight)| Chassi::value(c) * weight)
+            .sum();
+        match sum % 11 {
+            10 => b'X',
+            digit => b'0' + digit as u8,
+        }
+    }
+
+    /// Value of a character in the check digit computation.
+    fn value(c: u8) -> u32 {
+        match c {
+            b'0'..=b'9' => u32::from(c - b'0'),
+            b'A'..=b'H' => u32::from(c - b'A') + 1,
+            b'J'..=b'N' => u32::from(c - b'J') + 1,
+            b'P' => 7,
+            b'R' => 9,
+            _ => u32::from(c - b'S') + 2,
+        }
+    }
+}
+
+impl FromStr for Chassi {
+    type Err = ParseChassiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Chassi::from_bytes(s.as_bytes())
+    }
+}
+
+impl Display for Chassi {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl BrDocument for Chassi {
+    fn kind(&self) -> &'static str {
+        "chassis number"
+    }
+
+    /// Only the characters that are digits, the letters having no digit value.
+    fn digits(&self) -> Vec<u8> {
+        self.inner
+            .iter()
+            .filter(|c| c.is_ascii_digit())
+            .map(|c| c - b'0')
+            .collect()
+    }
+
+    fn masked(&self) -> String {
+        Chassi::masked(self)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Chassi::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Chassi> for rand::distributions::Standard {
+    /// Uniform over the chassis numbers whose check digit matches.
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Chassi {
+        use rand::seq::SliceRandom;
+
+        let mut inner = [0u8; 17];
+        for character in inner.iter_mut() {
+            *character = *CHARACTERS.choose(rng).expect("non-empty");
+        }
+        inner[9] = *YEAR_CODES.choose(rng).expect("non-empty");
+        inner[8] = Chassi::check_digit(&inner);
+        Chassi { inner }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Chassi {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut inner = [0u8; 17];
+        for character in inner.iter_mut() {
+            *character = *u.choose(CHARACTERS)?;
+        }
+        inner[9] = *u.choose(YEAR_CODES)?;
+        Ok(Chassi { inner })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Chassi {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Chassi>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (
+            proptest::collection::vec(proptest::sample::select(CHARACTERS.to_vec()), 17),
+            proptest::sample::select(YEAR_CODES.to_vec()),
+        )
+            .prop_map(|(characters, year)| {
+                let mut inner = [0u8; 17];
+                inner.copy_from_slice(&characters);
+                inner[9] = year;
+                Chassi { inner }
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let parse = |s: &str| s.parse::<Chassi>();
+        assert_eq!(
+            Err(ParseChassiError::InvalidLength { got: 16 }),
+            parse("9BWZZZ377VT00425")
+        );
+        assert_eq!(
+            Err(ParseChassiError::InvalidCharacter { position: 4 }),
+            parse("9BWZOZ377VT004251")
+        );
+        assert_eq!(
+            Err(ParseChassiError::InvalidCharacter { position: 16 }),
+            parse("9BWZZZ377VT00425-")
+        );
+        assert_eq!(
+            Err(ParseChassiError::InvalidYear),
+            parse("9BWZZZ377UT004251")
+        );
+        assert_eq!(
+            Err(ParseChassiError::InvalidYear),
+            parse("9BWZZZ3770T004251")
+        );
+    }
+
+    #[test]
+    fn year_codes_cycle() {
+        let ano = |code: char| {
+            alloc::format!("9BWZZZ377{}T004251", code)
+                .parse::<Chassi>()
+                .unwrap()
+                .anos_modelo()
+        };
+        assert_eq!([1980, 2010], ano('A'));
+        assert_eq!([2000, 2030], ano('Y'));
+        assert_eq!([2001, 2031], ano('1'));
+        assert_eq!([2009, 2039], ano('9'));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_chassis_are_valid() {
+        for _ in 0..1000 {
+            let chassi = Chassi::generate();
+            assert!(chassi.check_digit_matches());
+            assert_eq!(Ok(chassi), chassi.to_string().parse());
+        }
+    }
+}
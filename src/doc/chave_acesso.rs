@@ -0,0 +1,586 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::chave_nfse::{check_digit, number};
+use super::mask::mask;
+#[cfg(feature = "cpf")]
+use crate::Cpf;
+use crate::{BrDocument, Cnpj, Competencia, MaskStyle, Uf};
+
+/// Access key (chave de acesso) of the NF-e, the 44 digit number printed on the DANFE that
+/// also identifies the NFC-e, CT-e and MDF-e. It is made of the federative unit of the
+/// emitter, the emission year and month, the emitter CNPJ, or CPF padded with zeros, the
+/// model, series and number of the document, the kind of emission, a random numeric code
+/// and a modulo 11 check digit.
+///
+/// # Examples
+/// ```
+/// use bras::{ChaveAcesso, Uf};
+/// # use bras::ParseChaveAcessoError;
+///
+/// let chave: ChaveAcesso = "35240311222333000181550010000001231123456788".parse()?;
+/// assert_eq!(Uf::SP, chave.uf());
+/// assert_eq!("03/2024", chave.competencia().to_string());
+/// assert_eq!("11222333000181", chave.emitente());
+/// assert_eq!(55, chave.modelo());
+/// assert_eq!(1, chave.serie());
+/// assert_eq!(123, chave.numero());
+///
+/// # Ok::<(), ParseChaveAcessoError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ChaveAcesso {
+    inner: [u8; 44],
+}
+
+/// Assembles a [`ChaveAcesso`] from its fields, computing the check digit, for those
+/// emitting documents. The model defaults to 55, the NF-e, the series to 0 and the kind of
+/// emission to 1, the normal one.
+///
+/// # Examples
+/// ```
+/// use bras::{cnpj, ChaveAcesso, Competencia, Uf};
+///
+/// let chave = ChaveAcesso::builder()
+///     .uf(Uf::SP)
+///     .competencia(Competencia::new(2024, 3).unwrap())
+///     .emitente(cnpj!("11.222.333/0001-81"))
+///     .serie(1)
+///     .numero(123)
+///     .codigo_numerico(12345678)
+///     .build()?;
+/// assert_eq!("35240311222333000181550010000001231123456788", chave.to_string());
+///
+/// # Ok::<(), bras::BuildChaveAcessoError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ChaveAcessoBuilder {
+    uf: Option<Uf>,
+    competencia: Option<Competencia>,
+    emitente: Option<Emitente>,
+    modelo: u8,
+    serie: u16,
+    numero: Option<u32>,
+    tipo_emissao: u8,
+    codigo_numerico: Option<u32>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum Emitente {
+    Cnpj(Cnpj),
+    #[cfg(feature = "cpf")]
+    Cpf(Cpf),
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseChaveAcessoError {
+    /// Not 44 characters long.
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter { position: usize },
+    /// The first 2 digits are not the code of a federative unit.
+    InvalidUf,
+    /// The emission month is not between 1 and 12.
+    InvalidCompetencia,
+    /// The check digit does not match.
+    InvalidCheckDigit,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum BuildChaveAcessoError {
+    /// The `field` without a default was not set.
+    Missing { field: &'static str },
+    /// The `field` does not fit in its digits of the key.
+    OutOfRange { field: &'static str },
+    /// The emitter CNPJ is alphanumeric, which the 44 digit key can't hold.
+    InvalidEmitente,
+}
+
+impl Display for ParseChaveAcessoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseChaveAcessoError::InvalidLength { got } => write!(
+                f,
+                "invalid access key, expected 44 digits but got {} characters",
+                got
+            ),
+            ParseChaveAcessoError::InvalidCharacter { position } => write!(
+                f,
+                "invalid access key, character at position {} is not a digit",
+                position
+            ),
+            ParseChaveAcessoError::InvalidUf => {
+                f.write_str("invalid access key, unknown federative unit code")
+            }
+            ParseChaveAcessoError::InvalidCompetencia => {
+                f.write_str("invalid access key, invalid emission month")
+            }
+            ParseChaveAcessoError::InvalidCheckDigit => {
+                f.write_str("invalid access key, the check digit does not match")
+            }
+        }
+    }
+}
+
+impl ParseChaveAcessoError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseChaveAcessoError::InvalidCharacter { position } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseChaveAcessoError {}
+
+impl Display for BuildChaveAcessoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BuildChaveAcessoError::Missing { field } => {
+                write!(f, "could not build the access key, {} is missing", field)
+            }
+            BuildChaveAcessoError::OutOfRange { field } => {
+                write!(
+                    f,
+                    "could not build the access key, {} is out of range",
+                    field
+                )
+            }
+            BuildChaveAcessoError::InvalidEmitente => f.write_str(
+                "could not build the access key, an alphanumeric CNPJ does not fit in its digits",
+            ),
+        }
+    }
+}
+
+impl core::error::Error for BuildChaveAcessoError {}
+
+impl ChaveAcesso {
+    pub fn builder() -> ChaveAcessoBuilder {
+        ChaveAcessoBuilder {
+            uf: None,
+            competencia: None,
+            emitente: None,
+            modelo: 55,
+            serie: 0,
+            numero: None,
+            tipo_emissao: 1,
+            codigo_numerico: None,
+        }
+    }
+
+    /// Federative unit of the emitter.
+    pub fn uf(&self) -> Uf {
+        Uf::try_from(number(&self.inner[..2]) as u8).expect("validated on construction")
+    }
+
+    /// Year and month of emission.
+    pub fn competencia(&self) -> Competencia {
+        let ano = number(&self.inner[2..4]) as i32;
+        let mes = number(&self.inner[4..6]) as u32;
+        Competencia::new(2000 + ano, mes).expect("validated on construction")
+    }
+
+    /// The 14 digits of the emitter CNPJ, or of its CPF padded with zeros on the left.
+    pub fn emitente(&self) -> &str {
+        &self.as_str()[6..20]
+    }
+
+    /// Model of the document, such as 55 for the NF-e and 65 for the NFC-e.
+    pub fn modelo(&self) -> u8 {
+        number(&self.inner[20..22]) as u8
+    }
+
+    pub fn serie(&self) -> u16 {
+        number(&self.inner[22..25]) as u16
+    }
+
+    /// Number of the document within its series.
+    pub fn numero(&self) -> u32 {
+        number(&self.inner[25..34]) as u32
+    }
+
+    /// Kind of emission (`tpEmis`), 1 for the normal one and the others for contingencies.
+    pub fn tipo_emissao(&self) -> u8 {
+        self.inner[34] - b'0'
+    }
+
+    /// Random code (`cNF`) chosen by the emitter so keys cannot be guessed.
+    pub fn codigo_numerico(&self) -> u32 {
+        number(&self.inner[35..43]) as u32
+    }
+
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.inner).expect("validated on construction")
+    }
+
+    /// Formatted with the digits chosen by `style` hidden. The recommended mask keeps only
+    /// the federative unit and the emission month visible, since the whole key is enough to
+    /// look the document up.
+    pub fn masked_with(&self, style: MaskStyle) -> String {
+        mask(self.as_str(), style.visible(44, 0..6))
+    }
+
+    /// Whether `s` is a valid access key.
+    pub fn is_valid(s: &str) -> bool {
+        ChaveAcesso::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Parses the same format as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`chave_acesso!`](crate::chave_acesso!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseChaveAcessoError> {
+        if bytes.len() != 44 {
+            return Err(ParseChaveAcessoError::InvalidLength { got: bytes.len() });
+        }
+        let mut inner = [0u8; 44];
+        let mut position = 0;
+        while position < bytes.len() {
+            if !bytes[position].is_ascii_digit() {
+                return Err(ParseChaveAcessoError::InvalidCharacter { position });
+            }
+            inner[position] = bytes[position];
+            position += 1;
+        }
+        if Uf::from_code((inner[0] - b'0') * 10 + (inner[1] - b'0')).is_none() {
+            return Err(ParseChaveAcessoError::InvalidUf);
+        }
+        let mes = (inner[4] - b'0') * 10 + (inner[5] - b'0');
+        if mes < 1 || mes > 12 {
+            return Err(ParseChaveAcessoError::InvalidCompetencia);
+        }
+        if inner[43] != check_digit(inner.split_at(43).0) {
+            return Err(ParseChaveAcessoError::InvalidCheckDigit);
+        }
+        Ok(ChaveAcesso { inner })
+    }
+
+    /// Random key with a valid federative unit, month and check digit.
+    ///
+    /// ```
+    /// use bras::ChaveAcesso;
+    ///
+    /// let chave = ChaveAcesso::generate();
+    /// assert_eq!(Ok(chave), chave.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`ChaveAcesso::generate`], drawing from the given generator so runs can be
+    /// reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// Key made of random `digits`, from 0 to 9, adjusted to be valid: the federative unit
+    /// and the month are wrapped into their allowed values and the last digit becomes the
+    /// check digit.
+    #[cfg(any(feature = "rand", feature = "arbitrary"))]
+    fn with_digits(digits: [u8; 44]) -> Self {
+        let mut inner = digits.map(|digit| b'0' + digit);
+        let uf = Uf::ALL[usize::from(digits[0] * 10 + digits[1]) % Uf::ALL.len()].code();
+        inner[0] = b'0' + uf / 10;
+        inner[1] = b'0' + uf % 10;
+        let mes = 1 + (digits[4] * 10 + digits[5]) % 12;
+        inner[4] = b'0' + mes / 10;
+        inner[5] = b'0' + mes % 10;
+        inner[43] = check_digit(&inner[..43]);
+        ChaveAcesso { inner }
+    }
+}
+
+impl ChaveAcessoBuilder {
+    /// Federative unit of the emitter (`cUF`).
+    pub fn uf(self, uf: Uf) -> Self {
+        ChaveAcessoBuilder {
+            uf: Some(uf),
+            ..self
+        }
+    }
+
+    /// Year and month of emission.
+    pub fn competencia(self, competencia: Competencia) -> Self {
+        ChaveAcessoBuilder {
+            competencia: Some(competencia),
+            ..self
+        }
+    }
+
+    /// CNPJ of the emitter. Only numeric CNPJs fit in the key.
+    pub fn emitente(self, cnpj: Cnpj) -> Self {
+        ChaveAcessoBuilder {
+            emitente: Some(Emitente::Cnpj(cnpj)),
+            ..self
+        }
+    }
+
+    /// CPF of the emitter, for the documents individuals can issue, padded with zeros.
+    #[cfg(feature = "cpf")]
+    pub fn emitente_cpf(self, cpf: Cpf) -> Self {
+        ChaveAcessoBuilder {
+            emitente: Some(Emitente::Cpf(cpf)),
+            ..self
+        }
+    }
+
+    pub fn modelo(self, modelo: u8) -> Self {
+        ChaveAcessoBuilder { modelo, ..self }
+    }
+
+    pub fn serie(self, serie: u16) -> Self {
+        ChaveAcessoBuilder { serie, ..self }
+    }
+
+    pub fn numero(self, numero: u32) -> Self {
+        ChaveAcessoBuilder {
+            numero: Some(numero),
+            ..self
+        }
+    }
+
+    pub fn tipo_emissao(self, tipo_emissao: u8) -> Self {
+        ChaveAcessoBuilder {
+            tipo_emissao,
+            ..self
+        }
+    }
+
+    /// The random code (`cNF`), up to 8 digits.
+    pub fn codigo_numerico(self, codigo_numerico: u32) -> Self {
+        ChaveAcessoBuilder {
+            codigo_numerico: Some(codigo_numerico),
+            ..self
+        }
+    }
+
+    /// The key with the check digit computed.
+    pub fn build(self) -> Result<ChaveAcesso, BuildChaveAcessoError> {
+        let missing = |field| BuildChaveAcessoError::Missing { field };
+        let out_of_range = |field| BuildChaveAcessoError::OutOfRange { field };
+        let uf = self.uf.ok_or(missing("uf"))?;
+        let competencia = self.competencia.ok_or(missing("competencia"))?;
+        let emitente = self.emitente.ok_or(missing("emitente"))?;
+        let numero = self.numero.ok_or(missing("numero"))?;
+        let codigo_numerico = self.codigo_numerico.ok_or(missing("codigo_numerico"))?;
+
+        let emitente = match emitente {
+            Emitente::Cnpj(cnpj) => {
+                u64::try_from(cnpj).map_err(|_| BuildChaveAcessoError::InvalidEmitente)?
+            }
+            #[cfg(feature = "cpf")]
+            Emitente::Cpf(cpf) => u64::from(cpf),
+        };
+        if !(2000..2100).contains(&competencia.ano()) {
+            return Err(out_of_range("competencia"));
+        }
+        if self.modelo > 99 {
+            return Err(out_of_range("modelo"));
+        }
+        if self.serie > 999 {
+            return Err(out_of_range("serie"));
+        }
+        if !(1..=999_999_999).contains(&numero) {
+            return Err(out_of_range("numero"));
+        }
+        if !(1..=9).contains(&self.tipo_emissao) {
+            return Err(out_of_range("tipo_emissao"));
+        }
+        if codigo_numerico > 99_999_999 {
+            return Err(out_of_range("codigo_numerico"));
+        }
+
+        let mut inner = [b'0'; 44];
+        let mut write = |range: core::ops::Range<usize>, mut value: u64| {
+            for digit in inner[range].iter_mut().rev() {
+                *digit = b'0' + (value % 10) as u8;
+                value /= 10;
+            }
+        };
+        write(0..2, u64::from(uf.code()));
+        write(2..4, competencia.ano() as u64 % 100);
+        write(4..6, u64::from(competencia.mes()));
+        write(6..20, emitente);
+        write(20..22, u64::from(self.modelo));
+        write(22..25, u64::from(self.serie));
+        write(25..34, u64::from(numero));
+        write(34..35, u64::from(self.tipo_emissao));
+        write(35..43, u64::from(codigo_numerico));
+        inner[43] = check_digit(&inner[..43]);
+        Ok(ChaveAcesso { inner })
+    }
+}
+
+impl FromStr for ChaveAcesso {
+    type Err = ParseChaveAcessoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ChaveAcesso::from_bytes(s.as_bytes())
+    }
+}
+
+impl Display for ChaveAcesso {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl BrDocument for ChaveAcesso {
+    fn kind(&self) -> &'static str {
+        "access key"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        self.inner.iter().map(|digit| digit - b'0').collect()
+    }
+
+    fn masked(&self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        ChaveAcesso::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<ChaveAcesso> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> ChaveAcesso {
+        ChaveAcesso::with_digits(core::array::from_fn(|_| rng.gen_range(0..10)))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ChaveAcesso {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut digits = [0u8; 44];
+        for digit in digits.iter_mut() {
+            *digit = u.int_in_range(0..=9)?;
+        }
+        Ok(ChaveAcesso::with_digits(digits))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for ChaveAcesso {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<ChaveAcesso>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::collection::vec(0u8..10, 44)
+            .prop_map(|digits| ChaveAcesso::with_digits(digits.try_into().expect("44 digits")))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "cpf")]
+    #[test]
+    fn builder_is_the_inverse_of_the_parser() {
+        let chave: ChaveAcesso = "41251200098484485439650000000000429000000013"
+            .parse()
+            .unwrap();
+        let built = ChaveAcesso::builder()
+            .uf(chave.uf())
+            .competencia(chave.competencia())
+            .emitente_cpf("984.844.854-39".parse().unwrap())
+            .modelo(chave.modelo())
+            .serie(chave.serie())
+            .numero(chave.numero())
+            .tipo_emissao(chave.tipo_emissao())
+            .codigo_numerico(chave.codigo_numerico())
+            .build();
+        assert_eq!(Ok(chave), built);
+        assert_eq!(9, chave.tipo_emissao());
+        assert_eq!(1, chave.codigo_numerico());
+
+        let builder = ChaveAcesso::builder()
+            .uf(Uf::PR)
+            .competencia(Competencia::new(2025, 12).unwrap())
+            .emitente_cpf("984.844.854-39".parse().unwrap())
+            .numero(42);
+        assert_eq!(
+            Err(BuildChaveAcessoError::Missing {
+                field: "codigo_numerico"
+            }),
+            builder.build()
+        );
+        assert_eq!(
+            Err(BuildChaveAcessoError::OutOfRange { field: "serie" }),
+            builder.codigo_numerico(1).serie(1000).build()
+        );
+    }
+
+    #[test]
+    fn builder_takes_numeric_cnpjs_only() {
+        let builder = ChaveAcesso::builder()
+            .uf(Uf::SP)
+            .competencia(Competencia::new(2024, 3).unwrap())
+            .numero(123)
+            .serie(1)
+            .codigo_numerico(12345678);
+        assert_eq!(
+            Err(BuildChaveAcessoError::Missing { field: "emitente" }),
+            builder.build()
+        );
+
+        let chave = builder
+            .emitente("11.222.333/0001-81".parse().unwrap())
+            .build()
+            .unwrap();
+        assert_eq!("11222333000181", chave.emitente());
+
+        let alfanumerico = builder.emitente("12.ABC.345/01DE-35".parse().unwrap());
+        assert_eq!(
+            Err(BuildChaveAcessoError::InvalidEmitente),
+            alfanumerico.build()
+        );
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let parse = |s: &str| s.parse::<ChaveAcesso>();
+        assert_eq!(
+            Err(ParseChaveAcessoError::InvalidLength { got: 43 }),
+            parse("3524031122233300018155001000000123112345678")
+        );
+        assert_eq!(
+            Err(ParseChaveAcessoError::InvalidCharacter { position: 4 }),
+            parse("3524x311222333000181550010000001231123456788")
+        );
+        assert_eq!(
+            Err(ParseChaveAcessoError::InvalidUf),
+            parse("36240311222333000181550010000001231123456788")
+        );
+        assert_eq!(
+            Err(ParseChaveAcessoError::InvalidCompetencia),
+            parse("35241311222333000181550010000001231123456788")
+        );
+        assert_eq!(
+            Err(ParseChaveAcessoError::InvalidCheckDigit),
+            parse("35240311222333000181550010000001231123456780")
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_keys_are_valid() {
+        for _ in 0..1000 {
+            let chave = ChaveAcesso::generate();
+            assert_eq!(Ok(chave), chave.to_string().parse());
+        }
+    }
+}
@@ -0,0 +1,473 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::mask::mask;
+#[cfg(feature = "cpf")]
+use crate::Cpf;
+use crate::{BrDocument, Cnpj, CodigoMunicipio, Competencia, MaskStyle};
+
+/// Access key (chave de acesso) of the national standard NFS-e, the 50 digit number in the
+/// `Id` of `infNFSe`, after the "NFS" prefix. It is made of the IBGE code of the
+/// municipality where it was issued, the issuing environment, the kind and number of the
+/// emitter registration, the NFS-e number, the emission year and month, a random number
+/// and a modulo 11 check digit.
+///
+/// The "NFS" prefix is accepted and left out of [`Display`].
+///
+/// # Examples
+/// ```
+/// use bras::{AmbienteGerador, ChaveNfse, TipoEmitente, Uf};
+/// # use bras::ParseChaveNfseError;
+///
+/// let chave: ChaveNfse = "NFS35503082211222333000181000000000012324031234567892".parse()?;
+/// assert_eq!(Uf::SP, chave.municipio().uf());
+/// assert_eq!(AmbienteGerador::SefinNacional, chave.ambiente_gerador());
+/// assert_eq!(TipoEmitente::Cnpj, chave.tipo_emitente());
+/// assert_eq!("11222333000181", chave.emitente());
+/// assert_eq!(Some(bras::cnpj!("11.222.333/0001-81")), chave.cnpj());
+/// assert_eq!(123, chave.numero());
+/// assert_eq!("03/2024", chave.competencia().to_string());
+/// assert_eq!("35503082211222333000181000000000012324031234567892", chave.to_string());
+///
+/// let chave: ChaveNfse = "41069021100098484485439000000000004225129876543217".parse()?;
+/// assert_eq!("98484485439", chave.emitente());
+/// assert_eq!(None, chave.cnpj());
+///
+/// # Ok::<(), ParseChaveNfseError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ChaveNfse {
+    inner: [u8; 50],
+}
+
+/// System the NFS-e was issued in.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum AmbienteGerador {
+    /// `1`, the system of the municipality.
+    Prefeitura,
+    /// `2`, the national issuer of the Secretaria Especial da Receita Federal.
+    SefinNacional,
+}
+
+/// Kind of registration of the emitter.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum TipoEmitente {
+    /// `1`, a CPF, padded with zeros on the left to 14 digits.
+    Cpf,
+    /// `2`, a CNPJ.
+    Cnpj,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseChaveNfseError {
+    /// Neither 50 digits nor 53 characters starting with "NFS".
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter { position: usize },
+    /// The first 7 digits are not an IBGE municipality code.
+    InvalidMunicipio,
+    /// The issuing environment is neither 1 nor 2.
+    InvalidAmbienteGerador,
+    /// The kind of registration is neither 1 nor 2.
+    InvalidTipoEmitente,
+    /// A CPF not padded with zeros.
+    InvalidEmitente,
+    /// The emission month is not between 1 and 12.
+    InvalidCompetencia,
+    /// The check digit does not match.
+    InvalidCheckDigit,
+}
+
+impl Display for ParseChaveNfseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseChaveNfseError::InvalidLength { got } => write!(
+                f,
+                "invalid NFS-e access key, expected 50 digits but got {} characters",
+                got
+            ),
+            ParseChaveNfseError::InvalidCharacter { position } => write!(
+                f,
+                "invalid NFS-e access key, character at position {} is not a digit",
+                position
+            ),
+            ParseChaveNfseError::InvalidMunicipio => {
+                f.write_str("invalid NFS-e access key, invalid IBGE municipality code")
+            }
+            ParseChaveNfseError::InvalidAmbienteGerador => {
+                f.write_str("invalid NFS-e access key, unknown issuing environment")
+            }
+            ParseChaveNfseError::InvalidTipoEmitente => {
+                f.write_str("invalid NFS-e access key, unknown kind of registration")
+            }
+            ParseChaveNfseError::InvalidEmitente => {
+                f.write_str("invalid NFS-e access key, CPF not padded with zeros")
+            }
+            ParseChaveNfseError::InvalidCompetencia => {
+                f.write_str("invalid NFS-e access key, invalid emission month")
+            }
+            ParseChaveNfseError::InvalidCheckDigit => {
+                f.write_str("invalid NFS-e access key, the check digit does not match")
+            }
+        }
+    }
+}
+
+impl ParseChaveNfseError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseChaveNfseError::InvalidCharacter { position } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseChaveNfseError {}
+
+impl ChaveNfse {
+    /// Municipality where the NFS-e was issued.
+    pub fn municipio(&self) -> CodigoMunicipio {
+        CodigoMunicipio::from_bytes(&self.inner[..7]).expect("validated on construction")
+    }
+
+    pub fn ambiente_gerador(&self) -> AmbienteGerador {
+        match self.inner[7] {
+            b'1' => AmbienteGerador::Prefeitura,
+            _ => AmbienteGerador::SefinNacional,
+        }
+    }
+
+    pub fn tipo_emitente(&self) -> TipoEmitente {
+        match self.inner[8] {
+            b'1' => TipoEmitente::Cpf,
+            _ => TipoEmitente::Cnpj,
+        }
+    }
+
+    /// Digits of the emitter registration, 11 for a CPF and 14 for a CNPJ.
+    pub fn emitente(&self) -> &str {
+        match self.tipo_emitente() {
+            TipoEmitente::Cpf => &self.as_str()[12..23],
+            TipoEmitente::Cnpj => &self.as_str()[9..23],
+        }
+    }
+
+    /// The emitter CPF, when the kind of registration is [`TipoEmitente::Cpf`] and its
+    /// check digits are valid.
+    #[cfg(feature = "cpf")]
+    pub fn cpf(&self) -> Option<Cpf> {
+        match self.tipo_emitente() {
+            TipoEmitente::Cpf => Cpf::from_bytes(&self.inner[12..23]).ok(),
+            TipoEmitente::Cnpj => None,
+        }
+    }
+
+    /// The emitter CNPJ, when the kind of registration is [`TipoEmitente::Cnpj`] and its
+    /// check digits are valid.
+    pub fn cnpj(&self) -> Option<Cnpj> {
+        match self.tipo_emitente() {
+            TipoEmitente::Cpf => None,
+            TipoEmitente::Cnpj => Cnpj::from_bytes(&self.inner[9..23]).ok(),
+        }
+    }
+
+    /// Number of the NFS-e, sequential per emitter.
+    pub fn numero(&self) -> u64 {
+        number(&self.inner[23..36])
+    }
+
+    /// Year and month of emission.
+    pub fn competencia(&self) -> Competencia {
+        let ano = number(&self.inner[36..38]) as i32;
+        let mes = number(&self.inner[38..40]) as u32;
+        Competencia::new(2000 + ano, mes).expect("validated on construction")
+    }
+
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.inner).expect("validated on construction")
+    }
+
+    /// Formatted with the digits chosen by `style` hidden. The recommended mask keeps only
+    /// the municipality, issuing environment and kind of registration visible, since the
+    /// whole key is enough to look the NFS-e up.
+    pub fn masked_with(&self, style: MaskStyle) -> String {
+        mask(self.as_str(), style.visible(50, 0..9))
+    }
+
+    /// Whether `s` is a valid access key in a format accepted by [`FromStr`].
+    pub fn is_valid(s: &str) -> bool {
+        ChaveNfse::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`chave_nfse!`](crate::chave_nfse!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseChaveNfseError> {
+        let offset = match bytes.len() {
+            50 => 0,
+            53 if bytes[0] == b'N' && bytes[1] == b'F' && bytes[2] == b'S' => 3,
+            got => return Err(ParseChaveNfseError::InvalidLength { got }),
+        };
+        let mut inner = [0u8; 50];
+        let mut position = offset;
+        while position < bytes.len() {
+            if !bytes[position].is_ascii_digit() {
+                return Err(ParseChaveNfseError::InvalidCharacter { position });
+            }
+            inner[position - offset] = bytes[position];
+            position += 1;
+        }
+
+        if CodigoMunicipio::from_bytes(inner.split_at(7).0).is_err() {
+            return Err(ParseChaveNfseError::InvalidMunicipio);
+        }
+        if !matches!(inner[7], b'1' | b'2') {
+            return Err(ParseChaveNfseError::InvalidAmbienteGerador);
+        }
+        match inner[8] {
+            b'1' if inner[9] != b'0' || inner[10] != b'0' || inner[11] != b'0' => {
+                return Err(ParseChaveNfseError::InvalidEmitente)
+            }
+            b'1' | b'2' => {}
+            _ => return Err(ParseChaveNfseError::InvalidTipoEmitente),
+        }
+        let mes = (inner[38] - b'0') * 10 + (inner[39] - b'0');
+        if mes < 1 || mes > 12 {
+            return Err(ParseChaveNfseError::InvalidCompetencia);
+        }
+        if inner[49] != check_digit(inner.split_at(49).0) {
+            return Err(ParseChaveNfseError::InvalidCheckDigit);
+        }
+        Ok(ChaveNfse { inner })
+    }
+
+    /// Random key with a valid municipality, competence and check digit, the municipality
+    /// not necessarily existing.
+    ///
+    /// ```
+    /// use bras::ChaveNfse;
+    ///
+    /// let chave = ChaveNfse::generate();
+    /// assert_eq!(Ok(chave), chave.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`ChaveNfse::generate`], drawing from the given generator so runs can be
+    /// reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// Key made of random `digits`, from 0 to 9, adjusted to be valid: the municipality
+    /// replaces the first 7, the fields with few allowed values are wrapped into them and
+    /// the last becomes the check digit.
+    #[cfg(any(feature = "rand", feature = "arbitrary"))]
+    fn with_digits(municipio: CodigoMunicipio, digits: [u8; 50]) -> Self {
+        let mut inner = digits.map(|digit| b'0' + digit);
+        let mut codigo = u32::from(municipio);
+        for digit in inner[..7].iter_mut().rev() {
+            *digit = b'0' + (codigo % 10) as u8;
+            codigo /= 10;
+        }
+        inner[7] = b'1' + digits[7] % 2;
+        inner[8] = b'1' + digits[8] % 2;
+        if inner[8] == b'1' {
+            inner[9..12].copy_from_slice(b"000");
+        }
+        let mes = 1 + (digits[38] * 10 + digits[39]) % 12;
+        inner[38] = b'0' + mes / 10;
+        inner[39] = b'0' + mes % 10;
+        inner[49] = check_digit(&inner[..49]);
+        ChaveNfse { inner }
+    }
+}
+
+/// Modulo 11 with the weights 2 to 9 from right to left of ASCII `digits`, as an ASCII
+/// digit. Shared with the NF-e access key.
+pub(super) const fn check_digit(digits: &[u8]) -> u8 {
+    let mut sum = 0;
+    let mut weight = 2;
+    let mut index = digits.len();
+    while index > 0 {
+        index -= 1;
+        sum += (digits[index] - b'0') as u32 * weight;
+        weight = if weight == 9 { 2 } else { weight + 1 };
+    }
+    match sum % 11 {
+        0 | 1 => b'0',
+        rest => b'0' + (11 - rest) as u8,
+    }
+}
+
+/// Value of ASCII `digits`.
+pub(super) const fn number(digits: &[u8]) -> u64 {
+    let mut value = 0;
+    let mut index = 0;
+    while index < digits.len() {
+        value = value * 10 + (digits[index] - b'0') as u64;
+        index += 1;
+    }
+    value
+}
+
+impl FromStr for ChaveNfse {
+    type Err = ParseChaveNfseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ChaveNfse::from_bytes(s.as_bytes())
+    }
+}
+
+impl Display for ChaveNfse {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl BrDocument for ChaveNfse {
+    fn kind(&self) -> &'static str {
+        "NFS-e access key"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        self.inner.iter().map(|digit| digit - b'0').collect()
+    }
+
+    fn masked(&self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        ChaveNfse::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<ChaveNfse> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> ChaveNfse {
+        let municipio = rng.gen();
+        ChaveNfse::with_digits(municipio, core::array::from_fn(|_| rng.gen_range(0..10)))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ChaveNfse {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let municipio = u.arbitrary()?;
+        let mut digits = [0u8; 50];
+        for digit in digits.iter_mut() {
+            *digit = u.int_in_range(0..=9)?;
+        }
+        Ok(ChaveNfse::with_digits(municipio, digits))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for ChaveNfse {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<ChaveNfse>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (
+            proptest::arbitrary::any::<CodigoMunicipio>(),
+            proptest::collection::vec(0u8..10, 50),
+        )
+            .prop_map(|(municipio, digits)| {
+                ChaveNfse::with_digits(municipio, digits.try_into().expect("50 digits"))
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let chave = "35503082211222333000181000000000012324031234567892";
+        let parse = |s: &str| s.parse::<ChaveNfse>();
+        let with = |position: usize, digit: &str| {
+            let mut s = String::from(chave);
+            s.replace_range(position..position + 1, digit);
+            s
+        };
+        assert!(parse(chave).is_ok());
+        assert_eq!(
+            Err(ParseChaveNfseError::InvalidLength { got: 53 }),
+            parse(&alloc::format!("NFE{}", chave))
+        );
+        assert_eq!(
+            Err(ParseChaveNfseError::InvalidCharacter { position: 13 }),
+            parse(&alloc::format!("NFS{}", with(10, "x")))
+        );
+        assert_eq!(
+            Err(ParseChaveNfseError::InvalidMunicipio),
+            parse(&with(6, "9"))
+        );
+        assert_eq!(
+            Err(ParseChaveNfseError::InvalidAmbienteGerador),
+            parse(&with(7, "3"))
+        );
+        assert_eq!(
+            Err(ParseChaveNfseError::InvalidTipoEmitente),
+            parse(&with(8, "0"))
+        );
+        assert_eq!(
+            Err(ParseChaveNfseError::InvalidEmitente),
+            parse(&with(8, "1"))
+        );
+        assert_eq!(
+            Err(ParseChaveNfseError::InvalidCompetencia),
+            parse(&with(38, "1"))
+        );
+        assert_eq!(
+            Err(ParseChaveNfseError::InvalidCheckDigit),
+            parse(&with(49, "3"))
+        );
+    }
+
+    #[cfg(feature = "cpf")]
+    #[test]
+    fn emitter_cpf() {
+        let chave: ChaveNfse = "41069021100098484485439000000000004225129876543217"
+            .parse()
+            .unwrap();
+        assert_eq!(TipoEmitente::Cpf, chave.tipo_emitente());
+        assert_eq!(AmbienteGerador::Prefeitura, chave.ambiente_gerador());
+        assert_eq!("984.844.854-39".parse::<Cpf>().ok(), chave.cpf());
+        assert_eq!(42, chave.numero());
+        assert_eq!(Competencia::new(2025, 12), Ok(chave.competencia()));
+    }
+
+    #[test]
+    fn emitter_cnpj() {
+        let chave: ChaveNfse = "35503082211222333000181000000000012324031234567892"
+            .parse()
+            .unwrap();
+        assert_eq!(TipoEmitente::Cnpj, chave.tipo_emitente());
+        assert_eq!("11.222.333/0001-81".parse::<Cnpj>().ok(), chave.cnpj());
+        #[cfg(feature = "cpf")]
+        assert_eq!(None, chave.cpf());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_keys_are_valid() {
+        for _ in 0..1000 {
+            let chave = ChaveNfse::generate();
+            assert_eq!(Ok(chave), chave.to_string().parse());
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::mask::mask;
+use crate::{BrDocument, MaskStyle};
+
+/// Cadastro Nacional de Estabelecimentos de Saúde, the 7 digit code of hospitals, clinics
+/// and other health establishments used by the SUS and in HL7/FHIR messages. It has no
+/// check digit. Systems often drop its leading zeros, so shorter codes are padded back.
+///
+/// # Examples
+/// ```
+/// use bras::Cnes;
+/// # use bras::ParseCnesError;
+///
+/// let cnes: Cnes = "2077485".parse()?;
+/// assert_eq!("2077485", cnes.to_string());
+///
+/// let cnes: Cnes = "27049".parse()?;
+/// assert_eq!("0027049", cnes.to_string());
+/// assert_eq!(cnes, Cnes::try_from(27049)?);
+/// assert_eq!(27049u32, u32::from(cnes));
+///
+/// # Ok::<(), ParseCnesError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Cnes {
+    inner: u32,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseCnesError {
+    /// Empty or longer than 7 digits.
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter { position: usize },
+    /// Every digit is zero.
+    Zero,
+}
+
+impl Display for ParseCnesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseCnesError::InvalidLength { got } => {
+                write!(f, "invalid CNES, expected up to 7 digits but got {}", got)
+            }
+            ParseCnesError::InvalidCharacter { position } => write!(
+                f,
+                "invalid CNES, character at position {} is not a digit",
+                position
+            ),
+            ParseCnesError::Zero => f.write_str("invalid CNES, every digit is zero"),
+        }
+    }
+}
+
+impl ParseCnesError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseCnesError::InvalidCharacter { position } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseCnesError {}
+
+impl Cnes {
+    /// The 7 digits, leading zeros included.
+    pub fn digits(self) -> [u8; 7] {
+        let mut digits = [0u8; 7];
+        let mut rest = self.inner;
+        for digit in digits.iter_mut().rev() {
+            *digit = (rest % 10) as u8;
+            rest /= 10;
+        }
+        digits
+    }
+
+    /// Formatted with the digits chosen by `style` hidden. The recommended mask hides
+    /// nothing, establishments being public records.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        mask(&self.to_string(), style.visible(7, 0..7))
+    }
+
+    /// Whether `s` is a valid CNES in a format accepted by [`FromStr`].
+    pub fn is_valid(s: &str) -> bool {
+        Cnes::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Random CNES. The number has no check digit, so it is not necessarily assigned to an
+    /// establishment.
+    ///
+    /// ```
+    /// use bras::Cnes;
+    ///
+    /// let cnes = Cnes::generate();
+    /// assert_eq!(Ok(cnes), cnes.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Cnes::generate`], drawing from the given generator so runs can be
+    /// reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`cnes!`](crate::cnes!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseCnesError> {
+        if bytes.is_empty() || bytes.len() > 7 {
+            return Err(ParseCnesError::InvalidLength { got: bytes.len() });
+        }
+        let mut value = 0;
+        let mut position = 0;
+        while position < bytes.len() {
+            if !bytes[position].is_ascii_digit() {
+                return Err(ParseCnesError::InvalidCharacter { position });
+            }
+            value = value * 10 + (bytes[position] - b'0') as u32;
+            position += 1;
+        }
+        if value == 0 {
+            return Err(ParseCnesError::Zero);
+        }
+        Ok(Cnes { inner: value })
+    }
+}
+
+impl FromStr for Cnes {
+    type Err = ParseCnesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cnes::from_bytes(s.as_bytes())
+    }
+}
+
+impl From<Cnes> for u32 {
+    fn from(cnes: Cnes) -> Self {
+        cnes.inner
+    }
+}
+
+impl TryFrom<u32> for Cnes {
+    type Error = ParseCnesError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Err(ParseCnesError::Zero),
+            1..=9_999_999 => Ok(Cnes { inner: value }),
+            _ => Err(ParseCnesError::InvalidLength {
+                got: value.ilog10() as usize + 1,
+            }),
+        }
+    }
+}
+
+impl Display for Cnes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:07}", self.inner)
+    }
+}
+
+impl BrDocument for Cnes {
+    fn kind(&self) -> &'static str {
+        "CNES"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        Cnes::digits(*self).to_vec()
+    }
+
+    /// Establishments are public records, so nothing is hidden.
+    fn masked(&self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Cnes::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Cnes> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Cnes {
+        Cnes {
+            inner: rng.gen_range(1..10_000_000),
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Cnes {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Cnes {
+            inner: u.int_in_range(1..=9_999_999)?,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Cnes {
+    type Parameters = ();
+    type Strategy = proptest::strategy::Map<core::ops::Range<u32>, fn(u32) -> Cnes>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (1..10_000_000).prop_map(|inner| Cnes { inner })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let parse = |s: &str| s.parse::<Cnes>();
+        assert_eq!(Err(ParseCnesError::InvalidLength { got: 0 }), parse(""));
+        assert_eq!(
+            Err(ParseCnesError::InvalidLength { got: 8 }),
+            parse("02077485")
+        );
+        assert_eq!(
+            Err(ParseCnesError::InvalidCharacter { position: 3 }),
+            parse("207 485")
+        );
+        assert_eq!(Err(ParseCnesError::Zero), parse("0000000"));
+        assert_eq!(
+            Err(ParseCnesError::InvalidLength { got: 8 }),
+            Cnes::try_from(20_774_850)
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_cness_are_valid() {
+        for _ in 0..1000 {
+            let cnes = Cnes::generate();
+            assert_eq!(Ok(cnes), cnes.to_string().parse());
+        }
+    }
+}
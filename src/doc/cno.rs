@@ -0,0 +1,361 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::mask::mask;
+use crate::{BrDocument, Cei, MaskStyle};
+
+/// Cadastro Nacional de Obras, the 12 digit number identifying a construction work in the
+/// payroll declarations of the companies and people responsible for it. It replaced the
+/// CEI assigned to construction works, which Instrução Normativa RFB nº 1.845/2018 migrated
+/// with their numbers: the CNO keeps the format and the check digit of the CEI.
+///
+/// # Examples
+/// ```
+/// use bras::Cno;
+/// # use bras::ParseCnoError;
+///
+/// let cno: Cno = "51.204.09876/71".parse()?;
+/// assert_eq!(cno, "512040987671".parse::<Cno>()?);
+/// assert_eq!("512040987671", format!("{:#}", cno));
+///
+/// let cno = Cno::from_cei("51.204.09876/71")?;
+/// assert_eq!("51.204.09876/71", cno.to_string());
+///
+/// # Ok::<(), ParseCnoError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Cno {
+    inner: u64,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseCnoError {
+    /// Neither 12 digits nor 15 characters formatted as "00.000.00000/00".
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter { position: usize },
+    /// 15 characters with something other than the `expected` separator of
+    /// "00.000.00000/00" at the zero based `position`.
+    InvalidSeparator { position: usize, expected: char },
+    /// The check digit does not match.
+    InvalidCheckDigit,
+    /// Converting a CEI that is not valid or not of a construction work.
+    InvalidCei,
+}
+
+impl Display for ParseCnoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseCnoError::InvalidLength { got } => write!(
+                f,
+                "invalid CNO, expected 12 digits or 15 characters but got {}",
+                got
+            ),
+            ParseCnoError::InvalidCharacter { position } => write!(
+                f,
+                "invalid CNO, character at position {} is not a digit",
+                position
+            ),
+            ParseCnoError::InvalidSeparator { position, expected } => write!(
+                f,
+                "invalid CNO, expected '{}' at position {}",
+                expected, position
+            ),
+            ParseCnoError::InvalidCheckDigit => {
+                f.write_str("invalid CNO, the check digit does not match")
+            }
+            ParseCnoError::InvalidCei => {
+                f.write_str("invalid CEI, expected the CEI of a construction work")
+            }
+        }
+    }
+}
+
+impl ParseCnoError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseCnoError::InvalidCharacter { position }
+            | ParseCnoError::InvalidSeparator { position, .. } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseCnoError {}
+
+/// Positions of the separators in "00.000.00000/00", shared with the CEI.
+const SEPARATORS: [(usize, u8); 3] = [(2, b'.'), (6, b'.'), (12, b'/')];
+
+impl Cno {
+    /// The CNO of a construction work registered under the CEI `cei`, see
+    /// [`TryFrom<Cei>`](#impl-TryFrom<Cei>-for-Cno).
+    pub fn from_cei(cei: &str) -> Result<Self, ParseCnoError> {
+        cei.parse::<Cei>()
+            .map_err(|_| ParseCnoError::InvalidCei)
+            .and_then(Cno::try_from)
+    }
+
+    /// The 12 digits, the last one being the check digit.
+    pub fn digits(self) -> [u8; 12] {
+        let mut digits = [0u8; 12];
+        let mut rest = self.inner;
+        for digit in digits.iter_mut().rev() {
+            *digit = (rest % 10) as u8;
+            rest /= 10;
+        }
+        digits
+    }
+
+    /// Formatted with the digits chosen by `style` hidden. The recommended mask hides
+    /// nothing, construction works being public records.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        mask(&self.to_string(), style.visible(12, 0..12))
+    }
+
+    /// Whether `s` is a valid CNO in a format accepted by [`FromStr`].
+    pub fn is_valid(s: &str) -> bool {
+        Cno::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Random valid CNO.
+    ///
+    /// ```
+    /// use bras::Cno;
+    ///
+    /// let cno = Cno::generate();
+    /// assert_eq!(Ok(cno), cno.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Cno::generate`], drawing from the given generator so runs can be reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`cno!`](crate::cno!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseCnoError> {
+        let digits = match read_digits(bytes) {
+            Ok(digits) => digits,
+            Err(err) => return Err(err),
+        };
+        let cno = Cno::complete(&digits);
+        if cno.inner % 10 != digits[11] as u64 {
+            return Err(ParseCnoError::InvalidCheckDigit);
+        }
+        Ok(cno)
+    }
+
+    /// Builds from the first 11 `digits`, computing the check digit as the CEI does.
+    const fn complete(digits: &[u8; 12]) -> Self {
+        let mut base = 0u64;
+        let mut position = 0;
+        while position < 11 {
+            base = base * 10 + digits[position] as u64;
+            position += 1;
+        }
+        Cno {
+            inner: base * 10 + Cei::check_digit(digits) as u64,
+        }
+    }
+}
+
+/// The 12 digits of a CNO or CEI, unformatted or formatted as "00.000.00000/00".
+pub(super) const fn read_digits(bytes: &[u8]) -> Result<[u8; 12], ParseCnoError> {
+    let formatted = match bytes.len() {
+        12 => false,
+        15 => true,
+        got => return Err(ParseCnoError::InvalidLength { got }),
+    };
+    if formatted {
+        let mut separator = 0;
+        while separator < SEPARATORS.len() {
+            let (position, expected) = SEPARATORS[separator];
+            if bytes[position] != expected {
+                return Err(ParseCnoError::InvalidSeparator {
+                    position,
+                    expected: expected as char,
+                });
+            }
+            separator += 1;
+        }
+    }
+
+    let mut digits = [0u8; 12];
+    let mut count = 0;
+    let mut position = 0;
+    while position < bytes.len() {
+        if !(formatted && matches!(position, 2 | 6 | 12)) {
+            if !bytes[position].is_ascii_digit() {
+                return Err(ParseCnoError::InvalidCharacter { position });
+            }
+            digits[count] = bytes[position] - b'0';
+            count += 1;
+        }
+        position += 1;
+    }
+    Ok(digits)
+}
+
+impl FromStr for Cno {
+    type Err = ParseCnoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cno::from_bytes(s.as_bytes())
+    }
+}
+
+impl TryFrom<Cei> for Cno {
+    type Error = ParseCnoError;
+
+    /// The migrated CNO has the same number as the CEI. Only the CEI of a construction work
+    /// has a CNO.
+    ///
+    /// ```
+    /// use bras::{Cei, Cno};
+    ///
+    /// let cei: Cei = "51.204.09876/71".parse().unwrap();
+    /// assert_eq!("51.204.09876/71", Cno::try_from(cei).unwrap().to_string());
+    /// ```
+    fn try_from(cei: Cei) -> Result<Self, Self::Error> {
+        if !cei.is_obra() {
+            return Err(ParseCnoError::InvalidCei);
+        }
+        Ok(Cno {
+            inner: u64::from(cei),
+        })
+    }
+}
+
+impl From<Cno> for u64 {
+    fn from(cno: Cno) -> Self {
+        cno.inner
+    }
+}
+
+impl Display for Cno {
+    /// "00.000.00000/00", or only the 12 digits with the alternate flag.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            return write!(f, "{:012}", self.inner);
+        }
+        write!(
+            f,
+            "{:02}.{:03}.{:05}/{:02}",
+            self.inner / 10_000_000_000,
+            self.inner / 10_000_000 % 1000,
+            self.inner / 100 % 100_000,
+            self.inner % 100
+        )
+    }
+}
+
+impl BrDocument for Cno {
+    fn kind(&self) -> &'static str {
+        "CNO"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        Cno::digits(*self).to_vec()
+    }
+
+    /// Construction works are public records, so nothing is hidden.
+    fn masked(&self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Cno::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Cno> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Cno {
+        Cno::complete(&core::array::from_fn(|_| rng.gen_range(0..10)))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Cno {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut digits = [0u8; 12];
+        for digit in digits.iter_mut() {
+            *digit = u.int_in_range(0..=9)?;
+        }
+        Ok(Cno::complete(&digits))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Cno {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Cno>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::array::uniform12(0u8..10)
+            .prop_map(|digits| Cno::complete(&digits))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_formats() {
+        let cno: Cno = "51.204.09876/71".parse().unwrap();
+        assert_eq!(512040987671, u64::from(cno));
+        assert_eq!(Ok(cno), "512040987671".parse());
+        assert_eq!(Ok(cno), Cno::from_cei("512040987671"));
+        assert_eq!(
+            Err(ParseCnoError::InvalidCheckDigit),
+            "51.204.09876/77".parse::<Cno>()
+        );
+        assert_eq!(
+            Err(ParseCnoError::InvalidSeparator {
+                position: 12,
+                expected: '/'
+            }),
+            "51.204.09876-71".parse::<Cno>()
+        );
+        assert_eq!(
+            Err(ParseCnoError::InvalidLength { got: 11 }),
+            "51204098767".parse::<Cno>()
+        );
+    }
+
+    #[test]
+    fn only_cei_of_construction_works() {
+        assert_eq!(
+            Err(ParseCnoError::InvalidCei),
+            Cno::from_cei("51.204.09876/73")
+        );
+        assert_eq!(
+            Err(ParseCnoError::InvalidCei),
+            Cno::from_cei("51.204.09876/01")
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_cnos_are_valid() {
+        for _ in 0..1000 {
+            let cno = Cno::generate();
+            assert_eq!(Ok(cno), cno.to_string().parse());
+        }
+    }
+}
@@ -0,0 +1,674 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::{BrDocument, MaskStyle};
+
+/// Cadastro Nacional da Pessoa Jurídica: an 8 character root identifying the company, 4
+/// for the establishment and 2 check digits. Since July 2026 the Receita Federal also
+/// issues alphanumeric CNPJs, whose first 12 characters may be upper case letters.
+///
+/// # Examples
+/// ```
+/// use bras::Cnpj;
+/// # use bras::ParseCnpjError;
+///
+/// let cnpj: Cnpj = "11.222.333/0001-81".parse()?;
+/// assert_eq!("11222333", cnpj.raiz());
+/// assert_eq!(cnpj, "11222333000181".parse::<Cnpj>()?);
+///
+/// let cnpj: Cnpj = "12.ABC.345/01DE-35".parse()?;
+/// assert!(cnpj.is_alphanumeric());
+/// assert_eq!("12ABC34501DE35", cnpj.as_str());
+///
+/// # Ok::<(), ParseCnpjError>(())
+/// ```
+///
+/// ## Conversions
+/// Numeric CNPJs convert to and from `u64`. Every CNPJ converts to and from `u128` and to
+/// its 14 ASCII characters, for integer and fixed width binary columns.
+/// ```
+/// use bras::Cnpj;
+/// # use bras::ParseCnpjError;
+///
+/// let cnpj: Cnpj = "11.222.333/0001-81".parse()?;
+/// assert_eq!(Ok(11222333000181u64), u64::try_from(cnpj));
+/// assert_eq!(Ok(cnpj), Cnpj::try_from(11222333000181u64));
+/// assert_eq!(11222333000181u128, u128::from(cnpj));
+///
+/// let cnpj: Cnpj = "12.ABC.345/01DE-35".parse()?;
+/// assert_eq!(Err(ParseCnpjError::Alphanumeric), u64::try_from(cnpj));
+/// assert_eq!(Ok(cnpj), Cnpj::try_from(u128::from(cnpj)));
+/// assert_eq!(*b"12ABC34501DE35", <[u8; 14]>::from(cnpj));
+///
+/// # Ok::<(), ParseCnpjError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(not(feature = "redact-debug"), derive(Debug))]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression))]
+#[cfg_attr(feature = "diesel", derive(diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::BigInt))]
+pub struct Cnpj {
+    inner: [u8; 14],
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseCnpjError {
+    /// Neither 14 characters nor 18 formatted as "00.000.000/0000-00".
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is not a letter or digit, or not a
+    /// digit in the check digits.
+    InvalidCharacter { position: usize },
+    /// 18 characters with something other than the `expected` separator of
+    /// "00.000.000/0000-00" at the zero based `position`.
+    InvalidSeparator { position: usize, expected: char },
+    /// The value is not in a representation a CNPJ can be read from, such as a `u128` not
+    /// produced by the conversion from [`Cnpj`].
+    InvalidFormat,
+    /// Every character is the same, such as "00.000.000/0000-00".
+    RepeatedDigits,
+    /// The first (`which` is 1) or second (`which` is 2) check digit does not match.
+    InvalidCheckDigit { which: u8 },
+    /// An alphanumeric CNPJ converted to `u64`, which only holds numeric ones.
+    Alphanumeric,
+}
+
+impl Display for ParseCnpjError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseCnpjError::InvalidLength { got } => write!(
+                f,
+                "invalid CNPJ, expected 14 or 18 characters but got {}",
+                got
+            ),
+            ParseCnpjError::InvalidCharacter { position } => write!(
+                f,
+                "invalid CNPJ, character at position {} is not allowed",
+                position
+            ),
+            ParseCnpjError::InvalidSeparator { position, expected } => write!(
+                f,
+                "invalid CNPJ, expected '{}' at position {}",
+                expected, position
+            ),
+            ParseCnpjError::InvalidFormat => {
+                f.write_str("invalid CNPJ, expected the format 00.000.000/0000-00")
+            }
+            ParseCnpjError::RepeatedDigits => {
+                f.write_str("invalid CNPJ, all characters are the same")
+            }
+            ParseCnpjError::InvalidCheckDigit { which: 1 } => {
+                f.write_str("invalid CNPJ, the first check digit does not match")
+            }
+            ParseCnpjError::InvalidCheckDigit { .. } => {
+                f.write_str("invalid CNPJ, the second check digit does not match")
+            }
+            ParseCnpjError::Alphanumeric => {
+                f.write_str("invalid CNPJ, an alphanumeric CNPJ has no numeric value")
+            }
+        }
+    }
+}
+
+impl ParseCnpjError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseCnpjError::InvalidCharacter { position }
+            | ParseCnpjError::InvalidSeparator { position, .. } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseCnpjError {}
+
+/// Positions of the separators in "00.000.000/0000-00".
+const SEPARATORS: [(usize, u8); 4] = [(2, b'.'), (6, b'.'), (10, b'/'), (15, b'-')];
+
+const FIRST_DIGIT_WEIGHTS: [u32; 12] = [5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+const SECOND_DIGIT_WEIGHTS: [u32; 13] = [6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+
+/// Characters allowed in the first 12 positions, in the order of their `u128` encoding.
+const CHARACTERS: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Where the `u128` encoding of the alphanumeric CNPJs starts, past every numeric one.
+const ALPHANUMERIC_START: u128 = 100_000_000_000_000;
+
+impl Cnpj {
+    /// The 14 characters without separators.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.inner).expect("validated on construction")
+    }
+
+    /// The first 8 characters, shared by every establishment of the company.
+    pub fn raiz(&self) -> &str {
+        &self.as_str()[..8]
+    }
+
+    /// Whether the CNPJ has letters, as those issued since July 2026 may.
+    pub fn is_alphanumeric(&self) -> bool {
+        self.inner.iter().any(u8::is_ascii_uppercase)
+    }
+
+    /// Formatted with the establishment and the check digits hidden, keeping the root that
+    /// identifies the company.
+    ///
+    /// ```
+    /// use bras::{Cnpj, MaskStyle};
+    /// # use bras::ParseCnpjError;
+    ///
+    /// let cnpj: Cnpj = "12.ABC.345/01DE-35".parse()?;
+    /// assert_eq!("12.ABC.345/****-**", cnpj.masked());
+    /// assert_eq!("**.***.***/***E-35", cnpj.masked_with(MaskStyle::KeepLast(3)));
+    ///
+    /// # Ok::<(), ParseCnpjError>(())
+    /// ```
+    pub fn masked(&self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    /// Formatted with the characters chosen by `style` hidden, letters included.
+    pub fn masked_with(&self, style: MaskStyle) -> String {
+        let visible = style.visible(14, 0..8);
+        let mut index = 0;
+        self.to_string()
+            .chars()
+            .map(|c| {
+                if !c.is_ascii_alphanumeric() {
+                    return c;
+                }
+                index += 1;
+                if visible.contains(&(index - 1)) {
+                    c
+                } else {
+                    '*'
+                }
+            })
+            .collect()
+    }
+
+    /// Parses the letters and digits of `s`, ignoring every other character, for legacy
+    /// data with mixed punctuation. Unlike [`Cpf::parse_lossy`](crate::Cpf::parse_lossy),
+    /// letters are part of the CNPJ, so labels such as "CNPJ:" must be removed first.
+    /// `InvalidLength` reports how many letters and digits were found.
+    ///
+    /// ```
+    /// use bras::Cnpj;
+    /// # use bras::ParseCnpjError;
+    ///
+    /// let cnpj = Cnpj::parse_lossy(" 12 abc 345 01de 35")?;
+    /// assert_eq!("12.ABC.345/01DE-35", cnpj.to_string());
+    ///
+    /// # Ok::<(), ParseCnpjError>(())
+    /// ```
+    pub fn parse_lossy(s: &str) -> Result<Self, ParseCnpjError> {
+        let mut characters = [0u8; 14];
+        let mut got = 0;
+        for byte in s.bytes().filter(u8::is_ascii_alphanumeric) {
+            if let Some(character) = characters.get_mut(got) {
+                *character = byte;
+            }
+            got += 1;
+        }
+        if got != characters.len() {
+            return Err(ParseCnpjError::InvalidLength { got });
+        }
+        Cnpj::from_bytes(&characters)
+    }
+
+    /// Whether `s` is a valid CNPJ in a format accepted by [`FromStr`].
+    pub const fn is_valid(s: &str) -> bool {
+        Cnpj::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Random valid CNPJ, alphanumeric most of the time as each of the first 12 characters
+    /// is drawn from the digits and the letters alike.
+    ///
+    /// ```
+    /// use bras::Cnpj;
+    ///
+    /// let cnpj = Cnpj::generate();
+    /// assert_eq!(Ok(cnpj), cnpj.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Cnpj::generate`], drawing from the given generator so runs can be reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// Completes the 12 ASCII characters of `base` with the two check digits. Fails with
+    /// `InvalidCharacter` for anything but a digit or an upper case letter and with
+    /// `RepeatedDigits` when every character would be the same.
+    ///
+    /// ```
+    /// use bras::Cnpj;
+    /// # use bras::ParseCnpjError;
+    ///
+    /// let cnpj = Cnpj::complete(*b"12ABC34501DE")?;
+    /// assert_eq!("12.ABC.345/01DE-35", cnpj.to_string());
+    ///
+    /// # Ok::<(), ParseCnpjError>(())
+    /// ```
+    pub const fn complete(base: [u8; 12]) -> Result<Self, ParseCnpjError> {
+        let mut inner = [0u8; 14];
+        let mut position = 0;
+        while position < base.len() {
+            if !matches!(base[position], b'0'..=b'9' | b'A'..=b'Z') {
+                return Err(ParseCnpjError::InvalidCharacter { position });
+            }
+            inner[position] = base[position];
+            position += 1;
+        }
+        inner[12] = b'0' + Cnpj::check_digit(&inner, &FIRST_DIGIT_WEIGHTS);
+        inner[13] = b'0' + Cnpj::check_digit(&inner, &SECOND_DIGIT_WEIGHTS);
+        Cnpj::from_characters(inner)
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`cnpj!`](crate::cnpj!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseCnpjError> {
+        let formatted = match bytes.len() {
+            14 => false,
+            18 => true,
+            got => return Err(ParseCnpjError::InvalidLength { got }),
+        };
+        if formatted {
+            let mut separator = 0;
+            while separator < SEPARATORS.len() {
+                let (position, expected) = SEPARATORS[separator];
+                if bytes[position] != expected {
+                    return Err(ParseCnpjError::InvalidSeparator {
+                        position,
+                        expected: expected as char,
+                    });
+                }
+                separator += 1;
+            }
+        }
+
+        let mut inner = [0u8; 14];
+        let mut count = 0;
+        let mut position = 0;
+        while position < bytes.len() {
+            if !(formatted && matches!(position, 2 | 6 | 10 | 15)) {
+                let byte = bytes[position].to_ascii_uppercase();
+                let allowed = if count < 12 {
+                    byte.is_ascii_alphanumeric()
+                } else {
+                    byte.is_ascii_digit()
+                };
+                if !allowed {
+                    return Err(ParseCnpjError::InvalidCharacter { position });
+                }
+                inner[count] = byte;
+                count += 1;
+            }
+            position += 1;
+        }
+        Cnpj::from_characters(inner)
+    }
+
+    const fn from_characters(inner: [u8; 14]) -> Result<Self, ParseCnpjError> {
+        let mut repeated = true;
+        let mut position = 0;
+        while position < inner.len() {
+            repeated &= inner[position] == inner[0];
+            position += 1;
+        }
+        if repeated {
+            return Err(ParseCnpjError::RepeatedDigits);
+        }
+        if inner[12] != b'0' + Cnpj::check_digit(&inner, &FIRST_DIGIT_WEIGHTS) {
+            return Err(ParseCnpjError::InvalidCheckDigit { which: 1 });
+        }
+        if inner[13] != b'0' + Cnpj::check_digit(&inner, &SECOND_DIGIT_WEIGHTS) {
+            return Err(ParseCnpjError::InvalidCheckDigit { which: 2 });
+        }
+        Ok(Cnpj { inner })
+    }
+
+    /// Modulo 11 of the characters before the check digit, each worth its ASCII code minus
+    /// 48, so digits keep their value and letters go from 17 to 42.
+    const fn check_digit(inner: &[u8; 14], weights: &[u32]) -> u8 {
+        let mut sum = 0;
+        let mut position = 0;
+        while position < weights.len() {
+            sum += weights[position] * (inner[position] - b'0') as u32;
+            position += 1;
+        }
+        match sum % 11 {
+            0 | 1 => 0,
+            rest => (11 - rest) as u8,
+        }
+    }
+}
+
+impl FromStr for Cnpj {
+    type Err = ParseCnpjError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cnpj::from_bytes(s.as_bytes())
+    }
+}
+
+impl TryFrom<&[u8]> for Cnpj {
+    type Error = ParseCnpjError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Cnpj::from_bytes(bytes)
+    }
+}
+
+impl TryFrom<u64> for Cnpj {
+    type Error = ParseCnpjError;
+
+    /// A numeric CNPJ, whose leading zeros are not written.
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        if value > 99_999_999_999_999 {
+            return Err(ParseCnpjError::InvalidLength {
+                got: value.ilog10() as usize + 1,
+            });
+        }
+        let mut inner = [0u8; 14];
+        let mut rest = value;
+        for character in inner.iter_mut().rev() {
+            *character = b'0' + (rest % 10) as u8;
+            rest /= 10;
+        }
+        Cnpj::from_characters(inner)
+    }
+}
+
+impl TryFrom<Cnpj> for u64 {
+    type Error = ParseCnpjError;
+
+    /// The value of a numeric CNPJ, failing with `Alphanumeric` for the others.
+    fn try_from(cnpj: Cnpj) -> Result<Self, Self::Error> {
+        if cnpj.is_alphanumeric() {
+            return Err(ParseCnpjError::Alphanumeric);
+        }
+        Ok(cnpj
+            .inner
+            .iter()
+            .fold(0, |value, &c| value * 10 + u64::from(c - b'0')))
+    }
+}
+
+impl From<Cnpj> for u128 {
+    /// The value of a numeric CNPJ, so both conversions agree. An alphanumeric CNPJ comes
+    /// after every numeric one: its first 12 characters in base 36, digits before letters,
+    /// followed by the check digits.
+    fn from(cnpj: Cnpj) -> Self {
+        if let Ok(value) = u64::try_from(cnpj) {
+            return u128::from(value);
+        }
+        let base = cnpj.inner[..12].iter().fold(0, |value, &c| {
+            let index = CHARACTERS
+                .iter()
+                .position(|&x| x == c)
+                .expect("validated on construction");
+            value * 36 + index as u128
+        });
+        let check = u128::from((cnpj.inner[12] - b'0') * 10 + cnpj.inner[13] - b'0');
+        ALPHANUMERIC_START + base * 100 + check
+    }
+}
+
+impl TryFrom<u128> for Cnpj {
+    type Error = ParseCnpjError;
+
+    /// The inverse of the conversion to `u128`. Fails with `InvalidFormat` for values past
+    /// the last alphanumeric CNPJ and for those encoding one without letters, which the
+    /// conversion writes as a plain number instead.
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        if value < ALPHANUMERIC_START {
+            return Cnpj::try_from(value as u64);
+        }
+        let value = value - ALPHANUMERIC_START;
+        let check = (value % 100) as u8;
+        let mut base = value / 100;
+        let mut inner = [0u8; 14];
+        for character in inner[..12].iter_mut().rev() {
+            *character = CHARACTERS[(base % 36) as usize];
+            base /= 36;
+        }
+        inner[12] = b'0' + check / 10;
+        inner[13] = b'0' + check % 10;
+        let cnpj = Cnpj { inner };
+        if base != 0 || !cnpj.is_alphanumeric() {
+            return Err(ParseCnpjError::InvalidFormat);
+        }
+        Cnpj::from_characters(inner)
+    }
+}
+
+impl From<Cnpj> for [u8; 14] {
+    /// The 14 ASCII characters without separators.
+    fn from(cnpj: Cnpj) -> Self {
+        cnpj.inner
+    }
+}
+
+impl Display for Cnpj {
+    /// "00.000.000/0000-00", or only the 14 characters with the alternate flag.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let s = self.as_str();
+        if f.alternate() {
+            return f.write_str(s);
+        }
+        write!(
+            f,
+            "{}.{}.{}/{}-{}",
+            &s[..2],
+            &s[2..5],
+            &s[5..8],
+            &s[8..12],
+            &s[12..]
+        )
+    }
+}
+
+/// With the `redact-debug` feature, prints the [masked](Cnpj::masked) CNPJ so it cannot
+/// leak into logs through `{:?}`.
+#[cfg(feature = "redact-debug")]
+impl core::fmt::Debug for Cnpj {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Cnpj({})", self.masked())
+    }
+}
+
+impl BrDocument for Cnpj {
+    fn kind(&self) -> &'static str {
+        "CNPJ"
+    }
+
+    /// Only the characters that are digits, the letters having no digit value.
+    fn digits(&self) -> Vec<u8> {
+        self.inner
+            .iter()
+            .filter(|c| c.is_ascii_digit())
+            .map(|c| c - b'0')
+            .collect()
+    }
+
+    fn masked(&self) -> String {
+        Cnpj::masked(self)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Cnpj::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Cnpj> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Cnpj {
+        loop {
+            let base = core::array::from_fn(|_| CHARACTERS[rng.gen_range(0..CHARACTERS.len())]);
+            if let Ok(cnpj) = Cnpj::complete(base) {
+                return cnpj;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Cnpj {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut base = [0u8; 12];
+        for character in base.iter_mut() {
+            *character = *u.choose(CHARACTERS)?;
+        }
+        if base.iter().all(|&c| c == base[0]) {
+            base[11] = if base[11] == b'Z' { b'0' } else { b'Z' };
+        }
+        Cnpj::complete(base).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Cnpj {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Cnpj>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::collection::vec(proptest::sample::select(CHARACTERS.to_vec()), 12)
+            .prop_filter_map("all characters equal", |base| {
+                Cnpj::complete(base.try_into().expect("12 characters")).ok()
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_numeric_and_alphanumeric() {
+        let cnpj: Cnpj = "11.222.333/0001-81".parse().unwrap();
+        assert_eq!("11.222.333/0001-81", cnpj.to_string());
+        assert_eq!("11222333000181", alloc::format!("{:#}", cnpj));
+        assert!(!cnpj.is_alphanumeric());
+
+        let cnpj: Cnpj = "12abc34501de35".parse().unwrap();
+        assert_eq!("12.ABC.345/01DE-35", cnpj.to_string());
+        assert_eq!("12ABC345", cnpj.raiz());
+        assert_eq!(Ok(cnpj), Cnpj::try_from(&b"12.ABC.345/01DE-35"[..]));
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let parse = |s: &str| s.parse::<Cnpj>();
+        assert_eq!(
+            Err(ParseCnpjError::InvalidLength { got: 13 }),
+            parse("1122233300018")
+        );
+        assert_eq!(
+            Err(ParseCnpjError::InvalidSeparator {
+                position: 10,
+                expected: '/'
+            }),
+            parse("11.222.333-0001-81")
+        );
+        assert_eq!(
+            Err(ParseCnpjError::InvalidCharacter { position: 4 }),
+            parse("1122_333000181")
+        );
+        assert_eq!(
+            Err(ParseCnpjError::InvalidCharacter { position: 13 }),
+            parse("12ABC34501DE3A")
+        );
+        assert_eq!(
+            Err(ParseCnpjError::RepeatedDigits),
+            parse("00.000.000/0000-00")
+        );
+        assert_eq!(
+            Err(ParseCnpjError::InvalidCheckDigit { which: 1 }),
+            parse("11.222.333/0001-91")
+        );
+        assert_eq!(
+            Err(ParseCnpjError::InvalidCheckDigit { which: 2 }),
+            parse("12.ABC.345/01DE-36")
+        );
+        assert_eq!(Some(13), parse("12ABC34501DE3A").unwrap_err().position());
+    }
+
+    #[test]
+    fn parse_lossy_keeps_letters_and_digits() {
+        let cnpj: Cnpj = "11.222.333/0001-81".parse().unwrap();
+        assert_eq!(Ok(cnpj), Cnpj::parse_lossy("11 222 333 / 0001.81"));
+        assert_eq!(
+            Err(ParseCnpjError::InvalidLength { got: 18 }),
+            Cnpj::parse_lossy("CNPJ 11.222.333/0001-81")
+        );
+    }
+
+    #[cfg(feature = "redact-debug")]
+    #[test]
+    fn debug_is_masked() {
+        let cnpj: Cnpj = "11.222.333/0001-81".parse().unwrap();
+        assert_eq!("Cnpj(11.222.333/****-**)", alloc::format!("{:?}", cnpj));
+    }
+
+    #[test]
+    fn convert_to_and_from_integers() {
+        let numeric: Cnpj = "00.394.460/0058-87".parse().unwrap();
+        assert_eq!(Ok(394460005887), u64::try_from(numeric));
+        assert_eq!(Ok(numeric), Cnpj::try_from(394460005887u64));
+        assert_eq!(
+            Err(ParseCnpjError::InvalidLength { got: 15 }),
+            Cnpj::try_from(100_000_000_000_000u64)
+        );
+
+        let alphanumeric: Cnpj = "12.ABC.345/01DE-35".parse().unwrap();
+        let value = u128::from(alphanumeric);
+        assert!(value >= ALPHANUMERIC_START);
+        assert_eq!(Ok(alphanumeric), Cnpj::try_from(value));
+        assert_eq!(
+            Err(ParseCnpjError::InvalidFormat),
+            Cnpj::try_from(ALPHANUMERIC_START)
+        );
+        assert_eq!(
+            Err(ParseCnpjError::InvalidFormat),
+            Cnpj::try_from(u128::MAX)
+        );
+        assert_eq!(
+            Ok(numeric),
+            Cnpj::try_from(u128::from(u64::try_from(numeric).unwrap()))
+        );
+    }
+
+    #[test]
+    fn complete_base_characters() {
+        let cnpj: Cnpj = "11.222.333/0001-81".parse().unwrap();
+        assert_eq!(Ok(cnpj), Cnpj::complete(*b"112223330001"));
+        assert_eq!(
+            Err(ParseCnpjError::InvalidCharacter { position: 2 }),
+            Cnpj::complete(*b"12abc34501DE")
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_cnpjs_are_valid() {
+        for _ in 0..1000 {
+            let cnpj = Cnpj::generate();
+            assert_eq!(Ok(cnpj), cnpj.to_string().parse());
+            assert_eq!(Ok(cnpj), Cnpj::try_from(u128::from(cnpj)));
+        }
+    }
+}
@@ -1,6 +1,16 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
 use core::fmt::Display;
 use core::str::FromStr;
 
+use super::mask::mask;
+use super::pattern::format_digits;
+use crate::options::digits_only;
+use crate::{BrDocument, MaskStyle, ParseOptions, RegiaoFiscal};
+
 /// # Examples
 /// ```
 /// use bras::Cpf;
@@ -41,7 +51,12 @@ use core::str::FromStr;
 /// # Ok::<(), ParseCpfError>(())
 /// ```
 ///
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(not(feature = "redact-debug"), derive(Debug))]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression))]
+#[cfg_attr(feature = "diesel", derive(diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::BigInt))]
 pub struct Cpf {
     inner: u64,
 }
@@ -49,25 +64,96 @@ pub struct Cpf {
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[non_exhaustive]
 pub enum ParseCpfError {
-    Invalid,
+    /// Neither 11 digits nor 14 characters formatted as "000.000.000-00".
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter { position: usize },
+    /// 14 characters with something other than the `expected` separator of
+    /// "000.000.000-00" at the zero based `position`.
+    InvalidSeparator { position: usize, expected: char },
+    /// The value is not in a representation a CPF can be read from, such as a BSON value
+    /// that is not a string.
+    InvalidFormat,
+    /// Every digit is the same, such as "111.111.111-11".
+    RepeatedDigits,
+    /// The first (`which` is 1) or second (`which` is 2) verifier digit does not match.
+    InvalidCheckDigit { which: u8 },
+}
+
+impl Display for ParseCpfError {
+    /// ```
+    /// use bras::{Cpf, ParseCpfError};
+    ///
+    /// let err = "984.844.854-30".parse::<Cpf>().unwrap_err();
+    /// assert_eq!(ParseCpfError::InvalidCheckDigit { which: 2 }, err);
+    /// assert_eq!("invalid CPF, the second check digit does not match", err.to_string());
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseCpfError::InvalidLength { got } => write!(
+                f,
+                "invalid CPF, expected 11 digits or 14 characters but got {}",
+                got
+            ),
+            ParseCpfError::InvalidCharacter { position } => {
+                write!(
+                    f,
+                    "invalid CPF, character at position {} is not a digit",
+                    position
+                )
+            }
+            ParseCpfError::InvalidSeparator { position, expected } => write!(
+                f,
+                "invalid CPF, expected '{}' at position {}",
+                expected, position
+            ),
+            ParseCpfError::InvalidFormat => {
+                f.write_str("invalid CPF, expected the format 000.000.000-00")
+            }
+            ParseCpfError::RepeatedDigits => f.write_str("invalid CPF, all digits are the same"),
+            ParseCpfError::InvalidCheckDigit { which: 1 } => {
+                f.write_str("invalid CPF, the first check digit does not match")
+            }
+            ParseCpfError::InvalidCheckDigit { .. } => {
+                f.write_str("invalid CPF, the second check digit does not match")
+            }
+        }
+    }
+}
+
+impl ParseCpfError {
+    /// Zero based byte offset of the input where parsing went wrong, when the error points
+    /// to a single character.
+    ///
+    /// ```
+    /// use bras::Cpf;
+    ///
+    /// let err = "984.844,854-39".parse::<Cpf>().unwrap_err();
+    /// assert_eq!(Some(7), err.position());
+    /// assert_eq!("invalid CPF, expected '.' at position 7", err.to_string());
+    /// ```
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseCpfError::InvalidCharacter { position }
+            | ParseCpfError::InvalidSeparator { position, .. } => Some(*position),
+            _ => None,
+        }
+    }
 }
 
+impl core::error::Error for ParseCpfError {}
+
 impl FromStr for Cpf {
     type Err = ParseCpfError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Cpf::new(s.into())
+        Cpf::new(s)
     }
 }
 
 impl From<Cpf> for String {
     fn from(cpf: Cpf) -> Self {
-        let value_as_str = format!("{:011}", cpf.inner);
-        let a = &value_as_str[0..3];
-        let b = &value_as_str[3..6];
-        let c = &value_as_str[6..9];
-        let d = &value_as_str[9..];
-        format!("{}.{}.{}-{}", a, b, c, d)
+        cpf.to_string()
     }
 }
 
@@ -85,6 +171,14 @@ impl From<Cpf> for u64 {
     }
 }
 
+impl TryFrom<&[u8]> for Cpf {
+    type Error = ParseCpfError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Cpf::from_bytes(bytes)
+    }
+}
+
 impl TryFrom<u64> for Cpf {
     type Error = ParseCpfError;
 
@@ -98,7 +192,12 @@ impl TryFrom<u64> for Cpf {
     /// # Ok::<(), ParseCpfError>(())
     /// ```
     fn try_from(value: u64) -> Result<Self, Self::Error> {
-        Cpf::new(format!("{:011}", value))
+        if value > 99_999_999_999 {
+            return Err(ParseCpfError::InvalidLength {
+                got: value.ilog10() as usize + 1,
+            });
+        }
+        Cpf::from_digits(Cpf::split_digits(value))
     }
 }
 
@@ -110,96 +209,602 @@ impl Display for Cpf {
     /// let cpf: Cpf = "98484485439".parse()?;
     /// assert_eq!(String::from("984.844.854-39"), String::from(cpf));
     ///
+    /// // The alternate flag prints only the digits.
+    /// let cpf: Cpf = "016.783.460-63".parse()?;
+    /// assert_eq!("01678346063", format!("{:#}", cpf));
+    ///
     /// # Ok::<(), ParseCpfError>(())
     /// ```
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let formated = String::from(*self);
-        f.write_str(&formated)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            return write!(f, "{:011}", self.inner);
+        }
+        write!(
+            f,
+            "{:03}.{:03}.{:03}-{:02}",
+            self.inner / 100_000_000,
+            self.inner / 100_000 % 1000,
+            self.inner / 100 % 1000,
+            self.inner % 100
+        )
+    }
+}
+
+/// With the `redact-debug` feature, prints the [masked](Cpf::masked) CPF so it cannot leak
+/// into logs through `{:?}`.
+#[cfg(feature = "redact-debug")]
+impl core::fmt::Debug for Cpf {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Cpf({})", self.masked())
+    }
+}
+
+impl BrDocument for Cpf {
+    fn kind(&self) -> &'static str {
+        "CPF"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        Cpf::digits(*self).to_vec()
+    }
+
+    fn masked(&self) -> String {
+        Cpf::masked(*self)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Cpf::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Cpf> for rand::distributions::Standard {
+    /// ```
+    /// use bras::Cpf;
+    /// use rand::Rng;
+    ///
+    /// let cpf: Cpf = rand::thread_rng().gen();
+    /// assert_eq!(Ok(cpf), cpf.to_string().parse());
+    /// ```
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Cpf {
+        loop {
+            let base: [u8; 9] = core::array::from_fn(|_| rng.gen_range(0..10));
+            if let Ok(cpf) = Cpf::complete(base) {
+                return cpf;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Cpf {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut base = [0u8; 9];
+        for digit in base.iter_mut() {
+            *digit = u.int_in_range(0..=9)?;
+        }
+        if base.iter().all(|n| *n == base[0]) {
+            base[8] = (base[8] + 1) % 10;
+        }
+        Cpf::complete(base).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Cpf {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Cpf>;
+
+    /// Shrinks towards the base digits `000000001`.
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::array::uniform9(0u8..10)
+            .prop_filter_map("all digits equal", |base| Cpf::complete(base).ok())
+            .boxed()
     }
 }
 
+/// Positions of the separators in "000.000.000-00".
+const SEPARATORS: [(usize, u8); 3] = [(3, b'.'), (7, b'.'), (11, b'-')];
+
 const FIRST_DIGIT_ARRAY: [u32; 9] = [10, 9, 8, 7, 6, 5, 4, 3, 2];
 const SECOND_DIGIT_ARRAY: [u32; 10] = [11, 10, 9, 8, 7, 6, 5, 4, 3, 2];
 
 impl Cpf {
+    /// The 11 digits without separators, keeping leading zeros.
+    ///
+    /// ```
+    /// use bras::Cpf;
+    /// # use bras::ParseCpfError;
+    ///
+    /// let cpf: Cpf = "016.783.460-63".parse()?;
+    /// assert_eq!("01678346063", cpf.numbers_as_string());
+    ///
+    /// # Ok::<(), ParseCpfError>(())
+    /// ```
     pub fn numbers_as_string(self) -> String {
-        self.inner.to_string()
+        format!("{:#}", self)
     }
 
-    fn new(s: String) -> Result<Self, ParseCpfError> {
-        if s.len() != 11 && s.len() != 14 {
-            return Err(ParseCpfError::Invalid);
+    /// The 11 digits, the last two being the verifier digits.
+    ///
+    /// ```
+    /// use bras::Cpf;
+    /// # use bras::ParseCpfError;
+    ///
+    /// let cpf: Cpf = "016.783.460-63".parse()?;
+    /// assert_eq!([0, 1, 6, 7, 8, 3, 4, 6, 0, 6, 3], cpf.digits());
+    ///
+    /// # Ok::<(), ParseCpfError>(())
+    /// ```
+    pub fn digits(self) -> [u8; 11] {
+        Cpf::split_digits(self.inner)
+    }
+
+    /// Fiscal region of the Receita Federal where the CPF was issued, encoded in its ninth
+    /// digit.
+    ///
+    /// ```
+    /// use bras::{Cpf, RegiaoFiscal, Uf};
+    /// # use bras::ParseCpfError;
+    ///
+    /// let cpf: Cpf = "984.844.854-39".parse()?;
+    /// assert_eq!(RegiaoFiscal::Quarta, cpf.fiscal_region());
+    /// assert!(cpf.fiscal_region().ufs().contains(&Uf::PE));
+    ///
+    /// # Ok::<(), ParseCpfError>(())
+    /// ```
+    pub fn fiscal_region(self) -> RegiaoFiscal {
+        RegiaoFiscal::from_digit(self.digits()[8]).expect("every digit is a fiscal region")
+    }
+
+    /// Formatted following `pattern`, each `#` standing for the next digit. `None` when the
+    /// pattern does not have exactly 11 `#`.
+    ///
+    /// ```
+    /// use bras::Cpf;
+    /// # use bras::ParseCpfError;
+    ///
+    /// let cpf: Cpf = "984.844.854-39".parse()?;
+    /// assert_eq!(Some("984844854-39".into()), cpf.format_with("#########-##"));
+    /// assert_eq!(Some("984 844 854 39".into()), cpf.format_with("### ### ### ##"));
+    /// assert_eq!(None, cpf.format_with("###.###.###"));
+    ///
+    /// # Ok::<(), ParseCpfError>(())
+    /// ```
+    pub fn format_with(self, pattern: &str) -> Option<String> {
+        format_digits(&self.digits(), pattern)
+    }
+
+    /// Formatted with the first three and the check digits hidden, the way the government
+    /// publishes CPFs.
+    ///
+    /// ```
+    /// use bras::Cpf;
+    /// # use bras::ParseCpfError;
+    ///
+    /// let cpf: Cpf = "984.844.854-39".parse()?;
+    /// assert_eq!("***.844.854-**", cpf.masked());
+    ///
+    /// # Ok::<(), ParseCpfError>(())
+    /// ```
+    pub fn masked(self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    /// Formatted with the digits chosen by `style` hidden.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        mask(&self.to_string(), style.visible(11, 3..9))
+    }
+
+    /// Stable token standing for the CPF: the hex encoded HMAC-SHA256 of its 11 digits under
+    /// `key`. The same CPF and key always give the same token, so datasets can be joined on
+    /// it without storing the number, and without the key nobody can recover the CPF by
+    /// hashing every possible one.
+    ///
+    /// ```
+    /// use bras::Cpf;
+    /// # use bras::ParseCpfError;
+    ///
+    /// let cpf: Cpf = "984.844.854-39".parse()?;
+    /// let token = cpf.pseudonymize(b"secret");
+    /// assert_eq!(64, token.len());
+    /// assert_eq!(token, "98484485439".parse::<Cpf>()?.pseudonymize(b"secret"));
+    /// assert_ne!(token, cpf.pseudonymize(b"other secret"));
+    ///
+    /// # Ok::<(), ParseCpfError>(())
+    /// ```
+    #[cfg(feature = "pseudonym")]
+    pub fn pseudonymize(self, key: &[u8]) -> String {
+        use hmac::{Hmac, Mac};
+
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let mut mac =
+            Hmac::<sha2::Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+        mac.update(&self.digits().map(|digit| b'0' + digit));
+        let mut token = String::with_capacity(64);
+        for byte in mac.finalize().into_bytes() {
+            token.push(char::from(HEX[usize::from(byte >> 4)]));
+            token.push(char::from(HEX[usize::from(byte & 0xf)]));
         }
-        if s.len() == 14 {
-            let c: Vec<char> = s.chars().collect();
-            if c[3] != '.' || c[7] != '.' || c[11] != '-' {
-                return Err(ParseCpfError::Invalid);
+        token
+    }
+
+    /// Whether `s` is a valid CPF in a format accepted by [`FromStr`], without building it.
+    ///
+    /// ```
+    /// use bras::Cpf;
+    ///
+    /// assert!(Cpf::is_valid("984.844.854-39"));
+    /// assert!(!Cpf::is_valid("984.844.854-00"));
+    /// ```
+    pub const fn is_valid(s: &str) -> bool {
+        Cpf::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Number of CPFs checked together by [`Cpf::validate_batch`].
+    pub const BATCH_LANES: usize = 8;
+
+    /// [`Cpf::is_valid`] for every item of `documents`, for bulk jobs over millions of
+    /// records. Items are checked in blocks of [`Cpf::BATCH_LANES`], with the weighted sums
+    /// computed column by column so the compiler can vectorize them.
+    ///
+    /// ```
+    /// use bras::Cpf;
+    ///
+    /// let documents = ["984.844.854-39", "98484485439", "984.844.854-00", "111.111.111-11"];
+    /// assert_eq!(vec![true, true, false, false], Cpf::validate_batch(&documents));
+    /// ```
+    pub fn validate_batch(documents: &[&str]) -> Vec<bool> {
+        let mut valid = Vec::with_capacity(documents.len());
+        for block in documents.chunks(Self::BATCH_LANES) {
+            let mut columns = [[0u32; Self::BATCH_LANES]; 11];
+            let mut well_formed = [false; Self::BATCH_LANES];
+            for (lane, document) in block.iter().enumerate() {
+                if let Some(digits) = Self::batch_digits(document.as_bytes()) {
+                    for (column, digit) in columns.iter_mut().zip(digits) {
+                        column[lane] = u32::from(digit);
+                    }
+                    well_formed[lane] = true;
+                }
             }
+
+            let mut first = [0u32; Self::BATCH_LANES];
+            let mut second = [0u32; Self::BATCH_LANES];
+            let mut repeated = [true; Self::BATCH_LANES];
+            for (position, column) in columns.iter().enumerate() {
+                for lane in 0..Self::BATCH_LANES {
+                    if position < FIRST_DIGIT_ARRAY.len() {
+                        first[lane] += FIRST_DIGIT_ARRAY[position] * column[lane];
+                    }
+                    if position < SECOND_DIGIT_ARRAY.len() {
+                        second[lane] += SECOND_DIGIT_ARRAY[position] * column[lane];
+                    }
+                    repeated[lane] &= column[lane] == columns[0][lane];
+                }
+            }
+
+            valid.extend((0..block.len()).map(|lane| {
+                let check = |sum: u32| sum * 10 % 11 % 10;
+                well_formed[lane]
+                    && !repeated[lane]
+                    && check(first[lane]) == columns[9][lane]
+                    && check(second[lane]) == columns[10][lane]
+            }));
         }
-        let numbers: Vec<u32> = s.chars().filter_map(|c| c.to_digit(10)).collect();
-        if numbers.len() != 11 {
-            return Err(ParseCpfError::Invalid);
+        valid
+    }
+
+    /// Digits of a CPF written in one of the [`FromStr`] formats, without checking them.
+    fn batch_digits(bytes: &[u8]) -> Option<[u8; 11]> {
+        let formatted = match bytes.len() {
+            11 => false,
+            14 => true,
+            _ => return None,
+        };
+        if formatted
+            && SEPARATORS
+                .iter()
+                .any(|&(position, expected)| bytes[position] != expected)
+        {
+            return None;
         }
-        let first_verifier_digit = &numbers[9];
 
-        let all_equals = numbers.iter().all(|n| n == first_verifier_digit);
-        if all_equals {
-            return Err(ParseCpfError::Invalid);
+        let mut digits = [0u8; 11];
+        let mut count = 0;
+        for (position, &byte) in bytes.iter().enumerate() {
+            if formatted && matches!(position, 3 | 7 | 11) {
+                continue;
+            }
+            if !byte.is_ascii_digit() {
+                return None;
+            }
+            digits[count] = byte - b'0';
+            count += 1;
         }
+        Some(digits)
+    }
 
-        Self::check_first_verifier_digit(&numbers, first_verifier_digit)?;
+    /// Random valid CPF, never one with all digits equal.
+    ///
+    /// ```
+    /// use bras::Cpf;
+    ///
+    /// let cpf = Cpf::generate();
+    /// assert_eq!(Ok(cpf), cpf.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
 
-        let second_verifier_digit = &numbers[10];
-        Self::check_second_verifier_digit(&numbers, second_verifier_digit)?;
+    /// Like [`Cpf::generate`], drawing from the given generator so runs can be reproduced.
+    ///
+    /// ```
+    /// use bras::Cpf;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let a = Cpf::generate_with(&mut StdRng::seed_from_u64(42));
+    /// let b = Cpf::generate_with(&mut StdRng::seed_from_u64(42));
+    /// assert_eq!(a, b);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
 
-        let value_as_string: String = numbers.iter().map(|n| n.to_string()).collect();
-        let value: u64 = u64::from_str(&value_as_string).map_err(|_| ParseCpfError::Invalid)?;
+    /// Random valid CPF issued in the given fiscal region, i.e. with its ninth digit set
+    /// to the region's digit.
+    ///
+    /// ```
+    /// use bras::{Cpf, RegiaoFiscal};
+    ///
+    /// let cpf = Cpf::generate_for_region(RegiaoFiscal::Oitava);
+    /// assert_eq!('8', cpf.to_string().chars().nth(10).unwrap());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate_for_region(regiao: RegiaoFiscal) -> Self {
+        Self::generate_for_region_with(regiao, &mut rand::thread_rng())
+    }
 
-        Ok(Cpf { inner: value })
+    /// Like [`Cpf::generate_for_region`], drawing from the given generator.
+    #[cfg(feature = "rand")]
+    pub fn generate_for_region_with<R: rand::Rng + ?Sized>(
+        regiao: RegiaoFiscal,
+        rng: &mut R,
+    ) -> Self {
+        loop {
+            let mut base: [u8; 9] = core::array::from_fn(|_| rng.gen_range(0..10));
+            base[8] = regiao.digit();
+            if let Ok(cpf) = Cpf::complete(base) {
+                return cpf;
+            }
+        }
     }
 
-    fn check_first_verifier_digit(numbers: &[u32], got: &u32) -> Result<(), ParseCpfError> {
-        let calculated = Cpf::first_verifier_digit(&numbers.to_vec());
-        if got != &calculated {
-            Err(ParseCpfError::Invalid)
-        } else {
-            Ok(())
+    /// Completes the 9 base digits with the two verifier digits, for generating CPFs and
+    /// repairing ones whose check digits were lost. Fails with `InvalidCharacter` for a
+    /// base digit greater than 9 and with `RepeatedDigits` when every digit is the same.
+    ///
+    /// ```
+    /// use bras::Cpf;
+    /// # use bras::ParseCpfError;
+    ///
+    /// let cpf = Cpf::complete([9, 8, 4, 8, 4, 4, 8, 5, 4])?;
+    /// assert_eq!("984.844.854-39", cpf.to_string());
+    ///
+    /// # Ok::<(), ParseCpfError>(())
+    /// ```
+    pub const fn complete(base: [u8; 9]) -> Result<Self, ParseCpfError> {
+        let mut digits = [0u8; 11];
+        let mut position = 0;
+        while position < base.len() {
+            if base[position] > 9 {
+                return Err(ParseCpfError::InvalidCharacter { position });
+            }
+            digits[position] = base[position];
+            position += 1;
         }
+        digits[9] = Self::verifier_digit(&digits, &FIRST_DIGIT_ARRAY);
+        digits[10] = Self::verifier_digit(&digits, &SECOND_DIGIT_ARRAY);
+        Self::from_digits(digits)
     }
 
-    fn check_second_verifier_digit(numbers: &[u32], got: &u32) -> Result<(), ParseCpfError> {
-        let calculated = Cpf::second_verifier_digit(&numbers.to_vec());
-        if got != &calculated {
-            Err(ParseCpfError::Invalid)
-        } else {
-            Ok(())
+    fn new(s: &str) -> Result<Self, ParseCpfError> {
+        Cpf::from_bytes(s.as_bytes())
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`cpf!`](crate::cpf!).
+    ///
+    /// ```
+    /// use bras::Cpf;
+    /// # use bras::ParseCpfError;
+    ///
+    /// let record = b"98484485439JOAO DA SILVA";
+    /// let cpf = Cpf::from_bytes(&record[..11])?;
+    /// assert_eq!("984.844.854-39", cpf.to_string());
+    ///
+    /// # Ok::<(), ParseCpfError>(())
+    /// ```
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseCpfError> {
+        let formatted = match bytes.len() {
+            11 => false,
+            14 => true,
+            got => return Err(ParseCpfError::InvalidLength { got }),
+        };
+        if formatted {
+            let mut separator = 0;
+            while separator < SEPARATORS.len() {
+                let (position, expected) = SEPARATORS[separator];
+                if bytes[position] != expected {
+                    return Err(ParseCpfError::InvalidSeparator {
+                        position,
+                        expected: expected as char,
+                    });
+                }
+                separator += 1;
+            }
         }
+
+        let mut digits = [0u8; 11];
+        let mut count = 0;
+        let mut position = 0;
+        while position < bytes.len() {
+            if !(formatted && matches!(position, 3 | 7 | 11)) {
+                if !bytes[position].is_ascii_digit() {
+                    return Err(ParseCpfError::InvalidCharacter { position });
+                }
+                digits[count] = bytes[position] - b'0';
+                count += 1;
+            }
+            position += 1;
+        }
+        Self::from_digits(digits)
     }
 
-    fn first_verifier_digit(numbers: &Vec<u32>) -> u32 {
-        let sum = FIRST_DIGIT_ARRAY
-            .iter()
-            .zip(numbers)
-            .map(|pair| pair.0 * pair.1)
-            .sum();
-        Self::sum_to_digit(sum)
+    /// Parses with the relaxations turned on in `options`.
+    ///
+    /// ```
+    /// use bras::{Cpf, ParseOptions};
+    ///
+    /// let options = ParseOptions::strict().partial_formatting(true);
+    /// assert!(Cpf::parse_with("984844854-39", options).is_ok());
+    /// assert!(Cpf::parse_with("984.844854.39", options).is_err());
+    /// ```
+    pub fn parse_with(s: &str, options: ParseOptions) -> Result<Self, ParseCpfError> {
+        let s = options.apply_trim(s);
+        if let Some(digits) = options.pad_leading_zeros(s) {
+            return Cpf::from_digits(digits);
+        }
+        if !options.partial_formatting {
+            return Cpf::from_bytes(s.as_bytes());
+        }
+
+        let mut digits = [0u8; 11];
+        let mut count = 0;
+        let mut separated_at = None;
+        for (position, byte) in s.bytes().enumerate() {
+            match byte {
+                b'0'..=b'9' if count < digits.len() => {
+                    digits[count] = byte - b'0';
+                    count += 1;
+                }
+                b'0'..=b'9' => return Err(ParseCpfError::InvalidLength { got: s.len() }),
+                b'.' if matches!(count, 3 | 6) && separated_at != Some(count) => {
+                    separated_at = Some(count);
+                }
+                b'-' if count == 9 && separated_at != Some(count) => {
+                    separated_at = Some(count);
+                }
+                _ => return Err(ParseCpfError::InvalidCharacter { position }),
+            }
+        }
+        if count < digits.len() {
+            return Err(ParseCpfError::InvalidLength { got: s.len() });
+        }
+        Cpf::from_digits(digits)
     }
 
-    fn second_verifier_digit(numbers: &Vec<u32>) -> u32 {
-        let sum = SECOND_DIGIT_ARRAY
-            .iter()
-            .zip(numbers)
-            .map(|pair| pair.0 * pair.1)
-            .sum();
-        Self::sum_to_digit(sum)
+    /// Parses the digits of `s`, ignoring every other character, for legacy data with mixed
+    /// punctuation. `InvalidLength` reports how many digits were found.
+    ///
+    /// ```
+    /// use bras::Cpf;
+    /// # use bras::ParseCpfError;
+    ///
+    /// let cpf = Cpf::parse_lossy("CPF: 984-844-854.39 ")?;
+    /// assert_eq!("984.844.854-39", cpf.to_string());
+    ///
+    /// # Ok::<(), ParseCpfError>(())
+    /// ```
+    pub fn parse_lossy(s: &str) -> Result<Self, ParseCpfError> {
+        let digits = digits_only(s).map_err(|got| ParseCpfError::InvalidLength { got })?;
+        Cpf::from_digits(digits)
     }
 
-    fn sum_to_digit(sum: u32) -> u32 {
+    /// Valid CPFs one typo away from `s`, for helping people fix mistyped documents: a
+    /// single digit replaced or two adjacent digits swapped. Only the digits of `s` are
+    /// considered, as in [`Cpf::parse_lossy`]. A valid `s` is its only suggestion, and
+    /// there are none when `s` does not have 11 digits.
+    ///
+    /// ```
+    /// use bras::Cpf;
+    ///
+    /// let suggestions = Cpf::suggest("984.844.845-39");
+    /// assert!(suggestions.contains(&"984.844.854-39".parse().unwrap()));
+    /// ```
+    pub fn suggest(s: &str) -> Vec<Cpf> {
+        let Ok(digits) = digits_only::<11>(s) else {
+            return Vec::new();
+        };
+        if let Ok(cpf) = Cpf::from_digits(digits) {
+            return vec![cpf];
+        }
+
+        let mut suggestions = Vec::new();
+        for position in 0..digits.len() {
+            for digit in 0..10 {
+                let mut candidate = digits;
+                candidate[position] = digit;
+                suggestions.extend(Cpf::from_digits(candidate).ok());
+            }
+            if position + 1 < digits.len() {
+                let mut candidate = digits;
+                candidate.swap(position, position + 1);
+                suggestions.extend(Cpf::from_digits(candidate).ok());
+            }
+        }
+        suggestions.sort_unstable();
+        suggestions.dedup();
+        suggestions
+    }
+
+    fn split_digits(value: u64) -> [u8; 11] {
+        let mut digits = [0u8; 11];
+        let mut rest = value;
+        for digit in digits.iter_mut().rev() {
+            *digit = (rest % 10) as u8;
+            rest /= 10;
+        }
+        digits
+    }
+
+    const fn from_digits(digits: [u8; 11]) -> Result<Self, ParseCpfError> {
+        let mut repeated = true;
+        let mut inner = 0u64;
+        let mut position = 0;
+        while position < digits.len() {
+            repeated &= digits[position] == digits[0];
+            inner = inner * 10 + digits[position] as u64;
+            position += 1;
+        }
+        if repeated {
+            return Err(ParseCpfError::RepeatedDigits);
+        }
+        if Self::verifier_digit(&digits, &FIRST_DIGIT_ARRAY) != digits[9] {
+            return Err(ParseCpfError::InvalidCheckDigit { which: 1 });
+        }
+        if Self::verifier_digit(&digits, &SECOND_DIGIT_ARRAY) != digits[10] {
+            return Err(ParseCpfError::InvalidCheckDigit { which: 2 });
+        }
+        Ok(Cpf { inner })
+    }
+
+    const fn verifier_digit(digits: &[u8; 11], weights: &[u32]) -> u8 {
+        let mut sum = 0;
+        let mut position = 0;
+        while position < weights.len() {
+            sum += weights[position] * digits[position] as u32;
+            position += 1;
+        }
         let digit = sum * 10 % 11;
         if digit == 10 {
             0
         } else {
-            digit
+            digit as u8
         }
     }
 }
@@ -207,6 +812,7 @@ impl Cpf {
 #[cfg(test)]
 mod test {
     use super::*;
+    use alloc::boxed::Box;
 
     #[test]
     fn parse_cpf_from_str() {
@@ -218,26 +824,229 @@ mod test {
 
         let cpf = Cpf::from_str("05119439039").unwrap();
         assert_eq!("051.194.390-39", cpf.to_string());
+        assert_eq!("05119439039", cpf.numbers_as_string());
+        assert_eq!("05119439039", format!("{:#}", cpf));
     }
 
     #[test]
     fn return_error_on_invalid_str() {
         assert_eq!(
             Cpf::from_str("invalid_str").unwrap_err(),
-            ParseCpfError::Invalid
+            ParseCpfError::InvalidCharacter { position: 0 }
         );
         assert_eq!(
             Cpf::from_str("98484485401").unwrap_err(),
-            ParseCpfError::Invalid
+            ParseCpfError::InvalidCheckDigit { which: 1 }
         );
         assert_eq!(
             Cpf::from_str("98484485439invalid_str").unwrap_err(),
-            ParseCpfError::Invalid
+            ParseCpfError::InvalidLength { got: 22 }
         );
         assert_eq!(
             Cpf::from_str("984-844-854.39").unwrap_err(),
-            ParseCpfError::Invalid
+            ParseCpfError::InvalidSeparator {
+                position: 3,
+                expected: '.'
+            }
+        );
+        assert_eq!(
+            Cpf::from_str("984.844.8٤-39").unwrap_err(),
+            ParseCpfError::InvalidCharacter { position: 9 }
+        );
+    }
+
+    #[test]
+    fn parse_from_bytes() {
+        let cpf = Cpf::from_str("984.844.854-39").unwrap();
+        assert_eq!(Ok(cpf), Cpf::from_bytes(b"984.844.854-39"));
+        assert_eq!(Ok(cpf), Cpf::try_from(&b"98484485439"[..]));
+        assert_eq!(
+            Err(ParseCpfError::InvalidCharacter { position: 10 }),
+            Cpf::from_bytes(b"9848448543\xff")
+        );
+        assert_eq!(
+            Err(ParseCpfError::InvalidLength { got: 0 }),
+            Cpf::from_bytes(b"")
+        );
+    }
+
+    #[test]
+    fn parse_with_options() {
+        let cpf = Cpf::from_str("051.194.390-39").unwrap();
+        let lenient = ParseOptions::lenient();
+        for s in [
+            "051.194.390-39",
+            " 05119439039 ",
+            "051194390-39",
+            "051.194.39039",
+            "5119439039",
+            "\t5119439039",
+        ] {
+            assert_eq!(Ok(cpf), Cpf::parse_with(s, lenient), "{}", s);
+        }
+
+        let strict = ParseOptions::strict();
+        assert_eq!(Ok(cpf), Cpf::parse_with("051.194.390-39", strict));
+        assert_eq!(
+            Err(ParseCpfError::InvalidLength { got: 10 }),
+            Cpf::parse_with("5119439039", strict)
+        );
+        assert_eq!(
+            Err(ParseCpfError::InvalidCharacter { position: 4 }),
+            Cpf::parse_with("051..194.390-39", lenient)
+        );
+        assert_eq!(
+            Err(ParseCpfError::InvalidCharacter { position: 3 }),
+            Cpf::parse_with("051-194.390.39", lenient)
+        );
+        assert_eq!(
+            Err(ParseCpfError::InvalidLength { got: 13 }),
+            Cpf::parse_with("051.194.390-3", lenient)
+        );
+    }
+
+    #[test]
+    fn parse_lossy_ignores_everything_but_digits() {
+        let cpf = Cpf::from_str("984.844.854-39").unwrap();
+        assert_eq!(Ok(cpf), Cpf::parse_lossy("984 844 854 39"));
+        assert_eq!(Ok(cpf), Cpf::parse_lossy("n\u{ba} 984/844/854_39."));
+        assert_eq!(
+            Err(ParseCpfError::InvalidLength { got: 12 }),
+            Cpf::parse_lossy("CPF 1: 984.844.854-39")
+        );
+        assert_eq!(
+            Err(ParseCpfError::InvalidLength { got: 0 }),
+            Cpf::parse_lossy("CPF")
+        );
+        assert_eq!(
+            Err(ParseCpfError::InvalidCheckDigit { which: 2 }),
+            Cpf::parse_lossy("984-844-854.30")
+        );
+    }
+
+    #[cfg(feature = "pseudonym")]
+    #[test]
+    fn pseudonym_is_the_hmac_of_the_digits() {
+        let cpf = Cpf::from_str("984.844.854-39").unwrap();
+        assert_eq!(
+            "d86b5a27ec0d1e567bf4c35b5b573308461e6a919458b7e654c5cd8709365484",
+            cpf.pseudonymize(b"secret")
+        );
+    }
+
+    #[cfg(feature = "redact-debug")]
+    #[test]
+    fn debug_is_masked() {
+        let cpf = Cpf::from_str("984.844.854-39").unwrap();
+        assert_eq!("Cpf(***.844.854-**)", alloc::format!("{:?}", cpf));
+    }
+
+    #[test]
+    fn complete_base_digits() {
+        let cpf = Cpf::from_str("051.194.390-39").unwrap();
+        assert_eq!(Ok(cpf), Cpf::complete([0, 5, 1, 1, 9, 4, 3, 9, 0]));
+        assert_eq!(Err(ParseCpfError::RepeatedDigits), Cpf::complete([7; 9]));
+        assert_eq!(
+            Err(ParseCpfError::InvalidCharacter { position: 2 }),
+            Cpf::complete([0, 5, 10, 1, 9, 4, 3, 9, 0])
+        );
+    }
+
+    #[test]
+    fn suggestions_are_one_typo_away() {
+        let cpf = Cpf::from_str("984.844.854-39").unwrap();
+        assert_eq!(vec![cpf], Cpf::suggest("98484485439"));
+        assert!(Cpf::suggest("98484485430").contains(&cpf));
+        assert!(Cpf::suggest("98448485439").contains(&cpf));
+        for suggestion in Cpf::suggest("98484485430") {
+            let differences = suggestion
+                .digits()
+                .iter()
+                .zip([9, 8, 4, 8, 4, 4, 8, 5, 4, 3, 0])
+                .filter(|(a, b)| **a != *b)
+                .count();
+            assert!(differences <= 2);
+        }
+        assert!(Cpf::suggest("9848448543").is_empty());
+    }
+
+    #[test]
+    fn batch_agrees_with_is_valid() {
+        let documents = [
+            "984.844.854-39",
+            "98484485439",
+            "984.844.854-38",
+            "984.844.854-29",
+            "000.000.000-00",
+            "984-844-854-39",
+            "984.844.85a-39",
+            "9848448543",
+            "",
+            "051.194.390-39",
+            "00000000191",
+            "123.456.789-09",
+            "52998224725",
+            "529.982.247-25",
+            "11111111111",
+            "52998224724",
+            "984 844 854 39",
+        ];
+        let expected: Vec<bool> = documents.iter().map(|s| Cpf::is_valid(s)).collect();
+        assert_eq!(expected, Cpf::validate_batch(&documents));
+        assert!(Cpf::validate_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn digits_round_trip() {
+        let cpf = Cpf::from_str("05119439039").unwrap();
+        assert_eq!([0, 5, 1, 1, 9, 4, 3, 9, 0, 3, 9], cpf.digits());
+        assert_eq!(Ok(cpf), Cpf::from_digits(cpf.digits()));
+    }
+
+    #[test]
+    fn convert_from_u64() {
+        assert_eq!(
+            Ok(Cpf::from_str("05119439039").unwrap()),
+            Cpf::try_from(5119439039)
+        );
+        assert_eq!(
+            Err(ParseCpfError::InvalidLength { got: 12 }),
+            Cpf::try_from(198484485439)
+        );
+        assert_eq!(Err(ParseCpfError::RepeatedDigits), Cpf::try_from(0));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_cpfs_are_valid() {
+        for _ in 0..1000 {
+            let cpf = Cpf::generate();
+            assert_eq!(Ok(cpf), Cpf::from_str(&cpf.to_string()));
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_cpfs_match_the_region() {
+        for regiao in RegiaoFiscal::ALL {
+            let cpf = Cpf::generate_for_region(regiao);
+            assert_eq!(u64::from(regiao.digit()), u64::from(cpf) / 100 % 10);
+            assert_eq!(regiao, cpf.fiscal_region());
+        }
+    }
+
+    #[test]
+    fn error_is_human_readable() {
+        let err = Cpf::from_str("984.844.854-00").unwrap_err();
+        assert_eq!(
+            "invalid CPF, the first check digit does not match",
+            err.to_string()
+        );
+        assert_eq!(
+            "invalid CPF, character at position 1 is not a digit",
+            Cpf::from_str("0x000000000").unwrap_err().to_string()
         );
+        let _: Box<dyn core::error::Error> = Box::new(err);
     }
 
     #[test]
@@ -256,7 +1065,10 @@ mod test {
         ];
 
         for cpf in invalid_cpfs_by_definition {
-            assert_eq!(Cpf::from_str(cpf).unwrap_err(), ParseCpfError::Invalid);
+            assert_eq!(
+                Cpf::from_str(cpf).unwrap_err(),
+                ParseCpfError::RepeatedDigits
+            );
         }
     }
 }
@@ -0,0 +1,456 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+
+#[cfg(any(
+    feature = "cnpj",
+    feature = "cpf",
+    feature = "geo",
+    feature = "registro-profissional",
+    feature = "veiculos"
+))]
+use crate::MaskStyle;
+#[cfg(feature = "cpf")]
+use crate::{Caepf, Cpf, Pis, TituloEleitor};
+#[cfg(feature = "cnpj")]
+use crate::{Cei, ChaveAcesso, ChaveNfse, Cnes, Cno, Cnpj};
+#[cfg(feature = "geo")]
+use crate::{Cep, Telefone};
+#[cfg(feature = "veiculos")]
+use crate::{Chassi, Renavam, Rntrc};
+#[cfg(feature = "registro-profissional")]
+use crate::{Crea, Crm, Oab};
+
+/// Behavior shared by the document types, for code that handles them generically, such as
+/// audit logs and admin panels.
+///
+/// # Examples
+/// ```
+/// # #[cfg(all(feature = "cpf", feature = "geo"))]
+/// # fn main() -> Result<(), bras::ParseCpfError> {
+/// use bras::{BrDocument, Cep, Cpf};
+///
+/// let cpf: Cpf = "98484485439".parse()?;
+/// let cep: Cep = "01310100".parse().unwrap();
+/// let documents: [&dyn BrDocument; 2] = [&cpf, &cep];
+///
+/// let logged: Vec<String> = documents
+///     .iter()
+///     .map(|document| format!("{} {}", document.kind(), document.masked()))
+///     .collect();
+/// assert_eq!(["CPF ***.844.854-**", "CEP 01310-***"], logged.as_slice());
+///
+/// assert!(Cpf::is_valid_str("984.844.854-39"));
+/// # Ok(())
+/// # }
+/// # #[cfg(not(all(feature = "cpf", feature = "geo")))]
+/// # fn main() {}
+/// ```
+pub trait BrDocument: Display {
+    /// Short name of the kind of document, such as "CPF".
+    fn kind(&self) -> &'static str;
+
+    /// Formatted the way it is usually written, the same as [`Display`].
+    fn formatted(&self) -> String {
+        self.to_string()
+    }
+
+    /// Every digit, including check digits.
+    fn digits(&self) -> Vec<u8>;
+
+    /// Formatted with the identifying digits hidden, safe for logs.
+    fn masked(&self) -> String;
+
+    /// Whether `s` parses as this kind of document.
+    fn is_valid_str(s: &str) -> bool
+    where
+        Self: Sized;
+}
+
+/// Any of the document types, for code that finds documents without knowing their kind in
+/// advance, such as the [`scan`](crate::scan) module.
+///
+/// Each document type converts into its variant and compares equal to it, so code mixing
+/// the enum with the concrete types does not need to match on it.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "cnpj")]
+/// # fn main() -> Result<(), bras::Error> {
+/// use bras::{Cei, Cno, Document};
+///
+/// let cno: Cno = "51.204.09876/71".parse()?;
+/// let document = Document::from(cno);
+/// assert_eq!(document, cno);
+/// assert_eq!(cno, document);
+/// assert_ne!(document, "51.204.09876/01".parse::<Cei>()?);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "cnpj"))]
+/// # fn main() {}
+/// ```
+#[cfg(any(
+    feature = "cnpj",
+    feature = "cpf",
+    feature = "geo",
+    feature = "registro-profissional",
+    feature = "veiculos"
+))]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum Document {
+    #[cfg(feature = "cpf")]
+    Caepf(Caepf),
+    #[cfg(feature = "cnpj")]
+    Cei(Cei),
+    #[cfg(feature = "geo")]
+    Cep(Cep),
+    #[cfg(feature = "cnpj")]
+    ChaveAcesso(ChaveAcesso),
+    #[cfg(feature = "cnpj")]
+    ChaveNfse(ChaveNfse),
+    #[cfg(feature = "veiculos")]
+    Chassi(Chassi),
+    #[cfg(feature = "cnpj")]
+    Cnes(Cnes),
+    #[cfg(feature = "cnpj")]
+    Cnpj(Cnpj),
+    #[cfg(feature = "cnpj")]
+    Cno(Cno),
+    #[cfg(feature = "cpf")]
+    Cpf(Cpf),
+    #[cfg(feature = "registro-profissional")]
+    Crea(Crea),
+    #[cfg(feature = "registro-profissional")]
+    Crm(Crm),
+    #[cfg(feature = "registro-profissional")]
+    Oab(Oab),
+    #[cfg(feature = "cpf")]
+    Pis(Pis),
+    #[cfg(feature = "veiculos")]
+    Renavam(Renavam),
+    #[cfg(feature = "veiculos")]
+    Rntrc(Rntrc),
+    #[cfg(feature = "geo")]
+    Telefone(Telefone),
+    #[cfg(feature = "cpf")]
+    TituloEleitor(TituloEleitor),
+}
+
+#[cfg(any(
+    feature = "cnpj",
+    feature = "cpf",
+    feature = "geo",
+    feature = "registro-profissional",
+    feature = "veiculos"
+))]
+impl Document {
+    /// Formatted with the digits chosen by `style` hidden.
+    pub fn masked_with(&self, style: MaskStyle) -> String {
+        match self {
+            #[cfg(feature = "cpf")]
+            Document::Caepf(caepf) => caepf.masked_with(style),
+            #[cfg(feature = "cnpj")]
+            Document::Cei(cei) => cei.masked_with(style),
+            #[cfg(feature = "geo")]
+            Document::Cep(cep) => cep.masked_with(style),
+            #[cfg(feature = "cnpj")]
+            Document::ChaveAcesso(chave) => chave.masked_with(style),
+            #[cfg(feature = "cnpj")]
+            Document::ChaveNfse(chave) => chave.masked_with(style),
+            #[cfg(feature = "veiculos")]
+            Document::Chassi(chassi) => chassi.masked_with(style),
+            #[cfg(feature = "cnpj")]
+            Document::Cnes(cnes) => cnes.masked_with(style),
+            #[cfg(feature = "cnpj")]
+            Document::Cnpj(cnpj) => cnpj.masked_with(style),
+            #[cfg(feature = "cnpj")]
+            Document::Cno(cno) => cno.masked_with(style),
+            #[cfg(feature = "cpf")]
+            Document::Cpf(cpf) => cpf.masked_with(style),
+            #[cfg(feature = "registro-profissional")]
+            Document::Crea(crea) => crea.masked_with(style),
+            #[cfg(feature = "registro-profissional")]
+            Document::Crm(crm) => crm.masked_with(style),
+            #[cfg(feature = "registro-profissional")]
+            Document::Oab(oab) => oab.masked_with(style),
+            #[cfg(feature = "cpf")]
+            Document::Pis(pis) => pis.masked_with(style),
+            #[cfg(feature = "veiculos")]
+            Document::Renavam(renavam) => renavam.masked_with(style),
+            #[cfg(feature = "veiculos")]
+            Document::Rntrc(rntrc) => rntrc.masked_with(style),
+            #[cfg(feature = "geo")]
+            Document::Telefone(telefone) => telefone.masked_with(style),
+            #[cfg(feature = "cpf")]
+            Document::TituloEleitor(titulo) => titulo.masked_with(style),
+        }
+    }
+
+    fn inner(&self) -> &dyn BrDocument {
+        match self {
+            #[cfg(feature = "cpf")]
+            Document::Caepf(caepf) => caepf,
+            #[cfg(feature = "cnpj")]
+            Document::Cei(cei) => cei,
+            #[cfg(feature = "geo")]
+            Document::Cep(cep) => cep,
+            #[cfg(feature = "cnpj")]
+            Document::ChaveAcesso(chave) => chave,
+            #[cfg(feature = "cnpj")]
+            Document::ChaveNfse(chave) => chave,
+            #[cfg(feature = "veiculos")]
+            Document::Chassi(chassi) => chassi,
+            #[cfg(feature = "cnpj")]
+            Document::Cnes(cnes) => cnes,
+            #[cfg(feature = "cnpj")]
+            Document::Cnpj(cnpj) => cnpj,
+            #[cfg(feature = "cnpj")]
+            Document::Cno(cno) => cno,
+            #[cfg(feature = "cpf")]
+            Document::Cpf(cpf) => cpf,
+            #[cfg(feature = "registro-profissional")]
+            Document::Crea(crea) => crea,
+            #[cfg(feature = "registro-profissional")]
+            Document::Crm(crm) => crm,
+            #[cfg(feature = "registro-profissional")]
+            Document::Oab(oab) => oab,
+            #[cfg(feature = "cpf")]
+            Document::Pis(pis) => pis,
+            #[cfg(feature = "veiculos")]
+            Document::Renavam(renavam) => renavam,
+            #[cfg(feature = "veiculos")]
+            Document::Rntrc(rntrc) => rntrc,
+            #[cfg(feature = "geo")]
+            Document::Telefone(telefone) => telefone,
+            #[cfg(feature = "cpf")]
+            Document::TituloEleitor(titulo) => titulo,
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "cnpj",
+    feature = "cpf",
+    feature = "geo",
+    feature = "registro-profissional",
+    feature = "veiculos"
+))]
+impl Display for Document {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.inner(), f)
+    }
+}
+
+#[cfg(any(
+    feature = "cnpj",
+    feature = "cpf",
+    feature = "geo",
+    feature = "registro-profissional",
+    feature = "veiculos"
+))]
+impl BrDocument for Document {
+    fn kind(&self) -> &'static str {
+        self.inner().kind()
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        self.inner().digits()
+    }
+
+    fn masked(&self) -> String {
+        self.inner().masked()
+    }
+
+    /// Whether `s` is any of the document types.
+    fn is_valid_str(s: &str) -> bool {
+        #[cfg(feature = "cpf")]
+        if Cpf::is_valid(s) {
+            return true;
+        }
+        #[cfg(feature = "geo")]
+        if Cep::is_valid(s) || Telefone::is_valid(s) {
+            return true;
+        }
+        #[cfg(feature = "cpf")]
+        if Caepf::is_valid(s) {
+            return true;
+        }
+        #[cfg(feature = "cnpj")]
+        if Cnpj::is_valid(s) {
+            return true;
+        }
+        #[cfg(feature = "cnpj")]
+        if Cno::is_valid(s) {
+            return true;
+        }
+        #[cfg(feature = "cnpj")]
+        if Cei::is_valid(s) {
+            return true;
+        }
+        #[cfg(feature = "cnpj")]
+        if Cnes::is_valid(s) {
+            return true;
+        }
+        #[cfg(feature = "registro-profissional")]
+        if Oab::is_valid(s) || Crm::is_valid(s) || Crea::is_valid(s) {
+            return true;
+        }
+        #[cfg(feature = "veiculos")]
+        if Chassi::is_valid(s) || Rntrc::is_valid(s) {
+            return true;
+        }
+        #[cfg(feature = "cpf")]
+        if Pis::is_valid(s) || TituloEleitor::is_valid(s) {
+            return true;
+        }
+        #[cfg(feature = "veiculos")]
+        if Renavam::is_valid(s) {
+            return true;
+        }
+        #[cfg(feature = "cnpj")]
+        if ChaveAcesso::is_valid(s) || ChaveNfse::is_valid(s) {
+            return true;
+        }
+        false
+    }
+}
+
+/// Converts each document type into its variant and compares them with it.
+macro_rules! impl_variant {
+    ($($(#[$cfg:meta])* $variant:ident,)*) => {
+        $(
+            $(#[$cfg])*
+            impl From<$variant> for Document {
+                fn from(document: $variant) -> Self {
+                    Document::$variant(document)
+                }
+            }
+
+            $(#[$cfg])*
+            impl PartialEq<$variant> for Document {
+                fn eq(&self, other: &$variant) -> bool {
+                    matches!(self, Document::$variant(document) if document == other)
+                }
+            }
+
+            $(#[$cfg])*
+            impl PartialEq<Document> for $variant {
+                fn eq(&self, other: &Document) -> bool {
+                    other == self
+                }
+            }
+        )*
+    };
+}
+
+impl_variant! {
+    #[cfg(feature = "cpf")]
+    Caepf,
+    #[cfg(feature = "cnpj")]
+    Cei,
+    #[cfg(feature = "geo")]
+    Cep,
+    #[cfg(feature = "cnpj")]
+    ChaveAcesso,
+    #[cfg(feature = "cnpj")]
+    ChaveNfse,
+    #[cfg(feature = "veiculos")]
+    Chassi,
+    #[cfg(feature = "cnpj")]
+    Cnes,
+    #[cfg(feature = "cnpj")]
+    Cnpj,
+    #[cfg(feature = "cnpj")]
+    Cno,
+    #[cfg(feature = "cpf")]
+    Cpf,
+    #[cfg(feature = "registro-profissional")]
+    Crea,
+    #[cfg(feature = "registro-profissional")]
+    Crm,
+    #[cfg(feature = "registro-profissional")]
+    Oab,
+    #[cfg(feature = "cpf")]
+    Pis,
+    #[cfg(feature = "veiculos")]
+    Renavam,
+    #[cfg(feature = "veiculos")]
+    Rntrc,
+    #[cfg(feature = "geo")]
+    Telefone,
+    #[cfg(feature = "cpf")]
+    TituloEleitor,
+}
+
+#[cfg(all(
+    test,
+    feature = "cnpj",
+    feature = "cpf",
+    feature = "geo",
+    feature = "registro-profissional",
+    feature = "veiculos"
+))]
+mod test {
+    use super::*;
+    use crate::CodigoMunicipio;
+    use alloc::vec;
+
+    fn digits_match_formatted<T: BrDocument>(document: &T) {
+        let formatted: Vec<u8> = document
+            .formatted()
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .map(|b| b - b'0')
+            .collect();
+        assert_eq!(formatted, document.digits());
+        assert!(T::is_valid_str(&document.formatted()));
+    }
+
+    #[test]
+    fn every_document_is_consistent() {
+        digits_match_formatted(&"051.194.390-39".parse::<Cpf>().unwrap());
+        digits_match_formatted(&"01310-100".parse::<Cep>().unwrap());
+        digits_match_formatted(&"4106902".parse::<CodigoMunicipio>().unwrap());
+        digits_match_formatted(&"984.844.854/001-67".parse::<Caepf>().unwrap());
+        digits_match_formatted(&"51.204.09876/71".parse::<Cno>().unwrap());
+        digits_match_formatted(&"51.204.09876/01".parse::<Cei>().unwrap());
+        digits_match_formatted(&"2077485".parse::<Cnes>().unwrap());
+        digits_match_formatted(&"12.ABC.345/01DE-35".parse::<Cnpj>().unwrap());
+        digits_match_formatted(&"OAB-RJ 98765-A".parse::<Oab>().unwrap());
+        digits_match_formatted(&"CRM-PE 12345".parse::<Crm>().unwrap());
+        digits_match_formatted(&"CREA/RJ 123456/D".parse::<Crea>().unwrap());
+        digits_match_formatted(&"9BWZZZ377VT004251".parse::<Chassi>().unwrap());
+        digits_match_formatted(
+            &"35240311222333000181550010000001231123456788"
+                .parse::<ChaveAcesso>()
+                .unwrap(),
+        );
+        digits_match_formatted(
+            &"35503082211222333000181000000000012324031234567892"
+                .parse::<ChaveNfse>()
+                .unwrap(),
+        );
+        digits_match_formatted(&"ETC 012345678".parse::<Rntrc>().unwrap());
+        digits_match_formatted(&"(11) 98765-4321".parse::<Telefone>().unwrap());
+        digits_match_formatted(&"120.28384.76-1".parse::<Pis>().unwrap());
+        digits_match_formatted(&"63908792210".parse::<Renavam>().unwrap());
+        digits_match_formatted(&"0043 5687 0906".parse::<TituloEleitor>().unwrap());
+        assert_eq!(
+            vec![0, 1, 3, 1, 0, 1, 0, 0],
+            "01310100".parse::<Cep>().unwrap().digits()
+        );
+    }
+
+    #[test]
+    fn compare_with_concrete_types() {
+        let cpf: Cpf = "051.194.390-39".parse().unwrap();
+        let cep: Cep = "01310-100".parse().unwrap();
+        assert_eq!(Document::Cpf(cpf), Document::from(cpf));
+        assert_eq!(Document::from(cep), cep);
+        assert_eq!(cep, Document::from(cep));
+        assert_ne!(Document::from(cep), cpf);
+        assert_ne!(cpf, Document::from(cep));
+    }
+}
@@ -0,0 +1,107 @@
+/// Which digits [`Cpf::masked_with`](crate::Cpf::masked_with) and the other masking methods
+/// hide, for logs and screens that must follow the LGPD minimization principle.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "cpf")]
+/// # fn main() -> Result<(), bras::ParseCpfError> {
+/// use bras::{Cpf, MaskStyle};
+///
+/// let cpf: Cpf = "984.844.854-39".parse()?;
+/// assert_eq!("***.844.854-**", cpf.masked_with(MaskStyle::Recommended));
+/// assert_eq!("***.***.**4-39", cpf.masked_with(MaskStyle::KeepLast(3)));
+/// assert_eq!("***.***.***-**", cpf.masked_with(MaskStyle::Full));
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "cpf"))]
+/// # fn main() {}
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum MaskStyle {
+    /// The usual mask for the kind of document, such as the one the government uses when
+    /// publishing CPFs.
+    #[default]
+    Recommended,
+    /// Only the given number of trailing digits are shown.
+    KeepLast(u8),
+    /// Every digit is hidden, keeping only the separators.
+    Full,
+}
+
+/// Replaces with `*` the digits of `formatted` whose index, counting only digits, is not in
+/// `visible`.
+#[cfg(any(
+    feature = "cnpj",
+    feature = "cpf",
+    feature = "geo",
+    feature = "registro-profissional",
+    feature = "veiculos"
+))]
+pub(crate) fn mask(formatted: &str, visible: core::ops::Range<usize>) -> alloc::string::String {
+    let mut index = 0;
+    formatted
+        .chars()
+        .map(|c| {
+            if !c.is_ascii_digit() {
+                return c;
+            }
+            index += 1;
+            if visible.contains(&(index - 1)) {
+                c
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+#[cfg(any(
+    feature = "cnpj",
+    feature = "cpf",
+    feature = "geo",
+    feature = "registro-profissional",
+    feature = "veiculos"
+))]
+impl MaskStyle {
+    /// Digits left visible for a document with `len` digits whose recommended mask shows
+    /// `recommended`.
+    pub(crate) fn visible(
+        self,
+        len: usize,
+        recommended: core::ops::Range<usize>,
+    ) -> core::ops::Range<usize> {
+        match self {
+            MaskStyle::Recommended => recommended,
+            MaskStyle::KeepLast(n) => len.saturating_sub(usize::from(n))..len,
+            MaskStyle::Full => 0..0,
+        }
+    }
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "cnpj",
+        feature = "cpf",
+        feature = "geo",
+        feature = "registro-profissional",
+        feature = "veiculos"
+    )
+))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn masks_only_digits() {
+        assert_eq!("**3.4**", mask("123.456", 2..4));
+        assert_eq!("***.***", mask("123.456", MaskStyle::Full.visible(6, 0..3)));
+        assert_eq!(
+            "123.456",
+            mask("123.456", MaskStyle::KeepLast(20).visible(6, 0..3))
+        );
+        assert_eq!(
+            "123.***",
+            mask("123.456", MaskStyle::Recommended.visible(6, 0..3))
+        );
+    }
+}
@@ -1 +1,34 @@
+#[cfg(feature = "cpf")]
+pub mod caepf;
+#[cfg(feature = "cnpj")]
+pub mod cei;
+#[cfg(feature = "veiculos")]
+pub mod chassi;
+#[cfg(feature = "cnpj")]
+pub mod chave_acesso;
+#[cfg(feature = "cnpj")]
+pub mod chave_nfse;
+#[cfg(feature = "cnpj")]
+pub mod cnes;
+#[cfg(feature = "cnpj")]
+pub mod cno;
+#[cfg(feature = "cnpj")]
+pub mod cnpj;
+#[cfg(feature = "cpf")]
 pub mod cpf;
+pub mod document;
+pub mod mask;
+#[cfg(any(feature = "cpf", feature = "geo"))]
+pub mod pattern;
+#[cfg(feature = "cpf")]
+pub mod pis;
+#[cfg(feature = "cpf")]
+pub mod regiao_fiscal;
+#[cfg(feature = "registro-profissional")]
+pub mod registro_profissional;
+#[cfg(feature = "veiculos")]
+pub mod renavam;
+#[cfg(feature = "veiculos")]
+pub mod rntrc;
+#[cfg(feature = "cpf")]
+pub mod titulo_eleitor;
@@ -0,0 +1,29 @@
+use alloc::string::String;
+
+/// Writes `digits` in place of each `#` of `pattern`, copying every other character, or
+/// `None` when the number of `#` is not the number of digits.
+pub(crate) fn format_digits(digits: &[u8], pattern: &str) -> Option<String> {
+    let mut digits = digits.iter();
+    let mut formatted = String::with_capacity(pattern.len());
+    for c in pattern.chars() {
+        if c == '#' {
+            formatted.push(char::from(b'0' + digits.next()?));
+        } else {
+            formatted.push(c);
+        }
+    }
+    digits.next().is_none().then_some(formatted)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn every_digit_takes_a_placeholder() {
+        assert_eq!(Some("1-23".into()), format_digits(&[1, 2, 3], "#-##"));
+        assert_eq!(Some("(123)".into()), format_digits(&[1, 2, 3], "(###)"));
+        assert_eq!(None, format_digits(&[1, 2, 3], "##"));
+        assert_eq!(None, format_digits(&[1, 2, 3], "####"));
+    }
+}
@@ -0,0 +1,364 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::mask::mask;
+use crate::{BrDocument, MaskStyle};
+
+/// Número de Inscrição do Trabalhador, the 11 digit number of PIS, PASEP, NIT and NIS,
+/// which share the same numbering. The last digit is a check digit.
+///
+/// # Examples
+/// ```
+/// use bras::Pis;
+/// # use bras::ParsePisError;
+///
+/// let pis: Pis = "120.28384.76-1".parse()?;
+/// assert_eq!(pis, "12028384761".parse::<Pis>()?);
+/// assert_eq!("12028384761", format!("{:#}", pis));
+/// assert_eq!(12028384761u64, u64::from(pis));
+///
+/// # Ok::<(), ParsePisError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(not(feature = "redact-debug"), derive(Debug))]
+pub struct Pis {
+    inner: u64,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParsePisError {
+    /// Neither 11 digits nor 14 characters formatted as "000.00000.00-0".
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter { position: usize },
+    /// 14 characters with something other than the `expected` separator of
+    /// "000.00000.00-0" at the zero based `position`.
+    InvalidSeparator { position: usize, expected: char },
+    /// Every digit is the same, such as "000.00000.00-0".
+    RepeatedDigits,
+    /// The check digit does not match.
+    InvalidCheckDigit,
+}
+
+impl Display for ParsePisError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParsePisError::InvalidLength { got } => write!(
+                f,
+                "invalid PIS, expected 11 digits or 14 characters but got {}",
+                got
+            ),
+            ParsePisError::InvalidCharacter { position } => write!(
+                f,
+                "invalid PIS, character at position {} is not a digit",
+                position
+            ),
+            ParsePisError::InvalidSeparator { position, expected } => write!(
+                f,
+                "invalid PIS, expected '{}' at position {}",
+                expected, position
+            ),
+            ParsePisError::RepeatedDigits => f.write_str("invalid PIS, all digits are the same"),
+            ParsePisError::InvalidCheckDigit => {
+                f.write_str("invalid PIS, the check digit does not match")
+            }
+        }
+    }
+}
+
+impl ParsePisError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParsePisError::InvalidCharacter { position }
+            | ParsePisError::InvalidSeparator { position, .. } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParsePisError {}
+
+/// Positions of the separators in "000.00000.00-0".
+const SEPARATORS: [(usize, u8); 3] = [(3, b'.'), (9, b'.'), (12, b'-')];
+
+const WEIGHTS: [u64; 10] = [3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+
+impl Pis {
+    /// The 11 digits, the last one being the check digit.
+    pub fn digits(self) -> [u8; 11] {
+        let mut digits = [0u8; 11];
+        let mut rest = self.inner;
+        for digit in digits.iter_mut().rev() {
+            *digit = (rest % 10) as u8;
+            rest /= 10;
+        }
+        digits
+    }
+
+    /// Formatted with the first and last digits hidden, as in "***.28384.**-*".
+    ///
+    /// ```
+    /// use bras::Pis;
+    ///
+    /// let pis: Pis = "120.28384.76-1".parse().unwrap();
+    /// assert_eq!("***.28384.**-*", pis.masked());
+    /// ```
+    pub fn masked(self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    /// Formatted with the digits chosen by `style` hidden.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        mask(&self.to_string(), style.visible(11, 3..8))
+    }
+
+    /// Whether `s` is a valid PIS in a format accepted by [`FromStr`].
+    pub const fn is_valid(s: &str) -> bool {
+        Pis::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Random valid PIS.
+    ///
+    /// ```
+    /// use bras::Pis;
+    ///
+    /// let pis = Pis::generate();
+    /// assert_eq!(Ok(pis), pis.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Pis::generate`], drawing from the given generator so runs can be reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`pis!`](crate::pis!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParsePisError> {
+        let formatted = match bytes.len() {
+            11 => false,
+            14 => true,
+            got => return Err(ParsePisError::InvalidLength { got }),
+        };
+        if formatted {
+            let mut separator = 0;
+            while separator < SEPARATORS.len() {
+                let (position, expected) = SEPARATORS[separator];
+                if bytes[position] != expected {
+                    return Err(ParsePisError::InvalidSeparator {
+                        position,
+                        expected: expected as char,
+                    });
+                }
+                separator += 1;
+            }
+        }
+
+        let mut digits = [0u8; 11];
+        let mut count = 0;
+        let mut position = 0;
+        while position < bytes.len() {
+            if !(formatted && matches!(position, 3 | 9 | 12)) {
+                if !bytes[position].is_ascii_digit() {
+                    return Err(ParsePisError::InvalidCharacter { position });
+                }
+                digits[count] = bytes[position] - b'0';
+                count += 1;
+            }
+            position += 1;
+        }
+
+        let mut repeated = true;
+        let mut index = 1;
+        while index < digits.len() {
+            repeated &= digits[index] == digits[0];
+            index += 1;
+        }
+        if repeated {
+            return Err(ParsePisError::RepeatedDigits);
+        }
+        let pis = Pis::complete(&digits);
+        if pis.inner % 10 != digits[10] as u64 {
+            return Err(ParsePisError::InvalidCheckDigit);
+        }
+        Ok(pis)
+    }
+
+    /// Builds from the first 10 `digits`, computing the check digit.
+    const fn complete(digits: &[u8; 11]) -> Self {
+        let mut base = 0;
+        let mut sum = 0;
+        let mut position = 0;
+        while position < WEIGHTS.len() {
+            base = base * 10 + digits[position] as u64;
+            sum += digits[position] as u64 * WEIGHTS[position];
+            position += 1;
+        }
+        let check = match 11 - sum % 11 {
+            10 | 11 => 0,
+            check => check,
+        };
+        Pis {
+            inner: base * 10 + check,
+        }
+    }
+}
+
+impl FromStr for Pis {
+    type Err = ParsePisError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Pis::from_bytes(s.as_bytes())
+    }
+}
+
+impl From<Pis> for u64 {
+    fn from(pis: Pis) -> Self {
+        pis.inner
+    }
+}
+
+impl Display for Pis {
+    /// "000.00000.00-0", or only the 11 digits with the alternate flag.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            return write!(f, "{:011}", self.inner);
+        }
+        write!(
+            f,
+            "{:03}.{:05}.{:02}-{}",
+            self.inner / 100_000_000,
+            self.inner / 1000 % 100_000,
+            self.inner / 10 % 100,
+            self.inner % 10
+        )
+    }
+}
+
+/// With the `redact-debug` feature, prints the [masked](Pis::masked) PIS so it cannot leak
+/// into logs through `{:?}`.
+#[cfg(feature = "redact-debug")]
+impl core::fmt::Debug for Pis {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Pis({})", self.masked())
+    }
+}
+
+impl BrDocument for Pis {
+    fn kind(&self) -> &'static str {
+        "PIS"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        Pis::digits(*self).to_vec()
+    }
+
+    fn masked(&self) -> String {
+        Pis::masked(*self)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Pis::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Pis> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Pis {
+        Pis::complete(&distinct(core::array::from_fn(|_| rng.gen_range(0..10))))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Pis {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut digits = [0u8; 11];
+        for digit in digits.iter_mut() {
+            *digit = u.int_in_range(0..=9)?;
+        }
+        Ok(Pis::complete(&distinct(digits)))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Pis {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Pis>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::array::uniform11(0u8..10)
+            .prop_map(|digits| Pis::complete(&distinct(digits)))
+            .boxed()
+    }
+}
+
+/// `digits` with the second one changed when it repeats the first, so they are never all
+/// the same.
+#[cfg(any(feature = "arbitrary", feature = "rand"))]
+fn distinct(mut digits: [u8; 11]) -> [u8; 11] {
+    if digits[1] == digits[0] {
+        digits[1] = (digits[0] + 1) % 10;
+    }
+    digits
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_formats() {
+        let pis: Pis = "120.28384.76-1".parse().unwrap();
+        assert_eq!(12028384761, u64::from(pis));
+        assert_eq!(Ok(pis), "12028384761".parse());
+        assert_eq!([1, 2, 0, 2, 8, 3, 8, 4, 7, 6, 1], pis.digits());
+        assert_eq!(Ok(pis), pis.to_string().parse());
+        assert!(Pis::is_valid("170.43712.85-6"));
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let parse = |s: &str| s.parse::<Pis>();
+        assert_eq!(
+            Err(ParsePisError::InvalidCheckDigit),
+            parse("120.28384.76-2")
+        );
+        assert_eq!(
+            Err(ParsePisError::InvalidSeparator {
+                position: 9,
+                expected: '.'
+            }),
+            parse("120.28384-76-1")
+        );
+        assert_eq!(
+            Err(ParsePisError::InvalidCharacter { position: 4 }),
+            parse("120.x8384.76-1")
+        );
+        assert_eq!(
+            Err(ParsePisError::InvalidLength { got: 10 }),
+            parse("1202838476")
+        );
+        assert_eq!(Err(ParsePisError::RepeatedDigits), parse("00000000000"));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_pis_are_valid() {
+        for _ in 0..1000 {
+            let pis = Pis::generate();
+            assert_eq!(Ok(pis), pis.to_string().parse());
+        }
+    }
+}
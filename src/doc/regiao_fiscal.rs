@@ -0,0 +1,117 @@
+use core::fmt::Display;
+
+use crate::Uf;
+
+/// Fiscal regions of the Receita Federal. The ninth digit of a CPF is the region where it
+/// was issued, with the tenth region written as `0`.
+///
+/// # Examples
+/// ```
+/// use bras::{RegiaoFiscal, Uf};
+///
+/// assert_eq!(8, RegiaoFiscal::Oitava.digit());
+/// assert_eq!(RegiaoFiscal::Oitava, RegiaoFiscal::from(Uf::SP));
+/// assert_eq!(Some(RegiaoFiscal::Decima), RegiaoFiscal::from_digit(0));
+/// assert_eq!("8ª Região Fiscal", RegiaoFiscal::Oitava.to_string());
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum RegiaoFiscal {
+    Primeira,
+    Segunda,
+    Terceira,
+    Quarta,
+    Quinta,
+    Sexta,
+    Setima,
+    Oitava,
+    Nona,
+    Decima,
+}
+
+impl RegiaoFiscal {
+    pub const ALL: [RegiaoFiscal; 10] = [
+        RegiaoFiscal::Primeira,
+        RegiaoFiscal::Segunda,
+        RegiaoFiscal::Terceira,
+        RegiaoFiscal::Quarta,
+        RegiaoFiscal::Quinta,
+        RegiaoFiscal::Sexta,
+        RegiaoFiscal::Setima,
+        RegiaoFiscal::Oitava,
+        RegiaoFiscal::Nona,
+        RegiaoFiscal::Decima,
+    ];
+
+    /// Ordinal number of the region, from 1 to 10.
+    pub fn number(self) -> u8 {
+        self as u8 + 1
+    }
+
+    /// Digit used in the ninth position of a CPF.
+    pub fn digit(self) -> u8 {
+        self.number() % 10
+    }
+
+    pub fn from_digit(digit: u8) -> Option<RegiaoFiscal> {
+        match digit {
+            0 => Some(RegiaoFiscal::Decima),
+            1..=9 => Some(RegiaoFiscal::ALL[usize::from(digit) - 1]),
+            _ => None,
+        }
+    }
+
+    /// Federative units under the region, in alphabetical order of their siglas.
+    pub fn ufs(self) -> &'static [Uf] {
+        match self {
+            RegiaoFiscal::Primeira => &[Uf::DF, Uf::GO, Uf::MS, Uf::MT, Uf::TO],
+            RegiaoFiscal::Segunda => &[Uf::AC, Uf::AM, Uf::AP, Uf::PA, Uf::RO, Uf::RR],
+            RegiaoFiscal::Terceira => &[Uf::CE, Uf::MA, Uf::PI],
+            RegiaoFiscal::Quarta => &[Uf::AL, Uf::PB, Uf::PE, Uf::RN],
+            RegiaoFiscal::Quinta => &[Uf::BA, Uf::SE],
+            RegiaoFiscal::Sexta => &[Uf::MG],
+            RegiaoFiscal::Setima => &[Uf::ES, Uf::RJ],
+            RegiaoFiscal::Oitava => &[Uf::SP],
+            RegiaoFiscal::Nona => &[Uf::PR, Uf::SC],
+            RegiaoFiscal::Decima => &[Uf::RS],
+        }
+    }
+}
+
+impl From<Uf> for RegiaoFiscal {
+    fn from(uf: Uf) -> Self {
+        RegiaoFiscal::ALL
+            .into_iter()
+            .find(|regiao| regiao.ufs().contains(&uf))
+            .expect("every UF belongs to a fiscal region")
+    }
+}
+
+impl Display for RegiaoFiscal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}ª Região Fiscal", self.number())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn every_uf_in_exactly_one_region() {
+        for uf in Uf::ALL {
+            let count = RegiaoFiscal::ALL
+                .iter()
+                .filter(|regiao| regiao.ufs().contains(&uf))
+                .count();
+            assert_eq!(1, count, "{}", uf);
+        }
+    }
+
+    #[test]
+    fn digits_round_trip() {
+        for regiao in RegiaoFiscal::ALL {
+            assert_eq!(Some(regiao), RegiaoFiscal::from_digit(regiao.digit()));
+        }
+        assert_eq!(None, RegiaoFiscal::from_digit(10));
+    }
+}
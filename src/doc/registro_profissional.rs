@@ -0,0 +1,737 @@
+//! Registrations in the professional councils, which are written in many ways: with the
+//! council name or without it, the UF before or after the number, with or without
+//! thousands separators.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::mask::mask;
+use crate::{BrDocument, MaskStyle, ParseUfError, Uf};
+
+/// Registration of a lawyer in the Ordem dos Advogados do Brasil: the number in the
+/// section of the UF, with an optional letter some sections append, such as for
+/// supplementary registrations.
+///
+/// # Examples
+/// ```
+/// use bras::{Oab, Uf};
+/// # use bras::ParseRegistroProfissionalError;
+///
+/// let oab: Oab = "OAB/SP 123.456".parse()?;
+/// assert_eq!((Uf::SP, 123456, None), (oab.uf(), oab.numero(), oab.sufixo()));
+/// assert_eq!(oab, "123456/SP".parse::<Oab>()?);
+/// assert_eq!(oab, "sp 123456".parse::<Oab>()?);
+///
+/// let oab: Oab = "OAB-RJ 98765-A".parse()?;
+/// assert_eq!(Some('A'), oab.sufixo());
+/// assert_eq!("OAB/RJ 98.765-A", oab.to_string());
+///
+/// # Ok::<(), ParseRegistroProfissionalError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Oab {
+    uf: Uf,
+    numero: u32,
+    sufixo: Option<u8>,
+}
+
+/// Registration of a physician in the Conselho Regional de Medicina of a UF.
+///
+/// # Examples
+/// ```
+/// use bras::{Crm, Uf};
+/// # use bras::ParseRegistroProfissionalError;
+///
+/// let crm: Crm = "CRM-PE 12345".parse()?;
+/// assert_eq!((Uf::PE, 12345), (crm.uf(), crm.numero()));
+/// assert_eq!("CRM/PE 12345", crm.to_string());
+/// assert_eq!(crm, "12345/PE".parse::<Crm>()?);
+///
+/// # Ok::<(), ParseRegistroProfissionalError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Crm {
+    uf: Uf,
+    numero: u32,
+}
+
+/// Registration of an engineer, architect or agronomist in the Conselho Regional de
+/// Engenharia e Agronomia of a UF: either the 10 digit national number or an older state
+/// number, the latter sometimes followed by a letter such as the `D` of permanent
+/// registrations.
+///
+/// # Examples
+/// ```
+/// use bras::{Crea, Uf};
+/// # use bras::ParseRegistroProfissionalError;
+///
+/// let crea: Crea = "CREA-SP 5061234567".parse()?;
+/// assert_eq!((Uf::SP, 5061234567), (crea.uf(), crea.numero()));
+/// assert_eq!("CREA-SP 5061234567", crea.to_string());
+///
+/// let crea: Crea = "CREA/RJ 123456/D".parse()?;
+/// assert_eq!(Some('D'), crea.sufixo());
+/// assert_eq!("CREA-RJ 123456-D", crea.to_string());
+///
+/// # Ok::<(), ParseRegistroProfissionalError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Crea {
+    uf: Uf,
+    numero: u64,
+    sufixo: Option<u8>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseRegistroProfissionalError {
+    /// No UF at the start or at the end of the registration.
+    MissingUf,
+    InvalidUf(ParseUfError),
+    /// The number is missing, zero or too long.
+    InvalidNumber,
+    /// Byte at the zero based `position` of the input is neither a digit nor a thousands
+    /// separator of the number.
+    InvalidCharacter {
+        position: usize,
+    },
+    /// A letter after the number where the council does not use one.
+    InvalidSuffix,
+}
+
+impl Display for ParseRegistroProfissionalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseRegistroProfissionalError::MissingUf => {
+                f.write_str("invalid professional registration, missing the UF")
+            }
+            ParseRegistroProfissionalError::InvalidUf(err) => {
+                write!(f, "invalid professional registration, {}", err)
+            }
+            ParseRegistroProfissionalError::InvalidNumber => {
+                f.write_str("invalid professional registration, invalid number")
+            }
+            ParseRegistroProfissionalError::InvalidCharacter { position } => write!(
+                f,
+                "invalid professional registration, character at position {} is not a digit",
+                position
+            ),
+            ParseRegistroProfissionalError::InvalidSuffix => {
+                f.write_str("invalid professional registration, unexpected letter after the number")
+            }
+        }
+    }
+}
+
+impl ParseRegistroProfissionalError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseRegistroProfissionalError::InvalidCharacter { position } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseRegistroProfissionalError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseRegistroProfissionalError::InvalidUf(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Whether `byte` may stand between the council name, the UF and the number.
+const fn is_separator(byte: u8) -> bool {
+    matches!(byte, b'/' | b'-' | b' ' | b':')
+}
+
+/// Whether `bytes[start..start + 2]` look like a UF.
+const fn is_uf(bytes: &[u8], start: usize) -> bool {
+    bytes[start].is_ascii_alphabetic() && bytes[start + 1].is_ascii_alphabetic()
+}
+
+/// Parts of a registration of the council `conselho`: the UF, the number with at most
+/// `max_digits` digits and the letter after it, if any.
+const fn parse(
+    bytes: &[u8],
+    conselho: &[u8],
+    max_digits: usize,
+) -> Result<(Uf, u64, Option<u8>), ParseRegistroProfissionalError> {
+    let (mut start, mut end) = (0, bytes.len());
+    while start < end && bytes[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while start < end && bytes[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    if end - start >= conselho.len() {
+        let mut index = 0;
+        while index < conselho.len() && bytes[start + index].to_ascii_uppercase() == conselho[index]
+        {
+            index += 1;
+        }
+        if index == conselho.len() {
+            start += conselho.len();
+        }
+    }
+    while start < end && is_separator(bytes[start]) {
+        start += 1;
+    }
+    while start < end && is_separator(bytes[end - 1]) {
+        end -= 1;
+    }
+
+    let uf = if end - start >= 2 && is_uf(bytes, start) {
+        start += 2;
+        start - 2
+    } else if end - start >= 2 && is_uf(bytes, end - 2) {
+        end -= 2;
+        end
+    } else {
+        return Err(ParseRegistroProfissionalError::MissingUf);
+    };
+    let uf = match Uf::from_sigla(&[bytes[uf], bytes[uf + 1]]) {
+        Some(uf) => uf,
+        None => {
+            return Err(ParseRegistroProfissionalError::InvalidUf(
+                ParseUfError::Invalid,
+            ))
+        }
+    };
+    while start < end && is_separator(bytes[start]) {
+        start += 1;
+    }
+    while start < end && is_separator(bytes[end - 1]) {
+        end -= 1;
+    }
+
+    let mut sufixo = None;
+    if start < end && bytes[end - 1].is_ascii_alphabetic() {
+        sufixo = Some(bytes[end - 1].to_ascii_uppercase());
+        end -= 1;
+        while start < end && is_separator(bytes[end - 1]) {
+            end -= 1;
+        }
+    }
+    let mut value = 0u64;
+    let mut digits = 0;
+    let mut position = start;
+    while position < end {
+        let byte = bytes[position];
+        if byte != b'.' {
+            if !byte.is_ascii_digit() {
+                return Err(ParseRegistroProfissionalError::InvalidCharacter { position });
+            }
+            if digits == max_digits {
+                return Err(ParseRegistroProfissionalError::InvalidNumber);
+            }
+            value = value * 10 + (byte - b'0') as u64;
+            digits += 1;
+        }
+        position += 1;
+    }
+    if value == 0 {
+        return Err(ParseRegistroProfissionalError::InvalidNumber);
+    }
+    Ok((uf, value, sufixo))
+}
+
+/// `formatted` with the digits of `numero` chosen by `style` hidden. The recommended mask
+/// hides nothing, registrations being public.
+fn masked_with(formatted: &str, numero: u64, style: MaskStyle) -> String {
+    let len = digits(numero).len();
+    mask(formatted, style.visible(len, 0..len))
+}
+
+/// Every digit of `numero`, without leading zeros.
+fn digits(numero: u64) -> Vec<u8> {
+    numero.to_string().bytes().map(|b| b - b'0').collect()
+}
+
+impl Oab {
+    pub fn uf(self) -> Uf {
+        self.uf
+    }
+
+    pub fn numero(self) -> u32 {
+        self.numero
+    }
+
+    pub fn sufixo(self) -> Option<char> {
+        self.sufixo.map(char::from)
+    }
+
+    /// Formatted with the digits chosen by `style` hidden.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        masked_with(&self.to_string(), u64::from(self.numero), style)
+    }
+
+    /// Whether `s` is a registration in a format accepted by [`FromStr`].
+    pub fn is_valid(s: &str) -> bool {
+        s.parse::<Oab>().is_ok()
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`oab!`](crate::oab!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseRegistroProfissionalError> {
+        match parse(bytes, b"OAB", 6) {
+            Ok((uf, numero, sufixo)) => Ok(Oab {
+                uf,
+                numero: numero as u32,
+                sufixo,
+            }),
+            Err(err) => Err(err),
+        }
+    }
+    /// Random registration, with a letter after the number half of the time.
+    ///
+    /// ```
+    /// use bras::Oab;
+    ///
+    /// let registro = Oab::generate();
+    /// assert_eq!(Ok(registro), registro.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Oab::generate`], drawing from the given generator so runs can be reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+}
+
+impl FromStr for Oab {
+    type Err = ParseRegistroProfissionalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Oab::from_bytes(s.as_bytes())
+    }
+}
+
+impl Display for Oab {
+    /// "OAB/UF 000.000", followed by "-X" when there is a letter.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "OAB/{} ", self.uf)?;
+        if self.numero >= 1000 {
+            write!(f, "{}.{:03}", self.numero / 1000, self.numero % 1000)?;
+        } else {
+            write!(f, "{}", self.numero)?;
+        }
+        if let Some(sufixo) = self.sufixo() {
+            write!(f, "-{}", sufixo)?;
+        }
+        Ok(())
+    }
+}
+
+impl BrDocument for Oab {
+    fn kind(&self) -> &'static str {
+        "OAB"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        digits(u64::from(self.numero))
+    }
+
+    /// Registrations are public, so nothing is hidden.
+    fn masked(&self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Oab::is_valid(s)
+    }
+}
+
+impl Crm {
+    pub fn uf(self) -> Uf {
+        self.uf
+    }
+
+    pub fn numero(self) -> u32 {
+        self.numero
+    }
+
+    /// Formatted with the digits chosen by `style` hidden.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        masked_with(&self.to_string(), u64::from(self.numero), style)
+    }
+
+    /// Whether `s` is a registration in a format accepted by [`FromStr`].
+    pub fn is_valid(s: &str) -> bool {
+        s.parse::<Crm>().is_ok()
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`crm!`](crate::crm!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseRegistroProfissionalError> {
+        match parse(bytes, b"CRM", 7) {
+            Ok((uf, numero, None)) => Ok(Crm {
+                uf,
+                numero: numero as u32,
+            }),
+            Ok(_) => Err(ParseRegistroProfissionalError::InvalidSuffix),
+            Err(err) => Err(err),
+        }
+    }
+    /// Random registration, of any UF.
+    ///
+    /// ```
+    /// use bras::Crm;
+    ///
+    /// let registro = Crm::generate();
+    /// assert_eq!(Ok(registro), registro.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Crm::generate`], drawing from the given generator so runs can be reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+}
+
+impl FromStr for Crm {
+    type Err = ParseRegistroProfissionalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Crm::from_bytes(s.as_bytes())
+    }
+}
+
+impl Display for Crm {
+    /// "CRM/UF 000000".
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CRM/{} {}", self.uf, self.numero)
+    }
+}
+
+impl BrDocument for Crm {
+    fn kind(&self) -> &'static str {
+        "CRM"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        digits(u64::from(self.numero))
+    }
+
+    /// Registrations are public, so nothing is hidden.
+    fn masked(&self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Crm::is_valid(s)
+    }
+}
+
+impl Crea {
+    pub fn uf(self) -> Uf {
+        self.uf
+    }
+
+    pub fn numero(self) -> u64 {
+        self.numero
+    }
+
+    pub fn sufixo(self) -> Option<char> {
+        self.sufixo.map(char::from)
+    }
+
+    /// Formatted with the digits chosen by `style` hidden.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        masked_with(&self.to_string(), self.numero, style)
+    }
+
+    /// Whether `s` is a registration in a format accepted by [`FromStr`].
+    pub fn is_valid(s: &str) -> bool {
+        s.parse::<Crea>().is_ok()
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`crea!`](crate::crea!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseRegistroProfissionalError> {
+        match parse(bytes, b"CREA", 10) {
+            Ok((uf, numero, sufixo)) => Ok(Crea { uf, numero, sufixo }),
+            Err(err) => Err(err),
+        }
+    }
+    /// Random registration, with a letter after the number half of the time.
+    ///
+    /// ```
+    /// use bras::Crea;
+    ///
+    /// let registro = Crea::generate();
+    /// assert_eq!(Ok(registro), registro.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Crea::generate`], drawing from the given generator so runs can be reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+}
+
+impl FromStr for Crea {
+    type Err = ParseRegistroProfissionalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Crea::from_bytes(s.as_bytes())
+    }
+}
+
+impl Display for Crea {
+    /// "CREA-UF 0000000000", followed by "-X" when there is a letter.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CREA-{} {}", self.uf, self.numero)?;
+        if let Some(sufixo) = self.sufixo() {
+            write!(f, "-{}", sufixo)?;
+        }
+        Ok(())
+    }
+}
+
+impl BrDocument for Crea {
+    fn kind(&self) -> &'static str {
+        "CREA"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        digits(self.numero)
+    }
+
+    /// Registrations are public, so nothing is hidden.
+    fn masked(&self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Crea::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Oab> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Oab {
+        Oab {
+            uf: rng.gen(),
+            numero: rng.gen_range(1..1_000_000),
+            sufixo: random_sufixo(rng),
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Crm> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Crm {
+        Crm {
+            uf: rng.gen(),
+            numero: rng.gen_range(1..10_000_000),
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Crea> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Crea {
+        Crea {
+            uf: rng.gen(),
+            numero: rng.gen_range(1..10_000_000_000),
+            sufixo: random_sufixo(rng),
+        }
+    }
+}
+
+/// An upper case letter after the number half of the time.
+#[cfg(feature = "rand")]
+fn random_sufixo<R: rand::Rng + ?Sized>(rng: &mut R) -> Option<u8> {
+    if rng.gen() {
+        Some(rng.gen_range(b'A'..=b'Z'))
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Oab {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Oab {
+            uf: *u.choose(&Uf::ALL)?,
+            numero: u.int_in_range(1..=999_999)?,
+            sufixo: arbitrary_sufixo(u)?,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Oab {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Oab>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (
+            proptest::sample::select(Uf::ALL.to_vec()),
+            1..1_000_000u32,
+            proptest::option::of(b'A'..=b'Z'),
+        )
+            .prop_map(|(uf, numero, sufixo)| Oab { uf, numero, sufixo })
+            .boxed()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Crm {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Crm {
+            uf: *u.choose(&Uf::ALL)?,
+            numero: u.int_in_range(1..=9_999_999)?,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Crm {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Crm>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (proptest::sample::select(Uf::ALL.to_vec()), 1..10_000_000u32)
+            .prop_map(|(uf, numero)| Crm { uf, numero })
+            .boxed()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Crea {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Crea {
+            uf: *u.choose(&Uf::ALL)?,
+            numero: u.int_in_range(1..=9_999_999_999)?,
+            sufixo: arbitrary_sufixo(u)?,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Crea {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Crea>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (
+            proptest::sample::select(Uf::ALL.to_vec()),
+            1..10_000_000_000u64,
+            proptest::option::of(b'A'..=b'Z'),
+        )
+            .prop_map(|(uf, numero, sufixo)| Crea { uf, numero, sufixo })
+            .boxed()
+    }
+}
+
+/// An upper case letter after the number, or none.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_sufixo(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Option<u8>> {
+    Ok(if u.arbitrary()? {
+        Some(u.int_in_range(b'A'..=b'Z')?)
+    } else {
+        None
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_the_usual_ways_of_writing() {
+        let oab = Oab {
+            uf: Uf::SP,
+            numero: 123456,
+            sufixo: None,
+        };
+        for s in [
+            "OAB/SP 123.456",
+            "OAB/SP 123456",
+            "OAB-SP 123456",
+            "oab sp: 123456",
+            "123456/SP",
+            "123.456-SP",
+            "SP123456",
+            " SP 123456 ",
+        ] {
+            assert_eq!(Ok(oab), s.parse(), "{}", s);
+            assert_eq!(Ok(oab), oab.to_string().parse());
+        }
+        assert_eq!(Ok(1000), "CRM-SP 1.000".parse::<Crm>().map(Crm::numero));
+        assert_eq!("OAB/MG 999", "999/MG".parse::<Oab>().unwrap().to_string());
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        assert_eq!(
+            Err(ParseRegistroProfissionalError::MissingUf),
+            "OAB 123456".parse::<Oab>()
+        );
+        assert_eq!(
+            Err(ParseRegistroProfissionalError::InvalidUf(
+                ParseUfError::Invalid
+            )),
+            "OAB/XX 123456".parse::<Oab>()
+        );
+        assert_eq!(
+            Err(ParseRegistroProfissionalError::InvalidNumber),
+            "OAB/SP 1234567".parse::<Oab>()
+        );
+        assert_eq!(
+            Err(ParseRegistroProfissionalError::InvalidNumber),
+            "OAB/SP 000".parse::<Oab>()
+        );
+        assert_eq!(
+            Err(ParseRegistroProfissionalError::InvalidCharacter { position: 9 }),
+            "OAB/SP 12,345".parse::<Oab>()
+        );
+        assert_eq!(
+            Err(ParseRegistroProfissionalError::InvalidSuffix),
+            "CRM/SP 12345-A".parse::<Crm>()
+        );
+        assert_eq!(
+            Err(ParseRegistroProfissionalError::MissingUf),
+            "".parse::<Crea>()
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_registrations_are_valid() {
+        for _ in 0..1000 {
+            let oab = Oab::generate();
+            assert_eq!(Ok(oab), oab.to_string().parse());
+            let crm = Crm::generate();
+            assert_eq!(Ok(crm), crm.to_string().parse());
+            let crea = Crea::generate();
+            assert_eq!(Ok(crea), crea.to_string().parse());
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::mask::mask;
+use crate::{BrDocument, MaskStyle};
+
+/// Registro Nacional de Veículos Automotores, the number in the vehicle registration
+/// certificate (CRV and CRLV). It has 11 digits, the last one a check digit. Older
+/// certificates print 9 digits, which are the same number without the leading zeros.
+///
+/// # Examples
+/// ```
+/// use bras::Renavam;
+/// # use bras::ParseRenavamError;
+///
+/// let renavam: Renavam = "00639087922".parse()?;
+/// assert_eq!(renavam, "639087922".parse::<Renavam>()?);
+/// assert_eq!("00639087922", renavam.to_string());
+/// assert_eq!(639087922u64, u64::from(renavam));
+///
+/// # Ok::<(), ParseRenavamError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Renavam {
+    inner: u64,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseRenavamError {
+    /// Neither 11 digits nor the 9 of older certificates.
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter { position: usize },
+    /// Every digit is zero.
+    Zero,
+    /// The check digit does not match.
+    InvalidCheckDigit,
+}
+
+impl Display for ParseRenavamError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseRenavamError::InvalidLength { got } => write!(
+                f,
+                "invalid RENAVAM, expected 9 or 11 digits but got {}",
+                got
+            ),
+            ParseRenavamError::InvalidCharacter { position } => write!(
+                f,
+                "invalid RENAVAM, character at position {} is not a digit",
+                position
+            ),
+            ParseRenavamError::Zero => f.write_str("invalid RENAVAM, every digit is zero"),
+            ParseRenavamError::InvalidCheckDigit => {
+                f.write_str("invalid RENAVAM, the check digit does not match")
+            }
+        }
+    }
+}
+
+impl ParseRenavamError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseRenavamError::InvalidCharacter { position } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseRenavamError {}
+
+const WEIGHTS: [u64; 10] = [3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+
+impl Renavam {
+    /// The 11 digits, the last one being the check digit.
+    pub fn digits(self) -> [u8; 11] {
+        let mut digits = [0u8; 11];
+        let mut rest = self.inner;
+        for digit in digits.iter_mut().rev() {
+            *digit = (rest % 10) as u8;
+            rest /= 10;
+        }
+        digits
+    }
+
+    /// The 11 digits with all but the last 4 hidden, as in "*******7922".
+    ///
+    /// ```
+    /// use bras::Renavam;
+    ///
+    /// let renavam: Renavam = "00639087922".parse().unwrap();
+    /// assert_eq!("*******7922", renavam.masked());
+    /// ```
+    pub fn masked(self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    /// The 11 digits with the ones chosen by `style` hidden.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        mask(&self.to_string(), style.visible(11, 7..11))
+    }
+
+    /// Whether `s` is a valid RENAVAM in a format accepted by [`FromStr`].
+    pub const fn is_valid(s: &str) -> bool {
+        Renavam::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Random valid RENAVAM.
+    ///
+    /// ```
+    /// use bras::Renavam;
+    ///
+    /// let renavam = Renavam::generate();
+    /// assert_eq!(Ok(renavam), renavam.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Renavam::generate`], drawing from the given generator so runs can be
+    /// reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`renavam!`](crate::renavam!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseRenavamError> {
+        let padding = match bytes.len() {
+            11 => 0,
+            9 => 2,
+            got => return Err(ParseRenavamError::InvalidLength { got }),
+        };
+        let mut digits = [0u8; 11];
+        let mut position = 0;
+        while position < bytes.len() {
+            if !bytes[position].is_ascii_digit() {
+                return Err(ParseRenavamError::InvalidCharacter { position });
+            }
+            digits[padding + position] = bytes[position] - b'0';
+            position += 1;
+        }
+
+        let renavam = Renavam::complete(&digits);
+        if renavam.inner / 10 == 0 {
+            return Err(ParseRenavamError::Zero);
+        }
+        if renavam.inner % 10 != digits[10] as u64 {
+            return Err(ParseRenavamError::InvalidCheckDigit);
+        }
+        Ok(renavam)
+    }
+
+    /// Builds from the first 10 `digits`, computing the check digit.
+    const fn complete(digits: &[u8; 11]) -> Self {
+        let mut base = 0;
+        let mut sum = 0;
+        let mut position = 0;
+        while position < WEIGHTS.len() {
+            base = base * 10 + digits[position] as u64;
+            sum += digits[position] as u64 * WEIGHTS[position];
+            position += 1;
+        }
+        let check = match 11 - sum % 11 {
+            10 | 11 => 0,
+            check => check,
+        };
+        Renavam {
+            inner: base * 10 + check,
+        }
+    }
+}
+
+impl FromStr for Renavam {
+    type Err = ParseRenavamError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Renavam::from_bytes(s.as_bytes())
+    }
+}
+
+impl From<Renavam> for u64 {
+    fn from(renavam: Renavam) -> Self {
+        renavam.inner
+    }
+}
+
+impl Display for Renavam {
+    /// The 11 digits, zero padded.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:011}", self.inner)
+    }
+}
+
+impl BrDocument for Renavam {
+    fn kind(&self) -> &'static str {
+        "RENAVAM"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        Renavam::digits(*self).to_vec()
+    }
+
+    fn masked(&self) -> String {
+        Renavam::masked(*self)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Renavam::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Renavam> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Renavam {
+        Renavam::complete(&nonzero(core::array::from_fn(|_| rng.gen_range(0..10))))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Renavam {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut digits = [0u8; 11];
+        for digit in digits.iter_mut() {
+            *digit = u.int_in_range(0..=9)?;
+        }
+        Ok(Renavam::complete(&nonzero(digits)))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Renavam {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Renavam>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::array::uniform11(0u8..10)
+            .prop_map(|digits| Renavam::complete(&nonzero(digits)))
+            .boxed()
+    }
+}
+
+/// `digits` with the last digit before the check digit changed to 1 when every one before
+/// it is zero.
+#[cfg(any(feature = "arbitrary", feature = "rand"))]
+fn nonzero(mut digits: [u8; 11]) -> [u8; 11] {
+    if digits[..10].iter().all(|&digit| digit == 0) {
+        digits[9] = 1;
+    }
+    digits
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_formats() {
+        let renavam: Renavam = "63908792210".parse().unwrap();
+        assert_eq!(63908792210, u64::from(renavam));
+        assert_eq!([6, 3, 9, 0, 8, 7, 9, 2, 2, 1, 0], renavam.digits());
+        assert_eq!(Ok(renavam), renavam.to_string().parse());
+        assert!(Renavam::is_valid("00789123452"));
+        assert!(Renavam::is_valid("789123452"));
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let parse = |s: &str| s.parse::<Renavam>();
+        assert_eq!(
+            Err(ParseRenavamError::InvalidCheckDigit),
+            parse("63908792211")
+        );
+        assert_eq!(
+            Err(ParseRenavamError::InvalidLength { got: 10 }),
+            parse("6390879221")
+        );
+        assert_eq!(
+            Err(ParseRenavamError::InvalidCharacter { position: 3 }),
+            parse("639-8792210")
+        );
+        assert_eq!(Err(ParseRenavamError::Zero), parse("00000000000"));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_renavams_are_valid() {
+        for _ in 0..1000 {
+            let renavam = Renavam::generate();
+            assert_eq!(Ok(renavam), renavam.to_string().parse());
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::mask::mask;
+use crate::{BrDocument, MaskStyle};
+
+/// Registro Nacional de Transportadores Rodoviários de Cargas, the ANTT registration every
+/// paid road freight carrier needs, informed in the CT-e and the MDF-e. The number has 8
+/// digits, written with 9 by systems that pad it with a leading zero, and listings often
+/// put the carrier category before it.
+///
+/// # Examples
+/// ```
+/// use bras::{CategoriaTransportador, Rntrc};
+/// # use bras::ParseRntrcError;
+///
+/// let rntrc: Rntrc = "12345678".parse()?;
+/// assert_eq!(None, rntrc.categoria());
+/// assert_eq!(rntrc, "012345678".parse::<Rntrc>()?);
+///
+/// let rntrc: Rntrc = "ETC 012345678".parse()?;
+/// assert_eq!(Some(CategoriaTransportador::Etc), rntrc.categoria());
+/// assert_eq!(12345678, rntrc.numero());
+/// assert_eq!("ETC 12345678", rntrc.to_string());
+///
+/// # Ok::<(), ParseRntrcError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Rntrc {
+    numero: u32,
+    categoria: Option<CategoriaTransportador>,
+}
+
+/// Kinds of carriers the ANTT registers.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum CategoriaTransportador {
+    /// Transportador Autônomo de Cargas, a self-employed driver.
+    Tac,
+    /// Empresa de Transporte de Cargas, a company.
+    Etc,
+    /// Cooperativa de Transporte de Cargas.
+    Ctc,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseRntrcError {
+    /// Neither 8 digits nor 9 with a leading zero.
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter { position: usize },
+    /// Every digit is zero.
+    Zero,
+}
+
+impl Display for ParseRntrcError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseRntrcError::InvalidLength { got } => write!(
+                f,
+                "invalid RNTRC, expected 8 digits or 9 with a leading zero but got {}",
+                got
+            ),
+            ParseRntrcError::InvalidCharacter { position } => write!(
+                f,
+                "invalid RNTRC, character at position {} is not a digit",
+                position
+            ),
+            ParseRntrcError::Zero => f.write_str("invalid RNTRC, every digit is zero"),
+        }
+    }
+}
+
+impl ParseRntrcError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseRntrcError::InvalidCharacter { position } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseRntrcError {}
+
+impl CategoriaTransportador {
+    /// Abbreviation the ANTT uses, such as "TAC".
+    pub const fn sigla(self) -> &'static str {
+        match self {
+            CategoriaTransportador::Tac => "TAC",
+            CategoriaTransportador::Etc => "ETC",
+            CategoriaTransportador::Ctc => "CTC",
+        }
+    }
+}
+
+impl Display for CategoriaTransportador {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.sigla())
+    }
+}
+
+impl Rntrc {
+    /// The registration number, without the leading zero of the 9 digit form.
+    pub fn numero(self) -> u32 {
+        self.numero
+    }
+
+    /// The category written before the number, if any.
+    pub fn categoria(self) -> Option<CategoriaTransportador> {
+        self.categoria
+    }
+
+    /// The 8 digits, as the CT-e and the MDF-e take them.
+    pub fn digits(self) -> [u8; 8] {
+        let mut digits = [0u8; 8];
+        let mut rest = self.numero;
+        for digit in digits.iter_mut().rev() {
+            *digit = (rest % 10) as u8;
+            rest /= 10;
+        }
+        digits
+    }
+
+    /// Random RNTRC without a category. The number has no check digit, so it is not
+    /// necessarily assigned to a carrier.
+    ///
+    /// ```
+    /// use bras::Rntrc;
+    ///
+    /// let rntrc = Rntrc::generate();
+    /// assert_eq!(Ok(rntrc), rntrc.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Rntrc::generate`], drawing from the given generator so runs can be
+    /// reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// Formatted with the digits chosen by `style` hidden. The recommended mask hides
+    /// nothing, carriers being public records.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        mask(&self.to_string(), style.visible(8, 0..8))
+    }
+
+    /// Whether `s` is an RNTRC in a format accepted by [`FromStr`].
+    pub fn is_valid(s: &str) -> bool {
+        Rntrc::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`rntrc!`](crate::rntrc!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseRntrcError> {
+        let categoria = categoria(bytes);
+        let mut offset = 0;
+        if categoria.is_some() {
+            offset = 3;
+            while offset < bytes.len() && matches!(bytes[offset], b' ' | b'-' | b':') {
+                offset += 1;
+            }
+        }
+
+        let len = bytes.len() - offset;
+        let skipped = match len {
+            9 if bytes[offset] == b'0' => 1,
+            8 => 0,
+            got => return Err(ParseRntrcError::InvalidLength { got }),
+        };
+        let mut numero = 0;
+        let mut position = offset + skipped;
+        while position < bytes.len() {
+            let byte = bytes[position];
+            if !byte.is_ascii_digit() {
+                return Err(ParseRntrcError::InvalidCharacter { position });
+            }
+            numero = numero * 10 + (byte - b'0') as u32;
+            position += 1;
+        }
+        if numero == 0 {
+            return Err(ParseRntrcError::Zero);
+        }
+        Ok(Rntrc { numero, categoria })
+    }
+}
+
+/// Every category.
+#[cfg(feature = "arbitrary")]
+const CATEGORIAS: [CategoriaTransportador; 3] = [
+    CategoriaTransportador::Tac,
+    CategoriaTransportador::Etc,
+    CategoriaTransportador::Ctc,
+];
+
+/// Category whose abbreviation, in any case, starts `bytes`.
+const fn categoria(bytes: &[u8]) -> Option<CategoriaTransportador> {
+    if bytes.len() < 3 {
+        return None;
+    }
+    let prefix = [
+        bytes[0].to_ascii_uppercase(),
+        bytes[1].to_ascii_uppercase(),
+        bytes[2].to_ascii_uppercase(),
+    ];
+    match &prefix {
+        b"TAC" => Some(CategoriaTransportador::Tac),
+        b"ETC" => Some(CategoriaTransportador::Etc),
+        b"CTC" => Some(CategoriaTransportador::Ctc),
+        _ => None,
+    }
+}
+
+impl FromStr for Rntrc {
+    type Err = ParseRntrcError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rntrc::from_bytes(s.as_bytes())
+    }
+}
+
+impl Display for Rntrc {
+    /// The 8 digits, after the category when known.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(categoria) = self.categoria {
+            write!(f, "{} ", categoria)?;
+        }
+        write!(f, "{:08}", self.numero)
+    }
+}
+
+impl BrDocument for Rntrc {
+    fn kind(&self) -> &'static str {
+        "RNTRC"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        Rntrc::digits(*self).to_vec()
+    }
+
+    fn masked(&self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Rntrc::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Rntrc> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Rntrc {
+        Rntrc {
+            numero: rng.gen_range(1..100_000_000),
+            categoria: None,
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CategoriaTransportador {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(&CATEGORIAS).copied()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Rntrc {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Rntrc {
+            numero: u.int_in_range(1..=99_999_999)?,
+            categoria: u.arbitrary()?,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Rntrc {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Rntrc>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (
+            1..100_000_000u32,
+            proptest::option::of(proptest::sample::select(CATEGORIAS.to_vec())),
+        )
+            .prop_map(|(numero, categoria)| Rntrc { numero, categoria })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_categories() {
+        let rntrc: Rntrc = "tac-00123456".parse().unwrap();
+        assert_eq!(Some(CategoriaTransportador::Tac), rntrc.categoria());
+        assert_eq!("TAC 00123456", rntrc.to_string());
+        assert_eq!(Ok(rntrc), rntrc.to_string().parse());
+        assert_eq!(
+            Some(CategoriaTransportador::Ctc),
+            "CTC:12345678".parse::<Rntrc>().unwrap().categoria()
+        );
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let parse = |s: &str| s.parse::<Rntrc>();
+        assert_eq!(
+            Err(ParseRntrcError::InvalidLength { got: 9 }),
+            parse("112345678")
+        );
+        assert_eq!(
+            Err(ParseRntrcError::InvalidLength { got: 7 }),
+            parse("ETC 1234567")
+        );
+        assert_eq!(
+            Err(ParseRntrcError::InvalidCharacter { position: 6 }),
+            parse("ETC 01x345678")
+        );
+        assert_eq!(Err(ParseRntrcError::Zero), parse("00000000"));
+        assert_eq!(
+            Err(ParseRntrcError::InvalidCharacter { position: 0 }),
+            parse("ABC12345")
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_rntrcs_are_valid() {
+        for _ in 0..1000 {
+            let rntrc = Rntrc::generate();
+            assert_eq!(Ok(rntrc), rntrc.to_string().parse());
+        }
+    }
+}
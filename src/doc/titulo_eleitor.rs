@@ -0,0 +1,414 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::mask::mask;
+use crate::{BrDocument, MaskStyle, Uf};
+
+/// Título de eleitor, the voter registration: an 8 digit sequential number, 2 digits for
+/// the federative unit where the voter registered and 2 check digits.
+///
+/// # Examples
+/// ```
+/// use bras::{TituloEleitor, Uf};
+/// # use bras::ParseTituloEleitorError;
+///
+/// let titulo: TituloEleitor = "0043 5687 0906".parse()?;
+/// assert_eq!(titulo, "004356870906".parse::<TituloEleitor>()?);
+/// assert_eq!(Some(Uf::SC), titulo.uf());
+/// assert_eq!("004356870906", format!("{:#}", titulo));
+///
+/// # Ok::<(), ParseTituloEleitorError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(not(feature = "redact-debug"), derive(Debug))]
+pub struct TituloEleitor {
+    inner: u64,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseTituloEleitorError {
+    /// Neither 12 digits nor 14 characters formatted as "0000 0000 0000".
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter { position: usize },
+    /// 14 characters with something other than a space at the zero based `position`.
+    InvalidSeparator { position: usize },
+    /// The 9th and 10th digits are not the code of a federative unit, from 01 to 27, nor
+    /// 28 for voters abroad.
+    InvalidUf,
+    /// The first (`which` is 1) or second (`which` is 2) check digit does not match.
+    InvalidCheckDigit { which: u8 },
+}
+
+impl Display for ParseTituloEleitorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseTituloEleitorError::InvalidLength { got } => write!(
+                f,
+                "invalid título de eleitor, expected 12 digits or 14 characters but got {}",
+                got
+            ),
+            ParseTituloEleitorError::InvalidCharacter { position } => write!(
+                f,
+                "invalid título de eleitor, character at position {} is not a digit",
+                position
+            ),
+            ParseTituloEleitorError::InvalidSeparator { position } => write!(
+                f,
+                "invalid título de eleitor, expected ' ' at position {}",
+                position
+            ),
+            ParseTituloEleitorError::InvalidUf => {
+                f.write_str("invalid título de eleitor, unknown federative unit code")
+            }
+            ParseTituloEleitorError::InvalidCheckDigit { which: 1 } => {
+                f.write_str("invalid título de eleitor, the first check digit does not match")
+            }
+            ParseTituloEleitorError::InvalidCheckDigit { .. } => {
+                f.write_str("invalid título de eleitor, the second check digit does not match")
+            }
+        }
+    }
+}
+
+impl ParseTituloEleitorError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseTituloEleitorError::InvalidCharacter { position }
+            | ParseTituloEleitorError::InvalidSeparator { position } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseTituloEleitorError {}
+
+/// Federative units in the order of their electoral codes, 01 being SP.
+const UFS: [Uf; 27] = [
+    Uf::SP,
+    Uf::MG,
+    Uf::RJ,
+    Uf::RS,
+    Uf::BA,
+    Uf::PR,
+    Uf::CE,
+    Uf::PE,
+    Uf::SC,
+    Uf::GO,
+    Uf::MA,
+    Uf::PB,
+    Uf::PA,
+    Uf::ES,
+    Uf::PI,
+    Uf::RN,
+    Uf::AL,
+    Uf::MT,
+    Uf::MS,
+    Uf::DF,
+    Uf::SE,
+    Uf::AM,
+    Uf::RO,
+    Uf::AC,
+    Uf::AP,
+    Uf::RR,
+    Uf::TO,
+];
+
+/// Electoral code of the voters registered abroad.
+const EXTERIOR: u8 = 28;
+
+impl TituloEleitor {
+    /// Federative unit where the voter registered, `None` for voters abroad.
+    pub fn uf(self) -> Option<Uf> {
+        let code = (self.inner / 100 % 100) as usize;
+        UFS.get(code.wrapping_sub(1)).copied()
+    }
+
+    /// The 12 digits, the last two being the check digits.
+    pub fn digits(self) -> [u8; 12] {
+        let mut digits = [0u8; 12];
+        let mut rest = self.inner;
+        for digit in digits.iter_mut().rev() {
+            *digit = (rest % 10) as u8;
+            rest /= 10;
+        }
+        digits
+    }
+
+    /// Formatted with the sequential number hidden, as in "**** **** 0906".
+    ///
+    /// ```
+    /// use bras::TituloEleitor;
+    ///
+    /// let titulo: TituloEleitor = "004356870906".parse().unwrap();
+    /// assert_eq!("**** **** 0906", titulo.masked());
+    /// ```
+    pub fn masked(self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    /// Formatted with the digits chosen by `style` hidden.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        mask(&self.to_string(), style.visible(12, 8..12))
+    }
+
+    /// Whether `s` is a valid título de eleitor in a format accepted by [`FromStr`].
+    pub const fn is_valid(s: &str) -> bool {
+        TituloEleitor::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Random valid título de eleitor registered in `uf`.
+    ///
+    /// ```
+    /// use bras::{TituloEleitor, Uf};
+    ///
+    /// let titulo = TituloEleitor::generate(Uf::MG);
+    /// assert_eq!(Some(Uf::MG), titulo.uf());
+    /// assert_eq!(Ok(titulo), titulo.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate(uf: Uf) -> Self {
+        Self::generate_with(uf, &mut rand::thread_rng())
+    }
+
+    /// Like [`TituloEleitor::generate`], drawing from the given generator so runs can be
+    /// reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(uf: Uf, rng: &mut R) -> Self {
+        TituloEleitor::complete(rng.gen_range(0..100_000_000), code(uf))
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`titulo_eleitor!`](crate::titulo_eleitor!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseTituloEleitorError> {
+        let formatted = match bytes.len() {
+            12 => false,
+            14 => true,
+            got => return Err(ParseTituloEleitorError::InvalidLength { got }),
+        };
+        let mut inner = 0;
+        let mut position = 0;
+        while position < bytes.len() {
+            let byte = bytes[position];
+            if formatted && matches!(position, 4 | 9) {
+                if byte != b' ' {
+                    return Err(ParseTituloEleitorError::InvalidSeparator { position });
+                }
+            } else if byte.is_ascii_digit() {
+                inner = inner * 10 + (byte - b'0') as u64;
+            } else {
+                return Err(ParseTituloEleitorError::InvalidCharacter { position });
+            }
+            position += 1;
+        }
+
+        let uf = (inner / 100 % 100) as u8;
+        if uf == 0 || uf > EXTERIOR {
+            return Err(ParseTituloEleitorError::InvalidUf);
+        }
+        let titulo = TituloEleitor::complete(inner / 10_000, uf);
+        if titulo.inner / 10 % 10 != inner / 10 % 10 {
+            return Err(ParseTituloEleitorError::InvalidCheckDigit { which: 1 });
+        }
+        if titulo.inner % 10 != inner % 10 {
+            return Err(ParseTituloEleitorError::InvalidCheckDigit { which: 2 });
+        }
+        Ok(titulo)
+    }
+
+    /// Builds from the sequential `numero` and the electoral code of the federative unit,
+    /// computing the check digits.
+    const fn complete(numero: u64, uf: u8) -> Self {
+        let mut sum = 0;
+        let mut rest = numero;
+        let mut weight = 9;
+        while weight >= 2 {
+            sum += rest % 10 * weight;
+            rest /= 10;
+            weight -= 1;
+        }
+        let first = check_digit(sum, uf);
+        let second = check_digit((uf / 10 * 7 + uf % 10 * 8) as u64 + first * 9, uf);
+        TituloEleitor {
+            inner: (numero * 100 + uf as u64) * 100 + first * 10 + second,
+        }
+    }
+}
+
+/// The check digit for `sum`. SP and MG use 1 where the others use 0 for a sum divisible
+/// by 11.
+const fn check_digit(sum: u64, uf: u8) -> u64 {
+    match sum % 11 {
+        10 => 0,
+        0 if uf <= 2 => 1,
+        rest => rest,
+    }
+}
+
+/// Electoral code of `uf`.
+#[cfg(any(feature = "arbitrary", feature = "rand"))]
+fn code(uf: Uf) -> u8 {
+    let index = UFS.iter().position(|&other| other == uf);
+    index.expect("every federative unit has a code") as u8 + 1
+}
+
+impl FromStr for TituloEleitor {
+    type Err = ParseTituloEleitorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TituloEleitor::from_bytes(s.as_bytes())
+    }
+}
+
+impl From<TituloEleitor> for u64 {
+    fn from(titulo: TituloEleitor) -> Self {
+        titulo.inner
+    }
+}
+
+impl Display for TituloEleitor {
+    /// "0000 0000 0000", or only the 12 digits with the alternate flag.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            return write!(f, "{:012}", self.inner);
+        }
+        write!(
+            f,
+            "{:04} {:04} {:04}",
+            self.inner / 100_000_000,
+            self.inner / 10_000 % 10_000,
+            self.inner % 10_000
+        )
+    }
+}
+
+/// With the `redact-debug` feature, prints the [masked](TituloEleitor::masked) título so it
+/// cannot leak into logs through `{:?}`.
+#[cfg(feature = "redact-debug")]
+impl core::fmt::Debug for TituloEleitor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "TituloEleitor({})", self.masked())
+    }
+}
+
+impl BrDocument for TituloEleitor {
+    fn kind(&self) -> &'static str {
+        "título de eleitor"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        TituloEleitor::digits(*self).to_vec()
+    }
+
+    fn masked(&self) -> String {
+        TituloEleitor::masked(*self)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        TituloEleitor::is_valid(s)
+    }
+}
+
+/// Registered in any federative unit or abroad.
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<TituloEleitor> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> TituloEleitor {
+        TituloEleitor::complete(rng.gen_range(0..100_000_000), rng.gen_range(1..=EXTERIOR))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for TituloEleitor {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(TituloEleitor::complete(
+            u.int_in_range(0..=99_999_999)?,
+            u.int_in_range(1..=EXTERIOR)?,
+        ))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for TituloEleitor {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<TituloEleitor>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (0..100_000_000u64, 1..=EXTERIOR)
+            .prop_map(|(numero, uf)| TituloEleitor::complete(numero, uf))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_formats() {
+        let titulo: TituloEleitor = "0043 5687 0906".parse().unwrap();
+        assert_eq!(4356870906, u64::from(titulo));
+        assert_eq!(Ok(titulo), "004356870906".parse());
+        assert_eq!([0, 0, 4, 3, 5, 6, 8, 7, 0, 9, 0, 6], titulo.digits());
+        assert_eq!("0043 5687 0906", titulo.to_string());
+
+        // SP and MG use 1 for a sum divisible by 11.
+        let titulo: TituloEleitor = "102304530108".parse().unwrap();
+        assert_eq!(Some(Uf::SP), titulo.uf());
+        assert_eq!(None, "000000002801".parse::<TituloEleitor>().unwrap().uf());
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let parse = |s: &str| s.parse::<TituloEleitor>();
+        assert_eq!(
+            Err(ParseTituloEleitorError::InvalidCheckDigit { which: 1 }),
+            parse("004356870916")
+        );
+        assert_eq!(
+            Err(ParseTituloEleitorError::InvalidCheckDigit { which: 2 }),
+            parse("004356870907")
+        );
+        assert_eq!(
+            Err(ParseTituloEleitorError::InvalidUf),
+            parse("004356872906")
+        );
+        assert_eq!(
+            Err(ParseTituloEleitorError::InvalidUf),
+            parse("004356870006")
+        );
+        assert_eq!(
+            Err(ParseTituloEleitorError::InvalidSeparator { position: 9 }),
+            parse("0043 5687-0906")
+        );
+        assert_eq!(
+            Err(ParseTituloEleitorError::InvalidCharacter { position: 2 }),
+            parse("00x356870906")
+        );
+        assert_eq!(
+            Err(ParseTituloEleitorError::InvalidLength { got: 11 }),
+            parse("04356870906")
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_titulos_are_valid() {
+        for uf in Uf::ALL {
+            let titulo = TituloEleitor::generate(uf);
+            assert_eq!(Some(uf), titulo.uf());
+            assert_eq!(Ok(titulo), titulo.to_string().parse());
+        }
+        for _ in 0..1000 {
+            let titulo: TituloEleitor = rand::random();
+            assert_eq!(Ok(titulo), titulo.to_string().parse());
+        }
+    }
+}
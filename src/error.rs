@@ -0,0 +1,342 @@
+use core::fmt::Display;
+
+#[cfg(feature = "dates")]
+use crate::data_br::ParseDataError;
+#[cfg(feature = "registro-profissional")]
+use crate::ParseRegistroProfissionalError;
+#[cfg(feature = "cpf")]
+use crate::{ParseCaepfError, ParseCpfError, ParsePisError, ParseTituloEleitorError};
+#[cfg(feature = "cnpj")]
+use crate::{
+    ParseCeiError, ParseChaveAcessoError, ParseChaveNfseError, ParseCnesError, ParseCnoError,
+    ParseCnpjError,
+};
+#[cfg(feature = "geo")]
+use crate::{ParseCepError, ParseTelefoneError, ParseTipoLogradouroError};
+#[cfg(feature = "veiculos")]
+use crate::{ParseChassiError, ParseRenavamError, ParseRntrcError};
+use crate::{ParseCodigoMunicipioError, ParseCompetenciaError, ParseRealError, ParseUfError};
+
+/// Any error of the crate, for applications that parse several kinds of values and want a
+/// single error type. The wrapped error is available through
+/// [`source`](core::error::Error::source).
+///
+/// # Examples
+/// ```
+/// use bras::{CodigoMunicipio, Real};
+///
+/// fn parse(valor: &str, municipio: &str) -> Result<(Real, CodigoMunicipio), bras::Error> {
+///     Ok((valor.parse()?, municipio.parse()?))
+/// }
+///
+/// let err = parse("R$ 1.234,56", "4106903").unwrap_err();
+/// assert_eq!("could not parse the IBGE municipality code", err.to_string());
+/// assert!(matches!(err, bras::Error::CodigoMunicipio(_)));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[cfg(feature = "cpf")]
+    Caepf(ParseCaepfError),
+    #[cfg(feature = "geo")]
+    Cep(ParseCepError),
+    #[cfg(feature = "cnpj")]
+    Cei(ParseCeiError),
+    #[cfg(feature = "veiculos")]
+    Chassi(ParseChassiError),
+    #[cfg(feature = "cnpj")]
+    ChaveAcesso(ParseChaveAcessoError),
+    #[cfg(feature = "cnpj")]
+    ChaveNfse(ParseChaveNfseError),
+    #[cfg(feature = "cnpj")]
+    Cnes(ParseCnesError),
+    #[cfg(feature = "cnpj")]
+    Cnpj(ParseCnpjError),
+    #[cfg(feature = "cnpj")]
+    Cno(ParseCnoError),
+    CodigoMunicipio(ParseCodigoMunicipioError),
+    Competencia(ParseCompetenciaError),
+    #[cfg(feature = "cpf")]
+    Cpf(ParseCpfError),
+    #[cfg(feature = "dates")]
+    Data(ParseDataError),
+    #[cfg(feature = "cpf")]
+    Pis(ParsePisError),
+    Real(ParseRealError),
+    #[cfg(feature = "registro-profissional")]
+    RegistroProfissional(ParseRegistroProfissionalError),
+    #[cfg(feature = "veiculos")]
+    Renavam(ParseRenavamError),
+    #[cfg(feature = "veiculos")]
+    Rntrc(ParseRntrcError),
+    #[cfg(feature = "geo")]
+    Telefone(ParseTelefoneError),
+    #[cfg(feature = "geo")]
+    TipoLogradouro(ParseTipoLogradouroError),
+    #[cfg(feature = "cpf")]
+    TituloEleitor(ParseTituloEleitorError),
+    Uf(ParseUfError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let what = match self {
+            #[cfg(feature = "cpf")]
+            Error::Caepf(_) => "CAEPF",
+            #[cfg(feature = "geo")]
+            Error::Cep(_) => "CEP",
+            #[cfg(feature = "cnpj")]
+            Error::Cei(_) => "CEI",
+            #[cfg(feature = "veiculos")]
+            Error::Chassi(_) => "chassis number",
+            #[cfg(feature = "cnpj")]
+            Error::ChaveAcesso(_) => "access key",
+            #[cfg(feature = "cnpj")]
+            Error::ChaveNfse(_) => "NFS-e access key",
+            #[cfg(feature = "cnpj")]
+            Error::Cnes(_) => "CNES",
+            #[cfg(feature = "cnpj")]
+            Error::Cnpj(_) => "CNPJ",
+            #[cfg(feature = "cnpj")]
+            Error::Cno(_) => "CNO",
+            Error::CodigoMunicipio(_) => "IBGE municipality code",
+            Error::Competencia(_) => "competência",
+            #[cfg(feature = "cpf")]
+            Error::Cpf(_) => "CPF",
+            #[cfg(feature = "dates")]
+            Error::Data(_) => "date",
+            #[cfg(feature = "cpf")]
+            Error::Pis(_) => "PIS",
+            Error::Real(_) => "amount",
+            #[cfg(feature = "registro-profissional")]
+            Error::RegistroProfissional(_) => "professional registration",
+            #[cfg(feature = "veiculos")]
+            Error::Renavam(_) => "RENAVAM",
+            #[cfg(feature = "veiculos")]
+            Error::Rntrc(_) => "RNTRC",
+            #[cfg(feature = "geo")]
+            Error::Telefone(_) => "phone number",
+            #[cfg(feature = "geo")]
+            Error::TipoLogradouro(_) => "street type",
+            #[cfg(feature = "cpf")]
+            Error::TituloEleitor(_) => "título de eleitor",
+            Error::Uf(_) => "federative unit",
+        };
+        write!(f, "could not parse the {}", what)
+    }
+}
+
+impl Error {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    ///
+    /// ```
+    /// # #[cfg(feature = "cnpj")]
+    /// # {
+    /// let err = bras::Error::from("51.204.09876-71".parse::<bras::Cno>().unwrap_err());
+    /// assert_eq!(Some(12), err.position());
+    /// # }
+    /// ```
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            #[cfg(feature = "cpf")]
+            Error::Caepf(err) => err.position(),
+            #[cfg(feature = "cnpj")]
+            Error::Cei(err) => err.position(),
+            #[cfg(feature = "cnpj")]
+            Error::ChaveAcesso(err) => err.position(),
+            #[cfg(feature = "cnpj")]
+            Error::ChaveNfse(err) => err.position(),
+            #[cfg(feature = "veiculos")]
+            Error::Chassi(err) => err.position(),
+            #[cfg(feature = "geo")]
+            Error::Cep(err) => err.position(),
+            #[cfg(feature = "cnpj")]
+            Error::Cnes(err) => err.position(),
+            #[cfg(feature = "cnpj")]
+            Error::Cnpj(err) => err.position(),
+            #[cfg(feature = "cnpj")]
+            Error::Cno(err) => err.position(),
+            #[cfg(feature = "cpf")]
+            Error::Cpf(err) => err.position(),
+            #[cfg(feature = "cpf")]
+            Error::Pis(err) => err.position(),
+            #[cfg(feature = "registro-profissional")]
+            Error::RegistroProfissional(err) => err.position(),
+            #[cfg(feature = "veiculos")]
+            Error::Renavam(err) => err.position(),
+            #[cfg(feature = "veiculos")]
+            Error::Rntrc(err) => err.position(),
+            #[cfg(feature = "geo")]
+            Error::Telefone(err) => err.position(),
+            #[cfg(feature = "cpf")]
+            Error::TituloEleitor(err) => err.position(),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "cpf")]
+            Error::Caepf(err) => Some(err),
+            #[cfg(feature = "geo")]
+            Error::Cep(err) => Some(err),
+            #[cfg(feature = "cnpj")]
+            Error::Cei(err) => Some(err),
+            #[cfg(feature = "veiculos")]
+            Error::Chassi(err) => Some(err),
+            #[cfg(feature = "cnpj")]
+            Error::ChaveAcesso(err) => Some(err),
+            #[cfg(feature = "cnpj")]
+            Error::ChaveNfse(err) => Some(err),
+            #[cfg(feature = "cnpj")]
+            Error::Cnes(err) => Some(err),
+            #[cfg(feature = "cnpj")]
+            Error::Cnpj(err) => Some(err),
+            #[cfg(feature = "cnpj")]
+            Error::Cno(err) => Some(err),
+            Error::CodigoMunicipio(err) => Some(err),
+            Error::Competencia(err) => Some(err),
+            #[cfg(feature = "cpf")]
+            Error::Cpf(err) => Some(err),
+            #[cfg(feature = "dates")]
+            Error::Data(err) => Some(err),
+            #[cfg(feature = "cpf")]
+            Error::Pis(err) => Some(err),
+            Error::Real(err) => Some(err),
+            #[cfg(feature = "registro-profissional")]
+            Error::RegistroProfissional(err) => Some(err),
+            #[cfg(feature = "veiculos")]
+            Error::Renavam(err) => Some(err),
+            #[cfg(feature = "veiculos")]
+            Error::Rntrc(err) => Some(err),
+            #[cfg(feature = "geo")]
+            Error::Telefone(err) => Some(err),
+            #[cfg(feature = "geo")]
+            Error::TipoLogradouro(err) => Some(err),
+            #[cfg(feature = "cpf")]
+            Error::TituloEleitor(err) => Some(err),
+            Error::Uf(err) => Some(err),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($($err:ty => $variant:ident,)*) => {
+        $(
+            impl From<$err> for Error {
+                fn from(err: $err) -> Self {
+                    Error::$variant(err)
+                }
+            }
+        )*
+    };
+}
+
+impl_from! {
+    ParseCodigoMunicipioError => CodigoMunicipio,
+    ParseCompetenciaError => Competencia,
+    ParseRealError => Real,
+    ParseUfError => Uf,
+}
+
+#[cfg(feature = "cnpj")]
+impl_from! {
+    ParseCeiError => Cei,
+    ParseChaveAcessoError => ChaveAcesso,
+    ParseChaveNfseError => ChaveNfse,
+    ParseCnesError => Cnes,
+    ParseCnpjError => Cnpj,
+    ParseCnoError => Cno,
+}
+
+#[cfg(feature = "cpf")]
+impl_from! {
+    ParseCaepfError => Caepf,
+    ParseCpfError => Cpf,
+    ParsePisError => Pis,
+    ParseTituloEleitorError => TituloEleitor,
+}
+
+#[cfg(feature = "registro-profissional")]
+impl_from! {
+    ParseRegistroProfissionalError => RegistroProfissional,
+}
+
+#[cfg(feature = "veiculos")]
+impl_from! {
+    ParseChassiError => Chassi,
+    ParseRenavamError => Renavam,
+    ParseRntrcError => Rntrc,
+}
+
+#[cfg(feature = "geo")]
+impl_from! {
+    ParseCepError => Cep,
+    ParseTelefoneError => Telefone,
+    ParseTipoLogradouroError => TipoLogradouro,
+}
+
+#[cfg(feature = "dates")]
+impl_from! {
+    ParseDataError => Data,
+}
+
+#[cfg(all(
+    test,
+    feature = "cnpj",
+    feature = "cpf",
+    feature = "registro-profissional",
+    feature = "veiculos"
+))]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+    use core::error::Error as _;
+
+    #[test]
+    fn source_is_the_parse_error() {
+        let err = Error::from("984.844.854-00".parse::<crate::Cpf>().unwrap_err());
+        assert_eq!("could not parse the CPF", err.to_string());
+        assert_eq!(
+            "invalid CPF, the first check digit does not match",
+            err.source().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn position_of_each_type() {
+        let caepf = "984.844.854/0x1-67".parse::<crate::Caepf>().unwrap_err();
+        assert_eq!(Some(13), Error::from(caepf).position());
+        let cei = "51.204.0987x/71".parse::<crate::Cei>().unwrap_err();
+        assert_eq!(Some(11), Error::from(cei).position());
+        let chave = "3524 311222333000181550010000001231123456788";
+        let chave_acesso = chave.parse::<crate::ChaveAcesso>().unwrap_err();
+        assert_eq!(Some(4), Error::from(chave_acesso).position());
+        let chave = "3550308221122233300018100000000001232403123456789x";
+        let chave_nfse = chave.parse::<crate::ChaveNfse>().unwrap_err();
+        assert_eq!(Some(49), Error::from(chave_nfse).position());
+        let chassi = "9BWZOZ377VT004251".parse::<crate::Chassi>().unwrap_err();
+        assert_eq!(Some(4), Error::from(chassi).position());
+        let cnes = "207 485".parse::<crate::Cnes>().unwrap_err();
+        assert_eq!(Some(3), Error::from(cnes).position());
+        let cnpj = "12.ABC.345/01DE-3x".parse::<crate::Cnpj>().unwrap_err();
+        assert_eq!(Some(17), Error::from(cnpj).position());
+        let cno = "51.204.09876-71".parse::<crate::Cno>().unwrap_err();
+        assert_eq!(Some(12), Error::from(cno).position());
+        let oab = "OAB/SP 12,345".parse::<crate::Oab>().unwrap_err();
+        assert_eq!(Some(9), Error::from(oab).position());
+        let pis = "120.28384-76-1".parse::<crate::Pis>().unwrap_err();
+        assert_eq!(Some(9), Error::from(pis).position());
+        let renavam = "639-8792210".parse::<crate::Renavam>().unwrap_err();
+        assert_eq!(Some(3), Error::from(renavam).position());
+        let rntrc = "ETC 01x345678".parse::<crate::Rntrc>().unwrap_err();
+        assert_eq!(Some(6), Error::from(rntrc).position());
+        let titulo = "0043 5687-0906"
+            .parse::<crate::TituloEleitor>()
+            .unwrap_err();
+        assert_eq!(Some(9), Error::from(titulo).position());
+    }
+}
@@ -0,0 +1,491 @@
+//! Identifiers of eSocial event submissions: the `Id` attribute each event is signed
+//! with, and the receipt number (`nrRecibo`) returned when it is accepted.
+//!
+//! # Examples
+//! ```
+//! use bras::esocial::{IdEvento, Recibo, TipoInscricao};
+//! use chrono::NaiveDate;
+//!
+//! let id: IdEvento = "ID1112223330000002024031514302500001".parse()?;
+//! assert_eq!(TipoInscricao::Cnpj, id.tipo_inscricao());
+//! assert_eq!("11222333000000", id.inscricao());
+//! assert_eq!(
+//!     NaiveDate::from_ymd_opt(2024, 3, 15).unwrap().and_hms_opt(14, 30, 25).unwrap(),
+//!     id.gerado()
+//! );
+//! assert_eq!(1, id.sequencial());
+//!
+//! let id: IdEvento = "ID2984844854390002024031514302500002".parse()?;
+//! assert_eq!("984.844.854-39", id.cpf().unwrap().to_string());
+//!
+//! let recibo: Recibo = "1.2.0000000000012345678".parse()?;
+//! assert_eq!(12345678, recibo.numero());
+//! assert_eq!("1.2.0000000000012345678", recibo.to_string());
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+
+use crate::Cpf;
+
+/// Kind of registration of the employer identified in the event.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum TipoInscricao {
+    /// `1`, the CNPJ, either its 8 digit root or all 14 digits.
+    Cnpj,
+    /// `2`, the CPF of an employer person.
+    Cpf,
+}
+
+/// Event identifier, "ID" followed by the kind of registration, the registration number
+/// padded to 14 digits with zeros on the right, the generation timestamp as
+/// "aaaammddhhmmss" and a 5 digit sequential number.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct IdEvento {
+    tipo: TipoInscricao,
+    inscricao: [u8; 14],
+    gerado: NaiveDateTime,
+    sequencial: u32,
+}
+
+/// Receipt number, formatted as "0.0.0000000000000000000".
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Recibo {
+    ambiente: u8,
+    grupo: u8,
+    numero: u64,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseIdEventoError {
+    /// Not 36 characters long.
+    InvalidLength { got: usize },
+    /// Does not start with "ID".
+    InvalidPrefix,
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter { position: usize },
+    /// The kind of registration is neither 1 nor 2.
+    InvalidTipoInscricao,
+    /// A CPF with invalid check digits, or not padded with zeros.
+    InvalidInscricao,
+    /// The timestamp is not a valid date and time.
+    InvalidTimestamp,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseReciboError {
+    /// Not 23 characters long.
+    InvalidLength { got: usize },
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter { position: usize },
+    /// Something other than '.' at the zero based `position`.
+    InvalidSeparator { position: usize },
+}
+
+impl Display for ParseIdEventoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseIdEventoError::InvalidLength { got } => write!(
+                f,
+                "invalid eSocial event ID, expected 36 characters but got {}",
+                got
+            ),
+            ParseIdEventoError::InvalidPrefix => {
+                f.write_str("invalid eSocial event ID, expected it to start with \"ID\"")
+            }
+            ParseIdEventoError::InvalidCharacter { position } => write!(
+                f,
+                "invalid eSocial event ID, character at position {} is not a digit",
+                position
+            ),
+            ParseIdEventoError::InvalidTipoInscricao => {
+                f.write_str("invalid eSocial event ID, unknown kind of registration")
+            }
+            ParseIdEventoError::InvalidInscricao => {
+                f.write_str("invalid eSocial event ID, invalid registration number")
+            }
+            ParseIdEventoError::InvalidTimestamp => {
+                f.write_str("invalid eSocial event ID, invalid timestamp")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseIdEventoError {}
+
+impl Display for ParseReciboError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseReciboError::InvalidLength { got } => write!(
+                f,
+                "invalid eSocial receipt, expected 23 characters but got {}",
+                got
+            ),
+            ParseReciboError::InvalidCharacter { position } => write!(
+                f,
+                "invalid eSocial receipt, character at position {} is not a digit",
+                position
+            ),
+            ParseReciboError::InvalidSeparator { position } => write!(
+                f,
+                "invalid eSocial receipt, expected '.' at position {}",
+                position
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ParseReciboError {}
+
+impl IdEvento {
+    pub fn tipo_inscricao(&self) -> TipoInscricao {
+        self.tipo
+    }
+
+    /// The 14 digits of the registration number, padding included.
+    pub fn inscricao(&self) -> &str {
+        core::str::from_utf8(&self.inscricao).unwrap_or_default()
+    }
+
+    /// The employer CPF, when the kind of registration is [`TipoInscricao::Cpf`].
+    pub fn cpf(&self) -> Option<Cpf> {
+        match self.tipo {
+            TipoInscricao::Cpf => Cpf::from_bytes(&self.inscricao[..11]).ok(),
+            TipoInscricao::Cnpj => None,
+        }
+    }
+
+    /// When the event was generated, in the local time of the employer.
+    pub fn gerado(&self) -> NaiveDateTime {
+        self.gerado
+    }
+
+    /// Distinguishes events generated in the same second, starting at 1.
+    pub fn sequencial(&self) -> u32 {
+        self.sequencial
+    }
+
+    /// ID of the employer with `cpf`, or else with the 14 `digits`, from 0 to 9, generated
+    /// `segundo` seconds after midnight of the given day.
+    #[cfg(feature = "arbitrary")]
+    fn with_fields(
+        cpf: Option<Cpf>,
+        digits: [u8; 14],
+        (ano, mes, dia): (i32, u32, u32),
+        segundo: u32,
+        sequencial: u32,
+    ) -> Option<Self> {
+        let (tipo, inscricao) = match cpf {
+            Some(cpf) => {
+                let mut inscricao = [b'0'; 14];
+                for (ascii, digit) in inscricao.iter_mut().zip(cpf.digits()) {
+                    *ascii = b'0' + digit;
+                }
+                (TipoInscricao::Cpf, inscricao)
+            }
+            None => (TipoInscricao::Cnpj, digits.map(|digit| b'0' + digit)),
+        };
+        let gerado = NaiveDate::from_ymd_opt(ano, mes, dia)?.and_time(
+            chrono::NaiveTime::from_num_seconds_from_midnight_opt(segundo, 0)?,
+        );
+        Some(IdEvento {
+            tipo,
+            inscricao,
+            gerado,
+            sequencial,
+        })
+    }
+}
+
+impl FromStr for IdEvento {
+    type Err = ParseIdEventoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 36 {
+            return Err(ParseIdEventoError::InvalidLength { got: bytes.len() });
+        }
+        if &bytes[..2] != b"ID" {
+            return Err(ParseIdEventoError::InvalidPrefix);
+        }
+        if let Some(position) = bytes[2..].iter().position(|b| !b.is_ascii_digit()) {
+            return Err(ParseIdEventoError::InvalidCharacter {
+                position: position + 2,
+            });
+        }
+
+        let mut inscricao = [0; 14];
+        inscricao.copy_from_slice(&bytes[3..17]);
+        let tipo = match bytes[2] {
+            b'1' => TipoInscricao::Cnpj,
+            b'2' => TipoInscricao::Cpf,
+            _ => return Err(ParseIdEventoError::InvalidTipoInscricao),
+        };
+        if tipo == TipoInscricao::Cpf
+            && (&inscricao[11..] != b"000" || Cpf::from_bytes(&inscricao[..11]).is_err())
+        {
+            return Err(ParseIdEventoError::InvalidInscricao);
+        }
+
+        let campo = |range: core::ops::Range<usize>| numero(&bytes[range]) as u32;
+        let gerado = NaiveDate::from_ymd_opt(campo(17..21) as i32, campo(21..23), campo(23..25))
+            .and_then(|data| data.and_hms_opt(campo(25..27), campo(27..29), campo(29..31)))
+            .ok_or(ParseIdEventoError::InvalidTimestamp)?;
+
+        Ok(IdEvento {
+            tipo,
+            inscricao,
+            gerado,
+            sequencial: campo(31..36),
+        })
+    }
+}
+
+impl Display for IdEvento {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let tipo = match self.tipo {
+            TipoInscricao::Cnpj => 1,
+            TipoInscricao::Cpf => 2,
+        };
+        let gerado = self.gerado;
+        write!(
+            f,
+            "ID{}{}{:04}{:02}{:02}{:02}{:02}{:02}{:05}",
+            tipo,
+            self.inscricao(),
+            gerado.year(),
+            gerado.month(),
+            gerado.day(),
+            gerado.hour(),
+            gerado.minute(),
+            gerado.second(),
+            self.sequencial
+        )
+    }
+}
+
+impl Recibo {
+    /// First digit of the receipt.
+    pub fn ambiente(&self) -> u8 {
+        self.ambiente
+    }
+
+    /// Second digit of the receipt.
+    pub fn grupo(&self) -> u8 {
+        self.grupo
+    }
+
+    /// The last 19 digits of the receipt.
+    pub fn numero(&self) -> u64 {
+        self.numero
+    }
+}
+
+impl FromStr for Recibo {
+    type Err = ParseReciboError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 23 {
+            return Err(ParseReciboError::InvalidLength { got: bytes.len() });
+        }
+        for (position, &byte) in bytes.iter().enumerate() {
+            match position {
+                1 | 3 if byte != b'.' => {
+                    return Err(ParseReciboError::InvalidSeparator { position })
+                }
+                1 | 3 => {}
+                _ if !byte.is_ascii_digit() => {
+                    return Err(ParseReciboError::InvalidCharacter { position })
+                }
+                _ => {}
+            }
+        }
+        Ok(Recibo {
+            ambiente: bytes[0] - b'0',
+            grupo: bytes[2] - b'0',
+            numero: numero(&bytes[4..]),
+        })
+    }
+}
+
+impl Display for Recibo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}.{:019}", self.ambiente, self.grupo, self.numero)
+    }
+}
+
+/// Value of ASCII digits, at most 19 of them.
+fn numero(digits: &[u8]) -> u64 {
+    digits
+        .iter()
+        .fold(0, |acc, &digit| acc * 10 + u64::from(digit - b'0'))
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IdEvento {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let cpf = u.arbitrary()?;
+        let mut digits = [0u8; 14];
+        for digit in digits.iter_mut() {
+            *digit = u.int_in_range(0..=9)?;
+        }
+        let dia = (
+            u.int_in_range(0..=9999)?,
+            u.int_in_range(1..=12)?,
+            u.int_in_range(1..=28)?,
+        );
+        let segundo = u.int_in_range(0..=86_399)?;
+        let sequencial = u.int_in_range(0..=99_999)?;
+        IdEvento::with_fields(cpf, digits, dia, segundo, sequencial)
+            .ok_or(arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for IdEvento {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<IdEvento>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (
+            proptest::option::of(proptest::arbitrary::any::<Cpf>()),
+            proptest::array::uniform14(0u8..10),
+            (0..=9999i32, 1..=12u32, 1..=28u32),
+            0..86_400u32,
+            0..100_000u32,
+        )
+            .prop_filter_map(
+                "valid timestamp",
+                |(cpf, digits, dia, segundo, sequencial)| {
+                    IdEvento::with_fields(cpf, digits, dia, segundo, sequencial)
+                },
+            )
+            .boxed()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Recibo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Recibo {
+            ambiente: u.int_in_range(0..=9)?,
+            grupo: u.int_in_range(0..=9)?,
+            numero: u.int_in_range(0..=MAX_NUMERO)?,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Recibo {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Recibo>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (0..10u8, 0..10u8, 0..=MAX_NUMERO)
+            .prop_map(|(ambiente, grupo, numero)| Recibo {
+                ambiente,
+                grupo,
+                numero,
+            })
+            .boxed()
+    }
+}
+
+/// Largest receipt number, 19 digits.
+#[cfg(feature = "arbitrary")]
+const MAX_NUMERO: u64 = 9_999_999_999_999_999_999;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn id_evento_roundtrip() {
+        for id in [
+            "ID1112223330000002024031514302500001",
+            "ID1112223330001812024123123595999999",
+            "ID2984844854390002024022900000000001",
+        ] {
+            assert_eq!(id, id.parse::<IdEvento>().unwrap().to_string());
+        }
+        let id: IdEvento = "ID1112223330000002024031514302500001".parse().unwrap();
+        assert_eq!(None, id.cpf());
+    }
+
+    #[test]
+    fn reject_invalid_id_evento() {
+        let parse = |s: &str| s.parse::<IdEvento>();
+        assert_eq!(
+            Err(ParseIdEventoError::InvalidLength { got: 35 }),
+            parse("ID111222333000000202403151430250001")
+        );
+        assert_eq!(
+            Err(ParseIdEventoError::InvalidPrefix),
+            parse("Id1112223330000002024031514302500001")
+        );
+        assert_eq!(
+            Err(ParseIdEventoError::InvalidCharacter { position: 20 }),
+            parse("ID111222333000000202-031514302500001")
+        );
+        assert_eq!(
+            Err(ParseIdEventoError::InvalidTipoInscricao),
+            parse("ID3112223330000002024031514302500001")
+        );
+        assert_eq!(
+            Err(ParseIdEventoError::InvalidInscricao),
+            parse("ID2984844854300002024031514302500001")
+        );
+        assert_eq!(
+            Err(ParseIdEventoError::InvalidInscricao),
+            parse("ID2984844854390012024031514302500001")
+        );
+        assert_eq!(
+            Err(ParseIdEventoError::InvalidTimestamp),
+            parse("ID1112223330000002023022914302500001")
+        );
+    }
+
+    #[test]
+    fn reject_invalid_recibo() {
+        let parse = |s: &str| s.parse::<Recibo>();
+        assert_eq!(
+            Err(ParseReciboError::InvalidLength { got: 22 }),
+            parse("1.2.000000000001234567")
+        );
+        assert_eq!(
+            Err(ParseReciboError::InvalidSeparator { position: 3 }),
+            parse("1.2-0000000000012345678")
+        );
+        assert_eq!(
+            Err(ParseReciboError::InvalidCharacter { position: 0 }),
+            parse("A.2.0000000000012345678")
+        );
+        let recibo = parse("1.2.9999999999999999999").unwrap();
+        assert_eq!((1, 2), (recibo.ambiente(), recibo.grupo()));
+        assert_eq!(9_999_999_999_999_999_999, recibo.numero());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    proptest::proptest! {
+        #[test]
+        fn arbitrary_values_round_trip(
+            id in proptest::arbitrary::any::<IdEvento>(),
+            recibo in proptest::arbitrary::any::<Recibo>(),
+        ) {
+            proptest::prop_assert_eq!(Ok(id), id.to_string().parse());
+            proptest::prop_assert_eq!(Ok(recibo), recibo.to_string().parse());
+        }
+    }
+}
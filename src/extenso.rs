@@ -0,0 +1,416 @@
+//! Numbers written out in Brazilian Portuguese (por extenso).
+//!
+//! # Examples
+//! ```
+//! use bras::extenso::{cardinal, decimal, Genero};
+//!
+//! assert_eq!("mil duzentos e trinta e quatro", cardinal(1234, Genero::Masculino));
+//! assert_eq!("duas mil e duzentas", cardinal(2200, Genero::Feminino));
+//! assert_eq!("três inteiros e catorze centésimos", decimal(314, 2));
+//! ```
+//!
+//! ## Ordinals
+//! ```
+//! use bras::extenso::{ordinal, ordinal_abreviado, Genero};
+//!
+//! assert_eq!("1º", ordinal_abreviado(1, Genero::Masculino));
+//! assert_eq!(Some("primeira".to_string()), ordinal(1, Genero::Feminino));
+//! assert_eq!("23ª", ordinal_abreviado(23, Genero::Feminino));
+//! assert_eq!(Some("vigésima terceira".to_string()), ordinal(23, Genero::Feminino));
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Grammatical gender of the noun being counted ("dois reais", "duas pessoas").
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum Genero {
+    #[default]
+    Masculino,
+    Feminino,
+}
+
+const UNIDADES: [&str; 20] = [
+    "zero",
+    "um",
+    "dois",
+    "três",
+    "quatro",
+    "cinco",
+    "seis",
+    "sete",
+    "oito",
+    "nove",
+    "dez",
+    "onze",
+    "doze",
+    "treze",
+    "catorze",
+    "quinze",
+    "dezesseis",
+    "dezessete",
+    "dezoito",
+    "dezenove",
+];
+
+const DEZENAS: [&str; 10] = [
+    "",
+    "",
+    "vinte",
+    "trinta",
+    "quarenta",
+    "cinquenta",
+    "sessenta",
+    "setenta",
+    "oitenta",
+    "noventa",
+];
+
+const CENTENAS: [&str; 10] = [
+    "",
+    "cento",
+    "duzentos",
+    "trezentos",
+    "quatrocentos",
+    "quinhentos",
+    "seiscentos",
+    "setecentos",
+    "oitocentos",
+    "novecentos",
+];
+
+/// (singular, plural) of each power of one thousand, starting from 10^6.
+const ESCALAS: [(&str, &str); 5] = [
+    ("milhão", "milhões"),
+    ("bilhão", "bilhões"),
+    ("trilhão", "trilhões"),
+    ("quatrilhão", "quatrilhões"),
+    ("quintilhão", "quintilhões"),
+];
+
+const FRACOES: [(&str, &str); 9] = [
+    ("décimo", "décimos"),
+    ("centésimo", "centésimos"),
+    ("milésimo", "milésimos"),
+    ("décimo de milésimo", "décimos de milésimo"),
+    ("centésimo de milésimo", "centésimos de milésimo"),
+    ("milionésimo", "milionésimos"),
+    ("décimo de milionésimo", "décimos de milionésimo"),
+    ("centésimo de milionésimo", "centésimos de milionésimo"),
+    ("bilionésimo", "bilionésimos"),
+];
+
+const ORDINAIS_UNIDADES: [&str; 10] = [
+    "", "primeiro", "segundo", "terceiro", "quarto", "quinto", "sexto", "sétimo", "oitavo", "nono",
+];
+
+const ORDINAIS_DEZENAS: [&str; 10] = [
+    "",
+    "décimo",
+    "vigésimo",
+    "trigésimo",
+    "quadragésimo",
+    "quinquagésimo",
+    "sexagésimo",
+    "septuagésimo",
+    "octogésimo",
+    "nonagésimo",
+];
+
+const ORDINAIS_CENTENAS: [&str; 10] = [
+    "",
+    "centésimo",
+    "ducentésimo",
+    "trecentésimo",
+    "quadringentésimo",
+    "quingentésimo",
+    "sexcentésimo",
+    "septingentésimo",
+    "octingentésimo",
+    "nongentésimo",
+];
+
+/// Cardinal number in words, agreeing with the gender of the counted noun.
+///
+/// ```
+/// use bras::extenso::{cardinal, Genero};
+///
+/// assert_eq!("zero", cardinal(0, Genero::Masculino));
+/// assert_eq!("cem", cardinal(100, Genero::Masculino));
+/// assert_eq!("cento e uma", cardinal(101, Genero::Feminino));
+/// assert_eq!("mil e trinta e quatro", cardinal(1034, Genero::Masculino));
+/// assert_eq!(
+///     "dois milhões duzentas e uma mil e duas",
+///     cardinal(2_201_002, Genero::Feminino)
+/// );
+/// assert_eq!("menos quinze", cardinal(-15, Genero::Masculino));
+/// ```
+pub fn cardinal(n: i64, genero: Genero) -> String {
+    let words = cardinal_unsigned(n.unsigned_abs(), genero, Estilo::Corrido);
+    if n < 0 {
+        format!("menos {}", words)
+    } else {
+        words
+    }
+}
+
+/// Decimal number given as an integer `valor` with `casas` decimal places,
+/// e.g. `decimal(314, 2)` for 3,14.
+///
+/// # Panics
+///
+/// If `casas` is greater than 9.
+///
+/// ```
+/// use bras::extenso::decimal;
+///
+/// assert_eq!("um inteiro e cinco décimos", decimal(15, 1));
+/// assert_eq!("zero inteiro e um milésimo", decimal(1, 3));
+/// assert_eq!("doze inteiros", decimal(1200, 2));
+/// assert_eq!("menos dois inteiros e vinte e cinco centésimos", decimal(-225, 2));
+/// ```
+pub fn decimal(valor: i64, casas: u32) -> String {
+    assert!(casas as usize <= FRACOES.len(), "at most 9 decimal places");
+    let absolute = valor.unsigned_abs();
+    let divisor = 10u64.pow(casas);
+    let inteiro = absolute / divisor;
+    let fracao = absolute % divisor;
+
+    let mut words = String::new();
+    if valor < 0 {
+        words.push_str("menos ");
+    }
+    words.push_str(&cardinal_unsigned(
+        inteiro,
+        Genero::Masculino,
+        Estilo::Corrido,
+    ));
+    words.push_str(if inteiro > 1 { " inteiros" } else { " inteiro" });
+    if fracao > 0 {
+        let (singular, plural) = FRACOES[casas as usize - 1];
+        words.push_str(" e ");
+        words.push_str(&cardinal_unsigned(
+            fracao,
+            Genero::Masculino,
+            Estilo::Corrido,
+        ));
+        words.push(' ');
+        words.push_str(if fracao == 1 { singular } else { plural });
+    }
+    words
+}
+
+/// How the groups of thousands are written.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) enum Estilo {
+    /// "mil duzentos e trinta e quatro"
+    Corrido,
+    /// "um mil, duzentos e trinta e quatro", as written on cheques to prevent tampering.
+    Cheque,
+}
+
+/// Ordinal number in words, for 1 to 999.
+///
+/// ```
+/// use bras::extenso::{ordinal, Genero};
+///
+/// assert_eq!(Some("décimo".to_string()), ordinal(10, Genero::Masculino));
+/// assert_eq!(
+///     Some("centésima quinquagésima primeira".to_string()),
+///     ordinal(151, Genero::Feminino)
+/// );
+/// assert_eq!(None, ordinal(0, Genero::Masculino));
+/// assert_eq!(None, ordinal(1000, Genero::Masculino));
+/// ```
+pub fn ordinal(n: u32, genero: Genero) -> Option<String> {
+    if !(1..=999).contains(&n) {
+        return None;
+    }
+    let words: Vec<String> = [
+        ORDINAIS_CENTENAS[(n / 100) as usize],
+        ORDINAIS_DEZENAS[(n / 10 % 10) as usize],
+        ORDINAIS_UNIDADES[(n % 10) as usize],
+    ]
+    .iter()
+    .filter(|word| !word.is_empty())
+    .map(|word| match genero {
+        Genero::Masculino => word.to_string(),
+        Genero::Feminino => format!("{}a", &word[..word.len() - 1]),
+    })
+    .collect();
+    Some(words.join(" "))
+}
+
+/// Ordinal written with digits and the ordinal indicator, "1º" or "1ª".
+pub fn ordinal_abreviado(n: u64, genero: Genero) -> String {
+    match genero {
+        Genero::Masculino => format!("{}º", n),
+        Genero::Feminino => format!("{}ª", n),
+    }
+}
+
+pub(crate) fn cardinal_unsigned(n: u64, genero: Genero, estilo: Estilo) -> String {
+    if n == 0 {
+        return UNIDADES[0].to_string();
+    }
+
+    let mut groups = Vec::new();
+    let mut rest = n;
+    while rest > 0 {
+        groups.push((rest % 1000) as u16);
+        rest /= 1000;
+    }
+
+    let mut parts: Vec<String> = Vec::new();
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        let part = match scale {
+            0 => hundreds(group, genero),
+            1 if group == 1 && estilo == Estilo::Corrido => "mil".to_string(),
+            1 => format!("{} mil", hundreds(group, genero)),
+            _ => {
+                let (singular, plural) = ESCALAS[scale - 2];
+                let noun = if group == 1 { singular } else { plural };
+                format!("{} {}", hundreds(group, Genero::Masculino), noun)
+            }
+        };
+        parts.push(part);
+    }
+
+    // The last group is joined with "e" when it is below one hundred or a round hundred,
+    // as in "mil e duzentos" and "mil e trinta", but "mil duzentos e trinta".
+    let last = groups.iter().position(|&g| g != 0).expect("n is not zero");
+    let joined_with_e = parts.len() > 1 && (groups[last] < 100 || groups[last].is_multiple_of(100));
+    let tail = if joined_with_e { parts.pop() } else { None };
+    let mut words = parts.join(match estilo {
+        Estilo::Corrido => " ",
+        Estilo::Cheque => ", ",
+    });
+    if let Some(tail) = tail {
+        words.push_str(" e ");
+        words.push_str(&tail);
+    }
+    words
+}
+
+/// Words for 1..=999.
+fn hundreds(n: u16, genero: Genero) -> String {
+    if n == 100 {
+        return "cem".to_string();
+    }
+    let centena = (n / 100) as usize;
+    let resto = n % 100;
+
+    let mut words = Vec::new();
+    if centena > 0 {
+        let centena = CENTENAS[centena];
+        if genero == Genero::Feminino && centena.ends_with("os") {
+            words.push(format!("{}as", &centena[..centena.len() - 2]));
+        } else {
+            words.push(centena.to_string());
+        }
+    }
+    if resto >= 20 {
+        words.push(DEZENAS[(resto / 10) as usize].to_string());
+        if !resto.is_multiple_of(10) {
+            words.push(unit(resto % 10, genero).to_string());
+        }
+    } else if resto > 0 {
+        words.push(unit(resto, genero).to_string());
+    }
+    words.join(" e ")
+}
+
+fn unit(n: u16, genero: Genero) -> &'static str {
+    match (n, genero) {
+        (1, Genero::Feminino) => "uma",
+        (2, Genero::Feminino) => "duas",
+        _ => UNIDADES[n as usize],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn small_numbers() {
+        let expected = [
+            (1, "um"),
+            (2, "dois"),
+            (10, "dez"),
+            (14, "catorze"),
+            (16, "dezesseis"),
+            (20, "vinte"),
+            (21, "vinte e um"),
+            (99, "noventa e nove"),
+            (110, "cento e dez"),
+            (200, "duzentos"),
+            (999, "novecentos e noventa e nove"),
+        ];
+        for (n, words) in expected {
+            assert_eq!(words, cardinal(n, Genero::Masculino));
+        }
+    }
+
+    #[test]
+    fn large_numbers() {
+        let expected = [
+            (1000, "mil"),
+            (1001, "mil e um"),
+            (1100, "mil e cem"),
+            (1234, "mil duzentos e trinta e quatro"),
+            (21000, "vinte e um mil"),
+            (100_000, "cem mil"),
+            (1_000_000, "um milhão"),
+            (1_000_100, "um milhão e cem"),
+            (2_001_000, "dois milhões e mil"),
+            (
+                1_234_567,
+                "um milhão duzentos e trinta e quatro mil quinhentos e sessenta e sete",
+            ),
+            (3_000_000_000, "três bilhões"),
+            (
+                i64::MAX,
+                "nove quintilhões duzentos e vinte e três quatrilhões trezentos e setenta e dois \
+                 trilhões trinta e seis bilhões oitocentos e cinquenta e quatro milhões setecentos \
+                 e setenta e cinco mil oitocentos e sete",
+            ),
+        ];
+        for (n, words) in expected {
+            assert_eq!(words, cardinal(n, Genero::Masculino));
+        }
+    }
+
+    #[test]
+    fn ordinals() {
+        let expected = [
+            (1, "primeiro"),
+            (2, "segundo"),
+            (7, "sétimo"),
+            (11, "décimo primeiro"),
+            (20, "vigésimo"),
+            (99, "nonagésimo nono"),
+            (100, "centésimo"),
+            (305, "trecentésimo quinto"),
+            (999, "nongentésimo nonagésimo nono"),
+        ];
+        for (n, words) in expected {
+            assert_eq!(Some(words.to_string()), ordinal(n, Genero::Masculino));
+        }
+        assert_eq!(Some("sétima".to_string()), ordinal(7, Genero::Feminino));
+    }
+
+    #[test]
+    fn feminine_agreement() {
+        assert_eq!("uma", cardinal(1, Genero::Feminino));
+        assert_eq!("vinte e duas", cardinal(22, Genero::Feminino));
+        assert_eq!("trezentas e duas", cardinal(302, Genero::Feminino));
+        assert_eq!("duas mil", cardinal(2000, Genero::Feminino));
+        assert_eq!("um milhão e uma", cardinal(1_000_001, Genero::Feminino));
+        assert_eq!("duzentos milhões", cardinal(200_000_000, Genero::Feminino));
+    }
+}
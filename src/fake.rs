@@ -0,0 +1,157 @@
+//! Coherent fake personal data, for seeding staging databases and demos.
+//!
+//! # Examples
+//! ```
+//! use bras::fake::PessoaFake;
+//!
+//! let pessoa = PessoaFake::generate();
+//! assert_eq!(pessoa.cep.uf(), Some(pessoa.uf));
+//! assert!(pessoa.uf.ddds().contains(&pessoa.ddd()));
+//! ```
+
+use chrono::{Datelike, NaiveDate};
+use rand::distributions::{Distribution, Standard};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{Cep, Cpf, Uf};
+
+const PRIMEIROS_NOMES: [&str; 24] = [
+    "Ana",
+    "Antônio",
+    "Beatriz",
+    "Bruno",
+    "Camila",
+    "Carlos",
+    "Fernanda",
+    "Francisco",
+    "Gabriela",
+    "Gustavo",
+    "Helena",
+    "João",
+    "Juliana",
+    "Lucas",
+    "Luiza",
+    "Marcos",
+    "Maria",
+    "Mateus",
+    "Patrícia",
+    "Paulo",
+    "Rafael",
+    "Sofia",
+    "Tiago",
+    "Vitória",
+];
+
+const SOBRENOMES: [&str; 20] = [
+    "Almeida",
+    "Alves",
+    "Barbosa",
+    "Carvalho",
+    "Costa",
+    "Ferreira",
+    "Fernandes",
+    "Gomes",
+    "Lima",
+    "Lopes",
+    "Martins",
+    "Oliveira",
+    "Pereira",
+    "Ribeiro",
+    "Rodrigues",
+    "Santos",
+    "Silva",
+    "Soares",
+    "Souza",
+    "Vieira",
+];
+
+/// Fake person whose CEP, state and phone area code agree with each other.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PessoaFake {
+    pub nome: String,
+    pub cpf: Cpf,
+    /// Between 1940 and 2005, so the person is an adult.
+    pub nascimento: NaiveDate,
+    pub cep: Cep,
+    pub uf: Uf,
+    /// Mobile number formatted as "(11) 91234-5678".
+    pub telefone: String,
+}
+
+impl PessoaFake {
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`PessoaFake::generate`], drawing from the given generator so runs can be
+    /// reproduced.
+    ///
+    /// ```
+    /// use bras::fake::PessoaFake;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let pessoas: Vec<_> = (0..10).map(|_| PessoaFake::generate_with(&mut rng)).collect();
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// assert_eq!(pessoas[0], PessoaFake::generate_with(&mut rng));
+    /// ```
+    pub fn generate_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// Area code of the phone number.
+    pub fn ddd(&self) -> u8 {
+        self.telefone[1..3].parse().expect("formatted phone number")
+    }
+}
+
+impl Distribution<PessoaFake> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PessoaFake {
+        let nome = format!(
+            "{} {} {}",
+            PRIMEIROS_NOMES.choose(rng).expect("non-empty"),
+            SOBRENOMES.choose(rng).expect("non-empty"),
+            SOBRENOMES.choose(rng).expect("non-empty"),
+        );
+
+        let primeiro = NaiveDate::from_ymd_opt(1940, 1, 1).expect("valid date");
+        let ultimo = NaiveDate::from_ymd_opt(2005, 12, 31).expect("valid date");
+        let dia = rng.gen_range(primeiro.num_days_from_ce()..=ultimo.num_days_from_ce());
+        let nascimento = NaiveDate::from_num_days_from_ce_opt(dia).expect("valid date");
+
+        let cep: Cep = rng.gen();
+        let uf = cep.uf().expect("generated CEPs are in an assigned range");
+        let ddd = uf.ddds().choose(rng).expect("every UF has a DDD");
+        let numero: u32 = rng.gen_range(0..100_000_000);
+        let telefone = format!("({}) 9{:04}-{:04}", ddd, numero / 10_000, numero % 10_000);
+
+        PessoaFake {
+            nome,
+            cpf: rng.gen(),
+            nascimento,
+            cep,
+            uf,
+            telefone,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn profiles_are_coherent() {
+        for _ in 0..200 {
+            let pessoa = PessoaFake::generate();
+            assert_eq!(Some(pessoa.uf), pessoa.cep.uf());
+            assert!(pessoa.uf.ddds().contains(&pessoa.ddd()));
+            assert_eq!(15, pessoa.telefone.len());
+            assert!((1940..=2005).contains(&pessoa.nascimento.year()));
+            assert_eq!(3, pessoa.nome.split(' ').count());
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use alloc::vec::Vec;
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+use super::holidays;
+
+/// Holidays of the last year looked up, so walking through consecutive dates doesn't
+/// rebuild the calendar for every day.
+struct Calendario {
+    year: i32,
+    feriados: Vec<NaiveDate>,
+}
+
+impl Calendario {
+    fn new() -> Self {
+        Calendario {
+            year: i32::MIN,
+            feriados: Vec::new(),
+        }
+    }
+
+    fn is_business_day(&mut self, date: NaiveDate) -> bool {
+        if matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            return false;
+        }
+        if date.year() != self.year {
+            self.year = date.year();
+            self.feriados = holidays(self.year).iter().map(|f| f.data).collect();
+        }
+        !self.feriados.contains(&date)
+    }
+}
+
+/// Whether the date is a weekday that is not in [`holidays`], the calendar used for
+/// settlement and CDI accrual.
+///
+/// ```
+/// use bras::feriados::is_business_day;
+/// use chrono::NaiveDate;
+///
+/// assert!(is_business_day(NaiveDate::from_ymd_opt(2024, 3, 28).unwrap()));
+/// // Sexta-feira Santa
+/// assert!(!is_business_day(NaiveDate::from_ymd_opt(2024, 3, 29).unwrap()));
+/// // Saturday
+/// assert!(!is_business_day(NaiveDate::from_ymd_opt(2024, 3, 30).unwrap()));
+/// ```
+pub fn is_business_day(date: NaiveDate) -> bool {
+    Calendario::new().is_business_day(date)
+}
+
+/// Moves `n` business days forward (or backward, when negative) from `date`.
+///
+/// ```
+/// use bras::feriados::add_business_days;
+/// use chrono::NaiveDate;
+///
+/// // D+2 from a wednesday before Sexta-feira Santa
+/// let date = NaiveDate::from_ymd_opt(2024, 3, 27).unwrap();
+/// assert_eq!(NaiveDate::from_ymd_opt(2024, 4, 1), add_business_days(date, 2));
+/// assert_eq!(NaiveDate::from_ymd_opt(2024, 3, 26), add_business_days(date, -1));
+/// ```
+pub fn add_business_days(date: NaiveDate, n: i64) -> Option<NaiveDate> {
+    let mut calendario = Calendario::new();
+    let mut current = date;
+    for _ in 0..n.unsigned_abs() {
+        loop {
+            current = if n > 0 {
+                current.checked_add_days(Days::new(1))?
+            } else {
+                current.checked_sub_days(Days::new(1))?
+            };
+            if calendario.is_business_day(current) {
+                break;
+            }
+        }
+    }
+    Some(current)
+}
+
+/// Business day conventions for shifting dates that fall on weekends or holidays.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Adjustment {
+    /// Next business day.
+    Following,
+    /// Next business day, unless it falls in the next month, in which case the previous one.
+    ModifiedFollowing,
+    /// Previous business day.
+    Preceding,
+}
+
+/// Shifts `date` to a business day according to `convention`; business days are returned
+/// unchanged.
+///
+/// ```
+/// use bras::feriados::{adjust, Adjustment};
+/// use chrono::NaiveDate;
+///
+/// // Saturday, 2024-08-31
+/// let vencimento = NaiveDate::from_ymd_opt(2024, 8, 31).unwrap();
+/// assert_eq!(NaiveDate::from_ymd_opt(2024, 9, 2), adjust(vencimento, Adjustment::Following));
+/// assert_eq!(
+///     NaiveDate::from_ymd_opt(2024, 8, 30),
+///     adjust(vencimento, Adjustment::ModifiedFollowing)
+/// );
+/// assert_eq!(NaiveDate::from_ymd_opt(2024, 8, 30), adjust(vencimento, Adjustment::Preceding));
+/// ```
+pub fn adjust(date: NaiveDate, convention: Adjustment) -> Option<NaiveDate> {
+    if is_business_day(date) {
+        return Some(date);
+    }
+    match convention {
+        Adjustment::Following => add_business_days(date, 1),
+        Adjustment::Preceding => add_business_days(date, -1),
+        Adjustment::ModifiedFollowing => {
+            let following = add_business_days(date, 1)?;
+            if following.month() == date.month() {
+                Some(following)
+            } else {
+                add_business_days(date, -1)
+            }
+        }
+    }
+}
+
+/// Number of business days from `start`, inclusive, to `end`, exclusive; negative when
+/// `end` comes before `start`.
+///
+/// ```
+/// use bras::feriados::business_days_between;
+/// use chrono::NaiveDate;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+/// assert_eq!(253, business_days_between(start, end));
+/// assert_eq!(-253, business_days_between(end, start));
+/// ```
+pub fn business_days_between(start: NaiveDate, end: NaiveDate) -> i64 {
+    if end < start {
+        return -business_days_between(end, start);
+    }
+    let mut calendario = Calendario::new();
+    start
+        .iter_days()
+        .take_while(|date| *date < end)
+        .filter(|date| calendario.is_business_day(*date))
+        .count() as i64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn add_and_count_agree() {
+        let start = date(2023, 12, 28);
+        for n in 0..300 {
+            let end = add_business_days(start, n).unwrap();
+            assert_eq!(n, business_days_between(start, end));
+        }
+    }
+
+    #[test]
+    fn adjust_around_holidays() {
+        // Natal 2024 is a wednesday
+        let natal = date(2024, 12, 25);
+        assert_eq!(
+            Some(date(2024, 12, 26)),
+            adjust(natal, Adjustment::Following)
+        );
+        assert_eq!(
+            Some(date(2024, 12, 26)),
+            adjust(natal, Adjustment::ModifiedFollowing)
+        );
+        assert_eq!(
+            Some(date(2024, 12, 24)),
+            adjust(natal, Adjustment::Preceding)
+        );
+
+        let business_day = date(2024, 12, 27);
+        for convention in [
+            Adjustment::Following,
+            Adjustment::ModifiedFollowing,
+            Adjustment::Preceding,
+        ] {
+            assert_eq!(Some(business_day), adjust(business_day, convention));
+        }
+    }
+
+    #[test]
+    fn skip_carnaval() {
+        assert_eq!(
+            Some(date(2025, 3, 5)),
+            add_business_days(date(2025, 2, 28), 1)
+        );
+        assert_eq!(0, business_days_between(date(2025, 3, 1), date(2025, 3, 5)));
+    }
+}
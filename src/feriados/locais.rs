@@ -0,0 +1,95 @@
+//! Fixed-date state holidays and municipal holidays of the state capitals.
+
+use crate::Uf;
+
+/// (uf, mes, dia, nome)
+pub(super) const ESTADUAIS: [(Uf, u32, u32, &str); 31] = [
+    (Uf::AC, 1, 23, "Dia do Evangélico"),
+    (Uf::AC, 6, 15, "Aniversário do Acre"),
+    (Uf::AC, 9, 5, "Dia da Amazônia"),
+    (Uf::AC, 11, 17, "Assinatura do Tratado de Petrópolis"),
+    (Uf::AL, 6, 24, "São João"),
+    (Uf::AL, 6, 29, "São Pedro"),
+    (Uf::AL, 9, 16, "Emancipação Política de Alagoas"),
+    (Uf::AP, 3, 19, "Dia de São José"),
+    (Uf::AP, 9, 13, "Criação do Território Federal do Amapá"),
+    (
+        Uf::AM,
+        9,
+        5,
+        "Elevação do Amazonas à Categoria de Província",
+    ),
+    (Uf::BA, 7, 2, "Independência da Bahia"),
+    (Uf::CE, 3, 19, "Dia de São José"),
+    (Uf::CE, 3, 25, "Data Magna do Ceará"),
+    (Uf::DF, 4, 21, "Fundação de Brasília"),
+    (Uf::DF, 11, 30, "Dia do Evangélico"),
+    (
+        Uf::MA,
+        7,
+        28,
+        "Adesão do Maranhão à Independência do Brasil",
+    ),
+    (Uf::MS, 10, 11, "Criação do Estado de Mato Grosso do Sul"),
+    (
+        Uf::PA,
+        8,
+        15,
+        "Adesão do Grão-Pará à Independência do Brasil",
+    ),
+    (Uf::PB, 8, 5, "Fundação do Estado da Paraíba"),
+    (Uf::PR, 12, 19, "Emancipação Política do Paraná"),
+    (Uf::PE, 3, 6, "Revolução Pernambucana"),
+    (Uf::PI, 10, 19, "Dia do Piauí"),
+    (Uf::RJ, 4, 23, "Dia de São Jorge"),
+    (Uf::RN, 10, 3, "Mártires de Cunhaú e Uruaçu"),
+    (Uf::RS, 9, 20, "Revolução Farroupilha"),
+    (Uf::RO, 1, 4, "Criação do Estado de Rondônia"),
+    (Uf::RO, 6, 18, "Dia do Evangélico"),
+    (Uf::RR, 10, 5, "Criação do Estado de Roraima"),
+    (Uf::SP, 7, 9, "Revolução Constitucionalista"),
+    (Uf::SE, 7, 8, "Emancipação Política de Sergipe"),
+    (Uf::TO, 10, 5, "Criação do Estado do Tocantins"),
+];
+
+/// (IBGE code, mes, dia, nome)
+pub(super) const MUNICIPAIS: [(u32, u32, u32, &str); 38] = [
+    (1100205, 10, 2, "Aniversário de Porto Velho"),
+    (1200401, 12, 28, "Aniversário de Rio Branco"),
+    (1302603, 10, 24, "Aniversário de Manaus"),
+    (1302603, 12, 8, "Nossa Senhora da Conceição"),
+    (1400100, 6, 9, "Aniversário de Boa Vista"),
+    (1501402, 1, 12, "Aniversário de Belém"),
+    (1501402, 12, 8, "Nossa Senhora da Conceição"),
+    (1600303, 2, 4, "Aniversário de Macapá"),
+    (1721000, 5, 20, "Aniversário de Palmas"),
+    (2111300, 9, 8, "Aniversário de São Luís"),
+    (2111300, 12, 8, "Nossa Senhora da Conceição"),
+    (2211001, 8, 16, "Aniversário de Teresina"),
+    (2304400, 4, 13, "Aniversário de Fortaleza"),
+    (2304400, 8, 15, "Nossa Senhora da Assunção"),
+    (2408102, 1, 6, "Santos Reis"),
+    (2408102, 11, 21, "Nossa Senhora da Apresentação"),
+    (2507507, 8, 5, "Nossa Senhora das Neves"),
+    (2611606, 6, 24, "São João"),
+    (2611606, 7, 16, "Nossa Senhora do Carmo"),
+    (2611606, 12, 8, "Nossa Senhora da Conceição"),
+    (2704302, 8, 27, "Nossa Senhora dos Prazeres"),
+    (2704302, 12, 8, "Nossa Senhora da Conceição"),
+    (2800308, 3, 17, "Aniversário de Aracaju"),
+    (2800308, 12, 8, "Nossa Senhora da Conceição"),
+    (2927408, 6, 24, "São João"),
+    (2927408, 12, 8, "Nossa Senhora da Conceição da Praia"),
+    (3106200, 8, 15, "Assunção de Nossa Senhora"),
+    (3106200, 12, 8, "Imaculada Conceição"),
+    (3205309, 9, 8, "Nossa Senhora da Vitória"),
+    (3304557, 1, 20, "Dia de São Sebastião"),
+    (3550308, 1, 25, "Aniversário de São Paulo"),
+    (4106902, 9, 8, "Nossa Senhora da Luz dos Pinhais"),
+    (4205407, 3, 23, "Aniversário de Florianópolis"),
+    (4314902, 2, 2, "Nossa Senhora dos Navegantes"),
+    (5002704, 8, 26, "Aniversário de Campo Grande"),
+    (5103403, 4, 8, "Aniversário de Cuiabá"),
+    (5208707, 5, 24, "Nossa Senhora Auxiliadora"),
+    (5208707, 10, 24, "Aniversário de Goiânia"),
+];
@@ -0,0 +1,310 @@
+//! Brazilian holidays.
+//!
+//! # Examples
+//! ```
+//! use bras::feriados::{holidays, is_national_holiday, national_holidays};
+//! use chrono::NaiveDate;
+//!
+//! let natal = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+//! assert!(is_national_holiday(natal));
+//!
+//! let feriados = national_holidays(2024);
+//! assert_eq!(10, feriados.len());
+//! assert_eq!("Sexta-feira Santa", feriados[1].nome);
+//!
+//! let carnaval = NaiveDate::from_ymd_opt(2024, 2, 13).unwrap();
+//! assert!(holidays(2024).iter().any(|feriado| feriado.data == carnaval));
+//! ```
+
+use alloc::vec::Vec;
+
+use chrono::{Datelike, Days, NaiveDate};
+
+#[cfg(feature = "feriados-locais")]
+use crate::{CodigoMunicipio, Uf};
+
+mod dias_uteis;
+#[cfg(feature = "feriados-locais")]
+mod locais;
+
+pub use self::dias_uteis::{
+    add_business_days, adjust, business_days_between, is_business_day, Adjustment,
+};
+
+/// A holiday on a given date.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Feriado {
+    pub data: NaiveDate,
+    pub nome: &'static str,
+    pub tipo: TipoFeriado,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum TipoFeriado {
+    /// Federal holiday established by law.
+    Nacional,
+    /// Day off by custom and federal decree, observed by banks and the financial market.
+    PontoFacultativo,
+    /// Established by state law.
+    Estadual,
+    /// Established by municipal law.
+    Municipal,
+}
+
+/// (mes, dia, nome, first year, last year)
+const FIXOS: [(u32, u32, &str, i32, i32); 9] = [
+    (1, 1, "Confraternização Universal", i32::MIN, i32::MAX),
+    (4, 21, "Tiradentes", i32::MIN, i32::MAX),
+    (5, 1, "Dia do Trabalho", i32::MIN, i32::MAX),
+    (9, 7, "Independência do Brasil", i32::MIN, i32::MAX),
+    // Lei 6.802/1980
+    (10, 12, "Nossa Senhora Aparecida", 1980, i32::MAX),
+    (11, 2, "Finados", i32::MIN, i32::MAX),
+    (11, 15, "Proclamação da República", i32::MIN, i32::MAX),
+    // Lei 14.759/2023
+    (
+        11,
+        20,
+        "Dia Nacional de Zumbi e da Consciência Negra",
+        2024,
+        i32::MAX,
+    ),
+    (12, 25, "Natal", i32::MIN, i32::MAX),
+];
+
+/// (days relative to Easter Sunday, nome, tipo)
+const MOVEIS: [(i64, &str, TipoFeriado); 4] = [
+    (-48, "Carnaval", TipoFeriado::PontoFacultativo),
+    (-47, "Carnaval", TipoFeriado::PontoFacultativo),
+    (-2, "Sexta-feira Santa", TipoFeriado::Nacional),
+    (60, "Corpus Christi", TipoFeriado::PontoFacultativo),
+];
+
+/// Federal holidays of the year, the fixed-date ones and Sexta-feira Santa, in chronological
+/// order.
+///
+/// ```
+/// use bras::feriados::national_holidays;
+/// use chrono::NaiveDate;
+///
+/// let feriados = national_holidays(2025);
+/// assert_eq!(NaiveDate::from_ymd_opt(2025, 4, 18).unwrap(), feriados[1].data);
+/// assert_eq!("Sexta-feira Santa", feriados[1].nome);
+/// ```
+pub fn national_holidays(year: i32) -> Vec<Feriado> {
+    let mut feriados: Vec<Feriado> = FIXOS
+        .iter()
+        .filter(|&&(_, _, _, from, until)| (from..=until).contains(&year))
+        .filter_map(|&(month, day, nome, _, _)| {
+            NaiveDate::from_ymd_opt(year, month, day).map(|data| Feriado {
+                data,
+                nome,
+                tipo: TipoFeriado::Nacional,
+            })
+        })
+        .chain(moveis(year).filter(|feriado| feriado.tipo == TipoFeriado::Nacional))
+        .collect();
+    feriados.sort();
+    feriados
+}
+
+/// Easter based holidays of the year, in the order of [`MOVEIS`].
+fn moveis(year: i32) -> impl Iterator<Item = Feriado> {
+    pascoa(year).into_iter().flat_map(|pascoa| {
+        MOVEIS.iter().filter_map(move |&(offset, nome, tipo)| {
+            let data = if offset < 0 {
+                pascoa.checked_sub_days(Days::new(offset.unsigned_abs()))
+            } else {
+                pascoa.checked_add_days(Days::new(offset.unsigned_abs()))
+            };
+            data.map(|data| Feriado { data, nome, tipo })
+        })
+    })
+}
+
+/// [`national_holidays`] plus the Easter based days off (Carnaval monday and tuesday and
+/// Corpus Christi), in chronological order. This is the calendar followed by banks and the
+/// financial market.
+///
+/// ```
+/// use bras::feriados::{holidays, TipoFeriado};
+/// use chrono::NaiveDate;
+///
+/// let feriados = holidays(2025);
+/// let corpus_christi = feriados.iter().find(|f| f.nome == "Corpus Christi").unwrap();
+/// assert_eq!(NaiveDate::from_ymd_opt(2025, 6, 19).unwrap(), corpus_christi.data);
+/// assert_eq!(TipoFeriado::PontoFacultativo, corpus_christi.tipo);
+/// ```
+pub fn holidays(year: i32) -> Vec<Feriado> {
+    let mut feriados = national_holidays(year);
+    feriados.extend(moveis(year).filter(|feriado| feriado.tipo != TipoFeriado::Nacional));
+    feriados.sort();
+    feriados
+}
+
+/// Easter Sunday of the year in the Gregorian calendar, by the Meeus/Jones/Butcher
+/// algorithm.
+///
+/// ```
+/// use bras::feriados::pascoa;
+/// use chrono::NaiveDate;
+///
+/// assert_eq!(NaiveDate::from_ymd_opt(2024, 3, 31), pascoa(2024));
+/// assert_eq!(NaiveDate::from_ymd_opt(2025, 4, 20), pascoa(2025));
+/// ```
+pub fn pascoa(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b.div_euclid(4);
+    let e = b.rem_euclid(4);
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+/// [`holidays`] plus the state holidays of `uf` and, when given, the municipal holidays of
+/// `municipio`. Municipal holidays are known for the state capitals.
+///
+/// ```
+/// use bras::feriados::{holidays_for, TipoFeriado};
+/// use bras::{CodigoMunicipio, Uf};
+/// use chrono::NaiveDate;
+/// # use bras::ParseCodigoMunicipioError;
+///
+/// let sao_paulo: CodigoMunicipio = "3550308".parse()?;
+/// let feriados = holidays_for(2024, Uf::SP, Some(sao_paulo));
+///
+/// let revolucao = NaiveDate::from_ymd_opt(2024, 7, 9).unwrap();
+/// assert!(feriados.iter().any(|f| f.data == revolucao && f.tipo == TipoFeriado::Estadual));
+///
+/// let aniversario = NaiveDate::from_ymd_opt(2024, 1, 25).unwrap();
+/// assert!(feriados.iter().any(|f| f.data == aniversario && f.tipo == TipoFeriado::Municipal));
+///
+/// # Ok::<(), ParseCodigoMunicipioError>(())
+/// ```
+#[cfg(feature = "feriados-locais")]
+pub fn holidays_for(year: i32, uf: Uf, municipio: Option<CodigoMunicipio>) -> Vec<Feriado> {
+    let mut feriados = holidays(year);
+    let estaduais = locais::ESTADUAIS
+        .iter()
+        .filter(|&&(estado, _, _, _)| estado == uf)
+        .map(|&(_, month, day, nome)| (month, day, nome, TipoFeriado::Estadual));
+    let municipais = locais::MUNICIPAIS
+        .iter()
+        .filter(|&&(codigo, _, _, _)| Some(codigo) == municipio.map(u32::from))
+        .map(|&(_, month, day, nome)| (month, day, nome, TipoFeriado::Municipal));
+    feriados.extend(
+        estaduais
+            .chain(municipais)
+            .filter_map(|(month, day, nome, tipo)| {
+                NaiveDate::from_ymd_opt(year, month, day).map(|data| Feriado { data, nome, tipo })
+            }),
+    );
+    feriados.sort();
+    feriados
+}
+
+/// Whether the date is in [`holidays_for`].
+#[cfg(feature = "feriados-locais")]
+pub fn is_holiday_in(date: NaiveDate, uf: Uf, municipio: Option<CodigoMunicipio>) -> bool {
+    holidays_for(date.year(), uf, municipio)
+        .iter()
+        .any(|feriado| feriado.data == date)
+}
+
+/// ```
+/// use bras::feriados::is_national_holiday;
+/// use chrono::NaiveDate;
+///
+/// assert!(is_national_holiday(NaiveDate::from_ymd_opt(2024, 11, 20).unwrap()));
+/// assert!(!is_national_holiday(NaiveDate::from_ymd_opt(2023, 11, 20).unwrap()));
+/// ```
+pub fn is_national_holiday(date: NaiveDate) -> bool {
+    national_holidays(date.year())
+        .iter()
+        .any(|feriado| feriado.data == date)
+}
+
+/// Whether the date is in [`holidays`].
+pub fn is_holiday(date: NaiveDate) -> bool {
+    holidays(date.year())
+        .iter()
+        .any(|feriado| feriado.data == date)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn holidays_are_sorted() {
+        for year in [1979, 1980, 2023, 2024] {
+            let feriados = national_holidays(year);
+            assert!(feriados.windows(2).all(|pair| pair[0].data < pair[1].data));
+            let feriados = holidays(year);
+            assert!(feriados.windows(2).all(|pair| pair[0].data < pair[1].data));
+        }
+        assert_eq!(8, national_holidays(1979).len());
+        assert_eq!(9, national_holidays(2023).len());
+        assert_eq!(13, holidays(2024).len());
+    }
+
+    #[test]
+    fn regular_days_are_not_holidays() {
+        assert!(!is_national_holiday(date(2024, 3, 15)));
+        assert!(!is_holiday(date(2024, 3, 15)));
+    }
+
+    #[test]
+    fn easter_dates() {
+        let known = [
+            date(2000, 4, 23),
+            date(2008, 3, 23),
+            date(2011, 4, 24),
+            date(2019, 4, 21),
+            date(2023, 4, 9),
+            date(2026, 4, 5),
+            date(2038, 4, 25),
+        ];
+        for easter in known {
+            assert_eq!(Some(easter), pascoa(easter.year()));
+        }
+    }
+
+    #[cfg(feature = "feriados-locais")]
+    #[test]
+    fn local_holidays() {
+        for (codigo, _, _, _) in locais::MUNICIPAIS {
+            assert!(CodigoMunicipio::try_from(codigo).is_ok());
+        }
+        let rio = CodigoMunicipio::try_from(3304557).unwrap();
+        assert!(is_holiday_in(date(2025, 1, 20), Uf::RJ, Some(rio)));
+        assert!(!is_holiday_in(date(2025, 1, 20), Uf::RJ, None));
+        assert!(is_holiday_in(date(2025, 4, 23), Uf::RJ, None));
+        assert!(!is_holiday_in(date(2025, 4, 23), Uf::SP, None));
+        assert_eq!(holidays(2025), holidays_for(2025, Uf::GO, None));
+    }
+
+    #[test]
+    fn movable_holidays() {
+        assert!(is_holiday(date(2026, 2, 16)));
+        assert!(is_holiday(date(2026, 2, 17)));
+        assert!(is_holiday(date(2026, 4, 3)));
+        assert!(is_national_holiday(date(2026, 4, 3)));
+        assert!(is_holiday(date(2026, 6, 4)));
+        assert!(!is_national_holiday(date(2026, 6, 4)));
+    }
+}
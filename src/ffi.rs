@@ -0,0 +1,281 @@
+//! C bindings, for systems in other languages that need the same validation rules.
+//!
+//! Build the library with `cargo rustc --release --features ffi --crate-type cdylib` (or
+//! `staticlib`) and include `include/bras.h`. Every function returns [`BRAS_OK`] or one
+//! of the negative error codes, writing its output as a NUL terminated string into a
+//! buffer owned by the caller.
+//!
+//! ```c
+//! #include "bras.h"
+//!
+//! char cpf[BRAS_CPF_LEN];
+//! if (bras_cpf_format("98484485439", cpf, sizeof cpf) == BRAS_OK) {
+//!     puts(cpf); // 984.844.854-39
+//! }
+//! ```
+
+use core::ffi::{c_char, c_int, CStr};
+
+use alloc::string::ToString;
+
+use crate::{Cep, Cnpj, Cpf};
+
+pub const BRAS_OK: c_int = 0;
+/// A pointer argument is null.
+pub const BRAS_ERR_NULL: c_int = -1;
+/// The input is not valid UTF-8.
+pub const BRAS_ERR_UTF8: c_int = -2;
+/// The input is not a valid document.
+pub const BRAS_ERR_INVALID: c_int = -3;
+/// The output buffer can't hold the result and its NUL terminator.
+pub const BRAS_ERR_BUFFER_TOO_SMALL: c_int = -4;
+
+/// Size of a buffer for a formatted CPF, "000.000.000-00", and its NUL terminator.
+pub const BRAS_CPF_LEN: usize = 15;
+/// Size of a buffer for a formatted CNPJ, "00.000.000/0000-00", and its NUL terminator.
+pub const BRAS_CNPJ_LEN: usize = 19;
+/// Size of a buffer for a formatted CEP, "00000-000", and its NUL terminator.
+pub const BRAS_CEP_LEN: usize = 10;
+
+/// # Safety
+/// `s` must be null or point to a NUL terminated string.
+unsafe fn input<'a>(s: *const c_char) -> Result<&'a str, c_int> {
+    if s.is_null() {
+        return Err(BRAS_ERR_NULL);
+    }
+    CStr::from_ptr(s).to_str().map_err(|_| BRAS_ERR_UTF8)
+}
+
+/// # Safety
+/// `out` must be null or valid for writes of `len` bytes.
+unsafe fn output(value: &str, out: *mut c_char, len: usize) -> c_int {
+    if out.is_null() {
+        return BRAS_ERR_NULL;
+    }
+    if value.len() >= len {
+        return BRAS_ERR_BUFFER_TOO_SMALL;
+    }
+    core::ptr::copy_nonoverlapping(value.as_ptr(), out.cast::<u8>(), value.len());
+    *out.add(value.len()) = 0;
+    BRAS_OK
+}
+
+/// Checks the CPF in `s`, formatted or not.
+///
+/// # Safety
+/// `s` must be null or point to a NUL terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn bras_cpf_validate(s: *const c_char) -> c_int {
+    match input(s) {
+        Ok(s) if Cpf::is_valid(s) => BRAS_OK,
+        Ok(_) => BRAS_ERR_INVALID,
+        Err(code) => code,
+    }
+}
+
+/// Writes the CPF in `s` as "000.000.000-00", ignoring its original punctuation.
+///
+/// # Safety
+/// `s` must be null or point to a NUL terminated string, and `out` must be null or valid
+/// for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bras_cpf_format(s: *const c_char, out: *mut c_char, len: usize) -> c_int {
+    match input(s).map(Cpf::parse_lossy) {
+        Ok(Ok(cpf)) => output(&cpf.to_string(), out, len),
+        Ok(Err(_)) => BRAS_ERR_INVALID,
+        Err(code) => code,
+    }
+}
+
+/// Writes a random valid CPF as "000.000.000-00".
+///
+/// # Safety
+/// `out` must be null or valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bras_cpf_generate(out: *mut c_char, len: usize) -> c_int {
+    output(&Cpf::generate().to_string(), out, len)
+}
+
+/// Checks the CNPJ in `s`, numeric or alphanumeric, formatted or not.
+///
+/// # Safety
+/// `s` must be null or point to a NUL terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn bras_cnpj_validate(s: *const c_char) -> c_int {
+    match input(s) {
+        Ok(s) if Cnpj::is_valid(s) => BRAS_OK,
+        Ok(_) => BRAS_ERR_INVALID,
+        Err(code) => code,
+    }
+}
+
+/// Writes the CNPJ in `s` as "00.000.000/0000-00", ignoring its original punctuation.
+///
+/// # Safety
+/// `s` must be null or point to a NUL terminated string, and `out` must be null or valid
+/// for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bras_cnpj_format(s: *const c_char, out: *mut c_char, len: usize) -> c_int {
+    match input(s).map(Cnpj::parse_lossy) {
+        Ok(Ok(cnpj)) => output(&cnpj.to_string(), out, len),
+        Ok(Err(_)) => BRAS_ERR_INVALID,
+        Err(code) => code,
+    }
+}
+
+/// Writes a random valid CNPJ as "00.000.000/0000-00".
+///
+/// # Safety
+/// `out` must be null or valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bras_cnpj_generate(out: *mut c_char, len: usize) -> c_int {
+    output(&Cnpj::generate().to_string(), out, len)
+}
+
+/// Checks the CEP in `s`, formatted or not.
+///
+/// # Safety
+/// `s` must be null or point to a NUL terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn bras_cep_validate(s: *const c_char) -> c_int {
+    match input(s) {
+        Ok(s) if Cep::is_valid(s) => BRAS_OK,
+        Ok(_) => BRAS_ERR_INVALID,
+        Err(code) => code,
+    }
+}
+
+/// Writes the CEP in `s` as "00000-000", ignoring its original punctuation.
+///
+/// # Safety
+/// `s` must be null or point to a NUL terminated string, and `out` must be null or valid
+/// for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bras_cep_format(s: *const c_char, out: *mut c_char, len: usize) -> c_int {
+    match input(s).map(Cep::parse_lossy) {
+        Ok(Ok(cep)) => output(&cep.to_string(), out, len),
+        Ok(Err(_)) => BRAS_ERR_INVALID,
+        Err(code) => code,
+    }
+}
+
+/// Writes a random valid CEP as "00000-000".
+///
+/// # Safety
+/// `out` must be null or valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bras_cep_generate(out: *mut c_char, len: usize) -> c_int {
+    output(&Cep::generate().to_string(), out, len)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn format_into_caller_buffer() {
+        let mut out = [1 as c_char; BRAS_CPF_LEN];
+        let code = unsafe { bras_cpf_format(c"98484485439".as_ptr(), out.as_mut_ptr(), out.len()) };
+        assert_eq!(BRAS_OK, code);
+        let formatted = unsafe { CStr::from_ptr(out.as_ptr()) };
+        assert_eq!(c"984.844.854-39", formatted);
+
+        let code = unsafe { bras_cpf_format(c"98484485439".as_ptr(), out.as_mut_ptr(), 14) };
+        assert_eq!(BRAS_ERR_BUFFER_TOO_SMALL, code);
+        let code = unsafe { bras_cep_format(c"0131-0100".as_ptr(), out.as_mut_ptr(), 3) };
+        assert_eq!(BRAS_ERR_BUFFER_TOO_SMALL, code);
+
+        let mut out = [1 as c_char; BRAS_CNPJ_LEN];
+        let code =
+            unsafe { bras_cnpj_format(c"12ABC34501DE35".as_ptr(), out.as_mut_ptr(), out.len()) };
+        assert_eq!(BRAS_OK, code);
+        let formatted = unsafe { CStr::from_ptr(out.as_ptr()) };
+        assert_eq!(c"12.ABC.345/01DE-35", formatted);
+    }
+
+    #[test]
+    fn error_codes() {
+        unsafe {
+            assert_eq!(BRAS_OK, bras_cpf_validate(c"984.844.854-39".as_ptr()));
+            assert_eq!(
+                BRAS_ERR_INVALID,
+                bras_cpf_validate(c"984.844.854-00".as_ptr())
+            );
+            assert_eq!(BRAS_OK, bras_cnpj_validate(c"11.222.333/0001-81".as_ptr()));
+            assert_eq!(
+                BRAS_ERR_INVALID,
+                bras_cnpj_validate(c"11.222.333/0001-80".as_ptr())
+            );
+            assert_eq!(BRAS_ERR_NULL, bras_cep_validate(core::ptr::null()));
+            assert_eq!(BRAS_ERR_UTF8, bras_cep_validate(c"0131\xff100".as_ptr()));
+            assert_eq!(
+                BRAS_ERR_NULL,
+                bras_cep_generate(core::ptr::null_mut(), BRAS_CEP_LEN)
+            );
+        }
+    }
+
+    /// C declaration of each `extern "C"` function and `#define` of each constant in
+    /// this file.
+    fn rust_declarations() -> Vec<String> {
+        let source = include_str!("ffi.rs");
+        let mut declarations = Vec::new();
+        for item in source.split("pub unsafe extern \"C\" fn ").skip(1) {
+            let (name, rest) = item.split_once('(').unwrap();
+            let (params, rest) = rest.split_once(')').unwrap();
+            assert!(rest.trim_start().starts_with("-> c_int"), "{}", name);
+            let params: Vec<String> = params
+                .split(',')
+                .map(str::trim)
+                .filter(|param| !param.is_empty())
+                .map(|param| {
+                    let (param, ty) = param.split_once(": ").unwrap();
+                    match ty {
+                        "*const c_char" => format!("const char *{}", param),
+                        "*mut c_char" => format!("char *{}", param),
+                        "usize" => format!("size_t {}", param),
+                        _ => panic!("no C type for {} in {}", ty, name),
+                    }
+                })
+                .collect();
+            declarations.push(format!("int {}({});", name, params.join(", ")));
+        }
+        for line in source.lines() {
+            if let Some(constant) = line.strip_prefix("pub const BRAS_") {
+                let (name, rest) = constant.split_once(':').unwrap();
+                let value = rest.split_once("= ").unwrap().1.trim_end_matches(';');
+                declarations.push(format!("#define BRAS_{} {}", name, value));
+            }
+        }
+        declarations
+    }
+
+    #[test]
+    fn header_matches_the_exports() {
+        let header = include_str!("../include/bras.h");
+        let header: Vec<&str> = header
+            .lines()
+            .filter(|line| line.starts_with("int bras_") || line.starts_with("#define BRAS_"))
+            // The include guard defines no value.
+            .filter(|&line| line != "#define BRAS_H")
+            .collect();
+        let rust = rust_declarations();
+        for declaration in &rust {
+            assert!(
+                header.contains(&declaration.as_str()),
+                "missing in header: {}",
+                declaration
+            );
+        }
+        for declaration in &header {
+            assert!(
+                rust.iter().any(|rust| rust == declaration),
+                "not exported: {}",
+                declaration
+            );
+        }
+    }
+}
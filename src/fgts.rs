@@ -0,0 +1,99 @@
+//! Monthly FGTS (Fundo de Garantia do Tempo de Serviço) deposits.
+//!
+//! # Examples
+//! ```
+//! use bras::fgts::{deposit, Categoria};
+//! use bras::Real;
+//!
+//! assert_eq!(Real::from_reais(240), deposit(Real::from_reais(3000), Categoria::Empregado));
+//! assert_eq!(Real::from_reais(60), deposit(Real::from_reais(3000), Categoria::Aprendiz));
+//! assert_eq!(Real::from_reais(336), deposit(Real::from_reais(3000), Categoria::Domestico));
+//! ```
+
+use crate::previdencia::percentual;
+use crate::Real;
+
+/// Employment categories with distinct deposit rates.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum Categoria {
+    /// 8%
+    #[default]
+    Empregado,
+    /// Jovem aprendiz, 2% (Lei 10.097/2000).
+    Aprendiz,
+    /// Empregado doméstico, 8% plus the 3,2% compensatory indemnity (LC 150/2015).
+    Domestico,
+}
+
+impl Categoria {
+    /// Deposit rate in hundredths of a percent.
+    pub fn aliquota(self) -> u32 {
+        match self {
+            Categoria::Empregado => 800,
+            Categoria::Aprendiz => 200,
+            Categoria::Domestico => 1120,
+        }
+    }
+}
+
+/// Deposit due on a month's gross remuneration, rounded half away from zero to the centavo.
+pub fn deposit(gross: Real, category: Categoria) -> Real {
+    percentual(gross, category.aliquota())
+}
+
+/// Sum of the deposits due on each monthly remuneration, without monetary correction.
+///
+/// ```
+/// use bras::fgts::{accumulate, Categoria};
+/// use bras::Real;
+///
+/// let salarios = [Real::from_reais(2000), Real::from_reais(2000), Real::from_centavos(215050)];
+/// assert_eq!(Real::from_centavos(49204), accumulate(salarios, Categoria::Empregado));
+/// ```
+pub fn accumulate<I>(gross: I, category: Categoria) -> Real
+where
+    I: IntoIterator<Item = Real>,
+{
+    gross
+        .into_iter()
+        .map(|remuneracao| deposit(remuneracao, category))
+        .sum()
+}
+
+/// The 40% fine over the FGTS balance owed on dismissal without cause.
+///
+/// ```
+/// use bras::fgts::multa_rescisoria;
+/// use bras::Real;
+///
+/// assert_eq!(Real::from_reais(400), multa_rescisoria(Real::from_reais(1000)));
+/// ```
+pub fn multa_rescisoria(saldo: Real) -> Real {
+    percentual(saldo, 4000)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn round_to_the_centavo() {
+        // 1.412,33 * 8% = 112,9864
+        assert_eq!(
+            Real::from_centavos(11299),
+            deposit(Real::from_centavos(141233), Categoria::Empregado)
+        );
+        // 1.412,33 * 11,2% = 158,18096
+        assert_eq!(
+            Real::from_centavos(15818),
+            deposit(Real::from_centavos(141233), Categoria::Domestico)
+        );
+    }
+
+    #[test]
+    fn accumulate_rounds_each_month() {
+        let salarios = vec![Real::from_centavos(1); 10];
+        assert_eq!(Real::ZERO, accumulate(salarios, Categoria::Empregado));
+    }
+}
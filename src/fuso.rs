@@ -0,0 +1,148 @@
+//! Time zones of Brazil. Daylight saving time was abolished in 2019, so every zone has a
+//! fixed offset from UTC.
+//!
+//! # Examples
+//! ```
+//! use bras::fuso::{timezone_for, Fuso};
+//! use bras::{CodigoMunicipio, Uf};
+//! # use bras::ParseCodigoMunicipioError;
+//!
+//! assert_eq!("America/Manaus", Uf::AM.timezone().iana());
+//! assert_eq!(Fuso::Amazonas, Uf::MT.timezone().fuso());
+//! assert_eq!(-5 * 3600, Uf::AC.timezone().utc_offset_seconds());
+//!
+//! let noronha: CodigoMunicipio = "2605459".parse()?;
+//! assert_eq!("America/Noronha", timezone_for(noronha).iana());
+//! assert_eq!(Fuso::Brasilia, timezone_for("2611606".parse()?).fuso());
+//!
+//! # Ok::<(), ParseCodigoMunicipioError>(())
+//! ```
+
+#[cfg(feature = "dates")]
+use chrono::FixedOffset;
+
+use crate::{CodigoMunicipio, Uf};
+
+/// The four official time zones (Lei 12.876/2013).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Fuso {
+    /// UTC-2, Fernando de Noronha and the Atlantic islands.
+    Noronha,
+    /// UTC-3, official time of Brasília.
+    Brasilia,
+    /// UTC-4
+    Amazonas,
+    /// UTC-5
+    Acre,
+}
+
+impl Fuso {
+    pub fn utc_offset_hours(self) -> i32 {
+        match self {
+            Fuso::Noronha => -2,
+            Fuso::Brasilia => -3,
+            Fuso::Amazonas => -4,
+            Fuso::Acre => -5,
+        }
+    }
+}
+
+/// IANA time zone together with its official Brazilian zone.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Timezone {
+    iana: &'static str,
+    fuso: Fuso,
+}
+
+/// Fernando de Noronha, a municipality of Pernambuco one hour ahead of Brasília.
+pub const NORONHA: Timezone = Timezone {
+    iana: "America/Noronha",
+    fuso: Fuso::Noronha,
+};
+
+const FERNANDO_DE_NORONHA: u32 = 2605459;
+
+impl Timezone {
+    /// Identifier in the IANA time zone database, e.g. "America/Sao_Paulo".
+    pub fn iana(self) -> &'static str {
+        self.iana
+    }
+
+    pub fn fuso(self) -> Fuso {
+        self.fuso
+    }
+
+    pub fn utc_offset_seconds(self) -> i32 {
+        self.fuso.utc_offset_hours() * 3600
+    }
+
+    #[cfg(feature = "dates")]
+    pub fn fixed_offset(self) -> FixedOffset {
+        FixedOffset::east_opt(self.utc_offset_seconds()).expect("valid offset")
+    }
+}
+
+impl Uf {
+    /// Time zone of the state capital, which is the one of the whole state except for
+    /// Fernando de Noronha (PE) and the far west of Amazonas ("America/Eirunepe").
+    pub fn timezone(self) -> Timezone {
+        let (iana, fuso) = match self {
+            Uf::AC => ("America/Rio_Branco", Fuso::Acre),
+            Uf::AM => ("America/Manaus", Fuso::Amazonas),
+            Uf::RR => ("America/Boa_Vista", Fuso::Amazonas),
+            Uf::RO => ("America/Porto_Velho", Fuso::Amazonas),
+            Uf::MT => ("America/Cuiaba", Fuso::Amazonas),
+            Uf::MS => ("America/Campo_Grande", Fuso::Amazonas),
+            Uf::PA | Uf::AP => ("America/Belem", Fuso::Brasilia),
+            Uf::TO => ("America/Araguaina", Fuso::Brasilia),
+            Uf::MA | Uf::PI | Uf::CE | Uf::RN | Uf::PB => ("America/Fortaleza", Fuso::Brasilia),
+            Uf::PE => ("America/Recife", Fuso::Brasilia),
+            Uf::AL | Uf::SE => ("America/Maceio", Fuso::Brasilia),
+            Uf::BA => ("America/Bahia", Fuso::Brasilia),
+            Uf::DF | Uf::GO | Uf::ES | Uf::MG | Uf::RJ | Uf::SP | Uf::PR | Uf::SC | Uf::RS => {
+                ("America/Sao_Paulo", Fuso::Brasilia)
+            }
+        };
+        Timezone { iana, fuso }
+    }
+}
+
+/// Time zone of a municipality, taking Fernando de Noronha into account.
+pub fn timezone_for(municipio: CodigoMunicipio) -> Timezone {
+    if u32::from(municipio) == FERNANDO_DE_NORONHA {
+        NORONHA
+    } else {
+        municipio.uf().timezone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn zones_per_uf() {
+        let brasilia = Uf::ALL
+            .iter()
+            .filter(|uf| uf.timezone().fuso() == Fuso::Brasilia)
+            .count();
+        assert_eq!(21, brasilia);
+        assert!(Uf::ALL
+            .iter()
+            .all(|uf| uf.timezone().iana().starts_with("America/")));
+        assert!(CodigoMunicipio::try_from(FERNANDO_DE_NORONHA).is_ok());
+    }
+
+    #[cfg(feature = "dates")]
+    #[test]
+    fn fixed_offset() {
+        assert_eq!(
+            FixedOffset::west_opt(3 * 3600).unwrap(),
+            Uf::SP.timezone().fixed_offset()
+        );
+        assert_eq!(
+            FixedOffset::west_opt(2 * 3600).unwrap(),
+            NORONHA.fixed_offset()
+        );
+    }
+}
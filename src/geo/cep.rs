@@ -0,0 +1,530 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::uf::Uf;
+use crate::doc::mask::mask;
+use crate::doc::pattern::format_digits;
+use crate::options::digits_only;
+use crate::{BrDocument, MaskStyle, ParseOptions};
+
+/// Código de Endereçamento Postal, the eight digit postal code used by the Correios.
+///
+/// # Examples
+/// ```
+/// use bras::Cep;
+/// # use bras::ParseCepError;
+///
+/// let cep: Cep = "01310-100".parse()?;
+/// assert_eq!("01310-100", cep.to_string());
+///
+/// let cep: Cep = "01310100".parse()?;
+/// assert_eq!("01310-100", cep.to_string());
+///
+/// # Ok::<(), ParseCepError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(not(feature = "redact-debug"), derive(Debug))]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression))]
+#[cfg_attr(feature = "diesel", derive(diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Integer))]
+pub struct Cep {
+    inner: u32,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseCepError {
+    Invalid,
+    /// Byte at the zero based `position` of the input is not a digit.
+    InvalidCharacter {
+        position: usize,
+    },
+    /// 9 characters without the hyphen of "00000-000" at the zero based `position`.
+    InvalidSeparator {
+        position: usize,
+    },
+}
+
+impl Display for ParseCepError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseCepError::Invalid => f.write_str("invalid CEP"),
+            ParseCepError::InvalidCharacter { position } => write!(
+                f,
+                "invalid CEP, character at position {} is not a digit",
+                position
+            ),
+            ParseCepError::InvalidSeparator { position } => {
+                write!(f, "invalid CEP, expected '-' at position {}", position)
+            }
+        }
+    }
+}
+
+impl ParseCepError {
+    /// Zero based byte offset of the input where parsing went wrong, when the error points
+    /// to a single character.
+    ///
+    /// ```
+    /// use bras::Cep;
+    ///
+    /// assert_eq!(Some(5), "01310.100".parse::<Cep>().unwrap_err().position());
+    /// assert_eq!(Some(1), "0x310-100".parse::<Cep>().unwrap_err().position());
+    /// ```
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseCepError::InvalidCharacter { position }
+            | ParseCepError::InvalidSeparator { position } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseCepError {}
+
+/// Ranges of the first five digits assigned to each federative unit by the Correios.
+const FAIXAS_UF: [(u32, u32, Uf); 31] = [
+    (1000, 19999, Uf::SP),
+    (20000, 28999, Uf::RJ),
+    (29000, 29999, Uf::ES),
+    (30000, 39999, Uf::MG),
+    (40000, 48999, Uf::BA),
+    (49000, 49999, Uf::SE),
+    (50000, 56999, Uf::PE),
+    (57000, 57999, Uf::AL),
+    (58000, 58999, Uf::PB),
+    (59000, 59999, Uf::RN),
+    (60000, 63999, Uf::CE),
+    (64000, 64999, Uf::PI),
+    (65000, 65999, Uf::MA),
+    (66000, 68899, Uf::PA),
+    (68900, 68999, Uf::AP),
+    (69000, 69299, Uf::AM),
+    (69300, 69399, Uf::RR),
+    (69400, 69899, Uf::AM),
+    (69900, 69999, Uf::AC),
+    (70000, 72799, Uf::DF),
+    (72800, 72999, Uf::GO),
+    (73000, 73699, Uf::DF),
+    (73700, 76799, Uf::GO),
+    (76800, 76999, Uf::RO),
+    (77000, 77999, Uf::TO),
+    (78000, 78899, Uf::MT),
+    (78900, 78999, Uf::RO),
+    (79000, 79999, Uf::MS),
+    (80000, 87999, Uf::PR),
+    (88000, 89999, Uf::SC),
+    (90000, 99999, Uf::RS),
+];
+
+impl Cep {
+    /// Federative unit the CEP belongs to, or `None` for CEPs outside the ranges assigned
+    /// by the Correios.
+    ///
+    /// ```
+    /// use bras::{Cep, Uf};
+    /// # use bras::ParseCepError;
+    ///
+    /// assert_eq!(Some(Uf::SP), "01310-100".parse::<Cep>()?.uf());
+    /// assert_eq!(Some(Uf::DF), "70040-010".parse::<Cep>()?.uf());
+    /// assert_eq!(None, "00100-000".parse::<Cep>()?.uf());
+    ///
+    /// # Ok::<(), ParseCepError>(())
+    /// ```
+    pub fn uf(self) -> Option<Uf> {
+        let prefixo = self.inner / 1000;
+        FAIXAS_UF
+            .iter()
+            .find(|(inicio, fim, _)| (*inicio..=*fim).contains(&prefixo))
+            .map(|(_, _, uf)| *uf)
+    }
+
+    /// Random CEP in the ranges assigned by the Correios.
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Cep::generate`], drawing from the given generator so runs can be reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// Random CEP in the ranges the Correios assigned to the given federative unit.
+    ///
+    /// ```
+    /// use bras::{Cep, Uf};
+    ///
+    /// let cep = Cep::generate_in(Uf::AM);
+    /// assert_eq!(Some(Uf::AM), cep.uf());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate_in(uf: Uf) -> Self {
+        Self::generate_in_with(uf, &mut rand::thread_rng())
+    }
+
+    /// Like [`Cep::generate_in`], drawing from the given generator.
+    #[cfg(feature = "rand")]
+    pub fn generate_in_with<R: rand::Rng + ?Sized>(uf: Uf, rng: &mut R) -> Self {
+        let faixas = FAIXAS_UF.iter().filter(|(_, _, faixa_uf)| *faixa_uf == uf);
+        let total: u32 = faixas
+            .clone()
+            .map(|(inicio, fim, _)| fim - inicio + 1)
+            .sum();
+        let mut prefixo = rng.gen_range(0..total);
+        for (inicio, fim, _) in faixas {
+            if prefixo <= fim - inicio {
+                return Cep {
+                    inner: (inicio + prefixo) * 1000 + rng.gen_range(0..1000),
+                };
+            }
+            prefixo -= fim - inicio + 1;
+        }
+        unreachable!("every federative unit has a range")
+    }
+
+    /// Formatted following `pattern`, each `#` standing for the next digit. `None` when the
+    /// pattern does not have exactly 8 `#`.
+    ///
+    /// ```
+    /// use bras::Cep;
+    /// # use bras::ParseCepError;
+    ///
+    /// let cep: Cep = "01310-100".parse()?;
+    /// assert_eq!(Some("01.310-100".into()), cep.format_with("##.###-###"));
+    ///
+    /// # Ok::<(), ParseCepError>(())
+    /// ```
+    pub fn format_with(self, pattern: &str) -> Option<String> {
+        format_digits(&BrDocument::digits(&self), pattern)
+    }
+
+    /// Formatted with the suffix hidden, keeping the region but not the street.
+    ///
+    /// ```
+    /// use bras::{Cep, MaskStyle};
+    /// # use bras::ParseCepError;
+    ///
+    /// let cep: Cep = "01310-100".parse()?;
+    /// assert_eq!("01310-***", cep.masked());
+    /// assert_eq!("*****-100", cep.masked_with(MaskStyle::KeepLast(3)));
+    ///
+    /// # Ok::<(), ParseCepError>(())
+    /// ```
+    pub fn masked(self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    /// Formatted with the digits chosen by `style` hidden.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        mask(&self.to_string(), style.visible(8, 0..5))
+    }
+
+    /// Whether `s` is a CEP in a format accepted by [`FromStr`], without building it.
+    ///
+    /// ```
+    /// use bras::Cep;
+    ///
+    /// assert!(Cep::is_valid("01310-100"));
+    /// assert!(!Cep::is_valid("01310100-"));
+    /// ```
+    pub const fn is_valid(s: &str) -> bool {
+        Cep::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Parses with the relaxations turned on in `options`. The hyphen is always optional,
+    /// so [`ParseOptions::partial_formatting`] makes no difference.
+    ///
+    /// ```
+    /// use bras::{Cep, ParseOptions};
+    ///
+    /// let cep = Cep::parse_with(" 1310100", ParseOptions::lenient())?;
+    /// assert_eq!("01310-100", cep.to_string());
+    ///
+    /// # Ok::<(), bras::ParseCepError>(())
+    /// ```
+    pub fn parse_with(s: &str, options: ParseOptions) -> Result<Self, ParseCepError> {
+        let s = options.apply_trim(s);
+        match options.pad_leading_zeros(s) {
+            Some(digits) => Cep::from_digits(digits),
+            None => Cep::from_bytes(s.as_bytes()),
+        }
+    }
+
+    /// Parses the digits of `s`, ignoring every other character.
+    ///
+    /// ```
+    /// use bras::Cep;
+    /// # use bras::ParseCepError;
+    ///
+    /// assert_eq!("01310-100", Cep::parse_lossy("CEP 01.310-100")?.to_string());
+    ///
+    /// # Ok::<(), ParseCepError>(())
+    /// ```
+    pub fn parse_lossy(s: &str) -> Result<Self, ParseCepError> {
+        let digits = digits_only(s).map_err(|_| ParseCepError::Invalid)?;
+        Cep::from_digits(digits)
+    }
+
+    fn from_digits(digits: [u8; 8]) -> Result<Self, ParseCepError> {
+        let value = digits
+            .iter()
+            .fold(0u32, |acc, digit| acc * 10 + u32::from(*digit));
+        if value == 0 {
+            return Err(ParseCepError::Invalid);
+        }
+        Ok(Cep { inner: value })
+    }
+
+    fn new(s: &str) -> Result<Self, ParseCepError> {
+        Cep::from_bytes(s.as_bytes())
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`cep!`](crate::cep!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseCepError> {
+        let separator = match bytes.len() {
+            8 => None,
+            9 if bytes[5] == b'-' => Some(5),
+            9 => return Err(ParseCepError::InvalidSeparator { position: 5 }),
+            _ => return Err(ParseCepError::Invalid),
+        };
+        let mut value = 0u32;
+        let mut position = 0;
+        while position < bytes.len() {
+            if !matches!(separator, Some(separator) if separator == position) {
+                if !bytes[position].is_ascii_digit() {
+                    return Err(ParseCepError::InvalidCharacter { position });
+                }
+                value = value * 10 + (bytes[position] - b'0') as u32;
+            }
+            position += 1;
+        }
+        if value == 0 {
+            return Err(ParseCepError::Invalid);
+        }
+        Ok(Cep { inner: value })
+    }
+}
+
+impl FromStr for Cep {
+    type Err = ParseCepError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cep::new(s)
+    }
+}
+
+impl From<Cep> for u32 {
+    /// ```
+    /// use bras::Cep;
+    /// # use bras::ParseCepError;
+    ///
+    /// let cep: Cep = "01310-100".parse()?;
+    /// assert_eq!(1310100u32, u32::from(cep));
+    /// # Ok::<(), ParseCepError>(())
+    /// ```
+    fn from(cep: Cep) -> Self {
+        cep.inner
+    }
+}
+
+impl TryFrom<u32> for Cep {
+    type Error = ParseCepError;
+
+    /// Leading zeros lost by storing the CEP as an integer come back when formatting.
+    ///
+    /// ```
+    /// use bras::Cep;
+    /// # use bras::ParseCepError;
+    ///
+    /// let cep = Cep::try_from(1310100)?;
+    /// assert_eq!("01310-100", cep.to_string());
+    ///
+    /// # Ok::<(), ParseCepError>(())
+    /// ```
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value == 0 || value > 99_999_999 {
+            return Err(ParseCepError::Invalid);
+        }
+        Ok(Cep { inner: value })
+    }
+}
+
+impl Display for Cep {
+    /// ```
+    /// use bras::Cep;
+    /// # use bras::ParseCepError;
+    ///
+    /// let cep: Cep = "01310100".parse()?;
+    /// assert_eq!("01310-100", cep.to_string());
+    ///
+    /// // The alternate flag prints only the digits.
+    /// assert_eq!("01310100", format!("{:#}", cep));
+    ///
+    /// # Ok::<(), ParseCepError>(())
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            return write!(f, "{:08}", self.inner);
+        }
+        write!(f, "{:05}-{:03}", self.inner / 1000, self.inner % 1000)
+    }
+}
+
+/// With the `redact-debug` feature, prints the [masked](Cep::masked) CEP so it cannot leak
+/// into logs through `{:?}`.
+#[cfg(feature = "redact-debug")]
+impl core::fmt::Debug for Cep {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Cep({})", self.masked())
+    }
+}
+
+impl BrDocument for Cep {
+    fn kind(&self) -> &'static str {
+        "CEP"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        format!("{:08}", self.inner)
+            .bytes()
+            .map(|b| b - b'0')
+            .collect()
+    }
+
+    fn masked(&self) -> String {
+        Cep::masked(*self)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Cep::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Cep> for rand::distributions::Standard {
+    /// Uniform over the CEPs from 01000-000 to 99999-999, the range used by the Correios.
+    ///
+    /// ```
+    /// use bras::Cep;
+    /// use rand::Rng;
+    ///
+    /// let cep: Cep = rand::thread_rng().gen();
+    /// assert_eq!(Ok(cep), cep.to_string().parse());
+    /// ```
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Cep {
+        Cep {
+            inner: rng.gen_range(1_000_000..100_000_000),
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Cep {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Cep {
+            inner: u.int_in_range(1_000_000..=99_999_999)?,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Cep {
+    type Parameters = ();
+    type Strategy = proptest::strategy::Map<core::ops::Range<u32>, fn(u32) -> Cep>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (1_000_000..100_000_000).prop_map(|inner| Cep { inner })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[cfg(feature = "redact-debug")]
+    #[test]
+    fn debug_is_masked() {
+        let cep = Cep::from_str("01310-100").unwrap();
+        assert_eq!("Cep(01310-***)", alloc::format!("{:?}", cep));
+    }
+
+    #[test]
+    fn parse_cep_from_str() {
+        assert_eq!("01310-100", Cep::from_str("01310100").unwrap().to_string());
+        assert_eq!("01310-100", Cep::from_str("01310-100").unwrap().to_string());
+        assert_eq!("90010-000", Cep::from_str("90010000").unwrap().to_string());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_ceps_match_the_uf() {
+        for uf in Uf::ALL {
+            for _ in 0..100 {
+                assert_eq!(Some(uf), Cep::generate_in(uf).uf());
+            }
+        }
+    }
+
+    #[test]
+    fn u32_round_trip() {
+        let cep = Cep::from_str("01001-000").unwrap();
+        assert_eq!(1001000, u32::from(cep));
+        assert_eq!(Ok(cep), Cep::try_from(1001000));
+        assert_eq!(
+            "01001000",
+            alloc::format!("{:#}", Cep::try_from(1001000).unwrap())
+        );
+        assert_eq!(Err(ParseCepError::Invalid), Cep::try_from(0));
+        assert_eq!(Err(ParseCepError::Invalid), Cep::try_from(100_000_000));
+    }
+
+    #[test]
+    fn uf_ranges() {
+        let expected = [
+            ("69300-000", Uf::RR),
+            ("69400-000", Uf::AM),
+            ("72800-000", Uf::GO),
+            ("73000-000", Uf::DF),
+            ("76800-000", Uf::RO),
+            ("78900-000", Uf::RO),
+            ("99999-999", Uf::RS),
+        ];
+        for (cep, uf) in expected {
+            assert_eq!(Some(uf), Cep::from_str(cep).unwrap().uf());
+        }
+        // The assigned ranges leave no gaps between 01000-000 and 99999-999.
+        for window in FAIXAS_UF.windows(2) {
+            assert_eq!(window[0].1 + 1, window[1].0);
+        }
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let separator = ParseCepError::InvalidSeparator { position: 5 };
+        let invalid = [
+            ("0131010", ParseCepError::Invalid),
+            ("013101000", separator),
+            ("01310_100", separator),
+            ("0131-0100", separator),
+            ("00000-000", ParseCepError::Invalid),
+            ("ab310-100", ParseCepError::InvalidCharacter { position: 0 }),
+            ("+1310100", ParseCepError::InvalidCharacter { position: 0 }),
+            ("", ParseCepError::Invalid),
+        ];
+        for (cep, err) in invalid {
+            assert_eq!(Cep::from_str(cep).unwrap_err(), err);
+            assert!(!Cep::is_valid(cep));
+        }
+    }
+}
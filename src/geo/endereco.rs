@@ -0,0 +1,412 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::cep::Cep;
+use super::normalize;
+use super::uf::Uf;
+
+/// Brazilian postal address, split in the fields used by the Correios.
+///
+/// # Examples
+/// ```
+/// use bras::{Endereco, TipoLogradouro, Uf};
+/// # use bras::ParseCepError;
+///
+/// let endereco = Endereco {
+///     tipo_logradouro: None,
+///     logradouro: "Av.  Paulista".to_string(),
+///     numero: Some("1578".to_string()),
+///     complemento: None,
+///     bairro: "Bela Vista".to_string(),
+///     municipio: "São Paulo".to_string(),
+///     uf: Uf::SP,
+///     cep: "01310-200".parse()?,
+/// };
+///
+/// let normalized = endereco.normalized();
+/// assert_eq!(Some(TipoLogradouro::Avenida), normalized.tipo_logradouro);
+/// assert_eq!("Paulista", normalized.logradouro);
+/// assert_eq!(
+///     "Avenida Paulista, 1578 - Bela Vista, São Paulo - SP, 01310-200",
+///     normalized.to_string()
+/// );
+///
+/// # Ok::<(), ParseCepError>(())
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Endereco {
+    pub tipo_logradouro: Option<TipoLogradouro>,
+    pub logradouro: String,
+    pub numero: Option<String>,
+    pub complemento: Option<String>,
+    pub bairro: String,
+    pub municipio: String,
+    pub uf: Uf,
+    pub cep: Cep,
+}
+
+/// Whether the tipo de logradouro is written in full or abbreviated.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum EstiloLogradouro {
+    /// "Rua", "Avenida"
+    Extenso,
+    /// "R", "AV", as in the Correios table
+    Abreviado,
+}
+
+/// Tipos de logradouro from the Correios table, with their official abbreviations.
+///
+/// # Examples
+/// ```
+/// use bras::TipoLogradouro;
+/// # use bras::ParseTipoLogradouroError;
+///
+/// assert_eq!(TipoLogradouro::Rua, "R.".parse()?);
+/// assert_eq!(TipoLogradouro::Avenida, "av".parse()?);
+/// assert_eq!(TipoLogradouro::Praca, "Praca".parse()?);
+/// assert_eq!("PC", TipoLogradouro::Praca.abbreviation());
+/// assert_eq!("Praça", TipoLogradouro::Praca.to_string());
+///
+/// # Ok::<(), ParseTipoLogradouroError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum TipoLogradouro {
+    Aeroporto,
+    Alameda,
+    Area,
+    Avenida,
+    Campo,
+    Chacara,
+    Colonia,
+    Condominio,
+    Conjunto,
+    Distrito,
+    Esplanada,
+    Estacao,
+    Estrada,
+    Favela,
+    Fazenda,
+    Feira,
+    Jardim,
+    Ladeira,
+    Lago,
+    Lagoa,
+    Largo,
+    Loteamento,
+    Morro,
+    Nucleo,
+    Parque,
+    Passarela,
+    Patio,
+    Praca,
+    Quadra,
+    Recanto,
+    Residencial,
+    Rodovia,
+    Rua,
+    Setor,
+    Sitio,
+    Travessa,
+    Trecho,
+    Trevo,
+    Vale,
+    Vereda,
+    Via,
+    Viaduto,
+    Viela,
+    Vila,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseTipoLogradouroError {
+    Invalid,
+}
+
+impl Display for ParseTipoLogradouroError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseTipoLogradouroError::Invalid => f.write_str("unknown street type"),
+        }
+    }
+}
+
+impl core::error::Error for ParseTipoLogradouroError {}
+
+/// (tipo, name, Correios abbreviation, other abbreviations seen in the wild)
+const TABLE: [(TipoLogradouro, &str, &str, &[&str]); 44] = [
+    (TipoLogradouro::Aeroporto, "Aeroporto", "AER", &[]),
+    (TipoLogradouro::Alameda, "Alameda", "AL", &[]),
+    (TipoLogradouro::Area, "Área", "A", &[]),
+    (TipoLogradouro::Avenida, "Avenida", "AV", &["AVD", "AVDA"]),
+    (TipoLogradouro::Campo, "Campo", "CPO", &[]),
+    (TipoLogradouro::Chacara, "Chácara", "CH", &["CHAC"]),
+    (TipoLogradouro::Colonia, "Colônia", "COL", &[]),
+    (TipoLogradouro::Condominio, "Condomínio", "COND", &[]),
+    (TipoLogradouro::Conjunto, "Conjunto", "CJ", &["CONJ"]),
+    (TipoLogradouro::Distrito, "Distrito", "DT", &[]),
+    (TipoLogradouro::Esplanada, "Esplanada", "ESP", &[]),
+    (TipoLogradouro::Estacao, "Estação", "ETC", &[]),
+    (TipoLogradouro::Estrada, "Estrada", "EST", &["ESTR"]),
+    (TipoLogradouro::Favela, "Favela", "FAV", &[]),
+    (TipoLogradouro::Fazenda, "Fazenda", "FAZ", &[]),
+    (TipoLogradouro::Feira, "Feira", "FRA", &[]),
+    (TipoLogradouro::Jardim, "Jardim", "JD", &["JARD"]),
+    (TipoLogradouro::Ladeira, "Ladeira", "LD", &[]),
+    (TipoLogradouro::Lago, "Lago", "LG", &[]),
+    (TipoLogradouro::Lagoa, "Lagoa", "LGA", &[]),
+    (TipoLogradouro::Largo, "Largo", "LGO", &[]),
+    (TipoLogradouro::Loteamento, "Loteamento", "LOT", &[]),
+    (TipoLogradouro::Morro, "Morro", "MRO", &[]),
+    (TipoLogradouro::Nucleo, "Núcleo", "NUC", &[]),
+    (TipoLogradouro::Parque, "Parque", "PQ", &["PRQ"]),
+    (TipoLogradouro::Passarela, "Passarela", "PSA", &[]),
+    (TipoLogradouro::Patio, "Pátio", "PAT", &[]),
+    (TipoLogradouro::Praca, "Praça", "PC", &["PCA", "PÇ", "PÇA"]),
+    (TipoLogradouro::Quadra, "Quadra", "Q", &["QD", "QDA"]),
+    (TipoLogradouro::Recanto, "Recanto", "REC", &[]),
+    (TipoLogradouro::Residencial, "Residencial", "RES", &[]),
+    (TipoLogradouro::Rodovia, "Rodovia", "ROD", &[]),
+    (TipoLogradouro::Rua, "Rua", "R", &[]),
+    (TipoLogradouro::Setor, "Setor", "ST", &[]),
+    (TipoLogradouro::Sitio, "Sítio", "SIT", &[]),
+    (TipoLogradouro::Travessa, "Travessa", "TV", &["TRAV"]),
+    (TipoLogradouro::Trecho, "Trecho", "TR", &[]),
+    (TipoLogradouro::Trevo, "Trevo", "TRV", &[]),
+    (TipoLogradouro::Vale, "Vale", "VLE", &[]),
+    (TipoLogradouro::Vereda, "Vereda", "VER", &[]),
+    (TipoLogradouro::Via, "Via", "V", &[]),
+    (TipoLogradouro::Viaduto, "Viaduto", "VD", &[]),
+    (TipoLogradouro::Viela, "Viela", "VLA", &[]),
+    (TipoLogradouro::Vila, "Vila", "VL", &[]),
+];
+
+impl TipoLogradouro {
+    fn entry(
+        self,
+    ) -> &'static (
+        TipoLogradouro,
+        &'static str,
+        &'static str,
+        &'static [&'static str],
+    ) {
+        TABLE
+            .iter()
+            .find(|entry| entry.0 == self)
+            .expect("every tipo is in the table")
+    }
+
+    pub fn name(self) -> &'static str {
+        self.entry().1
+    }
+
+    /// Abbreviation used by the Correios.
+    pub fn abbreviation(self) -> &'static str {
+        self.entry().2
+    }
+
+    /// Splits a leading tipo de logradouro from `logradouro`, returning the tipo and the rest.
+    ///
+    /// Single letter abbreviations are only recognized when followed by a dot, except for the
+    /// very common "R" and "Q".
+    ///
+    /// ```
+    /// use bras::TipoLogradouro;
+    ///
+    /// assert_eq!(
+    ///     Some((TipoLogradouro::Rua, "das Flores")),
+    ///     TipoLogradouro::split_prefix("R. das Flores")
+    /// );
+    /// assert_eq!(None, TipoLogradouro::split_prefix("Paulista"));
+    /// assert_eq!(None, TipoLogradouro::split_prefix("A Praça"));
+    /// ```
+    pub fn split_prefix(logradouro: &str) -> Option<(TipoLogradouro, &str)> {
+        let logradouro = logradouro.trim_start();
+        let (first, rest) = logradouro
+            .split_once(|c: char| c.is_whitespace())
+            .unwrap_or((logradouro, ""));
+        let rest = rest.trim_start();
+        if rest.is_empty() {
+            return None;
+        }
+        let tipo = TipoLogradouro::from_str(first).ok()?;
+        let single_letter = first.chars().count() == 1;
+        if single_letter && tipo != TipoLogradouro::Rua && tipo != TipoLogradouro::Quadra {
+            return None;
+        }
+        Some((tipo, rest))
+    }
+}
+
+impl FromStr for TipoLogradouro {
+    type Err = ParseTipoLogradouroError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let wanted = normalize(s.trim().trim_end_matches('.'));
+        if wanted.is_empty() {
+            return Err(ParseTipoLogradouroError::Invalid);
+        }
+        TABLE
+            .iter()
+            .find(|(_, name, abbreviation, others)| {
+                normalize(name) == wanted
+                    || normalize(abbreviation) == wanted
+                    || others.iter().any(|other| normalize(other) == wanted)
+            })
+            .map(|entry| entry.0)
+            .ok_or(ParseTipoLogradouroError::Invalid)
+    }
+}
+
+impl Display for TipoLogradouro {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Rewrites the tipo de logradouro at the start of `logradouro` in the given style,
+/// collapsing repeated whitespace.
+///
+/// ```
+/// use bras::{normalizar_logradouro, EstiloLogradouro};
+///
+/// assert_eq!("Rua das Flores", normalizar_logradouro("R. das  Flores", EstiloLogradouro::Extenso));
+/// assert_eq!("AV Paulista", normalizar_logradouro("Avenida Paulista", EstiloLogradouro::Abreviado));
+/// assert_eq!("Paulista", normalizar_logradouro(" Paulista ", EstiloLogradouro::Extenso));
+/// ```
+pub fn normalizar_logradouro(logradouro: &str, estilo: EstiloLogradouro) -> String {
+    let collapsed = collapse_whitespace(logradouro);
+    match TipoLogradouro::split_prefix(&collapsed) {
+        Some((tipo, rest)) => format!("{} {}", tipo_in_style(tipo, estilo), rest),
+        None => collapsed,
+    }
+}
+
+fn tipo_in_style(tipo: TipoLogradouro, estilo: EstiloLogradouro) -> &'static str {
+    match estilo {
+        EstiloLogradouro::Extenso => tipo.name(),
+        EstiloLogradouro::Abreviado => tipo.abbreviation(),
+    }
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+fn collapse_optional(s: &Option<String>) -> Option<String> {
+    s.as_deref()
+        .map(collapse_whitespace)
+        .filter(|s| !s.is_empty())
+}
+
+impl Endereco {
+    /// Canonical form of the address, suited for comparing records: whitespace is collapsed,
+    /// empty optional fields become `None` and a tipo de logradouro written at the start of
+    /// `logradouro` is moved to `tipo_logradouro`.
+    pub fn normalized(&self) -> Endereco {
+        let mut logradouro = collapse_whitespace(&self.logradouro);
+        let mut tipo_logradouro = self.tipo_logradouro;
+        if tipo_logradouro.is_none() {
+            if let Some((tipo, rest)) = TipoLogradouro::split_prefix(&logradouro) {
+                tipo_logradouro = Some(tipo);
+                logradouro = rest.to_string();
+            }
+        }
+        Endereco {
+            tipo_logradouro,
+            logradouro,
+            numero: collapse_optional(&self.numero),
+            complemento: collapse_optional(&self.complemento),
+            bairro: collapse_whitespace(&self.bairro),
+            municipio: collapse_whitespace(&self.municipio),
+            uf: self.uf,
+            cep: self.cep,
+        }
+    }
+
+    /// Tipo and logradouro together, e.g. "Rua das Flores" or "R das Flores".
+    pub fn logradouro_completo(&self, estilo: EstiloLogradouro) -> String {
+        match self.tipo_logradouro {
+            Some(tipo) => format!("{} {}", tipo_in_style(tipo, estilo), self.logradouro),
+            None => self.logradouro.clone(),
+        }
+    }
+}
+
+impl Display for Endereco {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.logradouro_completo(EstiloLogradouro::Extenso))?;
+        if let Some(numero) = &self.numero {
+            write!(f, ", {}", numero)?;
+        }
+        if let Some(complemento) = &self.complemento {
+            write!(f, ", {}", complemento)?;
+        }
+        write!(
+            f,
+            " - {}, {} - {}, {}",
+            self.bairro, self.municipio, self.uf, self.cep
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn every_abbreviation_is_parsed_back() {
+        for (tipo, name, abbreviation, others) in TABLE {
+            assert_eq!(tipo, TipoLogradouro::from_str(name).unwrap());
+            assert_eq!(tipo, TipoLogradouro::from_str(abbreviation).unwrap());
+            assert_eq!(
+                tipo,
+                TipoLogradouro::from_str(&format!("{}.", abbreviation)).unwrap()
+            );
+            for other in others {
+                assert_eq!(tipo, TipoLogradouro::from_str(other).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn normalized_addresses_compare_equal() {
+        let a = Endereco {
+            tipo_logradouro: None,
+            logradouro: "R. Augusta".to_string(),
+            numero: Some("100".to_string()),
+            complemento: Some("".to_string()),
+            bairro: "Consolação".to_string(),
+            municipio: "São Paulo".to_string(),
+            uf: Uf::SP,
+            cep: "01304-000".parse().unwrap(),
+        };
+        let b = Endereco {
+            tipo_logradouro: Some(TipoLogradouro::Rua),
+            logradouro: " Augusta ".to_string(),
+            numero: Some("100".to_string()),
+            complemento: None,
+            bairro: "Consolação ".to_string(),
+            municipio: "São  Paulo".to_string(),
+            uf: Uf::SP,
+            cep: "01304000".parse().unwrap(),
+        };
+        assert_ne!(a, b);
+        assert_eq!(a.normalized(), b.normalized());
+    }
+
+    #[test]
+    fn keep_logradouro_without_tipo() {
+        assert_eq!(None, TipoLogradouro::split_prefix("Rua"));
+        assert_eq!(None, TipoLogradouro::split_prefix("V Madalena"));
+        assert_eq!(
+            Some((TipoLogradouro::Via, "Anchieta")),
+            TipoLogradouro::split_prefix("V. Anchieta")
+        );
+    }
+}
@@ -0,0 +1,27 @@
+#[cfg(feature = "geo")]
+pub mod cep;
+#[cfg(feature = "geo")]
+pub mod endereco;
+pub mod municipio;
+#[cfg(feature = "municipios")]
+mod municipios_data;
+pub mod regiao;
+#[cfg(feature = "geo")]
+pub mod telefone;
+pub mod uf;
+
+use alloc::string::String;
+
+/// Lowercases, strips Portuguese diacritics and collapses whitespace.
+pub(crate) fn normalize(s: &str) -> String {
+    s.split_whitespace()
+        .flat_map(|word| {
+            core::iter::once(' ').chain(
+                word.chars()
+                    .flat_map(char::to_lowercase)
+                    .filter_map(crate::texto::ascii),
+            )
+        })
+        .skip(1)
+        .collect()
+}
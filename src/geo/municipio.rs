@@ -0,0 +1,478 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+#[cfg(feature = "municipios")]
+use super::municipios_data::MUNICIPIOS;
+use super::uf::Uf;
+use crate::options::digits_only;
+use crate::BrDocument;
+
+/// Seven digit IBGE municipality code (`cMun` on NF-e), the last digit being a check digit.
+///
+/// # Examples
+/// ```
+/// use bras::{CodigoMunicipio, Uf};
+/// # use bras::ParseCodigoMunicipioError;
+///
+/// let codigo: CodigoMunicipio = "3550308".parse()?;
+/// assert_eq!(Uf::SP, codigo.uf());
+/// assert_eq!("3550308", codigo.to_string());
+///
+/// let codigo = CodigoMunicipio::try_from(5300108)?;
+/// assert_eq!(Uf::DF, codigo.uf());
+/// assert_eq!(5300108u32, u32::from(codigo));
+///
+/// # Ok::<(), ParseCodigoMunicipioError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression))]
+#[cfg_attr(feature = "diesel", derive(diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Integer))]
+pub struct CodigoMunicipio {
+    inner: u32,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseCodigoMunicipioError {
+    Invalid,
+}
+
+impl Display for ParseCodigoMunicipioError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseCodigoMunicipioError::Invalid => f.write_str("invalid IBGE municipality code"),
+        }
+    }
+}
+
+impl core::error::Error for ParseCodigoMunicipioError {}
+
+const WEIGHTS: [u32; 6] = [1, 2, 1, 2, 1, 2];
+
+/// Codes assigned by the IBGE whose check digit does not follow the algorithm,
+/// accepted anyway by SEFAZ.
+const CHECK_DIGIT_EXCEPTIONS: [u32; 9] = [
+    2201919, 2201988, 2202251, 2611533, 3117836, 3152131, 4305871, 5203939, 5203962,
+];
+
+impl CodigoMunicipio {
+    /// Name of the municipality, when present in the embedded IBGE table.
+    ///
+    /// ```
+    /// use bras::CodigoMunicipio;
+    /// # use bras::ParseCodigoMunicipioError;
+    ///
+    /// let codigo: CodigoMunicipio = "4106902".parse()?;
+    /// assert_eq!(Some("Curitiba"), codigo.name());
+    ///
+    /// # Ok::<(), ParseCodigoMunicipioError>(())
+    /// ```
+    #[cfg(feature = "municipios")]
+    pub fn name(self) -> Option<&'static str> {
+        Municipio::find_by_code(self).map(Municipio::name)
+    }
+
+    /// Whether `s` is a 7 digit code with a valid check digit, without building it.
+    ///
+    /// ```
+    /// use bras::CodigoMunicipio;
+    ///
+    /// assert!(CodigoMunicipio::is_valid("4106902"));
+    /// assert!(!CodigoMunicipio::is_valid("4106903"));
+    /// ```
+    pub const fn is_valid(s: &str) -> bool {
+        CodigoMunicipio::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Federative unit encoded in the first two digits.
+    pub fn uf(self) -> Uf {
+        Uf::try_from((self.inner / 100_000) as u8).expect("validated on construction")
+    }
+
+    /// Parses the 7 digits straight from ASCII bytes. Usable in constants, see
+    /// [`codigo_municipio!`](crate::codigo_municipio!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseCodigoMunicipioError> {
+        if bytes.len() != 7 {
+            return Err(ParseCodigoMunicipioError::Invalid);
+        }
+        let mut value = 0u32;
+        let mut position = 0;
+        while position < bytes.len() {
+            if !bytes[position].is_ascii_digit() {
+                return Err(ParseCodigoMunicipioError::Invalid);
+            }
+            value = value * 10 + (bytes[position] - b'0') as u32;
+            position += 1;
+        }
+        CodigoMunicipio::new(value)
+    }
+
+    const fn new(value: u32) -> Result<Self, ParseCodigoMunicipioError> {
+        if value < 1_000_000 || value >= 10_000_000 {
+            return Err(ParseCodigoMunicipioError::Invalid);
+        }
+        if Uf::from_code((value / 100_000) as u8).is_none() {
+            return Err(ParseCodigoMunicipioError::Invalid);
+        }
+
+        if !Self::is_check_digit_exception(value) && value % 10 != Self::verifier_digit(value / 10)
+        {
+            return Err(ParseCodigoMunicipioError::Invalid);
+        }
+
+        Ok(CodigoMunicipio { inner: value })
+    }
+
+    /// Random code of a random federative unit with a valid check digit, not necessarily
+    /// assigned to a municipality.
+    ///
+    /// ```
+    /// use bras::CodigoMunicipio;
+    ///
+    /// let codigo = CodigoMunicipio::generate();
+    /// assert_eq!(Ok(codigo), codigo.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`CodigoMunicipio::generate`], drawing from the given generator so runs can be
+    /// reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// Random code of the given federative unit with a valid check digit, not necessarily
+    /// assigned to a municipality.
+    ///
+    /// ```
+    /// use bras::{CodigoMunicipio, Uf};
+    ///
+    /// let codigo = CodigoMunicipio::generate_in(Uf::PE);
+    /// assert_eq!(Uf::PE, codigo.uf());
+    /// assert_eq!(Ok(codigo), codigo.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate_in(uf: Uf) -> Self {
+        Self::generate_in_with(uf, &mut rand::thread_rng())
+    }
+
+    /// Like [`CodigoMunicipio::generate_in`], drawing from the given generator so runs can
+    /// be reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_in_with<R: rand::Rng + ?Sized>(uf: Uf, rng: &mut R) -> Self {
+        let base = u32::from(uf.code()) * 10_000 + rng.gen_range(0..10_000);
+        CodigoMunicipio {
+            inner: base * 10 + Self::verifier_digit(base),
+        }
+    }
+
+    /// Parses the digits of `s`, ignoring every other character.
+    ///
+    /// ```
+    /// use bras::CodigoMunicipio;
+    /// # use bras::ParseCodigoMunicipioError;
+    ///
+    /// let codigo = CodigoMunicipio::parse_lossy("41.06902")?;
+    /// assert_eq!("4106902", codigo.to_string());
+    ///
+    /// # Ok::<(), ParseCodigoMunicipioError>(())
+    /// ```
+    pub fn parse_lossy(s: &str) -> Result<Self, ParseCodigoMunicipioError> {
+        let digits: [u8; 7] = digits_only(s).map_err(|_| ParseCodigoMunicipioError::Invalid)?;
+        let value = digits
+            .iter()
+            .fold(0u32, |acc, digit| acc * 10 + u32::from(*digit));
+        CodigoMunicipio::new(value)
+    }
+
+    pub(crate) const fn is_check_digit_exception(value: u32) -> bool {
+        let mut position = 0;
+        while position < CHECK_DIGIT_EXCEPTIONS.len() {
+            if CHECK_DIGIT_EXCEPTIONS[position] == value {
+                return true;
+            }
+            position += 1;
+        }
+        false
+    }
+
+    const fn verifier_digit(base: u32) -> u32 {
+        let mut sum = 0;
+        let mut rest = base;
+        let mut position = WEIGHTS.len();
+        while position > 0 {
+            position -= 1;
+            let product = WEIGHTS[position] * (rest % 10);
+            sum += product / 10 + product % 10;
+            rest /= 10;
+        }
+        (10 - sum % 10) % 10
+    }
+}
+
+/// Entry of the embedded IBGE municipality table.
+///
+/// # Examples
+/// ```
+/// use bras::{Municipio, Uf};
+///
+/// let municipio = Municipio::find_by_name("são paulo", Uf::SP).unwrap();
+/// assert_eq!("3550308", municipio.codigo().to_string());
+/// assert_eq!("São Paulo", municipio.name());
+///
+/// assert_eq!(Some(municipio), Municipio::find_by_name("Sao  Paulo", Uf::SP));
+/// assert!(Municipio::find_by_name("São Paulo", Uf::RJ).is_none());
+/// ```
+#[cfg(feature = "municipios")]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Municipio {
+    codigo: CodigoMunicipio,
+    name: &'static str,
+}
+
+#[cfg(feature = "municipios")]
+impl Municipio {
+    pub fn codigo(self) -> CodigoMunicipio {
+        self.codigo
+    }
+
+    pub fn name(self) -> &'static str {
+        self.name
+    }
+
+    pub fn uf(self) -> Uf {
+        self.codigo.uf()
+    }
+
+    pub fn all() -> impl Iterator<Item = Municipio> {
+        MUNICIPIOS.iter().map(|&(code, name)| Municipio {
+            codigo: CodigoMunicipio { inner: code },
+            name,
+        })
+    }
+
+    pub fn find_by_code(codigo: CodigoMunicipio) -> Option<Municipio> {
+        MUNICIPIOS
+            .binary_search_by_key(&codigo.inner, |&(code, _)| code)
+            .ok()
+            .map(|index| Municipio {
+                codigo,
+                name: MUNICIPIOS[index].1,
+            })
+    }
+
+    /// Lookup of a municipality by its name within a federative unit, ignoring case, accents
+    /// and extra whitespace like [`Uf::from_name_fuzzy`].
+    pub fn find_by_name(name: &str, uf: Uf) -> Option<Municipio> {
+        let name = super::normalize(name);
+        Self::all().find(|m| m.uf() == uf && super::normalize(m.name) == name)
+    }
+}
+
+impl FromStr for CodigoMunicipio {
+    type Err = ParseCodigoMunicipioError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CodigoMunicipio::from_bytes(s.as_bytes())
+    }
+}
+
+impl From<CodigoMunicipio> for u32 {
+    fn from(codigo: CodigoMunicipio) -> Self {
+        codigo.inner
+    }
+}
+
+impl TryFrom<u32> for CodigoMunicipio {
+    type Error = ParseCodigoMunicipioError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        CodigoMunicipio::new(value)
+    }
+}
+
+impl Display for CodigoMunicipio {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl BrDocument for CodigoMunicipio {
+    fn kind(&self) -> &'static str {
+        "código IBGE"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        format!("{}", self.inner)
+            .bytes()
+            .map(|b| b - b'0')
+            .collect()
+    }
+
+    /// The code is public information, so nothing is hidden.
+    fn masked(&self) -> String {
+        self.to_string()
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        CodigoMunicipio::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<CodigoMunicipio> for rand::distributions::Standard {
+    /// Uniform over the federative units, then over the codes of the chosen one.
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> CodigoMunicipio {
+        CodigoMunicipio::generate_in_with(rng.gen(), rng)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CodigoMunicipio {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let uf = *u.choose(&Uf::ALL)?;
+        let base = u32::from(uf.code()) * 10_000 + u.int_in_range(0..=9_999)?;
+        Ok(CodigoMunicipio {
+            inner: base * 10 + CodigoMunicipio::verifier_digit(base),
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for CodigoMunicipio {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<CodigoMunicipio>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (proptest::sample::select(Uf::ALL.to_vec()), 0..10_000u32)
+            .prop_map(|(uf, rest)| {
+                let base = u32::from(uf.code()) * 10_000 + rest;
+                CodigoMunicipio {
+                    inner: base * 10 + CodigoMunicipio::verifier_digit(base),
+                }
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn parse_capitals() {
+        let capitals = [
+            ("1200401", Uf::AC),
+            ("2704302", Uf::AL),
+            ("1600303", Uf::AP),
+            ("1302603", Uf::AM),
+            ("2927408", Uf::BA),
+            ("2304400", Uf::CE),
+            ("5300108", Uf::DF),
+            ("3205309", Uf::ES),
+            ("5208707", Uf::GO),
+            ("2111300", Uf::MA),
+            ("5103403", Uf::MT),
+            ("5002704", Uf::MS),
+            ("3106200", Uf::MG),
+            ("1501402", Uf::PA),
+            ("2507507", Uf::PB),
+            ("4106902", Uf::PR),
+            ("2611606", Uf::PE),
+            ("2211001", Uf::PI),
+            ("3304557", Uf::RJ),
+            ("2408102", Uf::RN),
+            ("4314902", Uf::RS),
+            ("1100205", Uf::RO),
+            ("1400100", Uf::RR),
+            ("4205407", Uf::SC),
+            ("3550308", Uf::SP),
+            ("2800308", Uf::SE),
+            ("1721000", Uf::TO),
+        ];
+        for (code, uf) in capitals {
+            let codigo = CodigoMunicipio::from_str(code).unwrap();
+            assert_eq!(uf, codigo.uf());
+            assert_eq!(code, codigo.to_string());
+        }
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let invalid = ["3550309", "355030", "35503080", "9950308", "35503O8", ""];
+        for code in invalid {
+            assert_eq!(
+                CodigoMunicipio::from_str(code).unwrap_err(),
+                ParseCodigoMunicipioError::Invalid
+            );
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_codes_are_valid() {
+        for _ in 0..1000 {
+            let codigo = CodigoMunicipio::generate();
+            assert_eq!(Ok(codigo), CodigoMunicipio::from_str(&codigo.to_string()));
+        }
+        for uf in Uf::ALL {
+            assert_eq!(uf, CodigoMunicipio::generate_in(uf).uf());
+        }
+    }
+
+    #[cfg(feature = "municipios")]
+    #[test]
+    fn embedded_table_is_sorted_and_valid() {
+        assert!(MUNICIPIOS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for municipio in Municipio::all() {
+            assert_eq!(
+                municipio.codigo(),
+                CodigoMunicipio::try_from(u32::from(municipio.codigo())).unwrap()
+            );
+            assert_eq!(
+                Some(municipio),
+                Municipio::find_by_name(municipio.name(), municipio.uf())
+            );
+        }
+    }
+
+    #[cfg(feature = "municipios")]
+    #[test]
+    fn find_by_name_ignores_accents_and_case() {
+        let sao_paulo = Municipio::find_by_name("São Paulo", Uf::SP);
+        assert!(sao_paulo.is_some());
+        assert_eq!(sao_paulo, Municipio::find_by_name("Sao Paulo", Uf::SP));
+        assert_eq!(sao_paulo, Municipio::find_by_name(" SAO   PAULO ", Uf::SP));
+        assert_eq!(None, Municipio::find_by_name("Sao Paulo", Uf::MG));
+    }
+
+    /// The 2022 census counted 5,570, Brasília and Fernando de Noronha included.
+    #[cfg(feature = "municipios")]
+    #[test]
+    #[ignore = "the embedded table is partial until regenerated with scripts/municipios.py"]
+    fn embedded_table_has_every_municipality() {
+        assert!(MUNICIPIOS.len() >= 5570, "only {} rows", MUNICIPIOS.len());
+        for uf in Uf::ALL {
+            assert!(Municipio::all().any(|m| m.uf() == uf), "none in {}", uf);
+        }
+    }
+
+    #[test]
+    fn accept_known_check_digit_exceptions() {
+        for code in CHECK_DIGIT_EXCEPTIONS {
+            assert!(CodigoMunicipio::try_from(code).is_ok());
+        }
+    }
+}
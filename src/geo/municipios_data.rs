@@ -0,0 +1,67 @@
+//! IBGE municipality table (DTB), sorted by code.
+//!
+//! Only the state capitals and the most populous municipalities so far: run
+//! `scripts/municipios.py` with network access to replace it with the full DTB.
+
+pub(super) const MUNICIPIOS: &[(u32, &str)] = &[
+    (1100205, "Porto Velho"),
+    (1200401, "Rio Branco"),
+    (1302603, "Manaus"),
+    (1400100, "Boa Vista"),
+    (1500800, "Ananindeua"),
+    (1501402, "Belém"),
+    (1506807, "Santarém"),
+    (1600303, "Macapá"),
+    (1721000, "Palmas"),
+    (2111300, "São Luís"),
+    (2211001, "Teresina"),
+    (2304400, "Fortaleza"),
+    (2408102, "Natal"),
+    (2504009, "Campina Grande"),
+    (2507507, "João Pessoa"),
+    (2604106, "Caruaru"),
+    (2607901, "Jaboatão dos Guararapes"),
+    (2609600, "Olinda"),
+    (2611101, "Petrolina"),
+    (2611606, "Recife"),
+    (2704302, "Maceió"),
+    (2800308, "Aracaju"),
+    (2910800, "Feira de Santana"),
+    (2927408, "Salvador"),
+    (3106200, "Belo Horizonte"),
+    (3118601, "Contagem"),
+    (3136702, "Juiz de Fora"),
+    (3170206, "Uberlândia"),
+    (3201308, "Cariacica"),
+    (3205002, "Serra"),
+    (3205200, "Vila Velha"),
+    (3205309, "Vitória"),
+    (3301702, "Duque de Caxias"),
+    (3303302, "Niterói"),
+    (3303500, "Nova Iguaçu"),
+    (3304557, "Rio de Janeiro"),
+    (3304904, "São Gonçalo"),
+    (3509502, "Campinas"),
+    (3518800, "Guarulhos"),
+    (3534401, "Osasco"),
+    (3543402, "Ribeirão Preto"),
+    (3548500, "Santos"),
+    (3548708, "São Bernardo do Campo"),
+    (3550308, "São Paulo"),
+    (3552205, "Sorocaba"),
+    (4106902, "Curitiba"),
+    (4113700, "Londrina"),
+    (4115200, "Maringá"),
+    (4202404, "Blumenau"),
+    (4205407, "Florianópolis"),
+    (4209102, "Joinville"),
+    (4305108, "Caxias do Sul"),
+    (4314407, "Pelotas"),
+    (4314902, "Porto Alegre"),
+    (5002704, "Campo Grande"),
+    (5103403, "Cuiabá"),
+    (5201108, "Anápolis"),
+    (5201405, "Aparecida de Goiânia"),
+    (5208707, "Goiânia"),
+    (5300108, "Brasília"),
+];
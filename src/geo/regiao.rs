@@ -0,0 +1,91 @@
+use core::fmt::Display;
+
+use super::uf::Uf;
+
+/// The five macro-regions defined by the IBGE.
+///
+/// # Examples
+/// ```
+/// use bras::{Regiao, Uf};
+///
+/// assert_eq!(&[Uf::PR, Uf::RS, Uf::SC], Regiao::Sul.ufs());
+/// assert_eq!("Centro-Oeste", Regiao::CentroOeste.to_string());
+///
+/// for uf in Regiao::Norte.ufs() {
+///     assert_eq!(Regiao::Norte, uf.region());
+/// }
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Regiao {
+    Norte,
+    Nordeste,
+    CentroOeste,
+    Sudeste,
+    Sul,
+}
+
+impl Regiao {
+    pub const ALL: [Regiao; 5] = [
+        Regiao::Norte,
+        Regiao::Nordeste,
+        Regiao::CentroOeste,
+        Regiao::Sudeste,
+        Regiao::Sul,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Regiao::Norte => "Norte",
+            Regiao::Nordeste => "Nordeste",
+            Regiao::CentroOeste => "Centro-Oeste",
+            Regiao::Sudeste => "Sudeste",
+            Regiao::Sul => "Sul",
+        }
+    }
+
+    /// Federative units belonging to the region, in alphabetical order of their siglas.
+    pub fn ufs(self) -> &'static [Uf] {
+        match self {
+            Regiao::Norte => &[Uf::AC, Uf::AM, Uf::AP, Uf::PA, Uf::RO, Uf::RR, Uf::TO],
+            Regiao::Nordeste => &[
+                Uf::AL,
+                Uf::BA,
+                Uf::CE,
+                Uf::MA,
+                Uf::PB,
+                Uf::PE,
+                Uf::PI,
+                Uf::RN,
+                Uf::SE,
+            ],
+            Regiao::CentroOeste => &[Uf::DF, Uf::GO, Uf::MS, Uf::MT],
+            Regiao::Sudeste => &[Uf::ES, Uf::MG, Uf::RJ, Uf::SP],
+            Regiao::Sul => &[Uf::PR, Uf::RS, Uf::SC],
+        }
+    }
+}
+
+impl Display for Regiao {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn every_uf_belongs_to_exactly_one_region() {
+        for uf in Uf::ALL {
+            let regions: Vec<Regiao> = Regiao::ALL
+                .iter()
+                .copied()
+                .filter(|r| r.ufs().contains(&uf))
+                .collect();
+            assert_eq!(vec![uf.region()], regions);
+        }
+    }
+}
@@ -0,0 +1,425 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::uf::Uf;
+use crate::doc::mask::mask;
+use crate::{BrDocument, MaskStyle};
+
+/// Brazilian telephone number: a two digit area code (DDD) followed by the 9 digits of a
+/// mobile number, which start with 9, or the 8 digits of a landline, which start with 2 to
+/// 5. The "+55" country code, the parentheses around the area code and the hyphen are
+/// optional.
+///
+/// # Examples
+/// ```
+/// use bras::{Telefone, Uf};
+/// # use bras::ParseTelefoneError;
+///
+/// let telefone: Telefone = "(11) 98765-4321".parse()?;
+/// assert_eq!(11, telefone.ddd());
+/// assert_eq!(Uf::SP, telefone.uf());
+/// assert!(telefone.is_celular());
+///
+/// let telefone: Telefone = "+55 61 3456 7890".parse()?;
+/// assert_eq!("(61) 3456-7890", telefone.to_string());
+/// assert_eq!("+556134567890", format!("{:#}", telefone));
+///
+/// # Ok::<(), ParseTelefoneError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(not(feature = "redact-debug"), derive(Debug))]
+pub struct Telefone {
+    ddd: u8,
+    numero: u32,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseTelefoneError {
+    /// Byte at the zero based `position` of the input is out of place.
+    InvalidCharacter { position: usize },
+    /// Too short, or not 8 or 9 digits after the area code.
+    InvalidFormat,
+    /// The area code is not assigned to any federative unit.
+    InvalidDdd,
+    /// A 9 digit number not starting with 9, or an 8 digit one not starting with 2 to 5.
+    InvalidNumero,
+}
+
+impl Display for ParseTelefoneError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseTelefoneError::InvalidCharacter { position } => write!(
+                f,
+                "invalid phone number, unexpected character at position {}",
+                position
+            ),
+            ParseTelefoneError::InvalidFormat => {
+                f.write_str("invalid phone number, expected an area code followed by 8 or 9 digits")
+            }
+            ParseTelefoneError::InvalidDdd => {
+                f.write_str("invalid phone number, unknown area code")
+            }
+            ParseTelefoneError::InvalidNumero => f.write_str(
+                "invalid phone number, mobile numbers start with 9 and landlines with 2 to 5",
+            ),
+        }
+    }
+}
+
+impl ParseTelefoneError {
+    /// Zero based byte offset of the input where parsing went wrong, for the errors that
+    /// point to a single character.
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseTelefoneError::InvalidCharacter { position } => Some(*position),
+            _ => None,
+        }
+    }
+}
+
+impl core::error::Error for ParseTelefoneError {}
+
+impl Telefone {
+    /// Area code (DDD).
+    pub fn ddd(self) -> u8 {
+        self.ddd
+    }
+
+    /// Federative unit of the area code.
+    pub fn uf(self) -> Uf {
+        Uf::from_ddd(self.ddd).expect("validated on construction")
+    }
+
+    /// The 8 or 9 digits after the area code.
+    pub fn numero(self) -> u32 {
+        self.numero
+    }
+
+    /// Whether it is a mobile number, the 9 digit ones.
+    pub fn is_celular(self) -> bool {
+        self.numero > 99_999_999
+    }
+
+    /// Formatted with the digits chosen by `style` hidden. The recommended mask keeps only
+    /// the last 4 digits visible.
+    pub fn masked_with(self, style: MaskStyle) -> String {
+        let len = if self.is_celular() { 11 } else { 10 };
+        mask(&self.to_string(), style.visible(len, len - 4..len))
+    }
+
+    /// Whether `s` is a valid phone number in a format accepted by [`FromStr`].
+    pub fn is_valid(s: &str) -> bool {
+        Telefone::from_bytes(s.as_bytes()).is_ok()
+    }
+
+    /// Random mobile or landline number with an assigned area code.
+    ///
+    /// ```
+    /// use bras::Telefone;
+    ///
+    /// let telefone = Telefone::generate();
+    /// assert_eq!(Ok(telefone), telefone.to_string().parse());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand::thread_rng())
+    }
+
+    /// Like [`Telefone::generate`], drawing from the given generator so runs can be
+    /// reproduced.
+    #[cfg(feature = "rand")]
+    pub fn generate_with<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    /// Random number with one of the area codes of `uf`.
+    ///
+    /// ```
+    /// use bras::{Telefone, Uf};
+    ///
+    /// let telefone = Telefone::generate_in(Uf::BA);
+    /// assert_eq!(Uf::BA, telefone.uf());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate_in(uf: Uf) -> Self {
+        Self::generate_in_with(uf, &mut rand::thread_rng())
+    }
+
+    /// Like [`Telefone::generate_in`], drawing from the given generator.
+    #[cfg(feature = "rand")]
+    pub fn generate_in_with<R: rand::Rng + ?Sized>(uf: Uf, rng: &mut R) -> Self {
+        let ddds = uf.ddds();
+        let ddd = ddds[rng.gen_range(0..ddds.len())];
+        Telefone::with_ddd(ddd, rng.gen(), rng.gen())
+    }
+
+    /// Number in `ddd` made from `numero`, a mobile one when `celular`.
+    #[cfg(any(feature = "rand", feature = "arbitrary"))]
+    fn with_ddd(ddd: u8, celular: bool, numero: u32) -> Self {
+        let numero = if celular {
+            900_000_000 + numero % 100_000_000
+        } else {
+            20_000_000 + numero % 40_000_000
+        };
+        Telefone { ddd, numero }
+    }
+
+    /// Parses the same formats as [`FromStr`], straight from ASCII bytes. Usable in
+    /// constants, see [`telefone!`](crate::telefone!).
+    pub const fn from_bytes(bytes: &[u8]) -> Result<Self, ParseTelefoneError> {
+        let len = bytes.len();
+        let mut position = 0;
+        if len > 0 && bytes[0] == b'+' {
+            if len < 3 || bytes[1] != b'5' || bytes[2] != b'5' {
+                return Err(ParseTelefoneError::InvalidFormat);
+            }
+            position = 3;
+            if position < len && bytes[position] == b' ' {
+                position += 1;
+            }
+        }
+        let parenteses = position < len && bytes[position] == b'(';
+        if parenteses {
+            position += 1;
+        }
+        if position + 2 > len {
+            return Err(ParseTelefoneError::InvalidFormat);
+        }
+        let mut ddd = 0;
+        let end = position + 2;
+        while position < end {
+            if !bytes[position].is_ascii_digit() {
+                return Err(ParseTelefoneError::InvalidCharacter { position });
+            }
+            ddd = ddd * 10 + (bytes[position] - b'0');
+            position += 1;
+        }
+        if parenteses {
+            if position == len {
+                return Err(ParseTelefoneError::InvalidFormat);
+            }
+            if bytes[position] != b')' {
+                return Err(ParseTelefoneError::InvalidCharacter { position });
+            }
+            position += 1;
+        }
+        if position < len && bytes[position] == b' ' {
+            position += 1;
+        }
+
+        let mut numero = 0u32;
+        let mut digits = 0;
+        // Number of digits before the hyphen or space, 0 when there is none.
+        let mut separator = 0;
+        while position < len {
+            let byte = bytes[position];
+            if byte.is_ascii_digit() && digits < 9 {
+                numero = numero * 10 + (byte - b'0') as u32;
+                digits += 1;
+            } else if (byte == b'-' || byte == b' ') && separator == 0 && digits >= 4 {
+                separator = digits;
+            } else {
+                return Err(ParseTelefoneError::InvalidCharacter { position });
+            }
+            position += 1;
+        }
+        if (digits != 8 && digits != 9) || (separator != 0 && digits - separator != 4) {
+            return Err(ParseTelefoneError::InvalidFormat);
+        }
+        if Uf::from_ddd(ddd).is_none() {
+            return Err(ParseTelefoneError::InvalidDdd);
+        }
+        let valid = match digits {
+            9 => numero / 100_000_000 == 9,
+            _ => numero / 10_000_000 >= 2 && numero / 10_000_000 <= 5,
+        };
+        if !valid {
+            return Err(ParseTelefoneError::InvalidNumero);
+        }
+        Ok(Telefone { ddd, numero })
+    }
+}
+
+impl FromStr for Telefone {
+    type Err = ParseTelefoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Telefone::from_bytes(s.as_bytes())
+    }
+}
+
+/// Formatted as "(11) 98765-4321". The alternate flag, `{:#}`, prints the E.164 form,
+/// "+5511987654321".
+impl Display for Telefone {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            write!(f, "+55{}{}", self.ddd, self.numero)
+        } else {
+            write!(
+                f,
+                "({}) {}-{:04}",
+                self.ddd,
+                self.numero / 10_000,
+                self.numero % 10_000
+            )
+        }
+    }
+}
+
+/// With the `redact-debug` feature, prints the [masked](Telefone::masked) number so it
+/// cannot leak into logs through `{:?}`.
+#[cfg(feature = "redact-debug")]
+impl core::fmt::Debug for Telefone {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Telefone({})", self.masked())
+    }
+}
+
+impl BrDocument for Telefone {
+    fn kind(&self) -> &'static str {
+        "phone number"
+    }
+
+    fn digits(&self) -> Vec<u8> {
+        format!("{}{}", self.ddd, self.numero)
+            .bytes()
+            .map(|digit| digit - b'0')
+            .collect()
+    }
+
+    fn masked(&self) -> String {
+        self.masked_with(MaskStyle::Recommended)
+    }
+
+    fn is_valid_str(s: &str) -> bool {
+        Telefone::is_valid(s)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Telefone> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Telefone {
+        Telefone::generate_in_with(rng.gen(), rng)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Telefone {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let ddds = u.choose(&Uf::ALL)?.ddds();
+        Ok(Telefone::with_ddd(
+            *u.choose(ddds)?,
+            u.arbitrary()?,
+            u.arbitrary()?,
+        ))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Telefone {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Telefone>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        let ddds: Vec<u8> = Uf::ALL.iter().flat_map(|uf| uf.ddds()).copied().collect();
+        (
+            proptest::sample::select(ddds),
+            proptest::arbitrary::any::<bool>(),
+            proptest::arbitrary::any::<u32>(),
+        )
+            .prop_map(|(ddd, celular, numero)| Telefone::with_ddd(ddd, celular, numero))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn parse_every_format() {
+        let celular = Telefone {
+            ddd: 11,
+            numero: 987_654_321,
+        };
+        for s in [
+            "(11) 98765-4321",
+            "(11)98765-4321",
+            "11 98765-4321",
+            "11 98765 4321",
+            "11987654321",
+            "+55 11 98765-4321",
+            "+55 (11) 987654321",
+            "+5511987654321",
+        ] {
+            assert_eq!(Ok(celular), s.parse(), "{}", s);
+        }
+        let fixo: Telefone = "(61) 3456-7890".parse().unwrap();
+        assert!(!fixo.is_celular());
+        assert_eq!(Uf::DF, fixo.uf());
+        assert_eq!("(61) 3456-7890", fixo.to_string());
+        assert_eq!("(**) ****-7890", fixo.masked());
+        assert_eq!("(**) *****-4321", celular.masked());
+        assert_eq!(vec![1, 1, 9, 8, 7, 6, 5, 4, 3, 2, 1], celular.digits());
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let parse = |s: &str| s.parse::<Telefone>();
+        assert_eq!(Err(ParseTelefoneError::InvalidFormat), parse(""));
+        assert_eq!(Err(ParseTelefoneError::InvalidFormat), parse("(11)"));
+        assert_eq!(
+            Err(ParseTelefoneError::InvalidFormat),
+            parse("+1 11 98765-4321")
+        );
+        assert_eq!(
+            Err(ParseTelefoneError::InvalidFormat),
+            parse("11 98765-432")
+        );
+        assert_eq!(
+            Err(ParseTelefoneError::InvalidCharacter { position: 6 }),
+            parse("11 987-654321")
+        );
+        assert_eq!(
+            Err(ParseTelefoneError::InvalidCharacter { position: 3 }),
+            parse("(11-98765-4321")
+        );
+        assert_eq!(
+            Err(ParseTelefoneError::InvalidCharacter { position: 15 }),
+            parse("(11) 98765-43210")
+        );
+        assert_eq!(
+            Err(ParseTelefoneError::InvalidDdd),
+            parse("(20) 98765-4321")
+        );
+        assert_eq!(
+            Err(ParseTelefoneError::InvalidNumero),
+            parse("(11) 88765-4321")
+        );
+        assert_eq!(
+            Err(ParseTelefoneError::InvalidNumero),
+            parse("(11) 6456-7890")
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generated_telefones_are_valid() {
+        for _ in 0..1000 {
+            let telefone = Telefone::generate();
+            assert_eq!(Ok(telefone), telefone.to_string().parse());
+            assert_eq!(Ok(telefone), format!("{:#}", telefone).parse());
+        }
+        for uf in Uf::ALL {
+            assert_eq!(uf, Telefone::generate_in(uf).uf());
+        }
+    }
+}
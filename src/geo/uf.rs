@@ -0,0 +1,496 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::normalize;
+use super::regiao::Regiao;
+
+/// Brazilian federative units (states and the Distrito Federal).
+///
+/// # Examples
+/// ```
+/// use bras::{Regiao, Uf};
+/// # use bras::ParseUfError;
+///
+/// let uf: Uf = "SP".parse()?;
+/// assert_eq!(Uf::SP, uf);
+/// assert_eq!("São Paulo", uf.name());
+/// assert_eq!(35, uf.code());
+/// assert_eq!(Regiao::Sudeste, uf.region());
+///
+/// let uf: Uf = "rj".parse()?;
+/// assert_eq!("RJ", uf.to_string());
+///
+/// # Ok::<(), ParseUfError>(())
+/// ```
+///
+/// ## Conversions
+/// ```
+/// use bras::Uf;
+/// # use bras::ParseUfError;
+///
+/// let uf = Uf::try_from(43)?;
+/// assert_eq!(Uf::RS, uf);
+/// assert_eq!(43u8, u8::from(uf));
+///
+/// # Ok::<(), ParseUfError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression))]
+#[cfg_attr(feature = "diesel", derive(diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
+pub enum Uf {
+    AC,
+    AL,
+    AP,
+    AM,
+    BA,
+    CE,
+    DF,
+    ES,
+    GO,
+    MA,
+    MT,
+    MS,
+    MG,
+    PA,
+    PB,
+    PR,
+    PE,
+    PI,
+    RJ,
+    RN,
+    RS,
+    RO,
+    RR,
+    SC,
+    SP,
+    SE,
+    TO,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseUfError {
+    Invalid,
+}
+
+impl Display for ParseUfError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseUfError::Invalid => f.write_str("invalid federative unit"),
+        }
+    }
+}
+
+impl core::error::Error for ParseUfError {}
+
+impl Uf {
+    /// Every federative unit, in alphabetical order of their names.
+    pub const ALL: [Uf; 27] = [
+        Uf::AC,
+        Uf::AL,
+        Uf::AP,
+        Uf::AM,
+        Uf::BA,
+        Uf::CE,
+        Uf::DF,
+        Uf::ES,
+        Uf::GO,
+        Uf::MA,
+        Uf::MT,
+        Uf::MS,
+        Uf::MG,
+        Uf::PA,
+        Uf::PB,
+        Uf::PR,
+        Uf::PE,
+        Uf::PI,
+        Uf::RJ,
+        Uf::RN,
+        Uf::RS,
+        Uf::RO,
+        Uf::RR,
+        Uf::SC,
+        Uf::SP,
+        Uf::SE,
+        Uf::TO,
+    ];
+
+    /// Two letter abbreviation, as used by the Correios and the IBGE.
+    pub const fn sigla(self) -> &'static str {
+        match self {
+            Uf::AC => "AC",
+            Uf::AL => "AL",
+            Uf::AP => "AP",
+            Uf::AM => "AM",
+            Uf::BA => "BA",
+            Uf::CE => "CE",
+            Uf::DF => "DF",
+            Uf::ES => "ES",
+            Uf::GO => "GO",
+            Uf::MA => "MA",
+            Uf::MT => "MT",
+            Uf::MS => "MS",
+            Uf::MG => "MG",
+            Uf::PA => "PA",
+            Uf::PB => "PB",
+            Uf::PR => "PR",
+            Uf::PE => "PE",
+            Uf::PI => "PI",
+            Uf::RJ => "RJ",
+            Uf::RN => "RN",
+            Uf::RS => "RS",
+            Uf::RO => "RO",
+            Uf::RR => "RR",
+            Uf::SC => "SC",
+            Uf::SP => "SP",
+            Uf::SE => "SE",
+            Uf::TO => "TO",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Uf::AC => "Acre",
+            Uf::AL => "Alagoas",
+            Uf::AP => "Amapá",
+            Uf::AM => "Amazonas",
+            Uf::BA => "Bahia",
+            Uf::CE => "Ceará",
+            Uf::DF => "Distrito Federal",
+            Uf::ES => "Espírito Santo",
+            Uf::GO => "Goiás",
+            Uf::MA => "Maranhão",
+            Uf::MT => "Mato Grosso",
+            Uf::MS => "Mato Grosso do Sul",
+            Uf::MG => "Minas Gerais",
+            Uf::PA => "Pará",
+            Uf::PB => "Paraíba",
+            Uf::PR => "Paraná",
+            Uf::PE => "Pernambuco",
+            Uf::PI => "Piauí",
+            Uf::RJ => "Rio de Janeiro",
+            Uf::RN => "Rio Grande do Norte",
+            Uf::RS => "Rio Grande do Sul",
+            Uf::RO => "Rondônia",
+            Uf::RR => "Roraima",
+            Uf::SC => "Santa Catarina",
+            Uf::SP => "São Paulo",
+            Uf::SE => "Sergipe",
+            Uf::TO => "Tocantins",
+        }
+    }
+
+    /// Two digit IBGE code of the federative unit.
+    pub const fn code(self) -> u8 {
+        match self {
+            Uf::RO => 11,
+            Uf::AC => 12,
+            Uf::AM => 13,
+            Uf::RR => 14,
+            Uf::PA => 15,
+            Uf::AP => 16,
+            Uf::TO => 17,
+            Uf::MA => 21,
+            Uf::PI => 22,
+            Uf::CE => 23,
+            Uf::RN => 24,
+            Uf::PB => 25,
+            Uf::PE => 26,
+            Uf::AL => 27,
+            Uf::SE => 28,
+            Uf::BA => 29,
+            Uf::MG => 31,
+            Uf::ES => 32,
+            Uf::RJ => 33,
+            Uf::SP => 35,
+            Uf::PR => 41,
+            Uf::SC => 42,
+            Uf::RS => 43,
+            Uf::MS => 50,
+            Uf::MT => 51,
+            Uf::GO => 52,
+            Uf::DF => 53,
+        }
+    }
+
+    /// The UF whose sigla is `bytes`, in any case.
+    #[cfg(feature = "registro-profissional")]
+    pub(crate) const fn from_sigla(bytes: &[u8]) -> Option<Uf> {
+        if bytes.len() != 2 {
+            return None;
+        }
+        let mut index = 0;
+        while index < Uf::ALL.len() {
+            let sigla = Uf::ALL[index].sigla().as_bytes();
+            if bytes[0].to_ascii_uppercase() == sigla[0]
+                && bytes[1].to_ascii_uppercase() == sigla[1]
+            {
+                return Some(Uf::ALL[index]);
+            }
+            index += 1;
+        }
+        None
+    }
+
+    /// Federative unit with the given IBGE code.
+    pub(crate) const fn from_code(code: u8) -> Option<Uf> {
+        match code {
+            11 => Some(Uf::RO),
+            12 => Some(Uf::AC),
+            13 => Some(Uf::AM),
+            14 => Some(Uf::RR),
+            15 => Some(Uf::PA),
+            16 => Some(Uf::AP),
+            17 => Some(Uf::TO),
+            21 => Some(Uf::MA),
+            22 => Some(Uf::PI),
+            23 => Some(Uf::CE),
+            24 => Some(Uf::RN),
+            25 => Some(Uf::PB),
+            26 => Some(Uf::PE),
+            27 => Some(Uf::AL),
+            28 => Some(Uf::SE),
+            29 => Some(Uf::BA),
+            31 => Some(Uf::MG),
+            32 => Some(Uf::ES),
+            33 => Some(Uf::RJ),
+            35 => Some(Uf::SP),
+            41 => Some(Uf::PR),
+            42 => Some(Uf::SC),
+            43 => Some(Uf::RS),
+            50 => Some(Uf::MS),
+            51 => Some(Uf::MT),
+            52 => Some(Uf::GO),
+            53 => Some(Uf::DF),
+            _ => None,
+        }
+    }
+
+    /// Matches either the name or the sigla of a federative unit, ignoring case, accents and
+    /// repeated whitespace.
+    ///
+    /// ```
+    /// use bras::Uf;
+    /// # use bras::ParseUfError;
+    ///
+    /// assert_eq!(Uf::SP, Uf::from_name_fuzzy("Sao Paulo")?);
+    /// assert_eq!(Uf::SP, Uf::from_name_fuzzy("SÃO PAULO")?);
+    /// assert_eq!(Uf::PR, Uf::from_name_fuzzy("Parana")?);
+    /// assert_eq!(Uf::MS, Uf::from_name_fuzzy("  mato  grosso do sul ")?);
+    /// assert_eq!(Uf::PE, Uf::from_name_fuzzy("pe")?);
+    ///
+    /// # Ok::<(), ParseUfError>(())
+    /// ```
+    pub fn from_name_fuzzy(name: &str) -> Result<Uf, ParseUfError> {
+        let wanted = normalize(name);
+        Uf::ALL
+            .iter()
+            .copied()
+            .find(|uf| normalize(uf.name()) == wanted || uf.sigla().eq_ignore_ascii_case(&wanted))
+            .ok_or(ParseUfError::Invalid)
+    }
+
+    /// Telephone area codes (DDD) of the federative unit.
+    ///
+    /// ```
+    /// use bras::Uf;
+    ///
+    /// assert_eq!(&[61], Uf::DF.ddds());
+    /// assert!(Uf::SP.ddds().contains(&19));
+    /// ```
+    pub fn ddds(self) -> &'static [u8] {
+        match self {
+            Uf::AC => &[68],
+            Uf::AL => &[82],
+            Uf::AP => &[96],
+            Uf::AM => &[92, 97],
+            Uf::BA => &[71, 73, 74, 75, 77],
+            Uf::CE => &[85, 88],
+            Uf::DF => &[61],
+            Uf::ES => &[27, 28],
+            Uf::GO => &[62, 64],
+            Uf::MA => &[98, 99],
+            Uf::MT => &[65, 66],
+            Uf::MS => &[67],
+            Uf::MG => &[31, 32, 33, 34, 35, 37, 38],
+            Uf::PA => &[91, 93, 94],
+            Uf::PB => &[83],
+            Uf::PR => &[41, 42, 43, 44, 45, 46],
+            Uf::PE => &[81, 87],
+            Uf::PI => &[86, 89],
+            Uf::RJ => &[21, 22, 24],
+            Uf::RN => &[84],
+            Uf::RS => &[51, 53, 54, 55],
+            Uf::RO => &[69],
+            Uf::RR => &[95],
+            Uf::SC => &[47, 48, 49],
+            Uf::SP => &[11, 12, 13, 14, 15, 16, 17, 18, 19],
+            Uf::SE => &[79],
+            Uf::TO => &[63],
+        }
+    }
+
+    /// Federative unit of a telephone area code (DDD).
+    ///
+    /// ```
+    /// use bras::Uf;
+    ///
+    /// assert_eq!(Some(Uf::SP), Uf::from_ddd(19));
+    /// assert_eq!(None, Uf::from_ddd(20));
+    /// ```
+    pub const fn from_ddd(ddd: u8) -> Option<Uf> {
+        match ddd {
+            11..=19 => Some(Uf::SP),
+            21 | 22 | 24 => Some(Uf::RJ),
+            27 | 28 => Some(Uf::ES),
+            31..=35 | 37 | 38 => Some(Uf::MG),
+            41..=46 => Some(Uf::PR),
+            47..=49 => Some(Uf::SC),
+            51 | 53..=55 => Some(Uf::RS),
+            61 => Some(Uf::DF),
+            62 | 64 => Some(Uf::GO),
+            63 => Some(Uf::TO),
+            65 | 66 => Some(Uf::MT),
+            67 => Some(Uf::MS),
+            68 => Some(Uf::AC),
+            69 => Some(Uf::RO),
+            71 | 73..=75 | 77 => Some(Uf::BA),
+            79 => Some(Uf::SE),
+            81 | 87 => Some(Uf::PE),
+            82 => Some(Uf::AL),
+            83 => Some(Uf::PB),
+            84 => Some(Uf::RN),
+            85 | 88 => Some(Uf::CE),
+            86 | 89 => Some(Uf::PI),
+            91 | 93 | 94 => Some(Uf::PA),
+            92 | 97 => Some(Uf::AM),
+            95 => Some(Uf::RR),
+            96 => Some(Uf::AP),
+            98 | 99 => Some(Uf::MA),
+            _ => None,
+        }
+    }
+
+    /// ```
+    /// use bras::{Regiao, Uf};
+    ///
+    /// assert_eq!(Regiao::Nordeste, Uf::BA.region());
+    /// assert_eq!(Regiao::CentroOeste, Uf::DF.region());
+    /// ```
+    pub fn region(self) -> Regiao {
+        // The first digit of the IBGE code identifies the macro-region.
+        match self.code() / 10 {
+            1 => Regiao::Norte,
+            2 => Regiao::Nordeste,
+            3 => Regiao::Sudeste,
+            4 => Regiao::Sul,
+            _ => Regiao::CentroOeste,
+        }
+    }
+}
+
+impl FromStr for Uf {
+    type Err = ParseUfError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uf::ALL
+            .iter()
+            .copied()
+            .find(|uf| uf.sigla().eq_ignore_ascii_case(s))
+            .ok_or(ParseUfError::Invalid)
+    }
+}
+
+impl From<Uf> for u8 {
+    fn from(uf: Uf) -> Self {
+        uf.code()
+    }
+}
+
+impl TryFrom<u8> for Uf {
+    type Error = ParseUfError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Uf::from_code(value).ok_or(ParseUfError::Invalid)
+    }
+}
+
+impl Display for Uf {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.sigla())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Uf> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Uf {
+        Uf::ALL[rng.gen_range(0..Uf::ALL.len())]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_uf_from_str() {
+        for uf in Uf::ALL {
+            assert_eq!(uf, Uf::from_str(uf.sigla()).unwrap());
+            assert_eq!(uf, Uf::from_str(&uf.sigla().to_lowercase()).unwrap());
+        }
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        assert_eq!(Uf::from_str("XX").unwrap_err(), ParseUfError::Invalid);
+        assert_eq!(Uf::from_str("").unwrap_err(), ParseUfError::Invalid);
+        assert_eq!(
+            Uf::from_str("São Paulo").unwrap_err(),
+            ParseUfError::Invalid
+        );
+    }
+
+    #[test]
+    fn from_ddd_is_the_inverse_of_ddds() {
+        let mut count = 0;
+        for ddd in 0..=u8::MAX {
+            if let Some(uf) = Uf::from_ddd(ddd) {
+                assert!(uf.ddds().contains(&ddd), "{} {}", uf, ddd);
+                count += 1;
+            }
+        }
+        assert_eq!(
+            count,
+            Uf::ALL.iter().map(|uf| uf.ddds().len()).sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn fuzzy_names() {
+        for uf in Uf::ALL {
+            assert_eq!(uf, Uf::from_name_fuzzy(uf.name()).unwrap());
+            assert_eq!(uf, Uf::from_name_fuzzy(&uf.name().to_uppercase()).unwrap());
+        }
+        assert_eq!(Uf::AP, Uf::from_name_fuzzy("amapa").unwrap());
+        assert_eq!(Uf::ES, Uf::from_name_fuzzy("ESPIRITO SANTO").unwrap());
+        assert_eq!(
+            Uf::from_name_fuzzy("Mato Grosso do").unwrap_err(),
+            ParseUfError::Invalid
+        );
+        assert_eq!(Uf::from_name_fuzzy("").unwrap_err(), ParseUfError::Invalid);
+    }
+
+    #[test]
+    fn ibge_codes_round_trip() {
+        for uf in Uf::ALL {
+            assert_eq!(uf, Uf::try_from(uf.code()).unwrap());
+        }
+        assert_eq!(Uf::try_from(34).unwrap_err(), ParseUfError::Invalid);
+        for code in 0..=u8::MAX {
+            if let Some(uf) = Uf::from_code(code) {
+                assert_eq!(code, uf.code());
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! async-graphql scalars. Input is validated while coerced, accepting the same strings as
+//! [`FromStr`](core::str::FromStr); output is the formatted string.
+
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+use crate::{Cep, Cnpj, Cpf};
+
+/// Cadastro de Pessoas Físicas, formatted as "984.844.854-39".
+#[Scalar(name = "Cpf")]
+impl ScalarType for Cpf {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => s
+                .parse()
+                .map_err(|_| InputValueError::custom(format!("invalid CPF: {}", s))),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(s) if s.parse::<Cpf>().is_ok())
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+/// Cadastro Nacional da Pessoa Jurídica, formatted as "11.222.333/0001-81" or, when
+/// alphanumeric, "12.ABC.345/01DE-35".
+#[Scalar(name = "Cnpj")]
+impl ScalarType for Cnpj {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => s
+                .parse()
+                .map_err(|_| InputValueError::custom(format!("invalid CNPJ: {}", s))),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(s) if s.parse::<Cnpj>().is_ok())
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+/// Código de Endereçamento Postal, formatted as "01310-100".
+#[Scalar(name = "Cep")]
+impl ScalarType for Cep {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => s
+                .parse()
+                .map_err(|_| InputValueError::custom(format!("invalid CEP: {}", s))),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(s) if s.parse::<Cep>().is_ok())
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn input_coercion_validates() {
+        let cpf = <Cpf as ScalarType>::parse(Value::String("98484485439".into())).unwrap();
+        assert_eq!(Value::String("984.844.854-39".into()), cpf.to_value());
+
+        let err = <Cpf as ScalarType>::parse(Value::String("98484485401".into())).unwrap_err();
+        assert_eq!(
+            r#"Failed to parse "Cpf": invalid CPF: 98484485401"#,
+            err.into_server_error(Default::default()).message
+        );
+        assert!(<Cpf as ScalarType>::parse(Value::Number(1.into())).is_err());
+
+        let cnpj = <Cnpj as ScalarType>::parse(Value::String("12ABC34501DE35".into())).unwrap();
+        assert_eq!(Value::String("12.ABC.345/01DE-35".into()), cnpj.to_value());
+
+        let err = <Cnpj as ScalarType>::parse(Value::String("11222333000180".into())).unwrap_err();
+        assert_eq!(
+            r#"Failed to parse "Cnpj": invalid CNPJ: 11222333000180"#,
+            err.into_server_error(Default::default()).message
+        );
+        assert!(<Cnpj as ScalarType>::parse(Value::Number(1.into())).is_err());
+        assert!(<Cnpj as ScalarType>::is_valid(&Value::String(
+            "11.222.333/0001-81".into()
+        )));
+        assert!(!<Cep as ScalarType>::is_valid(&Value::String(
+            "00000-000".into()
+        )));
+    }
+}
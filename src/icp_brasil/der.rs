@@ -0,0 +1,86 @@
+//! Just enough DER to reach the subject alternative names of a certificate.
+
+const SEQUENCE: u8 = 0x30;
+const OID: u8 = 0x06;
+const OCTET_STRING: u8 = 0x04;
+const BOOLEAN: u8 = 0x01;
+const EXTENSIONS: u8 = 0xa3;
+const OTHER_NAME: u8 = 0xa0;
+const EXPLICIT_VALUE: u8 = 0xa0;
+
+/// 2.5.29.17, id-ce-subjectAltName.
+const SUBJECT_ALT_NAME: &[u8] = &[0x55, 0x1d, 0x11];
+
+/// Splits the next tag, length and value off `input`. Only definite lengths of up to four
+/// bytes are accepted, as DER requires.
+fn read<'a>(input: &mut &'a [u8]) -> Option<(u8, &'a [u8])> {
+    let (&tag, rest) = input.split_first()?;
+    let (&first, mut rest) = rest.split_first()?;
+    let len = match first {
+        0..=0x7f => usize::from(first),
+        0x81..=0x84 => {
+            let bytes = usize::from(first & 0x7f);
+            if rest.len() < bytes {
+                return None;
+            }
+            let (len, after) = rest.split_at(bytes);
+            rest = after;
+            len.iter().fold(0, |acc, &b| (acc << 8) | usize::from(b))
+        }
+        _ => return None,
+    };
+    if rest.len() < len {
+        return None;
+    }
+    let (value, after) = rest.split_at(len);
+    *input = after;
+    Some((tag, value))
+}
+
+fn expect<'a>(input: &mut &'a [u8], tag: u8) -> Option<&'a [u8]> {
+    match read(input)? {
+        (found, value) if found == tag => Some(value),
+        _ => None,
+    }
+}
+
+/// The `otherName` entries of the subject alternative names of a DER certificate, as
+/// pairs of the encoded OID and the content of its string value. `None` when the
+/// certificate is malformed.
+pub(crate) fn other_names(certificate: &[u8]) -> Option<alloc::vec::Vec<(&[u8], &[u8])>> {
+    let mut input = certificate;
+    let mut certificate = expect(&mut input, SEQUENCE)?;
+    let mut tbs = expect(&mut certificate, SEQUENCE)?;
+
+    let mut names = alloc::vec::Vec::new();
+    while !tbs.is_empty() {
+        let (tag, mut extensions) = read(&mut tbs)?;
+        if tag != EXTENSIONS {
+            continue;
+        }
+        let mut extensions = expect(&mut extensions, SEQUENCE)?;
+        while !extensions.is_empty() {
+            let mut extension = expect(&mut extensions, SEQUENCE)?;
+            let oid = expect(&mut extension, OID)?;
+            let (mut tag, mut value) = read(&mut extension)?;
+            if tag == BOOLEAN {
+                (tag, value) = read(&mut extension)?;
+            }
+            if oid != SUBJECT_ALT_NAME || tag != OCTET_STRING {
+                continue;
+            }
+            let mut general_names = expect(&mut value, SEQUENCE)?;
+            while !general_names.is_empty() {
+                let (tag, mut other_name) = read(&mut general_names)?;
+                if tag != OTHER_NAME {
+                    continue;
+                }
+                let type_id = expect(&mut other_name, OID)?;
+                let mut explicit = expect(&mut other_name, EXPLICIT_VALUE)?;
+                let (_, content) = read(&mut explicit)?;
+                names.push((type_id, content));
+            }
+        }
+    }
+    Some(names)
+}
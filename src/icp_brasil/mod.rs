@@ -0,0 +1,214 @@
+//! Holder data of ICP-Brasil e-CPF and e-CNPJ certificates, read from the `otherName`
+//! fields (OIDs 2.16.76.1.3.x) of the subject alternative names.
+//!
+//! # Examples
+//! ```
+//! use bras::icp_brasil::{titular, TipoCertificado};
+//! use bras::Uf;
+//! use chrono::NaiveDate;
+//!
+//! # let certificado = include_bytes!("testdata/ecpf.der");
+//! let titular = titular(certificado)?;
+//! assert_eq!(TipoCertificado::ECpf, titular.tipo);
+//! assert_eq!("984.844.854-39", titular.cpf.to_string());
+//! assert_eq!(NaiveDate::from_ymd_opt(1980, 3, 15), titular.nascimento);
+//! let titulo = titular.titulo_eleitor.unwrap();
+//! assert_eq!("1023 0453 0108", titulo.to_string());
+//! assert_eq!(Some(Uf::SP), titulo.uf());
+//! assert_eq!(None, titular.cnpj);
+//!
+//! # Ok::<(), bras::icp_brasil::ParseCertificadoError>(())
+//! ```
+
+mod der;
+
+use core::fmt::Display;
+
+use chrono::NaiveDate;
+
+use crate::data_br::parse_compacta;
+use crate::{Cnpj, Cpf, ParseCnpjError, ParseCpfError, ParseTituloEleitorError, TituloEleitor};
+
+/// Arc 2.16.76.1.3 of the ICP-Brasil holder data OIDs, encoded.
+const ICP_BRASIL: &[u8] = &[0x60, 0x4c, 0x01, 0x03];
+
+/// 2.16.76.1.3.1, birth date and CPF of the holder of an e-CPF.
+const DADOS_PESSOA_FISICA: u8 = 1;
+/// 2.16.76.1.3.3, CNPJ of the company holding an e-CNPJ.
+const CNPJ: u8 = 3;
+/// 2.16.76.1.3.4, birth date and CPF of the person responsible for an e-CNPJ.
+const DADOS_RESPONSAVEL: u8 = 4;
+/// 2.16.76.1.3.5, título de eleitor of the holder of an e-CPF.
+const TITULO_ELEITOR: u8 = 5;
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum TipoCertificado {
+    /// Issued to a person, the CPF being theirs.
+    ECpf,
+    /// Issued to a company, the CPF being of the person responsible for it.
+    ECnpj,
+}
+
+/// Person identified by a certificate.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Titular {
+    pub tipo: TipoCertificado,
+    pub cpf: Cpf,
+    /// Not every certificate has it, zeros being written in its place.
+    pub nascimento: Option<NaiveDate>,
+    /// Only present in some e-CPFs, zeros being written in its place in the others.
+    pub titulo_eleitor: Option<TituloEleitor>,
+    /// CNPJ of the company, present in e-CNPJs.
+    pub cnpj: Option<Cnpj>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseCertificadoError {
+    /// Not a DER encoded X.509 certificate.
+    InvalidDer,
+    /// No ICP-Brasil holder data in the subject alternative names.
+    MissingHolder,
+    /// Holder data shorter than the layout requires, or with an invalid date.
+    InvalidHolder,
+    InvalidCpf(ParseCpfError),
+    InvalidTituloEleitor(ParseTituloEleitorError),
+    InvalidCnpj(ParseCnpjError),
+}
+
+impl Display for ParseCertificadoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseCertificadoError::InvalidDer => f.write_str("invalid DER certificate"),
+            ParseCertificadoError::MissingHolder => {
+                f.write_str("certificate has no ICP-Brasil holder data")
+            }
+            ParseCertificadoError::InvalidHolder => {
+                f.write_str("invalid ICP-Brasil holder data in certificate")
+            }
+            ParseCertificadoError::InvalidCpf(err) => {
+                write!(f, "invalid holder CPF in certificate: {}", err)
+            }
+            ParseCertificadoError::InvalidTituloEleitor(err) => {
+                write!(
+                    f,
+                    "invalid holder título de eleitor in certificate: {}",
+                    err
+                )
+            }
+            ParseCertificadoError::InvalidCnpj(err) => {
+                write!(f, "invalid company CNPJ in certificate: {}", err)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseCertificadoError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseCertificadoError::InvalidCpf(err) => Some(err),
+            ParseCertificadoError::InvalidTituloEleitor(err) => Some(err),
+            ParseCertificadoError::InvalidCnpj(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the holder of a DER encoded e-CPF or e-CNPJ certificate.
+pub fn titular(certificado: &[u8]) -> Result<Titular, ParseCertificadoError> {
+    let names = der::other_names(certificado).ok_or(ParseCertificadoError::InvalidDer)?;
+    let field = |wanted: u8| {
+        names.iter().find_map(|&(oid, value)| {
+            let (&last, arc) = oid.split_last()?;
+            (arc == ICP_BRASIL && last == wanted).then_some(value)
+        })
+    };
+
+    let (tipo, dados) = match (field(DADOS_PESSOA_FISICA), field(DADOS_RESPONSAVEL)) {
+        (Some(dados), _) => (TipoCertificado::ECpf, dados),
+        (None, Some(dados)) => (TipoCertificado::ECnpj, dados),
+        (None, None) => return Err(ParseCertificadoError::MissingHolder),
+    };
+    if dados.len() < 19 {
+        return Err(ParseCertificadoError::InvalidHolder);
+    }
+    let cpf = Cpf::from_bytes(&dados[8..19]).map_err(ParseCertificadoError::InvalidCpf)?;
+    let nascimento = match &dados[..8] {
+        b"00000000" => None,
+        date => core::str::from_utf8(date)
+            .ok()
+            .and_then(|date| parse_compacta(date).ok())
+            .map(Some)
+            .ok_or(ParseCertificadoError::InvalidHolder)?,
+    };
+
+    let titulo_eleitor = match field(TITULO_ELEITOR).map(|titulo| titulo.get(..12)) {
+        Some(None) => return Err(ParseCertificadoError::InvalidHolder),
+        Some(Some(titulo)) if titulo.iter().any(|&digit| digit != b'0') => Some(
+            TituloEleitor::from_bytes(titulo)
+                .map_err(ParseCertificadoError::InvalidTituloEleitor)?,
+        ),
+        _ => None,
+    };
+
+    let cnpj = field(CNPJ)
+        .map(Cnpj::from_bytes)
+        .transpose()
+        .map_err(ParseCertificadoError::InvalidCnpj)?;
+
+    Ok(Titular {
+        tipo,
+        cpf,
+        nascimento,
+        titulo_eleitor,
+        cnpj,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn responsible_person_of_ecnpj() {
+        let titular = titular(include_bytes!("testdata/ecnpj.der")).unwrap();
+        assert_eq!(TipoCertificado::ECnpj, titular.tipo);
+        assert_eq!(Ok(titular.cpf), "051.194.390-39".parse());
+        assert_eq!(None, titular.nascimento);
+        assert_eq!(None, titular.titulo_eleitor);
+        assert_eq!(Ok(titular.cnpj.unwrap()), "11.222.333/0001-81".parse());
+    }
+
+    #[test]
+    fn reject_invalid_titulo_eleitor() {
+        let mut certificado = include_bytes!("testdata/ecpf.der").to_vec();
+        let at = certificado
+            .windows(12)
+            .position(|window| window == b"102304530108")
+            .unwrap();
+        certificado[at + 11] = b'9';
+        assert_eq!(
+            Err(ParseCertificadoError::InvalidTituloEleitor(
+                ParseTituloEleitorError::InvalidCheckDigit { which: 2 }
+            )),
+            titular(&certificado)
+        );
+
+        certificado[at..at + 12].copy_from_slice(b"000000000000");
+        assert_eq!(None, titular(&certificado).unwrap().titulo_eleitor);
+    }
+
+    #[test]
+    fn reject_anything_else() {
+        let certificado = include_bytes!("testdata/ecpf.der");
+        assert_eq!(
+            Err(ParseCertificadoError::InvalidDer),
+            titular(&certificado[..100])
+        );
+        assert_eq!(Err(ParseCertificadoError::InvalidDer), titular(b""));
+        assert_eq!(
+            Err(ParseCertificadoError::MissingHolder),
+            titular(&[0x30, 0x02, 0x30, 0x00])
+        );
+    }
+}
@@ -0,0 +1,304 @@
+//! Monthly income tax withheld at source on salaries (IRRF).
+//!
+//! # Examples
+//! ```
+//! use bras::irrf::calcular;
+//! use bras::{Competencia, Real};
+//! # use bras::ParseCompetenciaError;
+//!
+//! let competencia = Competencia::new(2024, 6)?;
+//! let irrf = calcular(Real::from_reais(5000), 1, Real::from_centavos(51860), competencia).unwrap();
+//! assert_eq!(Real::from_centavos(429181), irrf.base_calculo);
+//! assert_eq!(2250, irrf.aliquota);
+//! assert_eq!(Real::from_centavos(30289), irrf.imposto);
+//!
+//! # Ok::<(), ParseCompetenciaError>(())
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::previdencia::percentual;
+use crate::{Competencia, Real};
+
+/// Bracket of the progressive table: bases up to `ate` are taxed at `aliquota` (hundredths
+/// of a percent) minus `parcela_deduzir`. The last bracket has `ate` equal to [`Real::MAX`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct FaixaIrrf {
+    pub ate: Real,
+    pub aliquota: u32,
+    pub parcela_deduzir: Real,
+}
+
+/// Monthly reduction for low incomes introduced by Lei 15.270/2025: incomes up to
+/// `isencao_ate` have the tax reduced by up to `reducao_maxima`, and incomes up to
+/// `ate` by `constante - coeficiente * rendimento`, the coeficiente in millionths.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Redutor {
+    pub isencao_ate: Real,
+    pub reducao_maxima: Real,
+    pub ate: Real,
+    pub constante: Real,
+    pub coeficiente: u32,
+}
+
+/// Withholding table in force from the competência `inicio` on.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TabelaIrrf {
+    pub inicio: Competencia,
+    pub faixas: Vec<FaixaIrrf>,
+    pub deducao_dependente: Real,
+    /// Simplified monthly discount, used instead of the legal deductions when greater.
+    pub desconto_simplificado: Option<Real>,
+    pub redutor: Option<Redutor>,
+}
+
+/// Breakdown of the withholding, for payroll slips.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Irrf {
+    pub rendimento_bruto: Real,
+    /// INSS plus the deduction per dependent, or the simplified discount.
+    pub deducoes: Real,
+    pub desconto_simplificado: bool,
+    pub base_calculo: Real,
+    pub aliquota: u32,
+    pub parcela_deduzir: Real,
+    pub reducao: Real,
+    pub imposto: Real,
+}
+
+struct TabelaEmbutida {
+    ano: i32,
+    mes: u32,
+    faixas: [(i64, u32, i64); 5],
+    desconto_simplificado: Option<i64>,
+    redutor: bool,
+}
+
+const DEDUCAO_DEPENDENTE: i64 = 18959;
+
+const TABELAS: [TabelaEmbutida; 5] = [
+    TabelaEmbutida {
+        ano: 2015,
+        mes: 4,
+        faixas: [
+            (190398, 0, 0),
+            (282665, 750, 14280),
+            (375105, 1500, 35480),
+            (466468, 2250, 63613),
+            (i64::MAX, 2750, 86936),
+        ],
+        desconto_simplificado: None,
+        redutor: false,
+    },
+    TabelaEmbutida {
+        ano: 2023,
+        mes: 5,
+        faixas: [
+            (211200, 0, 0),
+            (282665, 750, 15840),
+            (375105, 1500, 37040),
+            (466468, 2250, 65173),
+            (i64::MAX, 2750, 88496),
+        ],
+        desconto_simplificado: Some(52800),
+        redutor: false,
+    },
+    TabelaEmbutida {
+        ano: 2024,
+        mes: 2,
+        faixas: [
+            (225920, 0, 0),
+            (282665, 750, 16944),
+            (375105, 1500, 38144),
+            (466468, 2250, 66277),
+            (i64::MAX, 2750, 89600),
+        ],
+        desconto_simplificado: Some(56480),
+        redutor: false,
+    },
+    TabelaEmbutida {
+        ano: 2025,
+        mes: 5,
+        faixas: [
+            (242880, 0, 0),
+            (282665, 750, 18216),
+            (375105, 1500, 39416),
+            (466468, 2250, 67549),
+            (i64::MAX, 2750, 90873),
+        ],
+        desconto_simplificado: Some(60720),
+        redutor: false,
+    },
+    TabelaEmbutida {
+        ano: 2026,
+        mes: 1,
+        faixas: [
+            (242880, 0, 0),
+            (282665, 750, 18216),
+            (375105, 1500, 39416),
+            (466468, 2250, 67549),
+            (i64::MAX, 2750, 90873),
+        ],
+        desconto_simplificado: Some(60720),
+        redutor: true,
+    },
+];
+
+/// Embedded official table in force on the competência, or `None` before April 2015.
+pub fn tabela_irrf(competencia: Competencia) -> Option<TabelaIrrf> {
+    let tabela = TABELAS
+        .iter()
+        .rev()
+        .find(|tabela| (tabela.ano, tabela.mes) <= (competencia.ano(), competencia.mes()))?;
+
+    let faixas = tabela
+        .faixas
+        .iter()
+        .map(|&(ate, aliquota, parcela)| FaixaIrrf {
+            ate: Real::from_centavos(ate),
+            aliquota,
+            parcela_deduzir: Real::from_centavos(parcela),
+        })
+        .collect();
+
+    Some(TabelaIrrf {
+        inicio: Competencia::new(tabela.ano, tabela.mes).expect("valid competência"),
+        faixas,
+        deducao_dependente: Real::from_centavos(DEDUCAO_DEPENDENTE),
+        desconto_simplificado: tabela.desconto_simplificado.map(Real::from_centavos),
+        redutor: tabela.redutor.then_some(Redutor {
+            isencao_ate: Real::from_reais(5000),
+            reducao_maxima: Real::from_centavos(31289),
+            ate: Real::from_reais(7350),
+            constante: Real::from_centavos(97862),
+            coeficiente: 133145,
+        }),
+    })
+}
+
+/// IRRF on a salary using the embedded table for the competência.
+pub fn calcular(
+    rendimento_bruto: Real,
+    dependentes: u32,
+    inss: Real,
+    competencia: Competencia,
+) -> Option<Irrf> {
+    tabela_irrf(competencia).map(|tabela| tabela.calcular(rendimento_bruto, dependentes, inss))
+}
+
+impl TabelaIrrf {
+    pub fn calcular(&self, rendimento_bruto: Real, dependentes: u32, inss: Real) -> Irrf {
+        let legais = inss + self.deducao_dependente * i64::from(dependentes);
+        let (deducoes, desconto_simplificado) = match self.desconto_simplificado {
+            Some(desconto) if desconto > legais => (desconto, true),
+            _ => (legais, false),
+        };
+        let base_calculo = (rendimento_bruto - deducoes).max(Real::ZERO);
+
+        let faixa = self
+            .faixas
+            .iter()
+            .find(|faixa| base_calculo <= faixa.ate)
+            .or(self.faixas.last())
+            .copied()
+            .unwrap_or(FaixaIrrf {
+                ate: Real::MAX,
+                aliquota: 0,
+                parcela_deduzir: Real::ZERO,
+            });
+        let devido =
+            (percentual(base_calculo, faixa.aliquota) - faixa.parcela_deduzir).max(Real::ZERO);
+
+        let reducao = match self.redutor {
+            Some(redutor) => redutor.reducao(rendimento_bruto).min(devido),
+            None => Real::ZERO,
+        };
+
+        Irrf {
+            rendimento_bruto,
+            deducoes,
+            desconto_simplificado,
+            base_calculo,
+            aliquota: faixa.aliquota,
+            parcela_deduzir: faixa.parcela_deduzir,
+            reducao,
+            imposto: devido - reducao,
+        }
+    }
+}
+
+impl Redutor {
+    fn reducao(self, rendimento: Real) -> Real {
+        if rendimento <= self.isencao_ate {
+            self.reducao_maxima
+        } else if rendimento <= self.ate {
+            let proporcional = i128::from(rendimento.centavos()) * i128::from(self.coeficiente);
+            let proporcional = Real::from_centavos(((proporcional + 500_000) / 1_000_000) as i64);
+            (self.constante - proporcional).max(Real::ZERO)
+        } else {
+            Real::ZERO
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn competencia(ano: i32, mes: u32) -> Competencia {
+        Competencia::new(ano, mes).unwrap()
+    }
+
+    #[test]
+    fn exempt_bracket() {
+        let irrf = calcular(Real::from_reais(2000), 0, Real::ZERO, competencia(2024, 6)).unwrap();
+        assert_eq!(Real::ZERO, irrf.imposto);
+        assert!(irrf.desconto_simplificado);
+        assert_eq!(Real::from_centavos(56480), irrf.deducoes);
+    }
+
+    #[test]
+    fn highest_bracket() {
+        let irrf = calcular(
+            Real::from_reais(10000),
+            0,
+            Real::from_centavos(90886),
+            competencia(2024, 6),
+        )
+        .unwrap();
+        assert_eq!(Real::from_centavos(909114), irrf.base_calculo);
+        assert_eq!(2750, irrf.aliquota);
+        // 9091,14 * 27,5% - 896,00
+        assert_eq!(Real::from_centavos(160406), irrf.imposto);
+    }
+
+    #[test]
+    fn before_simplified_discount() {
+        let irrf = calcular(Real::from_reais(3000), 2, Real::ZERO, competencia(2020, 1)).unwrap();
+        assert!(!irrf.desconto_simplificado);
+        assert_eq!(Real::from_centavos(37918), irrf.deducoes);
+        assert!(tabela_irrf(competencia(2015, 3)).is_none());
+    }
+
+    #[test]
+    fn redutor_from_2026() {
+        let isento = calcular(
+            Real::from_reais(5000),
+            0,
+            Real::from_centavos(55096),
+            competencia(2026, 1),
+        )
+        .unwrap();
+        assert_eq!(Real::ZERO, isento.imposto);
+        assert!(isento.reducao > Real::ZERO);
+
+        let parcial =
+            calcular(Real::from_reais(6000), 0, Real::ZERO, competencia(2026, 1)).unwrap();
+        // 978,62 - 0,133145 * 6000 = 179,75
+        assert_eq!(Real::from_centavos(17975), parcial.reducao);
+
+        let sem_reducao =
+            calcular(Real::from_reais(8000), 0, Real::ZERO, competencia(2026, 1)).unwrap();
+        assert_eq!(Real::ZERO, sem_reducao.reducao);
+    }
+}
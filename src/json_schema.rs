@@ -0,0 +1,357 @@
+//! JSON Schema descriptions matching the serde representation of each type.
+
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{
+    Caepf, Cei, Cep, Chassi, ChaveAcesso, ChaveNfse, Cnes, Cno, Cnpj, CodigoMunicipio, Competencia,
+    Cpf, Crea, Crm, Oab, Pis, Renavam, Rntrc, Telefone, TipoLogradouro, TituloEleitor, Uf,
+};
+
+impl JsonSchema for Cpf {
+    fn schema_name() -> Cow<'static, str> {
+        "Cpf".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "cpf",
+            "pattern": r"^(\d{11}|\d{3}\.\d{3}\.\d{3}-\d{2})$",
+            "description": "Cadastro de Pessoas Físicas, with or without punctuation.",
+            "examples": ["984.844.854-39"],
+        })
+    }
+}
+
+impl JsonSchema for Caepf {
+    fn schema_name() -> Cow<'static, str> {
+        "Caepf".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^(\d{14}|\d{3}\.\d{3}\.\d{3}/\d{3}-\d{2})$",
+            "description": "Cadastro de Atividade Econômica da Pessoa Física, with or without punctuation.",
+            "examples": ["984.844.854/001-67"],
+        })
+    }
+}
+
+impl JsonSchema for Cei {
+    fn schema_name() -> Cow<'static, str> {
+        "Cei".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^(\d{12}|\d{2}\.\d{3}\.\d{5}/\d{2})$",
+            "description": "Cadastro Específico do INSS, with or without punctuation.",
+            "examples": ["51.204.09876/01"],
+        })
+    }
+}
+
+impl JsonSchema for Cep {
+    fn schema_name() -> Cow<'static, str> {
+        "Cep".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "cep",
+            "pattern": r"^\d{5}-?\d{3}$",
+            "description": "Código de Endereçamento Postal.",
+            "examples": ["01310-100"],
+        })
+    }
+}
+
+impl JsonSchema for ChaveAcesso {
+    fn schema_name() -> Cow<'static, str> {
+        "ChaveAcesso".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^\d{44}$",
+            "description": "Access key of the NF-e, NFC-e, CT-e and MDF-e, the 44 digits printed on the DANFE.",
+            "examples": ["35240311222333000181550010000001231123456788"],
+        })
+    }
+}
+
+impl JsonSchema for ChaveNfse {
+    fn schema_name() -> Cow<'static, str> {
+        "ChaveNfse".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^(NFS)?\d{50}$",
+            "description": "Access key of the national standard NFS-e, 50 digits after an optional \"NFS\".",
+            "examples": ["35503082211222333000181000000000012324031234567892"],
+        })
+    }
+}
+
+impl JsonSchema for Chassi {
+    fn schema_name() -> Cow<'static, str> {
+        "Chassi".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^[A-HJ-NPR-Za-hj-npr-z0-9]{17}$",
+            "description": "Vehicle chassis number, the 17 character VIN.",
+            "examples": ["9BWZZZ377VT004251"],
+        })
+    }
+}
+
+impl JsonSchema for Cnes {
+    fn schema_name() -> Cow<'static, str> {
+        "Cnes".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^\d{1,7}$",
+            "description": "Cadastro Nacional de Estabelecimentos de Saúde, up to seven digits.",
+            "examples": ["2077485"],
+        })
+    }
+}
+
+impl JsonSchema for Cnpj {
+    fn schema_name() -> Cow<'static, str> {
+        "Cnpj".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^([0-9A-Za-z]{12}\d{2}|[0-9A-Za-z]{2}\.[0-9A-Za-z]{3}\.[0-9A-Za-z]{3}/[0-9A-Za-z]{4}-\d{2})$",
+            "description": "Cadastro Nacional da Pessoa Jurídica, numeric or alphanumeric, with or without punctuation.",
+            "examples": ["12.ABC.345/01DE-35"],
+        })
+    }
+}
+
+impl JsonSchema for Cno {
+    fn schema_name() -> Cow<'static, str> {
+        "Cno".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^(\d{12}|\d{2}\.\d{3}\.\d{5}/\d{2})$",
+            "description": "Cadastro Nacional de Obras, with or without punctuation.",
+            "examples": ["51.204.09876/71"],
+        })
+    }
+}
+
+impl JsonSchema for CodigoMunicipio {
+    fn schema_name() -> Cow<'static, str> {
+        "CodigoMunicipio".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^\d{7}$",
+            "description": "Seven digit IBGE municipality code.",
+            "examples": ["3550308"],
+        })
+    }
+}
+
+impl JsonSchema for Competencia {
+    fn schema_name() -> Cow<'static, str> {
+        "Competencia".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^(\d{2}/\d{4}|\d{4}-\d{2})$",
+            "description": "Reference month, as MM/YYYY or YYYY-MM.",
+            "examples": ["06/2024"],
+        })
+    }
+}
+
+impl JsonSchema for Crea {
+    fn schema_name() -> Cow<'static, str> {
+        "Crea".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "Registration in a Conselho Regional de Engenharia e Agronomia, with the UF.",
+            "examples": ["CREA-SP 5061234567"],
+        })
+    }
+}
+
+impl JsonSchema for Crm {
+    fn schema_name() -> Cow<'static, str> {
+        "Crm".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "Registration in a Conselho Regional de Medicina, with the UF.",
+            "examples": ["CRM/PE 12345"],
+        })
+    }
+}
+
+impl JsonSchema for Oab {
+    fn schema_name() -> Cow<'static, str> {
+        "Oab".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "Registration in the Ordem dos Advogados do Brasil, with the UF.",
+            "examples": ["OAB/SP 123.456"],
+        })
+    }
+}
+
+impl JsonSchema for Pis {
+    fn schema_name() -> Cow<'static, str> {
+        "Pis".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^(\d{11}|\d{3}\.\d{5}\.\d{2}-\d)$",
+            "description": "Número de Inscrição do Trabalhador (PIS, PASEP, NIT or NIS), with or without punctuation.",
+            "examples": ["120.28384.76-1"],
+        })
+    }
+}
+
+impl JsonSchema for Renavam {
+    fn schema_name() -> Cow<'static, str> {
+        "Renavam".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^(\d{9}|\d{11})$",
+            "description": "Registro Nacional de Veículos Automotores, with 11 digits or the 9 of older certificates.",
+            "examples": ["00639087922"],
+        })
+    }
+}
+
+impl JsonSchema for Rntrc {
+    fn schema_name() -> Cow<'static, str> {
+        "Rntrc".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^((TAC|ETC|CTC|tac|etc|ctc)[ :-]*)?0?\d{8}$",
+            "description": "Registro Nacional de Transportadores Rodoviários de Cargas, optionally after the carrier category.",
+            "examples": ["ETC 12345678"],
+        })
+    }
+}
+
+impl JsonSchema for Telefone {
+    fn schema_name() -> Cow<'static, str> {
+        "Telefone".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^(\+55 ?)?(\(\d{2}\) ?|\d{2} ?)\d{4,5}[- ]?\d{4}$",
+            "description": "Brazilian phone number with the area code, optionally after +55.",
+            "examples": ["(11) 98765-4321"],
+        })
+    }
+}
+
+impl JsonSchema for TipoLogradouro {
+    fn schema_name() -> Cow<'static, str> {
+        "TipoLogradouro".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "Street type, by name or Correios abbreviation.",
+            "examples": ["Avenida"],
+        })
+    }
+}
+
+impl JsonSchema for TituloEleitor {
+    fn schema_name() -> Cow<'static, str> {
+        "TituloEleitor".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": r"^(\d{12}|\d{4} \d{4} \d{4})$",
+            "description": "Título de eleitor, with or without the spaces between groups of four digits.",
+            "examples": ["0043 5687 0906"],
+        })
+    }
+}
+
+impl JsonSchema for Uf {
+    fn schema_name() -> Cow<'static, str> {
+        "Uf".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let siglas: Vec<&str> = Uf::ALL.iter().map(|uf| uf.sigla()).collect();
+        json_schema!({
+            "type": "string",
+            "enum": siglas,
+            "description": "Sigla of a federative unit.",
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn schemas_describe_the_string_forms() {
+        let schema = schemars::schema_for!(Cpf);
+        assert_eq!(Some("cpf"), schema.get("format").and_then(|v| v.as_str()));
+
+        let schema = schemars::schema_for!(Uf);
+        let siglas = schema.get("enum").and_then(|v| v.as_array()).unwrap();
+        assert_eq!(27, siglas.len());
+
+        let schema = schemars::schema_for!(ChaveNfse);
+        let pattern = schema.get("pattern").and_then(|v| v.as_str());
+        assert_eq!(Some(r"^(NFS)?\d{50}$"), pattern);
+    }
+}
@@ -1,4 +1,188 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "axum")]
+pub mod axum;
+pub mod batch;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "darf")]
+pub mod darf;
+#[cfg(feature = "dates")]
+pub mod data_br;
+#[cfg(any(
+    feature = "bson",
+    feature = "diesel",
+    feature = "sea-orm",
+    feature = "sqlx",
+    feature = "tokio-postgres"
+))]
+mod db;
 mod doc;
+mod error;
+#[cfg(feature = "esocial")]
+pub mod esocial;
+pub mod extenso;
+#[cfg(feature = "fake")]
+pub mod fake;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "dates")]
+pub mod feriados;
+pub mod fgts;
+pub mod fuso;
+mod geo;
+#[cfg(feature = "graphql")]
+mod graphql;
+#[cfg(feature = "icp-brasil")]
+pub mod icp_brasil;
+pub mod irrf;
+#[cfg(feature = "schemars")]
+mod json_schema;
+mod macros;
+pub mod moedas_antigas;
+mod money;
+pub mod nome;
+#[cfg(feature = "open-finance")]
+pub mod open_finance;
+#[cfg(feature = "utoipa")]
+mod openapi;
+mod options;
+pub mod prelude;
+pub mod previdencia;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "rocket")]
+mod rocket;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(any(feature = "cnpj", feature = "cpf", feature = "geo"))]
+pub mod scan;
+#[cfg(feature = "sped")]
+pub mod sped;
+#[cfg(feature = "arbitrary")]
+pub mod strategy;
+mod tempo;
+pub mod texto;
+#[cfg(feature = "validator")]
+pub mod validate;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
+#[cfg(feature = "cpf")]
+pub use self::doc::caepf::Caepf;
+#[cfg(feature = "cpf")]
+pub use self::doc::caepf::ParseCaepfError;
+#[cfg(feature = "cnpj")]
+pub use self::doc::cei::Cei;
+#[cfg(feature = "cnpj")]
+pub use self::doc::cei::ParseCeiError;
+#[cfg(feature = "veiculos")]
+pub use self::doc::chassi::Chassi;
+#[cfg(feature = "veiculos")]
+pub use self::doc::chassi::ParseChassiError;
+#[cfg(feature = "cnpj")]
+pub use self::doc::chave_acesso::BuildChaveAcessoError;
+#[cfg(feature = "cnpj")]
+pub use self::doc::chave_acesso::ChaveAcesso;
+#[cfg(feature = "cnpj")]
+pub use self::doc::chave_acesso::ChaveAcessoBuilder;
+#[cfg(feature = "cnpj")]
+pub use self::doc::chave_acesso::ParseChaveAcessoError;
+#[cfg(feature = "cnpj")]
+pub use self::doc::chave_nfse::AmbienteGerador;
+#[cfg(feature = "cnpj")]
+pub use self::doc::chave_nfse::ChaveNfse;
+#[cfg(feature = "cnpj")]
+pub use self::doc::chave_nfse::ParseChaveNfseError;
+#[cfg(feature = "cnpj")]
+pub use self::doc::chave_nfse::TipoEmitente;
+#[cfg(feature = "cnpj")]
+pub use self::doc::cnes::Cnes;
+#[cfg(feature = "cnpj")]
+pub use self::doc::cnes::ParseCnesError;
+#[cfg(feature = "cnpj")]
+pub use self::doc::cno::Cno;
+#[cfg(feature = "cnpj")]
+pub use self::doc::cno::ParseCnoError;
+#[cfg(feature = "cnpj")]
+pub use self::doc::cnpj::Cnpj;
+#[cfg(feature = "cnpj")]
+pub use self::doc::cnpj::ParseCnpjError;
+#[cfg(feature = "cpf")]
 pub use self::doc::cpf::Cpf;
+#[cfg(feature = "cpf")]
 pub use self::doc::cpf::ParseCpfError;
+pub use self::doc::document::BrDocument;
+#[cfg(any(
+    feature = "cnpj",
+    feature = "cpf",
+    feature = "geo",
+    feature = "registro-profissional",
+    feature = "veiculos"
+))]
+pub use self::doc::document::Document;
+pub use self::doc::mask::MaskStyle;
+#[cfg(feature = "cpf")]
+pub use self::doc::pis::ParsePisError;
+#[cfg(feature = "cpf")]
+pub use self::doc::pis::Pis;
+#[cfg(feature = "cpf")]
+pub use self::doc::regiao_fiscal::RegiaoFiscal;
+#[cfg(feature = "registro-profissional")]
+pub use self::doc::registro_profissional::Crea;
+#[cfg(feature = "registro-profissional")]
+pub use self::doc::registro_profissional::Crm;
+#[cfg(feature = "registro-profissional")]
+pub use self::doc::registro_profissional::Oab;
+#[cfg(feature = "registro-profissional")]
+pub use self::doc::registro_profissional::ParseRegistroProfissionalError;
+#[cfg(feature = "veiculos")]
+pub use self::doc::renavam::ParseRenavamError;
+#[cfg(feature = "veiculos")]
+pub use self::doc::renavam::Renavam;
+#[cfg(feature = "veiculos")]
+pub use self::doc::rntrc::CategoriaTransportador;
+#[cfg(feature = "veiculos")]
+pub use self::doc::rntrc::ParseRntrcError;
+#[cfg(feature = "veiculos")]
+pub use self::doc::rntrc::Rntrc;
+#[cfg(feature = "cpf")]
+pub use self::doc::titulo_eleitor::ParseTituloEleitorError;
+#[cfg(feature = "cpf")]
+pub use self::doc::titulo_eleitor::TituloEleitor;
+pub use self::error::Error;
+#[cfg(feature = "geo")]
+pub use self::geo::cep::Cep;
+#[cfg(feature = "geo")]
+pub use self::geo::cep::ParseCepError;
+#[cfg(feature = "geo")]
+pub use self::geo::endereco::normalizar_logradouro;
+#[cfg(feature = "geo")]
+pub use self::geo::endereco::Endereco;
+#[cfg(feature = "geo")]
+pub use self::geo::endereco::EstiloLogradouro;
+#[cfg(feature = "geo")]
+pub use self::geo::endereco::ParseTipoLogradouroError;
+#[cfg(feature = "geo")]
+pub use self::geo::endereco::TipoLogradouro;
+pub use self::geo::municipio::CodigoMunicipio;
+#[cfg(feature = "municipios")]
+pub use self::geo::municipio::Municipio;
+pub use self::geo::municipio::ParseCodigoMunicipioError;
+pub use self::geo::regiao::Regiao;
+#[cfg(feature = "geo")]
+pub use self::geo::telefone::ParseTelefoneError;
+#[cfg(feature = "geo")]
+pub use self::geo::telefone::Telefone;
+pub use self::geo::uf::ParseUfError;
+pub use self::geo::uf::Uf;
+pub use self::money::real::ParseRealError;
+pub use self::money::real::Real;
+pub use self::money::real::Remainder;
+pub use self::options::ParseOptions;
+#[cfg(feature = "dates")]
+pub use self::previdencia::salario_minimo;
+pub use self::tempo::competencia::Competencia;
+pub use self::tempo::competencia::ParseCompetenciaError;
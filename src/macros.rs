@@ -0,0 +1,501 @@
+//! Documents validated at compile time, for constants and test fixtures.
+
+/// Builds a [`Cpf`](crate::Cpf) from a string literal, failing the build when it is not a
+/// valid CPF.
+///
+/// ```
+/// use bras::{cpf, Cpf};
+///
+/// const CPF: Cpf = cpf!("984.844.854-39");
+/// assert_eq!("984.844.854-39", CPF.to_string());
+/// assert_eq!(CPF, cpf!("98484485439"));
+/// ```
+///
+/// ```compile_fail
+/// let cpf = bras::cpf!("984.844.854-00");
+/// ```
+#[cfg(feature = "cpf")]
+#[macro_export]
+macro_rules! cpf {
+    ($s:literal) => {{
+        const CPF: $crate::Cpf = match $crate::Cpf::from_bytes($s.as_bytes()) {
+            Ok(cpf) => cpf,
+            Err(_) => panic!(concat!("invalid CPF: ", $s)),
+        };
+        CPF
+    }};
+}
+
+/// Builds a [`Caepf`](crate::Caepf) from a string literal, failing the build when it is not
+/// a valid CAEPF.
+///
+/// ```
+/// use bras::{caepf, Caepf};
+///
+/// const CAEPF: Caepf = caepf!("984.844.854/001-67");
+/// assert_eq!(1, CAEPF.sequencial());
+/// ```
+///
+/// ```compile_fail
+/// let caepf = bras::caepf!("984.844.854/001-68");
+/// ```
+#[cfg(feature = "cpf")]
+#[macro_export]
+macro_rules! caepf {
+    ($s:literal) => {{
+        const CAEPF: $crate::Caepf = match $crate::Caepf::from_bytes($s.as_bytes()) {
+            Ok(caepf) => caepf,
+            Err(_) => panic!(concat!("invalid CAEPF: ", $s)),
+        };
+        CAEPF
+    }};
+}
+
+/// Builds a [`Cei`](crate::Cei) from a string literal, failing the build when it is not a
+/// valid CEI.
+///
+/// ```
+/// use bras::{cei, Cei};
+///
+/// const OBRA: Cei = cei!("51.204.09876/71");
+/// assert!(OBRA.is_obra());
+/// ```
+///
+/// ```compile_fail
+/// let cei = bras::cei!("51.204.09876/72");
+/// ```
+#[cfg(feature = "cnpj")]
+#[macro_export]
+macro_rules! cei {
+    ($s:literal) => {{
+        const CEI: $crate::Cei = match $crate::Cei::from_bytes($s.as_bytes()) {
+            Ok(cei) => cei,
+            Err(_) => panic!(concat!("invalid CEI: ", $s)),
+        };
+        CEI
+    }};
+}
+
+/// Builds a [`Cep`](crate::Cep) from a string literal, failing the build when it is not a
+/// valid CEP.
+///
+/// ```
+/// use bras::{cep, Cep, Uf};
+///
+/// const PAULISTA: Cep = cep!("01310-100");
+/// assert_eq!(Some(Uf::SP), PAULISTA.uf());
+/// ```
+///
+/// ```compile_fail
+/// let cep = bras::cep!("00000-000");
+/// ```
+#[cfg(feature = "geo")]
+#[macro_export]
+macro_rules! cep {
+    ($s:literal) => {{
+        const CEP: $crate::Cep = match $crate::Cep::from_bytes($s.as_bytes()) {
+            Ok(cep) => cep,
+            Err(_) => panic!(concat!("invalid CEP: ", $s)),
+        };
+        CEP
+    }};
+}
+
+/// Builds a [`ChaveAcesso`](crate::ChaveAcesso) from a string literal, failing the build
+/// when it is not a valid access key.
+///
+/// ```
+/// use bras::{chave_acesso, ChaveAcesso};
+///
+/// const NFE: ChaveAcesso = chave_acesso!("35240311222333000181550010000001231123456788");
+/// assert_eq!(55, NFE.modelo());
+/// ```
+///
+/// ```compile_fail
+/// let chave = bras::chave_acesso!("35240311222333000181550010000001231123456789");
+/// ```
+#[cfg(feature = "cnpj")]
+#[macro_export]
+macro_rules! chave_acesso {
+    ($s:literal) => {{
+        const CHAVE: $crate::ChaveAcesso = match $crate::ChaveAcesso::from_bytes($s.as_bytes()) {
+            Ok(chave) => chave,
+            Err(_) => panic!(concat!("invalid access key: ", $s)),
+        };
+        CHAVE
+    }};
+}
+
+/// Builds a [`ChaveNfse`](crate::ChaveNfse) from a string literal, failing the build when
+/// it is not a valid NFS-e access key.
+///
+/// ```
+/// use bras::{chave_nfse, ChaveNfse};
+///
+/// const NFSE: ChaveNfse = chave_nfse!("NFS35503082211222333000181000000000012324031234567892");
+/// assert_eq!(123, NFSE.numero());
+/// ```
+///
+/// ```compile_fail
+/// let chave = bras::chave_nfse!("35503082211222333000181000000000012324031234567893");
+/// ```
+#[cfg(feature = "cnpj")]
+#[macro_export]
+macro_rules! chave_nfse {
+    ($s:literal) => {{
+        const CHAVE: $crate::ChaveNfse = match $crate::ChaveNfse::from_bytes($s.as_bytes()) {
+            Ok(chave) => chave,
+            Err(_) => panic!(concat!("invalid NFS-e access key: ", $s)),
+        };
+        CHAVE
+    }};
+}
+
+/// Builds a [`Chassi`](crate::Chassi) from a string literal, failing the build when it is
+/// not a valid chassis number.
+///
+/// ```
+/// use bras::{chassi, Chassi};
+///
+/// const GOL: Chassi = chassi!("9BWZZZ377VT004251");
+/// assert_eq!("9BW", GOL.wmi());
+/// ```
+///
+/// ```compile_fail
+/// let chassi = bras::chassi!("9BWZOZ377VT004251");
+/// ```
+#[cfg(feature = "veiculos")]
+#[macro_export]
+macro_rules! chassi {
+    ($s:literal) => {{
+        const CHASSI: $crate::Chassi = match $crate::Chassi::from_bytes($s.as_bytes()) {
+            Ok(chassi) => chassi,
+            Err(_) => panic!(concat!("invalid chassis number: ", $s)),
+        };
+        CHASSI
+    }};
+}
+
+/// Builds a [`Cnes`](crate::Cnes) from a string literal, failing the build when it is not a
+/// valid CNES.
+///
+/// ```
+/// use bras::{cnes, Cnes};
+///
+/// const HOSPITAL: Cnes = cnes!("2077485");
+/// assert_eq!(2077485u32, u32::from(HOSPITAL));
+/// ```
+///
+/// ```compile_fail
+/// let cnes = bras::cnes!("20774850");
+/// ```
+#[cfg(feature = "cnpj")]
+#[macro_export]
+macro_rules! cnes {
+    ($s:literal) => {{
+        const CNES: $crate::Cnes = match $crate::Cnes::from_bytes($s.as_bytes()) {
+            Ok(cnes) => cnes,
+            Err(_) => panic!(concat!("invalid CNES: ", $s)),
+        };
+        CNES
+    }};
+}
+
+/// Builds a [`Cnpj`](crate::Cnpj) from a string literal, failing the build when it is not
+/// a valid CNPJ.
+///
+/// ```
+/// use bras::{cnpj, Cnpj};
+///
+/// const EMPRESA: Cnpj = cnpj!("12.ABC.345/01DE-35");
+/// assert_eq!("12ABC345", EMPRESA.raiz());
+/// assert_eq!(cnpj!("11.222.333/0001-81"), cnpj!("11222333000181"));
+/// ```
+///
+/// ```compile_fail
+/// let cnpj = bras::cnpj!("11.222.333/0001-80");
+/// ```
+#[cfg(feature = "cnpj")]
+#[macro_export]
+macro_rules! cnpj {
+    ($s:literal) => {{
+        const CNPJ: $crate::Cnpj = match $crate::Cnpj::from_bytes($s.as_bytes()) {
+            Ok(cnpj) => cnpj,
+            Err(_) => panic!(concat!("invalid CNPJ: ", $s)),
+        };
+        CNPJ
+    }};
+}
+
+/// Builds a [`Cno`](crate::Cno) from a string literal, failing the build when it is not a
+/// valid CNO.
+///
+/// ```
+/// use bras::{cno, Cno};
+///
+/// const OBRA: Cno = cno!("51.204.09876/71");
+/// assert_eq!(512040987671, u64::from(OBRA));
+/// ```
+///
+/// ```compile_fail
+/// let cno = bras::cno!("51.204.09876/77");
+/// ```
+#[cfg(feature = "cnpj")]
+#[macro_export]
+macro_rules! cno {
+    ($s:literal) => {{
+        const CNO: $crate::Cno = match $crate::Cno::from_bytes($s.as_bytes()) {
+            Ok(cno) => cno,
+            Err(_) => panic!(concat!("invalid CNO: ", $s)),
+        };
+        CNO
+    }};
+}
+
+/// Builds a [`CodigoMunicipio`](crate::CodigoMunicipio) from a string literal, failing the
+/// build when the check digit does not match.
+///
+/// ```
+/// use bras::{codigo_municipio, CodigoMunicipio, Uf};
+///
+/// const CURITIBA: CodigoMunicipio = codigo_municipio!("4106902");
+/// assert_eq!(Uf::PR, CURITIBA.uf());
+/// ```
+///
+/// ```compile_fail
+/// let codigo = bras::codigo_municipio!("4106903");
+/// ```
+#[macro_export]
+macro_rules! codigo_municipio {
+    ($s:literal) => {{
+        const CODIGO: $crate::CodigoMunicipio =
+            match $crate::CodigoMunicipio::from_bytes($s.as_bytes()) {
+                Ok(codigo) => codigo,
+                Err(_) => panic!(concat!("invalid IBGE municipality code: ", $s)),
+            };
+        CODIGO
+    }};
+}
+
+/// Builds a [`Crea`](crate::Crea) from a string literal, failing the build when it is not
+/// a valid CREA registration.
+///
+/// ```
+/// use bras::{crea, Crea};
+///
+/// const ENGENHEIRA: Crea = crea!("CREA-SP 5061234567");
+/// assert_eq!(5061234567, ENGENHEIRA.numero());
+/// ```
+///
+/// ```compile_fail
+/// let crea = bras::crea!("CREA-SP 50612345678");
+/// ```
+#[cfg(feature = "registro-profissional")]
+#[macro_export]
+macro_rules! crea {
+    ($s:literal) => {{
+        const CREA: $crate::Crea = match $crate::Crea::from_bytes($s.as_bytes()) {
+            Ok(crea) => crea,
+            Err(_) => panic!(concat!("invalid CREA registration: ", $s)),
+        };
+        CREA
+    }};
+}
+
+/// Builds a [`Crm`](crate::Crm) from a string literal, failing the build when it is not
+/// a valid CRM registration.
+///
+/// ```
+/// use bras::{crm, Crm};
+///
+/// const MEDICO: Crm = crm!("CRM-PE 12345");
+/// assert_eq!(bras::Uf::PE, MEDICO.uf());
+/// ```
+///
+/// ```compile_fail
+/// let crm = bras::crm!("CRM-PE 12345-A");
+/// ```
+#[cfg(feature = "registro-profissional")]
+#[macro_export]
+macro_rules! crm {
+    ($s:literal) => {{
+        const CRM: $crate::Crm = match $crate::Crm::from_bytes($s.as_bytes()) {
+            Ok(crm) => crm,
+            Err(_) => panic!(concat!("invalid CRM registration: ", $s)),
+        };
+        CRM
+    }};
+}
+
+/// Builds an [`Oab`](crate::Oab) from a string literal, failing the build when it is not
+/// a valid OAB registration.
+///
+/// ```
+/// use bras::{oab, Oab};
+///
+/// const ADVOGADA: Oab = oab!("OAB/SP 123.456");
+/// assert_eq!("OAB/SP 123.456", ADVOGADA.to_string());
+/// ```
+///
+/// ```compile_fail
+/// let oab = bras::oab!("OAB 123456");
+/// ```
+#[cfg(feature = "registro-profissional")]
+#[macro_export]
+macro_rules! oab {
+    ($s:literal) => {{
+        const OAB: $crate::Oab = match $crate::Oab::from_bytes($s.as_bytes()) {
+            Ok(oab) => oab,
+            Err(_) => panic!(concat!("invalid OAB registration: ", $s)),
+        };
+        OAB
+    }};
+}
+
+/// Builds a [`Pis`](crate::Pis) from a string literal, failing the build when it is not a
+/// valid PIS.
+///
+/// ```
+/// use bras::{pis, Pis};
+///
+/// const TRABALHADOR: Pis = pis!("120.28384.76-1");
+/// assert_eq!(12028384761, u64::from(TRABALHADOR));
+/// ```
+///
+/// ```compile_fail
+/// let pis = bras::pis!("120.28384.76-2");
+/// ```
+#[cfg(feature = "cpf")]
+#[macro_export]
+macro_rules! pis {
+    ($s:literal) => {{
+        const PIS: $crate::Pis = match $crate::Pis::from_bytes($s.as_bytes()) {
+            Ok(pis) => pis,
+            Err(_) => panic!(concat!("invalid PIS: ", $s)),
+        };
+        PIS
+    }};
+}
+
+/// Builds a [`Renavam`](crate::Renavam) from a string literal, failing the build when it
+/// is not a valid RENAVAM.
+///
+/// ```
+/// use bras::{renavam, Renavam};
+///
+/// const VEICULO: Renavam = renavam!("63908792210");
+/// assert_eq!(63908792210, u64::from(VEICULO));
+/// ```
+///
+/// ```compile_fail
+/// let renavam = bras::renavam!("63908792211");
+/// ```
+#[cfg(feature = "veiculos")]
+#[macro_export]
+macro_rules! renavam {
+    ($s:literal) => {{
+        const RENAVAM: $crate::Renavam = match $crate::Renavam::from_bytes($s.as_bytes()) {
+            Ok(renavam) => renavam,
+            Err(_) => panic!(concat!("invalid RENAVAM: ", $s)),
+        };
+        RENAVAM
+    }};
+}
+
+/// Builds an [`Rntrc`](crate::Rntrc) from a string literal, failing the build when it is
+/// not a valid RNTRC.
+///
+/// ```
+/// use bras::{rntrc, Rntrc};
+///
+/// const TRANSPORTADORA: Rntrc = rntrc!("ETC 012345678");
+/// assert_eq!(12345678, TRANSPORTADORA.numero());
+/// ```
+///
+/// ```compile_fail
+/// let rntrc = bras::rntrc!("ETC 1234567");
+/// ```
+#[cfg(feature = "veiculos")]
+#[macro_export]
+macro_rules! rntrc {
+    ($s:literal) => {{
+        const RNTRC: $crate::Rntrc = match $crate::Rntrc::from_bytes($s.as_bytes()) {
+            Ok(rntrc) => rntrc,
+            Err(_) => panic!(concat!("invalid RNTRC: ", $s)),
+        };
+        RNTRC
+    }};
+}
+
+/// Builds a [`Telefone`](crate::Telefone) from a string literal, failing the build when it
+/// is not a valid phone number.
+///
+/// ```
+/// use bras::{telefone, Telefone};
+///
+/// const SAC: Telefone = telefone!("(11) 3456-7890");
+/// assert_eq!(11, SAC.ddd());
+/// ```
+///
+/// ```compile_fail
+/// let telefone = bras::telefone!("(20) 3456-7890");
+/// ```
+#[cfg(feature = "geo")]
+#[macro_export]
+macro_rules! telefone {
+    ($s:literal) => {{
+        const TELEFONE: $crate::Telefone = match $crate::Telefone::from_bytes($s.as_bytes()) {
+            Ok(telefone) => telefone,
+            Err(_) => panic!(concat!("invalid phone number: ", $s)),
+        };
+        TELEFONE
+    }};
+}
+
+/// Builds a [`TituloEleitor`](crate::TituloEleitor) from a string literal, failing the
+/// build when it is not a valid título de eleitor.
+///
+/// ```
+/// use bras::{titulo_eleitor, TituloEleitor, Uf};
+///
+/// const ELEITOR: TituloEleitor = titulo_eleitor!("0043 5687 0906");
+/// assert_eq!(Some(Uf::SC), ELEITOR.uf());
+/// ```
+///
+/// ```compile_fail
+/// let titulo = bras::titulo_eleitor!("0043 5687 2906");
+/// ```
+#[cfg(feature = "cpf")]
+#[macro_export]
+macro_rules! titulo_eleitor {
+    ($s:literal) => {{
+        const TITULO_ELEITOR: $crate::TituloEleitor =
+            match $crate::TituloEleitor::from_bytes($s.as_bytes()) {
+                Ok(titulo) => titulo,
+                Err(_) => panic!(concat!("invalid título de eleitor: ", $s)),
+            };
+        TITULO_ELEITOR
+    }};
+}
+
+/// Array of fixtures of one document type, each validated at compile time by the
+/// matching macro.
+///
+/// ```
+/// # #[cfg(feature = "cnpj")]
+/// # {
+/// use bras::{docs, Cno};
+///
+/// const OBRAS: [Cno; 2] = docs![cno: "51.204.09876/71", "512040987671"];
+/// assert_eq!(OBRAS[0], OBRAS[1]);
+/// # }
+///
+/// let municipios = bras::docs![codigo_municipio: "4106902", "3550308"];
+/// assert_eq!(2, municipios.len());
+/// ```
+#[macro_export]
+macro_rules! docs {
+    ($kind:ident: $($s:literal),+ $(,)?) => {
+        [$($crate::$kind!($s)),+]
+    };
+}
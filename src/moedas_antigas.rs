@@ -0,0 +1,215 @@
+//! Conversion between the currencies that circulated in Brazil since 1942.
+//!
+//! Amounts are given in centavos of the respective currency. Converting to a newer currency
+//! divides by the official factors of each monetary reform, rounding half away from zero;
+//! converting to an older one multiplies by them.
+//!
+//! # Examples
+//! ```
+//! use bras::moedas_antigas::{converter, Moeda};
+//! use bras::Real;
+//!
+//! // CR$ 2.750,00 became R$ 1,00 on 1994-07-01.
+//! assert_eq!(Some(100), converter(275000, Moeda::CruzeiroReal, Moeda::Real));
+//!
+//! // Cz$ 1.000.000.000,00 in 1988 is worth R$ 0,36 in today's currency.
+//! assert_eq!(
+//!     Some(Real::from_centavos(36)),
+//!     Moeda::Cruzado.para_real(100_000_000_000)
+//! );
+//!
+//! assert_eq!(Some(275000), converter(100, Moeda::Real, Moeda::CruzeiroReal));
+//! ```
+
+#[cfg(feature = "dates")]
+use chrono::NaiveDate;
+
+use crate::Real;
+
+/// Brazilian currencies, from the oldest to the newest.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Moeda {
+    /// Cr$, from 1942-11-01.
+    Cruzeiro1942,
+    /// NCr$, from 1967-02-13, Cr$ 1.000 = NCr$ 1.
+    CruzeiroNovo,
+    /// Cr$, from 1970-05-15, renamed from the Cruzeiro Novo without a cut.
+    Cruzeiro1970,
+    /// Cz$, from 1986-02-28, Cr$ 1.000 = Cz$ 1.
+    Cruzado,
+    /// NCz$, from 1989-01-16, Cz$ 1.000 = NCz$ 1.
+    CruzadoNovo,
+    /// Cr$, from 1990-03-16, renamed from the Cruzado Novo without a cut.
+    Cruzeiro1990,
+    /// CR$, from 1993-08-01, Cr$ 1.000 = CR$ 1.
+    CruzeiroReal,
+    /// R$, from 1994-07-01, CR$ 2.750 = R$ 1.
+    Real,
+}
+
+impl Moeda {
+    pub const ALL: [Moeda; 8] = [
+        Moeda::Cruzeiro1942,
+        Moeda::CruzeiroNovo,
+        Moeda::Cruzeiro1970,
+        Moeda::Cruzado,
+        Moeda::CruzadoNovo,
+        Moeda::Cruzeiro1990,
+        Moeda::CruzeiroReal,
+        Moeda::Real,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Moeda::Cruzeiro1942 | Moeda::Cruzeiro1970 | Moeda::Cruzeiro1990 => "Cruzeiro",
+            Moeda::CruzeiroNovo => "Cruzeiro Novo",
+            Moeda::Cruzado => "Cruzado",
+            Moeda::CruzadoNovo => "Cruzado Novo",
+            Moeda::CruzeiroReal => "Cruzeiro Real",
+            Moeda::Real => "Real",
+        }
+    }
+
+    pub fn simbolo(self) -> &'static str {
+        match self {
+            Moeda::Cruzeiro1942 | Moeda::Cruzeiro1970 | Moeda::Cruzeiro1990 => "Cr$",
+            Moeda::CruzeiroNovo => "NCr$",
+            Moeda::Cruzado => "Cz$",
+            Moeda::CruzadoNovo => "NCz$",
+            Moeda::CruzeiroReal => "CR$",
+            Moeda::Real => "R$",
+        }
+    }
+
+    /// How many units of the previous currency became one unit of this one.
+    pub fn fator(self) -> u64 {
+        match self {
+            Moeda::Cruzeiro1942 | Moeda::Cruzeiro1970 | Moeda::Cruzeiro1990 => 1,
+            Moeda::CruzeiroNovo | Moeda::Cruzado | Moeda::CruzadoNovo | Moeda::CruzeiroReal => 1000,
+            Moeda::Real => 2750,
+        }
+    }
+
+    /// Date the currency started circulating.
+    #[cfg(feature = "dates")]
+    pub fn inicio(self) -> NaiveDate {
+        let (year, month, day) = match self {
+            Moeda::Cruzeiro1942 => (1942, 11, 1),
+            Moeda::CruzeiroNovo => (1967, 2, 13),
+            Moeda::Cruzeiro1970 => (1970, 5, 15),
+            Moeda::Cruzado => (1986, 2, 28),
+            Moeda::CruzadoNovo => (1989, 1, 16),
+            Moeda::Cruzeiro1990 => (1990, 3, 16),
+            Moeda::CruzeiroReal => (1993, 8, 1),
+            Moeda::Real => (1994, 7, 1),
+        };
+        NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+    }
+
+    /// Currency in force on the given date, if any.
+    ///
+    /// ```
+    /// use bras::moedas_antigas::Moeda;
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(1987, 6, 1).unwrap();
+    /// assert_eq!(Some(Moeda::Cruzado), Moeda::vigente_em(date));
+    /// ```
+    #[cfg(feature = "dates")]
+    pub fn vigente_em(date: NaiveDate) -> Option<Moeda> {
+        Moeda::ALL
+            .iter()
+            .rev()
+            .copied()
+            .find(|moeda| moeda.inicio() <= date)
+    }
+
+    /// Converts `centavos` of this currency to today's [`Real`], if it fits.
+    pub fn para_real(self, centavos: i128) -> Option<Real> {
+        let converted = converter(centavos, self, Moeda::Real)?;
+        i64::try_from(converted).ok().map(Real::from_centavos)
+    }
+
+    fn index(self) -> usize {
+        Moeda::ALL
+            .iter()
+            .position(|&moeda| moeda == self)
+            .expect("every currency is listed")
+    }
+}
+
+/// Converts `centavos` of currency `de` to centavos of currency `para`, or `None` on overflow.
+pub fn converter(centavos: i128, de: Moeda, para: Moeda) -> Option<i128> {
+    let (from, to) = (de.index(), para.index());
+    let (low, high) = if from < to { (from, to) } else { (to, from) };
+    let factor = Moeda::ALL[low + 1..=high]
+        .iter()
+        .try_fold(1i128, |acc, moeda| {
+            acc.checked_mul(i128::from(moeda.fator()))
+        })?;
+
+    if from <= to {
+        Some(div_round(centavos, factor))
+    } else {
+        centavos.checked_mul(factor)
+    }
+}
+
+/// Division rounding half away from zero.
+fn div_round(n: i128, d: i128) -> i128 {
+    let quotient = n / d;
+    let remainder = n % d;
+    if remainder.abs() * 2 >= d {
+        quotient + n.signum()
+    } else {
+        quotient
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn chain_of_reforms() {
+        assert_eq!(
+            Some(100),
+            converter(275_000_000_000_000_000, Moeda::Cruzeiro1942, Moeda::Real)
+        );
+        assert_eq!(
+            Some(100),
+            converter(100_000, Moeda::Cruzado, Moeda::CruzadoNovo)
+        );
+        assert_eq!(
+            Some(100),
+            converter(100, Moeda::CruzadoNovo, Moeda::Cruzeiro1990)
+        );
+        assert_eq!(Some(12345), converter(12345, Moeda::Real, Moeda::Real));
+    }
+
+    #[test]
+    fn round_half_away_from_zero() {
+        assert_eq!(Some(1), converter(1375, Moeda::CruzeiroReal, Moeda::Real));
+        assert_eq!(Some(0), converter(1374, Moeda::CruzeiroReal, Moeda::Real));
+        assert_eq!(Some(-1), converter(-1375, Moeda::CruzeiroReal, Moeda::Real));
+    }
+
+    #[test]
+    fn overflow_is_reported() {
+        assert_eq!(None, converter(i128::MAX, Moeda::Real, Moeda::Cruzeiro1942));
+        assert_eq!(None, Moeda::Real.para_real(i128::MAX));
+    }
+
+    #[cfg(feature = "dates")]
+    #[test]
+    fn currencies_are_in_chronological_order() {
+        assert!(Moeda::ALL
+            .windows(2)
+            .all(|pair| pair[0].inicio() < pair[1].inicio()));
+        let before = NaiveDate::from_ymd_opt(1942, 10, 31).unwrap();
+        assert_eq!(None, Moeda::vigente_em(before));
+        for moeda in Moeda::ALL {
+            assert_eq!(Some(moeda), Moeda::vigente_em(moeda.inicio()));
+        }
+    }
+}
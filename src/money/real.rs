@@ -0,0 +1,565 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use core::str::FromStr;
+
+use crate::extenso::{cardinal_unsigned, Estilo, Genero};
+
+/// Amount of Brazilian reais (BRL), stored as an integer number of centavos.
+///
+/// Arithmetic operators panic on overflow, like the primitive integers do in debug builds.
+/// Use the `checked_*` methods when the amounts come from untrusted input.
+///
+/// # Examples
+/// ```
+/// use bras::Real;
+///
+/// let price = Real::from_centavos(123456);
+/// assert_eq!("R$ 1.234,56", price.to_string());
+///
+/// let total = price * 3 - Real::from_reais(4);
+/// assert_eq!("R$ 3.699,68", total.to_string());
+/// assert_eq!(369968, total.centavos());
+///
+/// assert_eq!("-R$ 0,05", Real::from_centavos(-5).to_string());
+/// assert_eq!(None, Real::MAX.checked_add(Real::from_centavos(1)));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Real {
+    centavos: i64,
+}
+
+/// Which installment absorbs the centavos left over when splitting an amount.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum Remainder {
+    #[default]
+    First,
+    Last,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseRealError {
+    /// Empty input, unexpected characters or misplaced thousands separators.
+    Invalid,
+    /// The separators can't be told apart, as in "1.234" (one thousand or one point
+    /// two three four?) or the en-US style "1,234.56".
+    AmbiguousSeparator,
+    /// More than two digits after the decimal comma.
+    TooManyDecimalPlaces,
+    /// The amount does not fit in the type.
+    Overflow,
+}
+
+impl Display for ParseRealError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseRealError::Invalid => f.write_str("invalid amount"),
+            ParseRealError::AmbiguousSeparator => {
+                f.write_str("ambiguous separators, write amounts as 1.234,56")
+            }
+            ParseRealError::TooManyDecimalPlaces => {
+                f.write_str("too many decimal places, at most two are allowed")
+            }
+            ParseRealError::Overflow => f.write_str("amount out of range"),
+        }
+    }
+}
+
+impl core::error::Error for ParseRealError {}
+
+impl Real {
+    pub const ZERO: Real = Real { centavos: 0 };
+    pub const MIN: Real = Real { centavos: i64::MIN };
+    pub const MAX: Real = Real { centavos: i64::MAX };
+
+    pub const fn from_centavos(centavos: i64) -> Real {
+        Real { centavos }
+    }
+
+    /// # Panics
+    ///
+    /// If the amount does not fit in `i64` centavos.
+    pub const fn from_reais(reais: i64) -> Real {
+        match reais.checked_mul(100) {
+            Some(centavos) => Real { centavos },
+            None => panic!("amount in reais overflows"),
+        }
+    }
+
+    pub const fn centavos(self) -> i64 {
+        self.centavos
+    }
+
+    /// Whole reais, truncated toward zero.
+    pub const fn reais(self) -> i64 {
+        self.centavos / 100
+    }
+
+    pub const fn is_negative(self) -> bool {
+        self.centavos < 0
+    }
+
+    /// Amount written out in words, as required on cheques and legal documents.
+    ///
+    /// ```
+    /// use bras::Real;
+    ///
+    /// assert_eq!(
+    ///     "um mil, duzentos e trinta e quatro reais e cinquenta e seis centavos",
+    ///     Real::from_centavos(123456).por_extenso()
+    /// );
+    /// assert_eq!("um real e um centavo", Real::from_centavos(101).por_extenso());
+    /// assert_eq!("dois milhões de reais", Real::from_reais(2_000_000).por_extenso());
+    /// assert_eq!(
+    ///     "dois milhões e quinhentos mil reais",
+    ///     Real::from_reais(2_500_000).por_extenso()
+    /// );
+    /// assert_eq!("cinquenta centavos", Real::from_centavos(50).por_extenso());
+    /// ```
+    pub fn por_extenso(self) -> String {
+        let absolute = self.centavos.unsigned_abs();
+        let reais = absolute / 100;
+        let centavos = absolute % 100;
+
+        let mut words = String::new();
+        if self.is_negative() {
+            words.push_str("menos ");
+        }
+        if reais > 0 || centavos == 0 {
+            words.push_str(&cardinal_unsigned(reais, Genero::Masculino, Estilo::Cheque));
+            // Round millions and above take "de": "um milhão de reais".
+            if reais >= 1_000_000 && reais.is_multiple_of(1_000_000) {
+                words.push_str(" de");
+            }
+            words.push_str(if reais == 1 { " real" } else { " reais" });
+        }
+        if centavos > 0 {
+            if reais > 0 {
+                words.push_str(" e ");
+            }
+            words.push_str(&cardinal_unsigned(
+                centavos,
+                Genero::Masculino,
+                Estilo::Corrido,
+            ));
+            words.push_str(if centavos == 1 {
+                " centavo"
+            } else {
+                " centavos"
+            });
+        }
+        words
+    }
+
+    /// Splits the amount into `n` installments (parcelas) whose sum is exactly the amount.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is zero.
+    ///
+    /// ```
+    /// use bras::{Real, Remainder};
+    ///
+    /// let parcelas = Real::from_reais(100).split_installments(3, Remainder::First);
+    /// assert_eq!(
+    ///     vec![
+    ///         Real::from_centavos(3334),
+    ///         Real::from_centavos(3333),
+    ///         Real::from_centavos(3333)
+    ///     ],
+    ///     parcelas
+    /// );
+    ///
+    /// let parcelas = Real::from_reais(100).split_installments(3, Remainder::Last);
+    /// assert_eq!(Real::from_centavos(3334), parcelas[2]);
+    /// assert_eq!(Real::from_reais(100), parcelas.iter().sum());
+    /// ```
+    pub fn split_installments(self, n: u32, remainder: Remainder) -> Vec<Real> {
+        assert!(n > 0, "attempt to split in zero installments");
+        let n = i64::from(n);
+        let base = self.centavos / n;
+        let rest = self.centavos % n;
+
+        let mut parcelas = vec![Real::from_centavos(base); n as usize];
+        let index = match remainder {
+            Remainder::First => 0,
+            Remainder::Last => parcelas.len() - 1,
+        };
+        parcelas[index].centavos += rest;
+        parcelas
+    }
+
+    pub fn checked_add(self, rhs: Real) -> Option<Real> {
+        self.centavos
+            .checked_add(rhs.centavos)
+            .map(Real::from_centavos)
+    }
+
+    pub fn checked_sub(self, rhs: Real) -> Option<Real> {
+        self.centavos
+            .checked_sub(rhs.centavos)
+            .map(Real::from_centavos)
+    }
+
+    pub fn checked_mul(self, rhs: i64) -> Option<Real> {
+        self.centavos.checked_mul(rhs).map(Real::from_centavos)
+    }
+
+    pub fn checked_neg(self) -> Option<Real> {
+        self.centavos.checked_neg().map(Real::from_centavos)
+    }
+
+    pub fn checked_abs(self) -> Option<Real> {
+        self.centavos.checked_abs().map(Real::from_centavos)
+    }
+}
+
+impl FromStr for Real {
+    type Err = ParseRealError;
+
+    /// Parses amounts written the Brazilian way, with an optional "R$" prefix, "." as
+    /// thousands separator and "," as decimal separator.
+    ///
+    /// ```
+    /// use bras::{ParseRealError, Real};
+    ///
+    /// assert_eq!(Real::from_centavos(123456), "R$ 1.234,56".parse()?);
+    /// assert_eq!(Real::from_centavos(123456), "1234,56".parse()?);
+    /// assert_eq!(Real::from_centavos(123456), "1.234,56".parse()?);
+    /// assert_eq!(Real::from_centavos(-150), "-R$ 1,5".parse()?);
+    /// assert_eq!(Real::from_reais(1234567), "1.234.567".parse()?);
+    ///
+    /// assert_eq!(Err(ParseRealError::AmbiguousSeparator), "1.234".parse::<Real>());
+    /// assert_eq!(Err(ParseRealError::AmbiguousSeparator), "1,234.56".parse::<Real>());
+    /// assert_eq!(Err(ParseRealError::TooManyDecimalPlaces), "1,234".parse::<Real>());
+    ///
+    /// # Ok::<(), ParseRealError>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut s = s.trim();
+        let mut negative = false;
+        if let Some(rest) = s.strip_prefix('-') {
+            negative = true;
+            s = rest.trim_start();
+        }
+        if let Some(rest) = s.strip_prefix("R$") {
+            s = rest.trim_start();
+        }
+        if let Some(rest) = s.strip_prefix('-') {
+            if negative {
+                return Err(ParseRealError::Invalid);
+            }
+            negative = true;
+            s = rest.trim_start();
+        }
+
+        if !s
+            .bytes()
+            .all(|b| b.is_ascii_digit() || b == b'.' || b == b',')
+        {
+            return Err(ParseRealError::Invalid);
+        }
+
+        let (integer, fraction) = match s.split_once(',') {
+            Some((integer, fraction)) => {
+                if fraction.contains('.') {
+                    return Err(ParseRealError::AmbiguousSeparator);
+                }
+                if fraction.contains(',') {
+                    return Err(ParseRealError::Invalid);
+                }
+                (integer, Some(fraction))
+            }
+            None => (s, None),
+        };
+
+        let groups: Vec<&str> = integer.split('.').collect();
+        if groups.len() == 2 && fraction.is_none() {
+            return Err(ParseRealError::AmbiguousSeparator);
+        }
+        let well_grouped = groups[0].len() <= 3 && groups[1..].iter().all(|g| g.len() == 3);
+        if groups[0].is_empty() || (groups.len() > 1 && !well_grouped) {
+            return Err(ParseRealError::Invalid);
+        }
+
+        let centavos = match fraction {
+            None => 0,
+            Some("") => return Err(ParseRealError::Invalid),
+            Some(f) if f.len() > 2 => return Err(ParseRealError::TooManyDecimalPlaces),
+            Some(f) if f.len() == 1 => u64::from(f.as_bytes()[0] - b'0') * 10,
+            Some(f) => u64::from_str(f).map_err(|_| ParseRealError::Invalid)?,
+        };
+
+        let reais = groups.iter().try_fold(0u64, |acc, group| {
+            let value = u64::from_str(group).map_err(|_| ParseRealError::Overflow)?;
+            u32::try_from(group.len())
+                .ok()
+                .and_then(|len| 10u64.checked_pow(len))
+                .and_then(|shift| acc.checked_mul(shift))
+                .and_then(|acc| acc.checked_add(value))
+                .ok_or(ParseRealError::Overflow)
+        })?;
+
+        // The magnitude is read unsigned so that Real::MIN, one centavo further from zero
+        // than Real::MAX, parses back.
+        let magnitude = reais
+            .checked_mul(100)
+            .and_then(|value| value.checked_add(centavos))
+            .ok_or(ParseRealError::Overflow)?;
+        let total = if negative {
+            0i64.checked_sub_unsigned(magnitude)
+        } else {
+            i64::try_from(magnitude).ok()
+        };
+
+        total
+            .map(Real::from_centavos)
+            .ok_or(ParseRealError::Overflow)
+    }
+}
+
+impl Add for Real {
+    type Output = Real;
+
+    fn add(self, rhs: Real) -> Real {
+        self.checked_add(rhs).expect("attempt to add with overflow")
+    }
+}
+
+impl AddAssign for Real {
+    fn add_assign(&mut self, rhs: Real) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Real {
+    type Output = Real;
+
+    fn sub(self, rhs: Real) -> Real {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl SubAssign for Real {
+    fn sub_assign(&mut self, rhs: Real) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Real {
+    type Output = Real;
+
+    fn mul(self, rhs: i64) -> Real {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl Neg for Real {
+    type Output = Real;
+
+    fn neg(self) -> Real {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+impl Sum for Real {
+    fn sum<I: Iterator<Item = Real>>(iter: I) -> Real {
+        iter.fold(Real::ZERO, |acc, value| acc + value)
+    }
+}
+
+impl<'a> Sum<&'a Real> for Real {
+    fn sum<I: Iterator<Item = &'a Real>>(iter: I) -> Real {
+        iter.copied().sum()
+    }
+}
+
+impl Display for Real {
+    /// ```
+    /// use bras::Real;
+    ///
+    /// assert_eq!("R$ 0,00", Real::ZERO.to_string());
+    /// assert_eq!("R$ 1.000.000,01", Real::from_centavos(100000001).to_string());
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // "-R$ 92.233.720.368.547.758,08" is the longest amount.
+        let mut buffer = [0u8; 29];
+        let mut start = buffer.len();
+        let mut push_front = |byte: u8| {
+            start -= 1;
+            buffer[start] = byte;
+        };
+
+        let absolute = self.centavos.unsigned_abs();
+        push_front(b'0' + (absolute % 10) as u8);
+        push_front(b'0' + (absolute / 10 % 10) as u8);
+        push_front(b',');
+        let mut reais = absolute / 100;
+        let mut digits = 0u32;
+        loop {
+            if digits > 0 && digits.is_multiple_of(3) {
+                push_front(b'.');
+            }
+            push_front(b'0' + (reais % 10) as u8);
+            digits += 1;
+            reais /= 10;
+            if reais == 0 {
+                break;
+            }
+        }
+        for byte in b"R$ ".iter().rev() {
+            push_front(*byte);
+        }
+        if self.is_negative() {
+            push_front(b'-');
+        }
+        f.pad(core::str::from_utf8(&buffer[start..]).expect("ASCII digits and separators"))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Real {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Real::from_centavos(u.arbitrary()?))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Real {
+    type Parameters = ();
+    type Strategy = proptest::strategy::Map<proptest::num::i64::Any, fn(i64) -> Real>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::num::i64::ANY.prop_map(Real::from_centavos)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn format_with_thousands_separator() {
+        assert_eq!("R$ 0,01", Real::from_centavos(1).to_string());
+        assert_eq!("R$ 999,99", Real::from_centavos(99999).to_string());
+        assert_eq!("R$ 1.234,56", Real::from_centavos(123456).to_string());
+        assert_eq!("R$ 12.345,60", Real::from_centavos(1234560).to_string());
+        assert_eq!("-R$ 123.456,78", Real::from_centavos(-12345678).to_string());
+        assert_eq!("-R$ 92.233.720.368.547.758,08", Real::MIN.to_string());
+        assert_eq!("    R$ 1,00", format!("{:>11}", Real::from_reais(1)));
+    }
+
+    #[test]
+    fn parse_round_trips_display() {
+        for centavos in [0, 1, 10, 99999, 123456, 100000001, -42, i64::MAX] {
+            let real = Real::from_centavos(centavos);
+            assert_eq!(real, Real::from_str(&real.to_string()).unwrap());
+        }
+    }
+
+    #[test]
+    fn parse_round_trips_the_bounds() {
+        for real in [Real::MIN, Real::MAX] {
+            assert_eq!(Ok(real), Real::from_str(&real.to_string()));
+        }
+        assert_eq!(
+            Err(ParseRealError::Overflow),
+            Real::from_str("-R$ 92.233.720.368.547.758,09")
+        );
+        assert_eq!(
+            Err(ParseRealError::Overflow),
+            Real::from_str("R$ 92.233.720.368.547.758,08")
+        );
+    }
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let cases = [
+            ("", ParseRealError::Invalid),
+            ("R$", ParseRealError::Invalid),
+            ("12a", ParseRealError::Invalid),
+            ("1.23,45", ParseRealError::Invalid),
+            ("1234.567,00", ParseRealError::Invalid),
+            ("1,2,3", ParseRealError::Invalid),
+            ("1,", ParseRealError::Invalid),
+            ("--1", ParseRealError::Invalid),
+            ("12.345", ParseRealError::AmbiguousSeparator),
+            ("1.000,5.0", ParseRealError::AmbiguousSeparator),
+            ("0,001", ParseRealError::TooManyDecimalPlaces),
+            ("92233720368547758,08", ParseRealError::Overflow),
+            ("00000000000000000001", ParseRealError::Overflow),
+        ];
+        for (input, error) in cases {
+            assert_eq!(Real::from_str(input).unwrap_err(), error, "{}", input);
+        }
+    }
+
+    #[test]
+    fn por_extenso() {
+        let expected = [
+            (0, "zero reais"),
+            (1, "um centavo"),
+            (100, "um real"),
+            (200, "dois reais"),
+            (120000, "um mil e duzentos reais"),
+            (100000050, "um milhão de reais e cinquenta centavos"),
+            (100000100, "um milhão e um reais"),
+            (300000000000, "três bilhões de reais"),
+            (-250, "menos dois reais e cinquenta centavos"),
+        ];
+        for (centavos, words) in expected {
+            assert_eq!(words, Real::from_centavos(centavos).por_extenso());
+        }
+    }
+
+    #[test]
+    fn installments_add_up_to_the_amount() {
+        for centavos in [0, 1, 99, 100, 12345, -12345, i64::MAX, i64::MIN] {
+            for n in [1, 2, 3, 7, 12] {
+                for remainder in [Remainder::First, Remainder::Last] {
+                    let real = Real::from_centavos(centavos);
+                    let parcelas = real.split_installments(n, remainder);
+                    assert_eq!(n as usize, parcelas.len());
+                    let sum: i128 = parcelas.iter().map(|p| i128::from(p.centavos())).sum();
+                    assert_eq!(i128::from(centavos), sum);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Real::from_centavos(150);
+        let b = Real::from_reais(2);
+        assert_eq!(Real::from_centavos(350), a + b);
+        assert_eq!(Real::from_centavos(-50), a - b);
+        assert_eq!(Real::from_centavos(-150), -a);
+        assert_eq!(Real::from_centavos(600), b * 3);
+        assert_eq!(Real::from_centavos(500), [a, a, b].iter().sum());
+    }
+
+    #[test]
+    fn checked_arithmetic_detects_overflow() {
+        assert_eq!(None, Real::MIN.checked_sub(Real::from_centavos(1)));
+        assert_eq!(None, Real::MIN.checked_neg());
+        assert_eq!(None, Real::MIN.checked_abs());
+        assert_eq!(None, Real::MAX.checked_mul(2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_panics_on_overflow() {
+        let _ = Real::MAX + Real::from_centavos(1);
+    }
+}
@@ -0,0 +1,74 @@
+//! Personal names.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const PARTICULAS: [&str; 6] = ["da", "de", "do", "das", "dos", "e"];
+
+/// Capitalizes each word of a personal name, keeping the Portuguese particles
+/// "da", "de", "do", "das", "dos" and "e" lowercase. Letters after an apostrophe or a
+/// hyphen are capitalized too, and repeated whitespace is collapsed.
+///
+/// ```
+/// use bras::nome::title_case;
+///
+/// assert_eq!("Maria das Graças da Silva", title_case("MARIA DAS GRAÇAS DA SILVA"));
+/// assert_eq!("João D'Ávila e Souza", title_case("joão d'ávila e souza"));
+/// assert_eq!("Ana-Clara dos Anjos", title_case("ana-clara  dos anjos"));
+/// ```
+pub fn title_case(nome: &str) -> String {
+    nome.split_whitespace()
+        .enumerate()
+        .map(|(i, word)| {
+            let word = word.to_lowercase();
+            if i > 0 && PARTICULAS.contains(&word.as_str()) {
+                word
+            } else {
+                capitalize(&word)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn capitalize(word: &str) -> String {
+    let mut capitalized = String::with_capacity(word.len());
+    let mut start_of_part = true;
+    for c in word.chars() {
+        if start_of_part {
+            capitalized.extend(c.to_uppercase());
+        } else {
+            capitalized.push(c);
+        }
+        start_of_part = matches!(c, '\'' | '’' | '-');
+    }
+    capitalized
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn particles_stay_lowercase() {
+        assert_eq!("José dos Santos", title_case("JOSÉ DOS SANTOS"));
+        assert_eq!(
+            "Pedro de Alcântara e Silva",
+            title_case("pedro DE alcântara E silva")
+        );
+        assert_eq!("Da Costa", title_case("da costa"));
+    }
+
+    #[test]
+    fn apostrophes_and_hyphens() {
+        assert_eq!("Joana D’Arc", title_case("JOANA D’ARC"));
+        assert_eq!("Sant'Anna", title_case("sant'anna"));
+        assert_eq!("Maria-Eduarda", title_case("MARIA-EDUARDA"));
+    }
+
+    #[test]
+    fn whitespace_is_collapsed() {
+        assert_eq!("Ana Lima", title_case("  ana \t lima  "));
+        assert_eq!("", title_case("   "));
+    }
+}
@@ -0,0 +1,310 @@
+//! Identifiers of the Open Finance Brasil APIs.
+//!
+//! # Examples
+//! ```
+//! use bras::open_finance::IdConsentimento;
+//! # use bras::open_finance::ParseIdConsentimentoError;
+//!
+//! let id: IdConsentimento = "urn:bancoex:C1DD33123".parse()?;
+//! assert_eq!("bancoex", id.participante());
+//! assert_eq!("C1DD33123", id.identificador());
+//! assert_eq!("urn:bancoex:C1DD33123", id.to_string());
+//!
+//! assert_eq!(
+//!     Err(ParseIdConsentimentoError::InvalidPrefix),
+//!     "bancoex:C1DD33123".parse::<IdConsentimento>()
+//! );
+//!
+//! # Ok::<(), ParseIdConsentimentoError>(())
+//! ```
+
+use alloc::string::{String, ToString};
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+/// Consent identifier (`consentId`) the transmitting institution assigns when a consent is
+/// created, an URN whose namespace identifies the institution, such as
+/// "urn:bancoex:C1DD33123".
+///
+/// [`FromStr`] only checks the structure. The directory of participants changes as
+/// institutions join and leave, so it is not embedded: fetch it from the directory and use
+/// [`IdConsentimento::parse_registered`] to also check the namespace.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct IdConsentimento {
+    inner: String,
+    /// End of the namespace, where the second ':' is.
+    separator: usize,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseIdConsentimentoError {
+    /// Longer than the 256 characters the specification allows.
+    InvalidLength { got: usize },
+    /// Does not start with "urn:".
+    InvalidPrefix,
+    /// The namespace is missing, longer than 32 characters, does not start with a letter or
+    /// digit or has something other than letters, digits and '-'.
+    InvalidParticipante,
+    /// The identifier after the namespace is empty.
+    MissingIdentificador,
+    /// Byte at the zero based `position` of the input is not allowed in the identifier.
+    InvalidCharacter { position: usize },
+    /// The namespace is not one of the participants given to
+    /// [`IdConsentimento::parse_registered`].
+    UnknownParticipante,
+}
+
+impl Display for ParseIdConsentimentoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseIdConsentimentoError::InvalidLength { got } => write!(
+                f,
+                "invalid consent ID, expected at most 256 characters but got {}",
+                got
+            ),
+            ParseIdConsentimentoError::InvalidPrefix => {
+                f.write_str("invalid consent ID, expected it to start with \"urn:\"")
+            }
+            ParseIdConsentimentoError::InvalidParticipante => {
+                f.write_str("invalid consent ID, invalid participant namespace")
+            }
+            ParseIdConsentimentoError::MissingIdentificador => {
+                f.write_str("invalid consent ID, missing identifier after the namespace")
+            }
+            ParseIdConsentimentoError::InvalidCharacter { position } => write!(
+                f,
+                "invalid consent ID, character at position {} is not allowed",
+                position
+            ),
+            ParseIdConsentimentoError::UnknownParticipante => {
+                f.write_str("invalid consent ID, unknown participant namespace")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseIdConsentimentoError {}
+
+const PREFIX: &str = "urn:";
+
+/// Characters allowed in the identifier besides letters and digits.
+const SYMBOLS: &[u8] = b"()+,-.:=@;$_!*'%/?#";
+
+impl IdConsentimento {
+    /// ID in the `participante` namespace, both already checked.
+    #[cfg(feature = "arbitrary")]
+    fn from_parts(participante: &[u8], identificador: &[u8]) -> Self {
+        let mut inner = String::from(PREFIX);
+        inner.extend(participante.iter().map(|&b| char::from(b)));
+        let separator = inner.len();
+        inner.push(':');
+        inner.extend(identificador.iter().map(|&b| char::from(b)));
+        IdConsentimento { inner, separator }
+    }
+
+    /// Namespace of the URN, chosen by the institution that created the consent.
+    pub fn participante(&self) -> &str {
+        &self.inner[PREFIX.len()..self.separator]
+    }
+
+    /// What follows the namespace.
+    pub fn identificador(&self) -> &str {
+        &self.inner[self.separator + 1..]
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// Parses like [`FromStr`], also requiring the namespace to be one of `participantes`,
+    /// compared ignoring case as in any URN.
+    ///
+    /// ```
+    /// use bras::open_finance::{IdConsentimento, ParseIdConsentimentoError};
+    ///
+    /// let participantes = ["bancoex", "fintech-y"];
+    /// assert!(IdConsentimento::parse_registered("urn:BancoEx:C1DD33123", participantes).is_ok());
+    /// assert_eq!(
+    ///     Err(ParseIdConsentimentoError::UnknownParticipante),
+    ///     IdConsentimento::parse_registered("urn:outro:C1DD33123", participantes)
+    /// );
+    /// ```
+    pub fn parse_registered<'a>(
+        s: &str,
+        participantes: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self, ParseIdConsentimentoError> {
+        let id: IdConsentimento = s.parse()?;
+        if participantes
+            .into_iter()
+            .any(|participante| participante.eq_ignore_ascii_case(id.participante()))
+        {
+            Ok(id)
+        } else {
+            Err(ParseIdConsentimentoError::UnknownParticipante)
+        }
+    }
+
+    /// Whether `s` is a consent ID accepted by [`FromStr`].
+    pub fn is_valid(s: &str) -> bool {
+        s.parse::<IdConsentimento>().is_ok()
+    }
+}
+
+impl FromStr for IdConsentimento {
+    type Err = ParseIdConsentimentoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() > 256 {
+            return Err(ParseIdConsentimentoError::InvalidLength { got: s.len() });
+        }
+        let rest = s
+            .strip_prefix(PREFIX)
+            .ok_or(ParseIdConsentimentoError::InvalidPrefix)?;
+        let (participante, identificador) = rest
+            .split_once(':')
+            .ok_or(ParseIdConsentimentoError::InvalidParticipante)?;
+
+        let valid_participante = participante.len() <= 32
+            && participante
+                .bytes()
+                .next()
+                .is_some_and(|b| b.is_ascii_alphanumeric())
+            && participante
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-');
+        if !valid_participante {
+            return Err(ParseIdConsentimentoError::InvalidParticipante);
+        }
+        if identificador.is_empty() {
+            return Err(ParseIdConsentimentoError::MissingIdentificador);
+        }
+        let separator = PREFIX.len() + participante.len();
+        if let Some(position) = identificador
+            .bytes()
+            .position(|b| !b.is_ascii_alphanumeric() && !SYMBOLS.contains(&b))
+        {
+            return Err(ParseIdConsentimentoError::InvalidCharacter {
+                position: separator + 1 + position,
+            });
+        }
+
+        Ok(IdConsentimento {
+            inner: s.to_string(),
+            separator,
+        })
+    }
+}
+
+impl Display for IdConsentimento {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.inner)
+    }
+}
+
+/// Letters and digits, allowed anywhere.
+#[cfg(feature = "arbitrary")]
+const ALPHANUMERIC: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IdConsentimento {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut participante = alloc::vec![*u.choose(ALPHANUMERIC)?];
+        for _ in 0..u.int_in_range(0..=31)? {
+            participante.push(if u.ratio(1, 8)? {
+                b'-'
+            } else {
+                *u.choose(ALPHANUMERIC)?
+            });
+        }
+        let mut identificador = alloc::vec::Vec::new();
+        for _ in 0..u.int_in_range(1..=64)? {
+            identificador.push(if u.arbitrary()? {
+                *u.choose(ALPHANUMERIC)?
+            } else {
+                *u.choose(SYMBOLS)?
+            });
+        }
+        Ok(IdConsentimento::from_parts(&participante, &identificador))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for IdConsentimento {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<IdConsentimento>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::*;
+
+        let alphanumeric = || proptest::sample::select(ALPHANUMERIC.to_vec());
+        (
+            alphanumeric(),
+            proptest::collection::vec(prop_oneof![alphanumeric(), Just(b'-')], 0..32),
+            proptest::collection::vec(
+                prop_oneof![alphanumeric(), proptest::sample::select(SYMBOLS.to_vec())],
+                1..=64,
+            ),
+        )
+            .prop_map(|(first, rest, identificador)| {
+                let mut participante = alloc::vec![first];
+                participante.extend(rest);
+                IdConsentimento::from_parts(&participante, &identificador)
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let parse = |s: &str| s.parse::<IdConsentimento>();
+        assert_eq!(
+            Err(ParseIdConsentimentoError::InvalidPrefix),
+            parse("URN:bancoex:C1DD33123")
+        );
+        assert_eq!(
+            Err(ParseIdConsentimentoError::InvalidParticipante),
+            parse("urn:bancoex")
+        );
+        assert_eq!(
+            Err(ParseIdConsentimentoError::InvalidParticipante),
+            parse("urn:-bancoex:C1DD33123")
+        );
+        assert_eq!(
+            Err(ParseIdConsentimentoError::InvalidParticipante),
+            parse("urn:banco_ex:C1DD33123")
+        );
+        assert_eq!(
+            Err(ParseIdConsentimentoError::MissingIdentificador),
+            parse("urn:bancoex:")
+        );
+        assert_eq!(
+            Err(ParseIdConsentimentoError::InvalidCharacter { position: 14 }),
+            parse("urn:bancoex:C1 DD33123")
+        );
+        assert_eq!(
+            Err(ParseIdConsentimentoError::InvalidLength { got: 257 }),
+            parse(&alloc::format!("urn:bancoex:{}", "a".repeat(245)))
+        );
+    }
+
+    #[test]
+    fn identifier_may_contain_colons() {
+        let id: IdConsentimento = "urn:banco-ex:b5f7c1d2-8d3f-4e5a:9f1b".parse().unwrap();
+        assert_eq!("banco-ex", id.participante());
+        assert_eq!("b5f7c1d2-8d3f-4e5a:9f1b", id.identificador());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    proptest::proptest! {
+        #[test]
+        fn arbitrary_values_round_trip(id in proptest::arbitrary::any::<IdConsentimento>()) {
+            proptest::prop_assert_eq!(Ok(id.clone()), id.to_string().parse());
+        }
+    }
+}
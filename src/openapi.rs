@@ -0,0 +1,196 @@
+//! utoipa `ToSchema` implementations, describing the serde representation of each type.
+
+use std::borrow::Cow;
+
+use utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaFormat, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::{
+    Caepf, Cei, Cep, Chassi, ChaveAcesso, ChaveNfse, Cnes, Cno, Cnpj, CodigoMunicipio, Competencia,
+    Cpf, Crea, Crm, Oab, Pis, Renavam, Rntrc, Telefone, TipoLogradouro, TituloEleitor, Uf,
+};
+
+fn string_schema(
+    format: Option<&str>,
+    pattern: Option<&str>,
+    description: &str,
+    example: &str,
+) -> ObjectBuilder {
+    ObjectBuilder::new()
+        .schema_type(Type::String)
+        .format(format.map(|format| SchemaFormat::Custom(format.to_string())))
+        .pattern(pattern)
+        .description(Some(description))
+        .examples([example])
+}
+
+macro_rules! impl_to_schema {
+    ($($ty:ident => $schema:expr,)*) => {
+        $(
+            impl PartialSchema for $ty {
+                fn schema() -> RefOr<Schema> {
+                    let builder: ObjectBuilder = $schema;
+                    builder.into()
+                }
+            }
+
+            impl ToSchema for $ty {
+                fn name() -> Cow<'static, str> {
+                    stringify!($ty).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_schema! {
+    Caepf => string_schema(
+        None,
+        Some(r"^(\d{14}|\d{3}\.\d{3}\.\d{3}/\d{3}-\d{2})$"),
+        "Cadastro de Atividade Econômica da Pessoa Física, with or without punctuation.",
+        "984.844.854/001-67",
+    ),
+    Cei => string_schema(
+        None,
+        Some(r"^(\d{12}|\d{2}\.\d{3}\.\d{5}/\d{2})$"),
+        "Cadastro Específico do INSS, with or without punctuation.",
+        "51.204.09876/01",
+    ),
+    Cep => string_schema(
+        Some("cep"),
+        Some(r"^\d{5}-?\d{3}$"),
+        "Código de Endereçamento Postal.",
+        "01310-100",
+    ),
+    ChaveAcesso => string_schema(
+        None,
+        Some(r"^\d{44}$"),
+        "Access key of the NF-e, NFC-e, CT-e and MDF-e, the 44 digits printed on the DANFE.",
+        "35240311222333000181550010000001231123456788",
+    ),
+    ChaveNfse => string_schema(
+        None,
+        Some(r"^(NFS)?\d{50}$"),
+        "Access key of the national standard NFS-e, 50 digits after an optional \"NFS\".",
+        "35503082211222333000181000000000012324031234567892",
+    ),
+    Chassi => string_schema(
+        None,
+        Some(r"^[A-HJ-NPR-Za-hj-npr-z0-9]{17}$"),
+        "Vehicle chassis number, the 17 character VIN.",
+        "9BWZZZ377VT004251",
+    ),
+    Cnes => string_schema(
+        None,
+        Some(r"^\d{1,7}$"),
+        "Cadastro Nacional de Estabelecimentos de Saúde, up to seven digits.",
+        "2077485",
+    ),
+    Cnpj => string_schema(
+        None,
+        Some(r"^([0-9A-Za-z]{12}\d{2}|[0-9A-Za-z]{2}\.[0-9A-Za-z]{3}\.[0-9A-Za-z]{3}/[0-9A-Za-z]{4}-\d{2})$"),
+        "Cadastro Nacional da Pessoa Jurídica, numeric or alphanumeric, with or without punctuation.",
+        "12.ABC.345/01DE-35",
+    ),
+    Cno => string_schema(
+        None,
+        Some(r"^(\d{12}|\d{2}\.\d{3}\.\d{5}/\d{2})$"),
+        "Cadastro Nacional de Obras, with or without punctuation.",
+        "51.204.09876/71",
+    ),
+    CodigoMunicipio => string_schema(
+        None,
+        Some(r"^\d{7}$"),
+        "Seven digit IBGE municipality code.",
+        "3550308",
+    ),
+    Competencia => string_schema(
+        None,
+        Some(r"^(\d{2}/\d{4}|\d{4}-\d{2})$"),
+        "Reference month, as MM/YYYY or YYYY-MM.",
+        "06/2024",
+    ),
+    Cpf => string_schema(
+        Some("cpf"),
+        Some(r"^(\d{11}|\d{3}\.\d{3}\.\d{3}-\d{2})$"),
+        "Cadastro de Pessoas Físicas, with or without punctuation.",
+        "984.844.854-39",
+    ),
+    Crea => string_schema(
+        None,
+        None,
+        "Registration in a Conselho Regional de Engenharia e Agronomia, with the UF.",
+        "CREA-SP 5061234567",
+    ),
+    Crm => string_schema(
+        None,
+        None,
+        "Registration in a Conselho Regional de Medicina, with the UF.",
+        "CRM/PE 12345",
+    ),
+    Oab => string_schema(
+        None,
+        None,
+        "Registration in the Ordem dos Advogados do Brasil, with the UF.",
+        "OAB/SP 123.456",
+    ),
+    Pis => string_schema(
+        None,
+        Some(r"^(\d{11}|\d{3}\.\d{5}\.\d{2}-\d)$"),
+        "Número de Inscrição do Trabalhador (PIS, PASEP, NIT or NIS), with or without punctuation.",
+        "120.28384.76-1",
+    ),
+    Renavam => string_schema(
+        None,
+        Some(r"^(\d{9}|\d{11})$"),
+        "Registro Nacional de Veículos Automotores, with 11 digits or the 9 of older certificates.",
+        "00639087922",
+    ),
+    Rntrc => string_schema(
+        None,
+        Some(r"^((TAC|ETC|CTC|tac|etc|ctc)[ :-]*)?0?\d{8}$"),
+        "Registro Nacional de Transportadores Rodoviários de Cargas, optionally after the carrier category.",
+        "ETC 12345678",
+    ),
+    Telefone => string_schema(
+        None,
+        Some(r"^(\+55 ?)?(\(\d{2}\) ?|\d{2} ?)\d{4,5}[- ]?\d{4}$"),
+        "Brazilian phone number with the area code, optionally after +55.",
+        "(11) 98765-4321",
+    ),
+    TipoLogradouro => string_schema(
+        None,
+        None,
+        "Street type, by name or Correios abbreviation.",
+        "Avenida",
+    ),
+    TituloEleitor => string_schema(
+        None,
+        Some(r"^(\d{12}|\d{4} \d{4} \d{4})$"),
+        "Título de eleitor, with or without the spaces between groups of four digits.",
+        "0043 5687 0906",
+    ),
+    Uf => string_schema(None, None, "Sigla of a federative unit.", "SP")
+        .enum_values(Some(Uf::ALL.iter().map(|uf| uf.sigla()))),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn schemas_describe_the_string_forms() {
+        let RefOr::T(Schema::Object(cpf)) = Cpf::schema() else {
+            panic!("inline object schema");
+        };
+        assert!(cpf.format == Some(SchemaFormat::Custom("cpf".into())));
+        assert!(cpf.pattern.is_some());
+        assert_eq!("Cpf", Cpf::name());
+
+        let RefOr::T(Schema::Object(uf)) = Uf::schema() else {
+            panic!("inline object schema");
+        };
+        assert_eq!(27, uf.enum_values.unwrap().len());
+    }
+}
@@ -0,0 +1,121 @@
+/// How strictly to read documents with `parse_with`, such as [`Cpf::parse_with`].
+///
+/// [`ParseOptions::strict`], also the [`Default`], accepts the same strings as
+/// [`FromStr`](core::str::FromStr), which suits APIs. [`ParseOptions::lenient`] accepts what
+/// usually shows up when importing spreadsheets and legacy systems.
+///
+/// [`Cpf::parse_with`]: crate::Cpf::parse_with
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "cpf")]
+/// # fn main() -> Result<(), bras::ParseCpfError> {
+/// use bras::{Cpf, ParseOptions};
+///
+/// let options = ParseOptions::strict().trim_whitespace(true);
+/// assert!(Cpf::parse_with(" 984.844.854-39\n", options).is_ok());
+/// assert!(Cpf::parse_with("984844854-39", options).is_err());
+///
+/// let cpf = Cpf::parse_with("5119439039", ParseOptions::lenient())?;
+/// assert_eq!("051.194.390-39", cpf.to_string());
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "cpf"))]
+/// # fn main() {}
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ParseOptions {
+    pub(crate) partial_formatting: bool,
+    pub(crate) trim_whitespace: bool,
+    pub(crate) missing_leading_zeros: bool,
+}
+
+impl ParseOptions {
+    /// Only the formats accepted by [`FromStr`](core::str::FromStr).
+    pub const fn strict() -> Self {
+        ParseOptions {
+            partial_formatting: false,
+            trim_whitespace: false,
+            missing_leading_zeros: false,
+        }
+    }
+
+    /// Every relaxation turned on.
+    pub const fn lenient() -> Self {
+        ParseOptions {
+            partial_formatting: true,
+            trim_whitespace: true,
+            missing_leading_zeros: true,
+        }
+    }
+
+    /// Accepts any of the separators left out, as in "984844854-39".
+    pub const fn partial_formatting(self, allow: bool) -> Self {
+        ParseOptions {
+            partial_formatting: allow,
+            ..self
+        }
+    }
+
+    /// Ignores whitespace before and after the document.
+    pub const fn trim_whitespace(self, trim: bool) -> Self {
+        ParseOptions {
+            trim_whitespace: trim,
+            ..self
+        }
+    }
+
+    /// Accepts unformatted documents whose leading zeros were dropped, as happens when they
+    /// are stored as numbers.
+    pub const fn missing_leading_zeros(self, allow: bool) -> Self {
+        ParseOptions {
+            missing_leading_zeros: allow,
+            ..self
+        }
+    }
+
+    #[cfg(any(feature = "cpf", feature = "geo"))]
+    pub(crate) fn apply_trim(self, s: &str) -> &str {
+        if self.trim_whitespace {
+            s.trim()
+        } else {
+            s
+        }
+    }
+
+    /// The digits of `s` right aligned in `N` places, when leading zeros may be missing and
+    /// `s` is made of fewer than `N` digits only.
+    #[cfg(any(feature = "cpf", feature = "geo"))]
+    pub(crate) fn pad_leading_zeros<const N: usize>(self, s: &str) -> Option<[u8; N]> {
+        if !self.missing_leading_zeros
+            || s.is_empty()
+            || s.len() >= N
+            || !s.bytes().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        let mut digits = [0u8; N];
+        for (digit, byte) in digits[N - s.len()..].iter_mut().zip(s.bytes()) {
+            *digit = byte - b'0';
+        }
+        Some(digits)
+    }
+}
+
+/// Every ASCII digit of `s` in order, ignoring anything else, or how many there were when
+/// not exactly `N`.
+pub(crate) fn digits_only<const N: usize>(s: &str) -> Result<[u8; N], usize> {
+    let mut digits = [0u8; N];
+    let mut count = 0;
+    for byte in s.bytes().filter(u8::is_ascii_digit) {
+        if count < N {
+            digits[count] = byte - b'0';
+        }
+        count += 1;
+    }
+    if count == N {
+        Ok(digits)
+    } else {
+        Err(count)
+    }
+}
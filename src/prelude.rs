@@ -0,0 +1,38 @@
+//! The types, traits and macros most applications need, in a single import.
+//!
+//! ```
+//! # #[cfg(all(feature = "cnpj", feature = "cpf", feature = "geo"))]
+//! # fn main() -> Result<(), bras::Error> {
+//! use bras::prelude::*;
+//!
+//! let cpf: Cpf = "984.844.854-39".parse()?;
+//! assert_eq!("***.844.854-**", cpf.masked());
+//! assert_eq!(Uf::SP, cep!("01310-100").uf().unwrap());
+//!
+//! let cnpj: Cnpj = "12.ABC.345/01DE-35".parse()?;
+//! assert_eq!(cnpj!("12ABC34501DE35"), cnpj);
+//! # Ok(())
+//! # }
+//! # #[cfg(not(all(feature = "cnpj", feature = "cpf", feature = "geo")))]
+//! # fn main() {}
+//! ```
+
+pub use crate::batch::ValidateExt as _;
+#[cfg(any(
+    feature = "cnpj",
+    feature = "cpf",
+    feature = "geo",
+    feature = "registro-profissional",
+    feature = "veiculos"
+))]
+pub use crate::Document;
+#[cfg(feature = "geo")]
+pub use crate::{cep, Cep, ParseCepError};
+#[cfg(feature = "cnpj")]
+pub use crate::{cnpj, Cnpj, ParseCnpjError};
+pub use crate::{codigo_municipio, docs};
+#[cfg(feature = "cpf")]
+pub use crate::{cpf, Cpf, ParseCpfError};
+pub use crate::{BrDocument, Error, MaskStyle, ParseOptions};
+pub use crate::{CodigoMunicipio, ParseCodigoMunicipioError, ParseUfError, Uf};
+pub use crate::{Competencia, ParseCompetenciaError, ParseRealError, Real};
@@ -0,0 +1,315 @@
+//! INSS contribution of employees (segurados empregados), computed by progressive brackets
+//! since the 2019 pension reform (EC 103/2019).
+//!
+//! # Examples
+//! ```
+//! use bras::previdencia::contribuicao_inss;
+//! use bras::{Competencia, Real};
+//! # use bras::ParseCompetenciaError;
+//!
+//! let competencia: Competencia = "06/2024".parse()?;
+//! let contribuicao = contribuicao_inss(Real::from_reais(3000), competencia).unwrap();
+//! assert_eq!(Real::from_centavos(25882), contribuicao.total);
+//! assert_eq!(3, contribuicao.faixas.len());
+//!
+//! # Ok::<(), ParseCompetenciaError>(())
+//! ```
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "dates")]
+use chrono::NaiveDate;
+
+use crate::{Competencia, Real};
+
+/// Bracket of a contribution table: the part of the salary up to `ate` (inclusive) and above
+/// the previous bracket is charged at `aliquota`, expressed in hundredths of a percent
+/// (`750` is 7,5%).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Faixa {
+    pub ate: Real,
+    pub aliquota: u32,
+}
+
+/// Contribution table in force from the competência `inicio` on.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TabelaInss {
+    pub inicio: Competencia,
+    /// Brackets in increasing order; the last `ate` is the contribution ceiling (teto).
+    pub faixas: Vec<Faixa>,
+}
+
+/// Result of applying a [`TabelaInss`] to a salary.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ContribuicaoInss {
+    /// Contribution on each bracket reached by the salary, rounded to the centavo.
+    pub faixas: Vec<Real>,
+    /// Sum of the contributions of each bracket.
+    pub total: Real,
+}
+
+/// (ate em centavos, aliquota)
+type FaixasEmbutidas = [(i64, u32); 4];
+
+/// (ano, mes, faixas)
+const TABELAS: [(i32, u32, FaixasEmbutidas); 8] = [
+    (
+        2020,
+        3,
+        [(104500, 750), (208960, 900), (313440, 1200), (610106, 1400)],
+    ),
+    (
+        2021,
+        1,
+        [(110000, 750), (220348, 900), (330522, 1200), (643357, 1400)],
+    ),
+    (
+        2022,
+        1,
+        [(121200, 750), (242735, 900), (364103, 1200), (708722, 1400)],
+    ),
+    (
+        2023,
+        1,
+        [(130200, 750), (257129, 900), (385694, 1200), (750749, 1400)],
+    ),
+    (
+        2023,
+        5,
+        [(132000, 750), (257129, 900), (385694, 1200), (750749, 1400)],
+    ),
+    (
+        2024,
+        1,
+        [(141200, 750), (266668, 900), (400003, 1200), (778602, 1400)],
+    ),
+    (
+        2025,
+        1,
+        [(151800, 750), (279388, 900), (419083, 1200), (815741, 1400)],
+    ),
+    (
+        2026,
+        1,
+        [(162100, 750), (290284, 900), (435427, 1200), (847555, 1400)],
+    ),
+];
+
+/// National minimum wage since the Real was introduced, as (ano, mes, dia, centavos).
+#[cfg(feature = "dates")]
+const SALARIOS_MINIMOS: [(i32, u32, u32, i64); 37] = [
+    (1994, 7, 1, 6479),
+    (1994, 9, 1, 7000),
+    (1995, 5, 1, 10000),
+    (1996, 5, 1, 11200),
+    (1997, 5, 1, 12000),
+    (1998, 5, 1, 13000),
+    (1999, 5, 1, 13600),
+    (2000, 4, 3, 15100),
+    (2001, 4, 1, 18000),
+    (2002, 4, 1, 20000),
+    (2003, 4, 1, 24000),
+    (2004, 5, 1, 26000),
+    (2005, 5, 1, 30000),
+    (2006, 4, 1, 35000),
+    (2007, 4, 1, 38000),
+    (2008, 3, 1, 41500),
+    (2009, 2, 1, 46500),
+    (2010, 1, 1, 51000),
+    (2011, 1, 1, 54000),
+    (2011, 3, 1, 54500),
+    (2012, 1, 1, 62200),
+    (2013, 1, 1, 67800),
+    (2014, 1, 1, 72400),
+    (2015, 1, 1, 78800),
+    (2016, 1, 1, 88000),
+    (2017, 1, 1, 93700),
+    (2018, 1, 1, 95400),
+    (2019, 1, 1, 99800),
+    (2020, 1, 1, 103900),
+    (2020, 2, 1, 104500),
+    (2021, 1, 1, 110000),
+    (2022, 1, 1, 121200),
+    (2023, 1, 1, 130200),
+    (2023, 5, 1, 132000),
+    (2024, 1, 1, 141200),
+    (2025, 1, 1, 151800),
+    (2026, 1, 1, 162100),
+];
+
+/// National minimum wage in force on `date`, or `None` before July 1994, when the Real
+/// was introduced.
+///
+/// ```
+/// use bras::salario_minimo;
+/// use bras::Real;
+/// use chrono::NaiveDate;
+///
+/// let date = NaiveDate::from_ymd_opt(2023, 6, 15).unwrap();
+/// assert_eq!(Some(Real::from_reais(1320)), salario_minimo(date));
+///
+/// let date = NaiveDate::from_ymd_opt(1994, 6, 30).unwrap();
+/// assert_eq!(None, salario_minimo(date));
+/// ```
+#[cfg(feature = "dates")]
+pub fn salario_minimo(date: NaiveDate) -> Option<Real> {
+    SALARIOS_MINIMOS
+        .iter()
+        .rev()
+        .find(|&&(ano, mes, dia, _)| {
+            NaiveDate::from_ymd_opt(ano, mes, dia).expect("valid date") <= date
+        })
+        .map(|&(_, _, _, centavos)| Real::from_centavos(centavos))
+}
+
+/// Embedded official table in force on the competência, or `None` before March 2020,
+/// when the progressive brackets started.
+///
+/// ```
+/// use bras::previdencia::tabela_inss;
+/// use bras::{Competencia, Real};
+/// # use bras::ParseCompetenciaError;
+///
+/// let tabela = tabela_inss("04/2023".parse()?).unwrap();
+/// assert_eq!(Real::from_reais(1302), tabela.faixas[0].ate);
+/// assert_eq!(Real::from_centavos(750749), tabela.teto());
+///
+/// assert!(tabela_inss(Competencia::new(2019, 12)?).is_none());
+///
+/// # Ok::<(), ParseCompetenciaError>(())
+/// ```
+pub fn tabela_inss(competencia: Competencia) -> Option<TabelaInss> {
+    TABELAS
+        .iter()
+        .rev()
+        .find(|&&(ano, mes, _)| (ano, mes) <= (competencia.ano(), competencia.mes()))
+        .map(|&(ano, mes, ref faixas)| TabelaInss {
+            inicio: Competencia::new(ano, mes).expect("valid competência"),
+            faixas: faixas
+                .iter()
+                .map(|&(ate, aliquota)| Faixa {
+                    ate: Real::from_centavos(ate),
+                    aliquota,
+                })
+                .collect(),
+        })
+}
+
+/// Contribution on `salario` using the embedded table for the competência.
+pub fn contribuicao_inss(salario: Real, competencia: Competencia) -> Option<ContribuicaoInss> {
+    tabela_inss(competencia).map(|tabela| tabela.contribuicao(salario))
+}
+
+impl TabelaInss {
+    /// Highest salary subject to contribution.
+    pub fn teto(&self) -> Real {
+        self.faixas
+            .last()
+            .map(|faixa| faixa.ate)
+            .unwrap_or(Real::ZERO)
+    }
+
+    /// ```
+    /// use bras::previdencia::{Faixa, TabelaInss};
+    /// use bras::{Competencia, Real};
+    /// # use bras::ParseCompetenciaError;
+    ///
+    /// let tabela = TabelaInss {
+    ///     inicio: Competencia::new(2030, 1)?,
+    ///     faixas: vec![
+    ///         Faixa { ate: Real::from_reais(2000), aliquota: 800 },
+    ///         Faixa { ate: Real::from_reais(5000), aliquota: 1000 },
+    ///     ],
+    /// };
+    /// let contribuicao = tabela.contribuicao(Real::from_reais(9000));
+    /// assert_eq!(vec![Real::from_reais(160), Real::from_reais(300)], contribuicao.faixas);
+    /// assert_eq!(Real::from_reais(460), contribuicao.total);
+    ///
+    /// # Ok::<(), ParseCompetenciaError>(())
+    /// ```
+    pub fn contribuicao(&self, salario: Real) -> ContribuicaoInss {
+        let mut faixas = Vec::new();
+        let mut anterior = Real::ZERO;
+        for faixa in &self.faixas {
+            if salario <= anterior {
+                break;
+            }
+            let base = salario.min(faixa.ate) - anterior;
+            faixas.push(percentual(base, faixa.aliquota));
+            anterior = faixa.ate;
+        }
+        let total = faixas.iter().sum();
+        ContribuicaoInss { faixas, total }
+    }
+}
+
+/// `valor * aliquota / 10000`, rounded half away from zero to the centavo.
+pub(crate) fn percentual(valor: Real, aliquota: u32) -> Real {
+    let product = i128::from(valor.centavos()) * i128::from(aliquota);
+    let rounded = (product.abs() + 5000) / 10000 * product.signum();
+    Real::from_centavos(rounded as i64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn competencia(ano: i32, mes: u32) -> Competencia {
+        Competencia::new(ano, mes).unwrap()
+    }
+
+    #[test]
+    fn tables_are_progressive() {
+        for (ano, mes, _) in TABELAS {
+            let tabela = tabela_inss(competencia(ano, mes)).unwrap();
+            assert_eq!(competencia(ano, mes), tabela.inicio);
+            assert!(tabela
+                .faixas
+                .windows(2)
+                .all(|pair| pair[0].ate < pair[1].ate));
+        }
+    }
+
+    #[test]
+    fn contribution_up_to_the_ceiling() {
+        let contribuicao = contribuicao_inss(Real::from_reais(1518), competencia(2025, 1)).unwrap();
+        assert_eq!(Real::from_centavos(11385), contribuicao.total);
+
+        let teto = contribuicao_inss(Real::from_reais(50000), competencia(2025, 1)).unwrap();
+        assert_eq!(Real::from_centavos(95163), teto.total);
+        assert_eq!(4, teto.faixas.len());
+
+        let zero = contribuicao_inss(Real::ZERO, competencia(2024, 1)).unwrap();
+        assert_eq!(Real::ZERO, zero.total);
+    }
+
+    #[cfg(feature = "dates")]
+    #[test]
+    fn first_bracket_is_the_minimum_wage() {
+        for (ano, mes, _) in TABELAS {
+            let tabela = tabela_inss(competencia(ano, mes)).unwrap();
+            let date = NaiveDate::from_ymd_opt(ano, mes, 1).unwrap();
+            assert_eq!(salario_minimo(date), Some(tabela.faixas[0].ate));
+        }
+    }
+
+    #[cfg(feature = "dates")]
+    #[test]
+    fn minimum_wage_series_is_increasing() {
+        assert!(SALARIOS_MINIMOS
+            .windows(2)
+            .all(
+                |pair| (pair[0].0, pair[0].1, pair[0].2) < (pair[1].0, pair[1].1, pair[1].2)
+                    && pair[0].3 < pair[1].3
+            ));
+    }
+
+    #[test]
+    fn pick_table_by_competencia() {
+        let inicio = |ano, mes| tabela_inss(competencia(ano, mes)).unwrap().inicio;
+        assert_eq!(competencia(2023, 1), inicio(2023, 4));
+        assert_eq!(competencia(2023, 5), inicio(2023, 5));
+        assert_eq!(competencia(2026, 1), inicio(2031, 1));
+        assert!(tabela_inss(competencia(2020, 2)).is_none());
+    }
+}
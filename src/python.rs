@@ -0,0 +1,238 @@
+//! PyO3 bindings, exposing a `bras` Python module with the same rules as the crate. Build
+//! the wheel with `maturin build --features python`.
+//!
+//! ```python
+//! from bras import Cpf
+//!
+//! cpf = Cpf("98484485439")
+//! str(cpf)         # "984.844.854-39"
+//! cpf.digits()     # "98484485439"
+//! cpf.masked()     # "***.844.854-**"
+//! Cpf.is_valid("984.844.854-00")  # False
+//!
+//! from bras import Cnpj
+//!
+//! cnpj = Cnpj("12ABC34501DE35")
+//! str(cnpj)        # "12.ABC.345/01DE-35"
+//! cnpj.masked()    # "12.ABC.345/****-**"
+//! ```
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{Cep, Cnpj, Cpf};
+
+/// Cadastro de Pessoas Físicas, parsed from the formats accepted by [`FromStr`](core::str::FromStr).
+#[pyclass(name = "Cpf", module = "bras", frozen, eq, ord, hash)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash)]
+struct PyCpf(Cpf);
+
+#[pymethods]
+impl PyCpf {
+    #[new]
+    fn new(s: &str) -> PyResult<Self> {
+        s.parse()
+            .map(PyCpf)
+            .map_err(|err: crate::ParseCpfError| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Parses ignoring everything but the digits, see [`Cpf::parse_lossy`].
+    #[staticmethod]
+    fn parse_lossy(s: &str) -> PyResult<Self> {
+        Cpf::parse_lossy(s)
+            .map(PyCpf)
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    #[staticmethod]
+    fn is_valid(s: &str) -> bool {
+        Cpf::is_valid(s)
+    }
+
+    fn digits(&self) -> String {
+        format!("{:#}", self.0)
+    }
+
+    fn masked(&self) -> String {
+        self.0.masked()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Cpf('{}')", self.0)
+    }
+}
+
+/// Cadastro Nacional da Pessoa Jurídica, numeric or alphanumeric, parsed from the formats
+/// accepted by [`FromStr`](core::str::FromStr).
+#[pyclass(name = "Cnpj", module = "bras", frozen, eq, ord, hash)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash)]
+struct PyCnpj(Cnpj);
+
+#[pymethods]
+impl PyCnpj {
+    #[new]
+    fn new(s: &str) -> PyResult<Self> {
+        s.parse()
+            .map(PyCnpj)
+            .map_err(|err: crate::ParseCnpjError| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Parses ignoring everything but the letters and digits, see [`Cnpj::parse_lossy`].
+    #[staticmethod]
+    fn parse_lossy(s: &str) -> PyResult<Self> {
+        Cnpj::parse_lossy(s)
+            .map(PyCnpj)
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    #[staticmethod]
+    fn is_valid(s: &str) -> bool {
+        Cnpj::is_valid(s)
+    }
+
+    /// The 14 characters without separators.
+    fn digits(&self) -> String {
+        format!("{:#}", self.0)
+    }
+
+    fn masked(&self) -> String {
+        self.0.masked()
+    }
+
+    /// The first 8 characters, shared by every establishment of the company.
+    fn raiz(&self) -> String {
+        self.0.raiz().to_string()
+    }
+
+    fn is_alphanumeric(&self) -> bool {
+        self.0.is_alphanumeric()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Cnpj('{}')", self.0)
+    }
+}
+
+/// Código de Endereçamento Postal, parsed as "00000-000" or "00000000".
+#[pyclass(name = "Cep", module = "bras", frozen, eq, ord, hash)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash)]
+struct PyCep(Cep);
+
+#[pymethods]
+impl PyCep {
+    #[new]
+    fn new(s: &str) -> PyResult<Self> {
+        s.parse()
+            .map(PyCep)
+            .map_err(|err: crate::ParseCepError| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Parses ignoring everything but the digits, see [`Cep::parse_lossy`].
+    #[staticmethod]
+    fn parse_lossy(s: &str) -> PyResult<Self> {
+        Cep::parse_lossy(s)
+            .map(PyCep)
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    #[staticmethod]
+    fn is_valid(s: &str) -> bool {
+        Cep::is_valid(s)
+    }
+
+    fn digits(&self) -> String {
+        format!("{:#}", self.0)
+    }
+
+    fn masked(&self) -> String {
+        self.0.masked()
+    }
+
+    /// Abbreviation of the federative unit the CEP belongs to, if any.
+    fn uf(&self) -> Option<String> {
+        self.0.uf().map(|uf| uf.to_string())
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Cep('{}')", self.0)
+    }
+}
+
+#[pymodule]
+fn bras(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyCpf>()?;
+    m.add_class::<PyCnpj>()?;
+    m.add_class::<PyCep>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn module_from_python() {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "bras").unwrap();
+            bras(&module).unwrap();
+            let cpf = module
+                .getattr("Cpf")
+                .unwrap()
+                .call1(("98484485439",))
+                .unwrap();
+            assert_eq!("984.844.854-39", cpf.str().unwrap().to_string());
+            assert_eq!("Cpf('984.844.854-39')", cpf.repr().unwrap().to_string());
+            assert_eq!(
+                "***.844.854-**",
+                cpf.call_method0("masked")
+                    .unwrap()
+                    .extract::<String>()
+                    .unwrap()
+            );
+
+            let cnpj = module
+                .getattr("Cnpj")
+                .unwrap()
+                .call1(("12ABC34501DE35",))
+                .unwrap();
+            assert_eq!("12.ABC.345/01DE-35", cnpj.str().unwrap().to_string());
+            assert_eq!(
+                "Cnpj('12.ABC.345/01DE-35')",
+                cnpj.repr().unwrap().to_string()
+            );
+            assert_eq!(
+                "12.ABC.345/****-**",
+                cnpj.call_method0("masked")
+                    .unwrap()
+                    .extract::<String>()
+                    .unwrap()
+            );
+            let err = module
+                .getattr("Cnpj")
+                .unwrap()
+                .call1(("11.222.333/0001-80",))
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+
+            let err = module
+                .getattr("Cep")
+                .unwrap()
+                .call1(("0131-0100",))
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+}
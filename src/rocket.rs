@@ -0,0 +1,94 @@
+//! Rocket `FromParam` and `FromFormField`, so routes such as `/clientes/<cpf>` validate
+//! their parameters. A value that fails to parse makes the route not match, or the form
+//! field report the parse error.
+
+use ::rocket::form::{self, FromFormField, ValueField};
+use ::rocket::request::FromParam;
+
+use crate::{
+    Cep, Cnpj, CodigoMunicipio, Competencia, Cpf, ParseCepError, ParseCnpjError,
+    ParseCodigoMunicipioError, ParseCompetenciaError, ParseCpfError, ParseUfError, Uf,
+};
+
+macro_rules! impl_rocket {
+    ($($ty:ty => $err:ty,)*) => {
+        $(
+            impl<'a> FromParam<'a> for $ty {
+                type Error = $err;
+
+                fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+                    param.parse()
+                }
+            }
+
+            impl<'v> FromFormField<'v> for $ty {
+                fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+                    field
+                        .value
+                        .parse()
+                        .map_err(|err: $err| form::Error::validation(err.to_string()).into())
+                }
+            }
+        )*
+    };
+}
+
+impl_rocket! {
+    Cep => ParseCepError,
+    Cnpj => ParseCnpjError,
+    CodigoMunicipio => ParseCodigoMunicipioError,
+    Competencia => ParseCompetenciaError,
+    Cpf => ParseCpfError,
+    Uf => ParseUfError,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::rocket::http::Status;
+    use ::rocket::local::blocking::Client;
+    use ::rocket::{get, routes};
+
+    #[get("/clientes/<cpf>")]
+    fn cliente(cpf: Cpf) -> String {
+        cpf.to_string()
+    }
+
+    #[get("/empresas/<cnpj>")]
+    fn empresa(cnpj: Cnpj) -> String {
+        cnpj.to_string()
+    }
+
+    #[get("/enderecos?<cep>")]
+    fn endereco(cep: Cep) -> String {
+        cep.to_string()
+    }
+
+    #[test]
+    fn routes_validate_their_parameters() {
+        let rocket = ::rocket::build().mount("/", routes![cliente, empresa, endereco]);
+        let client = Client::untracked(rocket).unwrap();
+
+        let response = client.get("/clientes/98484485439").dispatch();
+        assert_eq!(Status::Ok, response.status());
+        assert_eq!("984.844.854-39", response.into_string().unwrap());
+
+        let response = client.get("/clientes/98484485401").dispatch();
+        assert_eq!(Status::UnprocessableEntity, response.status());
+
+        let response = client.get("/empresas/12ABC34501DE35").dispatch();
+        assert_eq!("12.ABC.345/01DE-35", response.into_string().unwrap());
+
+        let response = client.get("/enderecos?cep=01310100").dispatch();
+        assert_eq!("01310-100", response.into_string().unwrap());
+
+        let response = client.get("/enderecos?cep=00000000").dispatch();
+        assert_eq!(Status::UnprocessableEntity, response.status());
+    }
+
+    #[test]
+    fn param_errors_are_the_parse_errors() {
+        assert_eq!(Err(ParseUfError::Invalid), Uf::from_param("XX"));
+        assert_eq!(Ok(Uf::SP), Uf::from_param("sp"));
+    }
+}
@@ -0,0 +1,479 @@
+//! Finds documents in free text, such as contracts and e-mails.
+//!
+//! Candidates are runs of letters, digits, dots, hyphens and slashes, such as
+//! "11.222.333/0001-81", and phone numbers written as "(11) 98765-4321" or
+//! "+55 11 98765-4321". Only the candidates that parse as a valid document are reported.
+//!
+//! # Examples
+//! ```
+//! # #[cfg(all(feature = "cnpj", feature = "cpf", feature = "geo"))]
+//! # fn main() {
+//! use bras::scan::scan;
+//! use bras::{BrDocument, Document};
+//!
+//! let texto = "Cliente: CPF 984.844.854-39, CEP 01310-100. Protocolo 123. \
+//!              Fornecedor 12.ABC.345/01DE-35, telefone (11) 98765-4321.";
+//! let encontrados: Vec<_> = scan(texto).collect();
+//! assert_eq!(4, encontrados.len());
+//!
+//! assert_eq!("984.844.854-39", &texto[encontrados[0].range.clone()]);
+//! assert!(matches!(encontrados[0].document, Document::Cpf(_)));
+//! assert_eq!("CEP", encontrados[1].document.kind());
+//! assert_eq!("CNPJ", encontrados[2].document.kind());
+//! assert_eq!("(11) 98765-4321", &texto[encontrados[3].range.clone()]);
+//! # }
+//! # #[cfg(not(all(feature = "cnpj", feature = "cpf", feature = "geo")))]
+//! # fn main() {}
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::ops::Range;
+
+#[cfg(feature = "cnpj")]
+use crate::Cnpj;
+#[cfg(feature = "cpf")]
+use crate::Cpf;
+use crate::{BrDocument, Document, MaskStyle};
+#[cfg(feature = "geo")]
+use crate::{Cep, Telefone};
+
+/// A valid document found in the text, and where.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Match {
+    pub document: Document,
+    /// Byte range of the document in the scanned text.
+    pub range: Range<usize>,
+}
+
+/// Iterator over the documents of a text, in the order they appear.
+#[derive(Clone, Debug)]
+pub struct Scan<'a> {
+    texto: &'a str,
+    position: usize,
+}
+
+/// Valid documents in `texto`.
+pub fn scan(texto: &str) -> Scan<'_> {
+    Scan { texto, position: 0 }
+}
+
+impl Iterator for Scan<'_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        find(self.texto.as_bytes(), &mut self.position)
+    }
+}
+
+/// What replaces each document found by [`anonimizar_com`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Substituicao {
+    /// The document masked with the given style, as in "***.844.854-**".
+    Mascara(MaskStyle),
+    /// The kind of document between brackets, as in "[CPF]".
+    Marcador,
+}
+
+/// Text with its documents replaced, and what was replaced.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Anonimizado {
+    pub texto: String,
+    pub removidos: Vec<Removido>,
+}
+
+/// A document replaced by [`anonimizar`]. Only its kind is kept, so the report can be
+/// logged.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Removido {
+    /// Kind of document, as in [`BrDocument::kind`].
+    pub tipo: &'static str,
+    /// Byte range of the document in the original text.
+    pub range: Range<usize>,
+}
+
+/// Replaces every valid document of `texto` with its [recommended mask](MaskStyle::Recommended).
+///
+/// ```
+/// # #[cfg(all(feature = "cpf", feature = "geo"))]
+/// # {
+/// use bras::scan::anonimizar;
+///
+/// let anonimizado = anonimizar("CPF 984.844.854-39, CEP 01310-100.");
+/// assert_eq!("CPF ***.844.854-**, CEP 01310-***.", anonimizado.texto);
+/// assert_eq!("CPF", anonimizado.removidos[0].tipo);
+/// assert_eq!(4..18, anonimizado.removidos[0].range);
+/// # }
+/// ```
+pub fn anonimizar(texto: &str) -> Anonimizado {
+    anonimizar_com(texto, Substituicao::Mascara(MaskStyle::Recommended))
+}
+
+/// Replaces every valid document of `texto` as chosen by `substituicao`.
+///
+/// ```
+/// # #[cfg(feature = "cpf")]
+/// # {
+/// use bras::scan::{anonimizar_com, Substituicao};
+///
+/// let anonimizado = anonimizar_com("CPF: 98484485439", Substituicao::Marcador);
+/// assert_eq!("CPF: [CPF]", anonimizado.texto);
+/// # }
+/// ```
+pub fn anonimizar_com(texto: &str, substituicao: Substituicao) -> Anonimizado {
+    let mut anonimizado = Anonimizado {
+        texto: String::with_capacity(texto.len()),
+        removidos: Vec::new(),
+    };
+    let mut copiado = 0;
+    for encontrado in scan(texto) {
+        anonimizado
+            .texto
+            .push_str(&texto[copiado..encontrado.range.start]);
+        match substituicao {
+            Substituicao::Mascara(style) => anonimizado
+                .texto
+                .push_str(&encontrado.document.masked_with(style)),
+            Substituicao::Marcador => {
+                anonimizado.texto.push('[');
+                anonimizado.texto.push_str(encontrado.document.kind());
+                anonimizado.texto.push(']');
+            }
+        }
+        copiado = encontrado.range.end;
+        anonimizado.removidos.push(Removido {
+            tipo: encontrado.document.kind(),
+            range: encontrado.range,
+        });
+    }
+    anonimizado.texto.push_str(&texto[copiado..]);
+    anonimizado
+}
+
+/// Iterator over the documents read from `R`, for files too large to load in memory.
+/// Matches are the same [`scan`] finds, with ranges counted from the start of the reader.
+///
+/// ```
+/// use bras::scan::ScanReader;
+/// use std::io::BufReader;
+///
+/// let arquivo = BufReader::with_capacity(4, "CPF 984.844.854-39".as_bytes());
+/// let encontrados: Vec<_> = ScanReader::new(arquivo).collect::<Result<_, _>>()?;
+/// # #[cfg(feature = "cpf")]
+/// assert_eq!(4..18, encontrados[0].range);
+///
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ScanReader<R> {
+    reader: R,
+    pendente: Pendente,
+    fim: bool,
+}
+
+/// Text read but not scanned yet, and the documents found but not returned yet.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct Pendente {
+    /// Bytes read since the last point no document can span.
+    bytes: Vec<u8>,
+    /// Position of `bytes` in the reader.
+    offset: usize,
+    encontrados: alloc::collections::VecDeque<Match>,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::BufRead> ScanReader<R> {
+    pub fn new(reader: R) -> Self {
+        ScanReader {
+            reader,
+            pendente: Pendente {
+                bytes: Vec::new(),
+                offset: 0,
+                encontrados: alloc::collections::VecDeque::new(),
+            },
+            fim: false,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::BufRead> Iterator for ScanReader<R> {
+    type Item = std::io::Result<Match>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(encontrado) = self.pendente.encontrados.pop_front() {
+                return Some(Ok(encontrado));
+            }
+            if self.fim {
+                return None;
+            }
+            let buf = match self.reader.fill_buf() {
+                Ok(buf) => buf,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => return Some(Err(err)),
+            };
+            if buf.is_empty() {
+                self.fim = true;
+                self.pendente.flush();
+                continue;
+            }
+
+            let used = buf.len();
+            for &byte in buf {
+                self.pendente.push(byte);
+            }
+            self.reader.consume(used);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Pendente {
+    /// Longest text kept in memory without a point no document can span. Past it the text
+    /// is scanned anyway, which only matters for inputs without spaces or punctuation.
+    const LONGEST: usize = 4096;
+
+    fn push(&mut self, byte: u8) {
+        let cut = self.bytes.last().is_some_and(|&previous| {
+            !is_phone(previous) || (previous == b' ' && !starts_phone(byte))
+        });
+        if cut || self.bytes.len() == Pendente::LONGEST {
+            self.flush();
+        }
+        self.bytes.push(byte);
+    }
+
+    fn flush(&mut self) {
+        let mut position = 0;
+        while let Some(encontrado) = find(&self.bytes, &mut position) {
+            self.encontrados.push_back(Match {
+                document: encontrado.document,
+                range: encontrado.range.start + self.offset..encontrado.range.end + self.offset,
+            });
+        }
+        self.offset += self.bytes.len();
+        self.bytes.clear();
+    }
+}
+
+/// Finds the next document from `position`, leaving `position` past it.
+///
+/// Documents never span a byte that is neither in a candidate nor in a phone number, nor
+/// a space followed by something a phone number cannot continue with, so [`ScanReader`]
+/// scans the text in pieces cut after them.
+fn find(bytes: &[u8], position: &mut usize) -> Option<Match> {
+    while *position < bytes.len() {
+        let start = *position;
+        let byte = bytes[start];
+        let glued = start > 0 && bytes[start - 1].is_ascii_alphanumeric();
+        if glued || !(byte.is_ascii_alphanumeric() || starts_phone(byte)) {
+            *position += 1;
+            continue;
+        }
+        let mut end = start;
+        while end < bytes.len() && is_candidate(bytes[end]) {
+            end += 1;
+        }
+        *position = end.max(start + 1);
+
+        // Sentences end right after documents, as in "CPF 984.844.854-39."
+        while end > start && !bytes[end - 1].is_ascii_alphanumeric() {
+            end -= 1;
+        }
+        if let Some(document) = parse(&bytes[start..end]) {
+            return Some(Match {
+                document,
+                range: start..end,
+            });
+        }
+        #[cfg(feature = "geo")]
+        if let Some(end) = telefone(bytes, start) {
+            if let Ok(telefone) = Telefone::from_bytes(&bytes[start..end]) {
+                *position = end;
+                return Some(Match {
+                    document: Document::Telefone(telefone),
+                    range: start..end,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// End of the phone number written from `start`, as in "+55 (11) 98765-4321". Numbers
+/// without any punctuation are left out, they are too often something else.
+#[cfg(feature = "geo")]
+fn telefone(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut end = start;
+    let skip = |end: &mut usize, expected: u8| {
+        let found = bytes.get(*end) == Some(&expected);
+        if found {
+            *end += 1;
+        }
+        found
+    };
+    let digits = |end: &mut usize, most: usize| {
+        let from = *end;
+        while *end < bytes.len() && *end - from < most && bytes[*end].is_ascii_digit() {
+            *end += 1;
+        }
+        *end - from
+    };
+
+    if skip(&mut end, b'+') {
+        if !(skip(&mut end, b'5') && skip(&mut end, b'5')) {
+            return None;
+        }
+        skip(&mut end, b' ');
+    }
+    let parenteses = skip(&mut end, b'(');
+    if digits(&mut end, 2) != 2 || (parenteses && !skip(&mut end, b')')) {
+        return None;
+    }
+    skip(&mut end, b' ');
+    match digits(&mut end, 9) {
+        4 | 5 if matches!(bytes.get(end), Some(b'-' | b' ')) => {
+            end += 1;
+            if digits(&mut end, 4) != 4 {
+                return None;
+            }
+        }
+        8 | 9 => {}
+        _ => return None,
+    }
+
+    let glued = bytes.get(end).is_some_and(u8::is_ascii_alphanumeric);
+    let punctuated = bytes[start..end].iter().any(|b| !b.is_ascii_digit());
+    (!glued && punctuated).then_some(end)
+}
+
+fn is_candidate(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'-' | b'/')
+}
+
+fn starts_phone(byte: u8) -> bool {
+    byte.is_ascii_digit() || byte == b'(' || byte == b'+'
+}
+
+/// Bytes a phone number or a candidate can have.
+#[cfg(feature = "std")]
+fn is_phone(byte: u8) -> bool {
+    is_candidate(byte) || matches!(byte, b'(' | b')' | b'+' | b' ')
+}
+
+fn parse(candidate: &[u8]) -> Option<Document> {
+    // "00.000.000/0000-00", the longest way of writing a document.
+    if candidate.len() > 18 || !candidate.iter().any(u8::is_ascii_digit) {
+        return None;
+    }
+    #[cfg(feature = "cpf")]
+    if let Ok(cpf) = Cpf::from_bytes(candidate) {
+        return Some(Document::Cpf(cpf));
+    }
+    #[cfg(feature = "cnpj")]
+    if let Ok(cnpj) = Cnpj::from_bytes(candidate) {
+        return Some(Document::Cnpj(cnpj));
+    }
+    #[cfg(feature = "geo")]
+    if let Ok(cep) = Cep::from_bytes(candidate) {
+        return Some(Document::Cep(cep));
+    }
+    None
+}
+
+#[cfg(all(test, feature = "cnpj", feature = "cpf", feature = "geo"))]
+mod test {
+    use super::*;
+
+    fn found(texto: &str) -> Vec<&str> {
+        scan(texto).map(|m| &texto[m.range]).collect()
+    }
+
+    #[test]
+    fn finds_documents_between_punctuation() {
+        assert_eq!(
+            ["98484485439", "051.194.390-39", "01310100"],
+            found("(98484485439), 051.194.390-39; cep:01310100.").as_slice()
+        );
+        assert_eq!(["01310-100"], found("CEP 01310-100-").as_slice());
+    }
+
+    #[test]
+    fn finds_cnpjs_and_phones() {
+        let texto = "CNPJ 11.222.333/0001-81 ou 11222333000181, filial 12.ABC.345/01DE-35. \
+                     Tel.: (11) 98765-4321";
+        let encontrados: Vec<Match> = scan(texto).collect();
+        assert_eq!(
+            [
+                "11.222.333/0001-81",
+                "11222333000181",
+                "12.ABC.345/01DE-35",
+                "(11) 98765-4321"
+            ],
+            found(texto).as_slice()
+        );
+        assert!(matches!(encontrados[2].document, Document::Cnpj(_)));
+        assert!(matches!(encontrados[3].document, Document::Telefone(_)));
+        assert_eq!(
+            ["+55 11 98765-4321", "11 3456-7890"],
+            found("+55 11 98765-4321, 11 3456-7890").as_slice()
+        );
+    }
+
+    #[test]
+    fn anonymized_text_keeps_everything_else() {
+        let texto = "98484485439 e 984.844.854-30, depois 051.194.390-39";
+        let anonimizado = anonimizar_com(texto, Substituicao::Mascara(MaskStyle::Full));
+        assert_eq!(
+            "***.***.***-** e 984.844.854-30, depois ***.***.***-**",
+            anonimizado.texto
+        );
+        assert_eq!(2, anonimizado.removidos.len());
+        assert_eq!(
+            "051.194.390-39",
+            &texto[anonimizado.removidos[1].range.clone()]
+        );
+
+        let sem_documentos = anonimizar("nada a esconder");
+        assert_eq!("nada a esconder", sem_documentos.texto);
+        assert!(sem_documentos.removidos.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_matches_scan_across_buffer_boundaries() {
+        let texto = "ID98484485439 (98484485439), 051.194.390-39; cep:01310100. \
+                     984.844.854-39---- 01310-100-x 984844854390 são 70040-010 \
+                     12.ABC.345/01DE-35 tel +55 (11) 98765-4321 ou 11 3456-7890.";
+        let esperado: Vec<Match> = scan(texto).collect();
+        assert_eq!(8, esperado.len());
+        for capacity in 1..20 {
+            let reader = std::io::BufReader::with_capacity(capacity, texto.as_bytes());
+            let encontrados: Vec<Match> = ScanReader::new(reader).map(Result::unwrap).collect();
+            assert_eq!(esperado, encontrados, "capacity {}", capacity);
+        }
+    }
+
+    #[test]
+    fn ignores_invalid_and_glued_candidates() {
+        assert!(found("984.844.854-30").is_empty());
+        assert!(found("ID98484485439").is_empty());
+        assert!(found("98484485439x").is_empty());
+        assert!(found("984844854390").is_empty());
+        assert!(found("").is_empty());
+        assert!(found("são 1.2.3").is_empty());
+        assert!(found("11.222.333/0001-80").is_empty());
+        assert!(found("X11222333000181").is_empty());
+        assert!(found("11987654321").is_empty());
+        assert!(found("(00) 98765-4321").is_empty());
+        assert!(found("(11) 98765-4321x").is_empty());
+    }
+}
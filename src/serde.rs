@@ -0,0 +1,397 @@
+//! serde support.
+//!
+//! Every type with a textual representation serializes to its canonical formatted string
+//! and deserializes from any string its [`FromStr`] implementation accepts, so both
+//! "984.844.854-39" and "98484485439" are read as the same [`Cpf`].
+//!
+//! [`Real`] is the exception: it serializes to a plain decimal string, "-1234.56", and
+//! deserializes from that or any Brazilian format [`FromStr`] accepts, such as
+//! "R$ 1.234,56".
+//!
+//! # Examples
+//! ```
+//! use bras::{Cep, Cpf};
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Cliente {
+//!     cpf: Cpf,
+//!     cep: Cep,
+//! }
+//!
+//! let cliente: Cliente = serde_json::from_str(r#"{"cpf":"98484485439","cep":"01310100"}"#)?;
+//! assert_eq!(
+//!     r#"{"cpf":"984.844.854-39","cep":"01310-100"}"#,
+//!     serde_json::to_string(&cliente)?
+//! );
+//!
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! ## Choosing the representation per field
+//! The [`cpf_digits`], [`cpf_formatted`], [`cpf_u64`] and [`real_centavos`] modules work
+//! with `#[serde(with = "...")]`:
+//! ```
+//! use bras::Cpf;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Registro {
+//!     #[serde(with = "bras::serde::cpf_digits")]
+//!     digits: Cpf,
+//!     #[serde(with = "bras::serde::cpf_formatted")]
+//!     formatted: Cpf,
+//!     #[serde(with = "bras::serde::cpf_u64")]
+//!     number: Cpf,
+//! }
+//!
+//! let cpf: Cpf = "016.783.460-63".parse().unwrap();
+//! let registro = Registro { digits: cpf, formatted: cpf, number: cpf };
+//! assert_eq!(
+//!     r#"{"digits":"01678346063","formatted":"016.783.460-63","number":1678346063}"#,
+//!     serde_json::to_string(&registro)?
+//! );
+//!
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use core::fmt::Display;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use ::serde::de::{Deserialize, Deserializer, Error, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::{
+    Caepf, Cei, Cep, Chassi, ChaveAcesso, ChaveNfse, Cnes, Cno, Cnpj, CodigoMunicipio, Competencia,
+    Cpf, Crea, Crm, Oab, ParseRealError, Pis, Real, Renavam, Rntrc, Telefone, TipoLogradouro,
+    TituloEleitor, Uf,
+};
+
+struct FromStrVisitor<T> {
+    expecting: &'static str,
+    marker: PhantomData<T>,
+}
+
+impl<T> Visitor<'_> for FromStrVisitor<T>
+where
+    T: FromStr,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<T, E> {
+        v.parse()
+            .map_err(|_| E::invalid_value(::serde::de::Unexpected::Str(v), &self))
+    }
+}
+
+fn deserialize_from_str<'de, D, T>(deserializer: D, expecting: &'static str) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+{
+    deserializer.deserialize_str(FromStrVisitor {
+        expecting,
+        marker: PhantomData,
+    })
+}
+
+fn serialize_display<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Display,
+{
+    serializer.collect_str(value)
+}
+
+macro_rules! impl_serde_via_str {
+    ($($ty:ty => $expecting:literal,)*) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_display(self, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_from_str(deserializer, $expecting)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_via_str! {
+    Caepf => "a CAEPF",
+    Cei => "a CEI",
+    Cep => "a CEP",
+    ChaveAcesso => "an access key",
+    ChaveNfse => "an NFS-e access key",
+    Chassi => "a chassis number",
+    Cnes => "a CNES",
+    Cnpj => "a CNPJ",
+    Cno => "a CNO",
+    CodigoMunicipio => "a seven digit IBGE municipality code",
+    Competencia => "a competência as MM/YYYY or YYYY-MM",
+    Cpf => "a CPF",
+    Crea => "a CREA registration",
+    Crm => "a CRM registration",
+    Oab => "an OAB registration",
+    Pis => "a PIS",
+    Renavam => "a RENAVAM",
+    Rntrc => "an RNTRC",
+    Telefone => "a phone number",
+    TipoLogradouro => "a street type",
+    TituloEleitor => "a título de eleitor",
+    Uf => "the sigla of a federative unit",
+}
+
+/// [`Real`] read from a decimal string, "1234.56", or the Brazilian formats.
+struct DecimalReal(Real);
+
+impl FromStr for DecimalReal {
+    type Err = ParseRealError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix('-').unwrap_or(s);
+        let decimal = match digits.split_once('.') {
+            Some((reais, centavos)) => {
+                !reais.is_empty()
+                    && (1..=2).contains(&centavos.len())
+                    && reais
+                        .bytes()
+                        .chain(centavos.bytes())
+                        .all(|b| b.is_ascii_digit())
+            }
+            None => false,
+        };
+        if decimal {
+            s.replacen('.', ",", 1).parse().map(DecimalReal)
+        } else {
+            s.parse().map(DecimalReal)
+        }
+    }
+}
+
+impl Serialize for Real {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let centavos = self.centavos().unsigned_abs();
+        let sign = if self.is_negative() { "-" } else { "" };
+        serializer.collect_str(&format_args!(
+            "{}{}.{:02}",
+            sign,
+            centavos / 100,
+            centavos % 100
+        ))
+    }
+}
+
+impl<'de> Deserialize<'de> for Real {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_from_str(deserializer, "an amount in reais").map(|DecimalReal(real)| real)
+    }
+}
+
+/// A [`Cpf`] as its eleven digits, "01678346063". Deserializes from any accepted string.
+pub mod cpf_digits {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(cpf: &Cpf, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:011}", u64::from(*cpf)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Cpf, D::Error> {
+        deserialize_from_str(deserializer, "a CPF")
+    }
+}
+
+/// A [`Cpf`] as its formatted string, "016.783.460-63". Deserializes from any accepted
+/// string. This is also the default representation.
+pub mod cpf_formatted {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(cpf: &Cpf, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_display(cpf, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Cpf, D::Error> {
+        deserialize_from_str(deserializer, "a CPF")
+    }
+}
+
+/// A [`Cpf`] as an unsigned integer, 1678346063.
+pub mod cpf_u64 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(cpf: &Cpf, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(u64::from(*cpf))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Cpf, D::Error> {
+        let value = u64::deserialize(deserializer)?;
+        Cpf::try_from(value).map_err(|_| {
+            D::Error::invalid_value(::serde::de::Unexpected::Unsigned(value), &"a CPF")
+        })
+    }
+}
+
+/// A [`Real`] as its signed integer number of centavos, 123456 for R$ 1.234,56.
+pub mod real_centavos {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(real: &Real, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(real.centavos())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Real, D::Error> {
+        i64::deserialize(deserializer).map(Real::from_centavos)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip_through_json() {
+        let cpf: Cpf = serde_json::from_str(r#""984.844.854-39""#).unwrap();
+        assert_eq!(r#""984.844.854-39""#, serde_json::to_string(&cpf).unwrap());
+
+        let uf: Uf = serde_json::from_str(r#""sp""#).unwrap();
+        assert_eq!(r#""SP""#, serde_json::to_string(&uf).unwrap());
+
+        let competencia: Competencia = serde_json::from_str(r#""2024-06""#).unwrap();
+        assert_eq!(r#""06/2024""#, serde_json::to_string(&competencia).unwrap());
+
+        let codigo: CodigoMunicipio = serde_json::from_str(r#""3550308""#).unwrap();
+        assert_eq!(r#""3550308""#, serde_json::to_string(&codigo).unwrap());
+
+        let caepf: Caepf = serde_json::from_str(r#""98484485400167""#).unwrap();
+        assert_eq!(
+            r#""984.844.854/001-67""#,
+            serde_json::to_string(&caepf).unwrap()
+        );
+
+        let cei: Cei = serde_json::from_str(r#""512040987671""#).unwrap();
+        assert_eq!(r#""51.204.09876/71""#, serde_json::to_string(&cei).unwrap());
+
+        let chave: ChaveAcesso =
+            serde_json::from_str(r#""35240311222333000181550010000001231123456788""#).unwrap();
+        assert_eq!(
+            r#""35240311222333000181550010000001231123456788""#,
+            serde_json::to_string(&chave).unwrap()
+        );
+
+        let chave: ChaveNfse =
+            serde_json::from_str(r#""NFS35503082211222333000181000000000012324031234567892""#)
+                .unwrap();
+        assert_eq!(
+            r#""35503082211222333000181000000000012324031234567892""#,
+            serde_json::to_string(&chave).unwrap()
+        );
+
+        let chassi: Chassi = serde_json::from_str(r#""9bwzzz377vt004251""#).unwrap();
+        assert_eq!(
+            r#""9BWZZZ377VT004251""#,
+            serde_json::to_string(&chassi).unwrap()
+        );
+
+        let cnes: Cnes = serde_json::from_str(r#""27049""#).unwrap();
+        assert_eq!(r#""0027049""#, serde_json::to_string(&cnes).unwrap());
+
+        let cnpj: Cnpj = serde_json::from_str(r#""12abc34501de35""#).unwrap();
+        assert_eq!(
+            r#""12.ABC.345/01DE-35""#,
+            serde_json::to_string(&cnpj).unwrap()
+        );
+
+        let cno: Cno = serde_json::from_str(r#""512040987671""#).unwrap();
+        assert_eq!(r#""51.204.09876/71""#, serde_json::to_string(&cno).unwrap());
+
+        let oab: Oab = serde_json::from_str(r#""123456/SP""#).unwrap();
+        assert_eq!(r#""OAB/SP 123.456""#, serde_json::to_string(&oab).unwrap());
+
+        let telefone: Telefone = serde_json::from_str(r#""11987654321""#).unwrap();
+        assert_eq!(
+            r#""(11) 98765-4321""#,
+            serde_json::to_string(&telefone).unwrap()
+        );
+
+        let rntrc: Rntrc = serde_json::from_str(r#""etc-012345678""#).unwrap();
+        assert_eq!(r#""ETC 12345678""#, serde_json::to_string(&rntrc).unwrap());
+
+        let pis: Pis = serde_json::from_str(r#""12028384761""#).unwrap();
+        assert_eq!(r#""120.28384.76-1""#, serde_json::to_string(&pis).unwrap());
+
+        let renavam: Renavam = serde_json::from_str(r#""639087922""#).unwrap();
+        assert_eq!(r#""00639087922""#, serde_json::to_string(&renavam).unwrap());
+
+        let titulo: TituloEleitor = serde_json::from_str(r#""004356870906""#).unwrap();
+        assert_eq!(
+            r#""0043 5687 0906""#,
+            serde_json::to_string(&titulo).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        let err = serde_json::from_str::<Cpf>(r#""98484485401""#).unwrap_err();
+        assert!(err.to_string().contains("expected a CPF"));
+        assert!(serde_json::from_str::<Cpf>("98484485439").is_err());
+        assert!(serde_json::from_str::<Cep>(r#""00000-000""#).is_err());
+    }
+
+    #[test]
+    fn real_round_trips_as_a_decimal_string() {
+        let real: Real = serde_json::from_str(r#""1234.56""#).unwrap();
+        assert_eq!(Real::from_centavos(123456), real);
+        assert_eq!(r#""1234.56""#, serde_json::to_string(&real).unwrap());
+
+        let real: Real = serde_json::from_str(r#""R$ 1.234,5""#).unwrap();
+        assert_eq!(r#""1234.50""#, serde_json::to_string(&real).unwrap());
+
+        let real: Real = serde_json::from_str(r#""-0.05""#).unwrap();
+        assert_eq!(r#""-0.05""#, serde_json::to_string(&real).unwrap());
+
+        for real in [Real::MIN, Real::MAX, Real::from_reais(7)] {
+            let json = serde_json::to_string(&real).unwrap();
+            assert_eq!(real, serde_json::from_str(&json).unwrap(), "{}", json);
+        }
+
+        assert!(serde_json::from_str::<Real>(r#""1.234""#).is_err());
+        assert!(serde_json::from_str::<Real>(r#""1.234.56""#).is_err());
+        assert!(serde_json::from_str::<Real>(r#"".5""#).is_err());
+        assert!(serde_json::from_str::<Real>("1234.56").is_err());
+    }
+
+    #[test]
+    fn real_centavos_is_an_integer() {
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        struct Pagamento {
+            #[serde(with = "real_centavos")]
+            valor: Real,
+        }
+
+        let pagamento: Pagamento = serde_json::from_str(r#"{"valor":-123456}"#).unwrap();
+        assert_eq!(Real::from_centavos(-123456), pagamento.valor);
+        assert_eq!(
+            r#"{"valor":-123456}"#,
+            serde_json::to_string(&pagamento).unwrap()
+        );
+    }
+
+    #[test]
+    fn cpf_u64_rejects_invalid_numbers() {
+        let mut deserializer = serde_json::Deserializer::from_str("98484485401");
+        assert!(cpf_u64::deserialize(&mut deserializer).is_err());
+
+        let mut deserializer = serde_json::Deserializer::from_str("98484485439");
+        assert_eq!(
+            Cpf::from_str("98484485439").unwrap(),
+            cpf_u64::deserialize(&mut deserializer).unwrap()
+        );
+    }
+}
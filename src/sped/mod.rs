@@ -0,0 +1,354 @@
+//! EFD ICMS/IPI (SPED Fiscal) files: the pipe delimited records, checked against the
+//! block structure and the record counts the file declares about itself.
+//!
+//! # Examples
+//! ```
+//! use bras::sped::Efd;
+//! use bras::{Real, Uf};
+//!
+//! let arquivo = "\
+//! |0000|017|0|01012024|31012024|EMPRESA LTDA|11222333000181||SP|123456789012|3550308||A|0|
+//! |0001|0|
+//! |0990|3|
+//! |C001|0|
+//! |C100|1|0|CLI01|55|00|1|123|35240111222333000181550010000001231000001230|15012024|15012024|1000,00|
+//! |C990|3|
+//! |E001|0|
+//! |E110|180,00|0,00|0,00|0,00|50,00|0,00|0,00|0,00|0,00|130,00|0,00|130,00|0,00|0,00|
+//! |E990|3|
+//! |9001|0|
+//! |9900|0000|1|
+//! |9900|0001|1|
+//! |9900|0990|1|
+//! |9900|C001|1|
+//! |9900|C100|1|
+//! |9900|C990|1|
+//! |9900|E001|1|
+//! |9900|E110|1|
+//! |9900|E990|1|
+//! |9900|9001|1|
+//! |9900|9900|13|
+//! |9900|9990|1|
+//! |9900|9999|1|
+//! |9990|16|
+//! |9999|25|
+//! ";
+//! let efd = Efd::parse(arquivo)?;
+//! let abertura = efd.abertura()?;
+//! assert_eq!("EMPRESA LTDA", abertura.nome);
+//! assert_eq!(Some(bras::cnpj!("11.222.333/0001-81")), abertura.cnpj);
+//! assert_eq!(Uf::SP, abertura.uf);
+//!
+//! let notas = efd.documentos().collect::<Result<Vec<_>, _>>()?;
+//! assert_eq!(Some(Real::from_reais(1000)), notas[0].valor);
+//!
+//! let apuracao = efd.apuracoes_icms().next().unwrap()?;
+//! assert_eq!(Real::from_reais(130), apuracao.icms_a_recolher);
+//!
+//! # Ok::<(), bras::sped::ParseSpedError>(())
+//! ```
+
+mod registros;
+
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+
+pub use self::registros::{Abertura, ApuracaoIcms, DocumentoFiscal};
+
+/// Blocks in the order they must appear in the file.
+const BLOCOS: [char; 10] = ['0', 'B', 'C', 'D', 'E', 'G', 'H', 'K', '1', '9'];
+
+/// A record of the file, such as `|C100|0|1|...|`.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Registro<'a> {
+    linha: usize,
+    campos: Vec<&'a str>,
+}
+
+impl<'a> Registro<'a> {
+    /// Record code, the first field, such as "C100".
+    pub fn tipo(&self) -> &'a str {
+        self.campos[0]
+    }
+
+    /// Block the record belongs to, the first character of its code.
+    pub fn bloco(&self) -> char {
+        char::from(self.tipo().as_bytes()[0])
+    }
+
+    /// Field numbered as in the layout of the Guia Prático, where field 1 is the record
+    /// code.
+    pub fn campo(&self, numero: usize) -> Option<&'a str> {
+        self.campos.get(numero.checked_sub(1)?).copied()
+    }
+
+    /// One based line of the file.
+    pub fn linha(&self) -> usize {
+        self.linha
+    }
+
+    fn contagem(&self, numero: usize) -> Result<usize, ParseSpedError> {
+        self.campo(numero)
+            .filter(|campo| campo.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|campo| campo.parse().ok())
+            .ok_or(ParseSpedError::InvalidField {
+                line: self.linha,
+                field: numero,
+            })
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseSpedError {
+    /// The line is not a pipe delimited record with a four character code.
+    InvalidLine { line: usize },
+    /// The record is out of place: outside its block, or in a block out of order.
+    UnexpectedRecord { line: usize },
+    /// The file ends before the 9999 record.
+    Truncated,
+    /// A count declared in a 9900, X990 or 9999 record does not match the file.
+    CountMismatch {
+        line: usize,
+        declared: usize,
+        found: usize,
+    },
+    /// The field, numbered as in the layout, is missing or invalid.
+    InvalidField { line: usize, field: usize },
+}
+
+impl Display for ParseSpedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseSpedError::InvalidLine { line } => {
+                write!(f, "line {} is not a valid record", line)
+            }
+            ParseSpedError::UnexpectedRecord { line } => {
+                write!(f, "record out of place at line {}", line)
+            }
+            ParseSpedError::Truncated => f.write_str("file ends before the 9999 record"),
+            ParseSpedError::CountMismatch {
+                line,
+                declared,
+                found,
+            } => write!(
+                f,
+                "line {} declares {} lines or records, but there are {}",
+                line, declared, found
+            ),
+            ParseSpedError::InvalidField { line, field } => {
+                write!(f, "invalid field {:02} at line {}", field, line)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseSpedError {}
+
+/// A parsed and checked EFD ICMS/IPI file, borrowing from its text.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Efd<'a> {
+    registros: Vec<Registro<'a>>,
+}
+
+impl<'a> Efd<'a> {
+    /// Splits the records of `texto` and checks the structure of the file. Anything after
+    /// the 9999 record, such as the digital signature, is ignored.
+    pub fn parse(texto: &'a str) -> Result<Self, ParseSpedError> {
+        let mut registros = Vec::new();
+        for (index, linha) in texto.lines().enumerate() {
+            let registro = registro(index + 1, linha)?;
+            let fim = registro.tipo() == "9999";
+            registros.push(registro);
+            if fim {
+                let efd = Efd { registros };
+                efd.validar()?;
+                return Ok(efd);
+            }
+        }
+        Err(ParseSpedError::Truncated)
+    }
+
+    pub fn registros(&self) -> &[Registro<'a>] {
+        &self.registros
+    }
+
+    /// Records with the code `tipo`, in the order they appear.
+    pub fn do_tipo<'s>(&'s self, tipo: &'s str) -> impl Iterator<Item = &'s Registro<'a>> {
+        self.registros.iter().filter(move |r| r.tipo() == tipo)
+    }
+
+    /// The 0000 record, identifying the taxpayer and the period.
+    pub fn abertura(&self) -> Result<Abertura<'a>, ParseSpedError> {
+        Abertura::try_from(&self.registros[0])
+    }
+
+    /// The C100 records, one per NF-e and other model 01, 1B, 04 and 55 invoices.
+    pub fn documentos(
+        &self,
+    ) -> impl Iterator<Item = Result<DocumentoFiscal<'a>, ParseSpedError>> + '_ {
+        self.do_tipo("C100").map(DocumentoFiscal::try_from)
+    }
+
+    /// The E110 records, with the ICMS owed in each period.
+    pub fn apuracoes_icms(
+        &self,
+    ) -> impl Iterator<Item = Result<ApuracaoIcms, ParseSpedError>> + '_ {
+        self.do_tipo("E110").map(ApuracaoIcms::try_from)
+    }
+
+    fn validar(&self) -> Result<(), ParseSpedError> {
+        if self.registros[0].tipo() != "0000" {
+            return Err(ParseSpedError::UnexpectedRecord { line: 1 });
+        }
+
+        let mut proximo_bloco = 0;
+        let mut aberto: Option<(char, usize)> = None;
+        for (index, registro) in self.registros.iter().enumerate().skip(1) {
+            let unexpected = ParseSpedError::UnexpectedRecord {
+                line: registro.linha,
+            };
+            let codigo = &registro.tipo()[1..];
+            match aberto {
+                None if codigo == "001" => {
+                    let bloco = BLOCOS[proximo_bloco..]
+                        .iter()
+                        .position(|&bloco| bloco == registro.bloco())
+                        .ok_or(unexpected)?;
+                    proximo_bloco += bloco + 1;
+                    aberto = Some((registro.bloco(), index));
+                }
+                Some((bloco, inicio)) if bloco == registro.bloco() => {
+                    if codigo == "990" {
+                        // Block 0 counts the 0000 record as well, and block 9 the 9999.
+                        let linhas = match bloco {
+                            '0' => index + 1,
+                            '9' => index - inicio + 2,
+                            _ => index - inicio + 1,
+                        };
+                        verificar(registro, 2, linhas)?;
+                        aberto = None;
+                    }
+                }
+                _ if registro.tipo() == "9999" && aberto.is_none() => {}
+                _ => return Err(unexpected),
+            }
+        }
+        if aberto.is_some() || proximo_bloco != BLOCOS.len() {
+            return Err(ParseSpedError::Truncated);
+        }
+
+        for registro in self.do_tipo("9900") {
+            let tipo = registro.campo(2).ok_or(ParseSpedError::InvalidField {
+                line: registro.linha,
+                field: 2,
+            })?;
+            verificar(registro, 3, self.do_tipo(tipo).count())?;
+        }
+        let ultimo = &self.registros[self.registros.len() - 1];
+        verificar(ultimo, 2, self.registros.len())
+    }
+}
+
+fn registro(linha: usize, texto: &str) -> Result<Registro<'_>, ParseSpedError> {
+    let campos: Vec<&str> = texto
+        .strip_prefix('|')
+        .and_then(|texto| texto.strip_suffix('|'))
+        .ok_or(ParseSpedError::InvalidLine { line: linha })?
+        .split('|')
+        .collect();
+    let tipo = campos[0];
+    if tipo.len() != 4 || !tipo.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return Err(ParseSpedError::InvalidLine { line: linha });
+    }
+    Ok(Registro { linha, campos })
+}
+
+/// Checks the count declared in field `numero` of `registro`.
+fn verificar(registro: &Registro, numero: usize, found: usize) -> Result<(), ParseSpedError> {
+    let declared = registro.contagem(numero)?;
+    if declared != found {
+        return Err(ParseSpedError::CountMismatch {
+            line: registro.linha,
+            declared,
+            found,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::{String, ToString};
+
+    const MINIMO: &str = "\
+|0000|017|0|01012024|31012024|FULANO DE TAL||98484485439|SP|ISENTO|3550308|||B|1|
+|0001|1|
+|0990|3|
+|9001|0|
+|9900|0000|1|
+|9900|0001|1|
+|9900|0990|1|
+|9900|9001|1|
+|9900|9900|7|
+|9900|9990|1|
+|9900|9999|1|
+|9990|10|
+|9999|13|
+";
+
+    #[test]
+    fn check_counts() {
+        let efd = Efd::parse(MINIMO).unwrap();
+        assert_eq!(13, efd.registros().len());
+        assert_eq!(Some("ISENTO"), efd.registros()[0].campo(10));
+        assert_eq!(
+            "984.844.854-39",
+            efd.abertura().unwrap().cpf.unwrap().to_string()
+        );
+        let errado = MINIMO.replace("|FULANO DE TAL||", "|FULANO DE TAL|11222333000180|");
+        assert_eq!(
+            Err(ParseSpedError::InvalidField { line: 1, field: 7 }),
+            Efd::parse(&errado).unwrap().abertura()
+        );
+
+        let errado = MINIMO.replace("|9999|13|", "|9999|12|");
+        assert_eq!(
+            Err(ParseSpedError::CountMismatch {
+                line: 13,
+                declared: 12,
+                found: 13
+            }),
+            Efd::parse(&errado)
+        );
+        let errado = MINIMO.replace("|9900|0990|1|", "|9900|0990|2|");
+        assert!(matches!(
+            Efd::parse(&errado),
+            Err(ParseSpedError::CountMismatch { line: 7, .. })
+        ));
+        let errado = MINIMO.replace("|0990|3|", "|0990|2|");
+        assert!(matches!(
+            Efd::parse(&errado),
+            Err(ParseSpedError::CountMismatch { line: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn check_structure() {
+        let sem_fim: String = MINIMO.lines().take(12).flat_map(|l| [l, "\n"]).collect();
+        assert_eq!(Err(ParseSpedError::Truncated), Efd::parse(&sem_fim));
+        assert_eq!(
+            Err(ParseSpedError::InvalidLine { line: 2 }),
+            Efd::parse(&MINIMO.replace("|0001|1|", "0001|1|"))
+        );
+        assert_eq!(
+            Err(ParseSpedError::UnexpectedRecord { line: 3 }),
+            Efd::parse(&MINIMO.replace("|0990|3|", "|C990|3|"))
+        );
+
+        let assinado = alloc::format!("{}SBRCAAEPDR...\n", MINIMO);
+        assert!(Efd::parse(&assinado).is_ok());
+    }
+}
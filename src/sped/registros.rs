@@ -0,0 +1,166 @@
+//! Typed views of the records most integrations read.
+
+use chrono::NaiveDate;
+
+use super::{ParseSpedError, Registro};
+use crate::data_br::parse_compacta;
+use crate::{Cnpj, CodigoMunicipio, Cpf, Real, Uf};
+
+/// Record 0000, opening the file.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Abertura<'a> {
+    /// Layout version, such as "017".
+    pub versao: &'a str,
+    /// Whether the file replaces one sent before for the same period.
+    pub retificadora: bool,
+    pub inicio: NaiveDate,
+    pub fim: NaiveDate,
+    pub nome: &'a str,
+    /// When a company.
+    pub cnpj: Option<Cnpj>,
+    /// When a person, such as a rural producer.
+    pub cpf: Option<Cpf>,
+    pub uf: Uf,
+    pub inscricao_estadual: &'a str,
+    pub municipio: CodigoMunicipio,
+    /// Profile "A", "B" or "C" assigned by the state.
+    pub perfil: &'a str,
+}
+
+/// Record C100, an NF-e or other invoice of models 01, 1B, 04 and 55.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DocumentoFiscal<'a> {
+    /// Whether the taxpayer sold (`true`) or bought.
+    pub saida: bool,
+    /// Whether the taxpayer issued the invoice.
+    pub emissao_propria: bool,
+    /// Code of the other party, detailed in the 0150 records.
+    pub participante: &'a str,
+    pub modelo: &'a str,
+    /// Status code, "00" for a regular document.
+    pub situacao: &'a str,
+    pub serie: &'a str,
+    pub numero: &'a str,
+    /// The 44 digit access key of an NF-e.
+    pub chave: Option<&'a str>,
+    pub emissao: Option<NaiveDate>,
+    pub entrada_saida: Option<NaiveDate>,
+    /// Missing for cancelled and denied documents.
+    pub valor: Option<Real>,
+}
+
+/// Record E110, the ICMS computed for the period.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ApuracaoIcms {
+    pub total_debitos: Real,
+    pub total_creditos: Real,
+    pub saldo_apurado: Real,
+    pub icms_a_recolher: Real,
+    pub saldo_credor_a_transportar: Real,
+}
+
+impl<'a> TryFrom<&Registro<'a>> for Abertura<'a> {
+    type Error = ParseSpedError;
+
+    fn try_from(registro: &Registro<'a>) -> Result<Self, Self::Error> {
+        Ok(Abertura {
+            versao: texto(registro, 2)?,
+            retificadora: flag(registro, 3)?,
+            inicio: data(registro, 4)?.ok_or(invalid(registro, 4))?,
+            fim: data(registro, 5)?.ok_or(invalid(registro, 5))?,
+            nome: texto(registro, 6)?,
+            cnpj: opcional(registro, 7)?
+                .map(|cnpj| cnpj.parse().map_err(|_| invalid(registro, 7)))
+                .transpose()?,
+            cpf: opcional(registro, 8)?
+                .map(|cpf| cpf.parse().map_err(|_| invalid(registro, 8)))
+                .transpose()?,
+            uf: texto(registro, 9)?
+                .parse()
+                .map_err(|_| invalid(registro, 9))?,
+            inscricao_estadual: texto(registro, 10)?,
+            municipio: texto(registro, 11)?
+                .parse()
+                .map_err(|_| invalid(registro, 11))?,
+            perfil: texto(registro, 14)?,
+        })
+    }
+}
+
+impl<'a> TryFrom<&Registro<'a>> for DocumentoFiscal<'a> {
+    type Error = ParseSpedError;
+
+    fn try_from(registro: &Registro<'a>) -> Result<Self, Self::Error> {
+        Ok(DocumentoFiscal {
+            saida: flag(registro, 2)?,
+            emissao_propria: !flag(registro, 3)?,
+            participante: texto(registro, 4)?,
+            modelo: texto(registro, 5)?,
+            situacao: texto(registro, 6)?,
+            serie: texto(registro, 7)?,
+            numero: texto(registro, 8)?,
+            chave: opcional(registro, 9)?,
+            emissao: data(registro, 10)?,
+            entrada_saida: data(registro, 11)?,
+            valor: valor(registro, 12)?,
+        })
+    }
+}
+
+impl TryFrom<&Registro<'_>> for ApuracaoIcms {
+    type Error = ParseSpedError;
+
+    fn try_from(registro: &Registro<'_>) -> Result<Self, Self::Error> {
+        let obrigatorio = |numero| valor(registro, numero)?.ok_or(invalid(registro, numero));
+        Ok(ApuracaoIcms {
+            total_debitos: obrigatorio(2)?,
+            total_creditos: obrigatorio(6)?,
+            saldo_apurado: obrigatorio(11)?,
+            icms_a_recolher: obrigatorio(13)?,
+            saldo_credor_a_transportar: obrigatorio(14)?,
+        })
+    }
+}
+
+fn invalid(registro: &Registro, numero: usize) -> ParseSpedError {
+    ParseSpedError::InvalidField {
+        line: registro.linha,
+        field: numero,
+    }
+}
+
+fn texto<'a>(registro: &Registro<'a>, numero: usize) -> Result<&'a str, ParseSpedError> {
+    registro.campo(numero).ok_or(invalid(registro, numero))
+}
+
+fn opcional<'a>(registro: &Registro<'a>, numero: usize) -> Result<Option<&'a str>, ParseSpedError> {
+    texto(registro, numero).map(|campo| Some(campo).filter(|campo| !campo.is_empty()))
+}
+
+/// "0" or "1".
+fn flag(registro: &Registro, numero: usize) -> Result<bool, ParseSpedError> {
+    match texto(registro, numero)? {
+        "0" => Ok(false),
+        "1" => Ok(true),
+        _ => Err(invalid(registro, numero)),
+    }
+}
+
+/// "ddmmaaaa", when not empty.
+fn data(registro: &Registro, numero: usize) -> Result<Option<NaiveDate>, ParseSpedError> {
+    opcional(registro, numero)?
+        .map(|campo| parse_compacta(campo).map_err(|_| invalid(registro, numero)))
+        .transpose()
+}
+
+/// "1234,56", without thousands separators, when not empty.
+fn valor(registro: &Registro, numero: usize) -> Result<Option<Real>, ParseSpedError> {
+    opcional(registro, numero)?
+        .map(|campo| {
+            if campo.contains('.') {
+                return Err(invalid(registro, numero));
+            }
+            campo.parse().map_err(|_| invalid(registro, numero))
+        })
+        .transpose()
+}
@@ -0,0 +1,409 @@
+//! proptest strategies for malformed input, to exercise the error paths of the parsers.
+//!
+//! Valid values come from the [`proptest::arbitrary::Arbitrary`] implementations, e.g.
+//! `any::<Cpf>()`. The strategies here produce strings that must be rejected, and shrink
+//! towards the smallest failing input like any other proptest strategy.
+//!
+//! # Examples
+//! ```
+//! use bras::strategy::invalid_cpf;
+//! use bras::Cpf;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(s in invalid_cpf())| {
+//!     prop_assert!(s.parse::<Cpf>().is_err());
+//! });
+//! ```
+
+use proptest::prelude::*;
+
+use crate::{
+    Caepf, Cep, Chassi, ChaveAcesso, ChaveNfse, Cnes, Cno, Cnpj, CodigoMunicipio, Cpf, Pis, Real,
+    Renavam, Rntrc, TituloEleitor, Uf,
+};
+
+fn digits(len: core::ops::Range<usize>) -> impl Strategy<Value = String> {
+    proptest::collection::vec(0u32..10, len)
+        .prop_map(|digits| digits.iter().map(|d| d.to_string()).collect())
+}
+
+/// Strings that are not valid CPFs: wrong verifier digits, repeated digits, wrong
+/// lengths and misplaced punctuation.
+pub fn invalid_cpf() -> impl Strategy<Value = String> {
+    let wrong_verifier = (any::<Cpf>(), 1u64..10).prop_map(|(cpf, delta)| {
+        let value = u64::from(cpf);
+        format!("{:011}", value - value % 10 + (value % 10 + delta) % 10)
+    });
+    let repeated = (0u32..10).prop_map(|d| d.to_string().repeat(11));
+    let wrong_length = digits(0..20).prop_filter("11 digits", |s| s.len() != 11);
+    let punctuation = (
+        any::<Cpf>(),
+        proptest::array::uniform3(prop_oneof![Just('.'), Just('-'), Just('/'), Just(' ')]),
+    )
+        .prop_filter("canonical separators", |(_, separators)| {
+            separators != &['.', '.', '-']
+        })
+        .prop_map(|(cpf, [a, b, c])| {
+            let s = format!("{:011}", u64::from(cpf));
+            format!("{}{a}{}{b}{}{c}{}", &s[..3], &s[3..6], &s[6..9], &s[9..])
+        });
+
+    prop_oneof![wrong_verifier, repeated, wrong_length, punctuation]
+}
+
+/// Strings that are not valid CEPs: wrong lengths, the all-zero CEP and misplaced hyphens.
+pub fn invalid_cep() -> impl Strategy<Value = String> {
+    let wrong_length = digits(0..12).prop_filter("8 digits", |s| s.len() != 8);
+    let zero = Just("00000-000".to_string());
+    let hyphen = (any::<Cep>(), 0usize..8)
+        .prop_filter("canonical hyphen", |(_, at)| *at != 5)
+        .prop_map(|(cep, at)| {
+            let s = cep.to_string().replace('-', "");
+            format!("{}-{}", &s[..at], &s[at..])
+        });
+
+    prop_oneof![wrong_length, zero, hyphen]
+}
+
+/// `value` with its last digit replaced by another, as `len` digits.
+fn wrong_last_digit(value: u64, delta: u64, len: usize) -> String {
+    format!("{:0len$}", value - value % 10 + (value % 10 + delta) % 10)
+}
+
+/// Strings that are not valid IBGE municipality codes: wrong check digits, unknown UFs
+/// and wrong lengths.
+pub fn invalid_codigo_municipio() -> impl Strategy<Value = String> {
+    let wrong_verifier = (any::<CodigoMunicipio>(), 1u64..10)
+        .prop_map(|(codigo, delta)| wrong_last_digit(u32::from(codigo).into(), delta, 7))
+        .prop_filter("check digit exception", |s| {
+            !CodigoMunicipio::is_check_digit_exception(s.parse().unwrap())
+        });
+    let unknown_uf = (10u8..100, 0u32..100_000)
+        .prop_filter("known UF", |(uf, _)| Uf::try_from(*uf).is_err())
+        .prop_map(|(uf, rest)| format!("{uf}{rest:05}"));
+    let wrong_length = digits(0..10).prop_filter("7 digits", |s| s.len() != 7);
+
+    prop_oneof![wrong_verifier, unknown_uf, wrong_length]
+}
+
+/// Strings that are neither valid CNOs nor valid CEIs, which share the check digit: wrong
+/// check digits, wrong lengths and misplaced punctuation.
+pub fn invalid_cno() -> impl Strategy<Value = String> {
+    let wrong_check_digit = (any::<Cno>(), 1u64..10)
+        .prop_map(|(cno, delta)| wrong_last_digit(u64::from(cno), delta, 12));
+    let wrong_length = digits(0..16).prop_filter("12 digits", |s| s.len() != 12);
+    let punctuation = any::<Cno>().prop_map(|cno| cno.to_string().replace('/', "-"));
+
+    prop_oneof![wrong_check_digit, wrong_length, punctuation]
+}
+
+/// Strings that are not valid CAEPFs: wrong check digits and wrong lengths.
+pub fn invalid_caepf() -> impl Strategy<Value = String> {
+    let wrong_check_digits = (any::<Caepf>(), 1u64..10)
+        .prop_map(|(caepf, delta)| wrong_last_digit(u64::from(caepf), delta, 14));
+    let wrong_length = digits(0..18).prop_filter("14 digits", |s| s.len() != 14);
+
+    prop_oneof![wrong_check_digits, wrong_length]
+}
+
+/// Strings that are not valid CNES: zero, more than 7 digits and other characters.
+pub fn invalid_cnes() -> impl Strategy<Value = String> {
+    let zero = (1usize..8).prop_map(|len| "0".repeat(len));
+    let too_long = digits(8..12);
+    let character = (any::<Cnes>(), prop_oneof![Just(" "), Just("-"), Just("x")])
+        .prop_map(|(cnes, c)| format!("{}{c}", u32::from(cnes) / 10));
+
+    prop_oneof![zero, too_long, character]
+}
+
+/// Strings that are not valid CNPJs: wrong check digits, letters in the check digits,
+/// wrong lengths and misplaced punctuation.
+pub fn invalid_cnpj() -> impl Strategy<Value = String> {
+    let wrong_check_digit = (any::<Cnpj>(), 1u8..10).prop_map(|(cnpj, delta)| {
+        let mut s = format!("{cnpj:#}");
+        let digit = (s.as_bytes()[13] - b'0' + delta) % 10;
+        s.replace_range(13.., &digit.to_string());
+        s
+    });
+    let letter = (any::<Cnpj>(), b'A'..=b'Z').prop_map(|(cnpj, letter)| {
+        let mut s = format!("{cnpj:#}");
+        s.replace_range(13.., &char::from(letter).to_string());
+        s
+    });
+    let wrong_length =
+        digits(0..20).prop_filter("14 or 18 characters", |s| s.len() != 14 && s.len() != 18);
+    let punctuation = any::<Cnpj>().prop_map(|cnpj| cnpj.to_string().replace('/', "-"));
+
+    prop_oneof![wrong_check_digit, letter, wrong_length, punctuation]
+}
+
+/// Strings that are not valid chassis numbers: the letters I, O and Q, unknown model year
+/// codes and wrong lengths.
+pub fn invalid_chassi() -> impl Strategy<Value = String> {
+    let replace = |chassi: Chassi, at: usize, c: char| {
+        let mut s = chassi.to_string();
+        s.replace_range(at..at + 1, c.encode_utf8(&mut [0; 4]));
+        s
+    };
+    let forbidden_letter = (
+        any::<Chassi>(),
+        0usize..17,
+        prop_oneof![Just('I'), Just('O'), Just('Q')],
+    )
+        .prop_map(move |(chassi, at, c)| replace(chassi, at, c));
+    let unknown_year = (
+        any::<Chassi>(),
+        prop_oneof![Just('0'), Just('U'), Just('Z')],
+    )
+        .prop_map(move |(chassi, c)| replace(chassi, 9, c));
+    let wrong_length = (any::<Chassi>(), 0usize..17)
+        .prop_map(|(chassi, len)| chassi.to_string()[..len].to_string());
+
+    prop_oneof![forbidden_letter, unknown_year, wrong_length]
+}
+
+/// Strings that are not valid RNTRCs: zero, wrong lengths and unknown categories.
+pub fn invalid_rntrc() -> impl Strategy<Value = String> {
+    let zero = Just("00000000".to_string());
+    let wrong_length = digits(0..12).prop_filter("8 digits or 9 with a leading zero", |s| {
+        s.len() != 8 && !(s.len() == 9 && s.starts_with('0'))
+    });
+    let unknown_category = any::<Rntrc>().prop_map(|rntrc| format!("XYZ {:08}", rntrc.numero()));
+
+    prop_oneof![zero, wrong_length, unknown_category]
+}
+
+/// Strings that are not valid RENAVAMs: wrong check digits, zero and wrong lengths.
+pub fn invalid_renavam() -> impl Strategy<Value = String> {
+    let wrong_check_digit = (any::<Renavam>(), 1u64..10)
+        .prop_map(|(renavam, delta)| wrong_last_digit(u64::from(renavam), delta, 11));
+    let zero = Just("00000000000".to_string());
+    let wrong_length =
+        digits(0..14).prop_filter("9 or 11 digits", |s| s.len() != 9 && s.len() != 11);
+
+    prop_oneof![wrong_check_digit, zero, wrong_length]
+}
+
+/// Strings that are not valid PIS: wrong check digits, repeated digits, wrong lengths and
+/// misplaced punctuation.
+pub fn invalid_pis() -> impl Strategy<Value = String> {
+    let wrong_check_digit = (any::<Pis>(), 1u64..10)
+        .prop_map(|(pis, delta)| wrong_last_digit(u64::from(pis), delta, 11));
+    let repeated = (0u32..10).prop_map(|d| d.to_string().repeat(11));
+    let wrong_length = digits(0..16).prop_filter("11 digits", |s| s.len() != 11);
+    let punctuation = any::<Pis>().prop_map(|pis| pis.to_string().replace('-', "."));
+
+    prop_oneof![wrong_check_digit, repeated, wrong_length, punctuation]
+}
+
+/// Strings that are not valid títulos de eleitor: wrong check digits, unknown federative
+/// unit codes and wrong lengths.
+pub fn invalid_titulo_eleitor() -> impl Strategy<Value = String> {
+    let wrong_check_digit = (any::<TituloEleitor>(), 1u64..10)
+        .prop_map(|(titulo, delta)| wrong_last_digit(u64::from(titulo), delta, 12));
+    let unknown_uf =
+        (any::<TituloEleitor>(), prop_oneof![Just(0u64), 29u64..100]).prop_map(|(titulo, uf)| {
+            let value = u64::from(titulo);
+            format!("{:012}", value / 10_000 * 10_000 + uf * 100 + value % 100)
+        });
+    let wrong_length =
+        digits(0..16).prop_filter("12 or 14 characters", |s| s.len() != 12 && s.len() != 14);
+
+    prop_oneof![wrong_check_digit, unknown_uf, wrong_length]
+}
+
+/// Strings that are not valid NF-e access keys: wrong check digits and wrong lengths.
+pub fn invalid_chave_acesso() -> impl Strategy<Value = String> {
+    let wrong_check_digit = (any::<ChaveAcesso>(), 1u8..10).prop_map(|(chave, delta)| {
+        let mut s = chave.to_string();
+        let digit = (s.as_bytes()[43] - b'0' + delta) % 10;
+        s.replace_range(43.., &digit.to_string());
+        s
+    });
+    let wrong_length = digits(0..50).prop_filter("44 digits", |s| s.len() != 44);
+
+    prop_oneof![wrong_check_digit, wrong_length]
+}
+
+/// Strings that are not valid NFS-e access keys: wrong check digits, wrong prefixes and
+/// wrong lengths.
+pub fn invalid_chave_nfse() -> impl Strategy<Value = String> {
+    let wrong_check_digit = (any::<ChaveNfse>(), 1u8..10).prop_map(|(chave, delta)| {
+        let mut s = chave.to_string();
+        let digit = (s.as_bytes()[49] - b'0' + delta) % 10;
+        s.replace_range(49.., &digit.to_string());
+        s
+    });
+    let wrong_prefix = any::<ChaveNfse>().prop_map(|chave| format!("NFE{chave}"));
+    let wrong_length = digits(0..56).prop_filter("50 digits", |s| s.len() != 50);
+
+    prop_oneof![wrong_check_digit, wrong_prefix, wrong_length]
+}
+
+/// Strings that are not valid OAB, CRM or CREA registrations: unknown and missing UFs and
+/// a zero number.
+pub fn invalid_registro_profissional() -> impl Strategy<Value = String> {
+    let unknown_uf = (b'A'..=b'Z', b'A'..=b'Z', 1u32..100_000)
+        .prop_filter("known UF", |(a, b, _)| {
+            format!("{}{}", *a as char, *b as char)
+                .parse::<Uf>()
+                .is_err()
+        })
+        .prop_map(|(a, b, numero)| format!("{}{} {numero}", a as char, b as char));
+    let missing_uf = (1u32..100_000).prop_map(|numero| numero.to_string());
+    let zero = proptest::sample::select(Uf::ALL.to_vec()).prop_map(|uf| format!("{uf} 0"));
+
+    prop_oneof![unknown_uf, missing_uf, zero]
+}
+
+/// Strings that are not valid competências: months out of range.
+pub fn invalid_competencia() -> impl Strategy<Value = String> {
+    (0u32..100, 0i32..10_000)
+        .prop_filter("valid month", |(mes, _)| !(1..=12).contains(mes))
+        .prop_map(|(mes, ano)| format!("{mes:02}/{ano:04}"))
+}
+
+/// Strings that are not valid amounts: more than two decimal places, repeated signs and
+/// letters.
+pub fn invalid_real() -> impl Strategy<Value = String> {
+    let decimal_places =
+        (0u64..1_000_000, 100u32..1000).prop_map(|(reais, centavos)| format!("{reais},{centavos}"));
+    let signs = any::<Real>().prop_map(|real| format!("--{}", real.centavos().unsigned_abs()));
+    let letters = (
+        any::<Real>(),
+        prop_oneof![Just("a"), Just("BRL"), Just("x")],
+    )
+        .prop_map(|(real, letters)| format!("{real}{letters}"));
+
+    prop_oneof![decimal_places, signs, letters]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Cei, Competencia, Crea, Crm, Oab};
+
+    proptest! {
+        #[test]
+        fn arbitrary_values_round_trip(cpf in any::<Cpf>(), cep in any::<Cep>()) {
+            prop_assert_eq!(Ok(cpf), cpf.to_string().parse());
+            prop_assert_eq!(Ok(cep), cep.to_string().parse());
+        }
+
+        #[test]
+        fn invalid_inputs_are_rejected(cpf in invalid_cpf(), cep in invalid_cep()) {
+            prop_assert!(cpf.parse::<Cpf>().is_err());
+            prop_assert!(cep.parse::<Cep>().is_err());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_later_types_round_trip(
+            codigo in any::<CodigoMunicipio>(),
+            real in any::<Real>(),
+            competencia in any::<Competencia>(),
+            caepf in any::<Caepf>(),
+            cei in any::<Cei>(),
+            cno in any::<Cno>(),
+            cnes in any::<Cnes>(),
+            cnpj in any::<Cnpj>(),
+            chassi in any::<Chassi>(),
+            rntrc in any::<Rntrc>(),
+            renavam in any::<Renavam>(),
+        ) {
+            prop_assert_eq!(Ok(codigo), codigo.to_string().parse());
+            prop_assert_eq!(Ok(real), real.to_string().parse());
+            prop_assert_eq!(Ok(competencia), competencia.to_string().parse());
+            prop_assert_eq!(Ok(caepf), caepf.to_string().parse());
+            prop_assert_eq!(Ok(cei), cei.to_string().parse());
+            prop_assert_eq!(Ok(cno), cno.to_string().parse());
+            prop_assert_eq!(Ok(cnes), cnes.to_string().parse());
+            prop_assert_eq!(Ok(cnpj), cnpj.to_string().parse());
+            prop_assert_eq!(Ok(cnpj), Cnpj::try_from(u128::from(cnpj)));
+            prop_assert_eq!(Ok(chassi), chassi.to_string().parse());
+            prop_assert_eq!(Ok(rntrc), rntrc.to_string().parse());
+            prop_assert_eq!(Ok(renavam), renavam.to_string().parse());
+        }
+
+        #[test]
+        fn arbitrary_keys_and_registrations_round_trip(
+            chave_acesso in any::<ChaveAcesso>(),
+            chave_nfse in any::<ChaveNfse>(),
+            oab in any::<Oab>(),
+            crm in any::<Crm>(),
+            crea in any::<Crea>(),
+            pis in any::<Pis>(),
+            titulo in any::<TituloEleitor>(),
+        ) {
+            prop_assert_eq!(Ok(chave_acesso), chave_acesso.to_string().parse());
+            prop_assert_eq!(Ok(chave_nfse), chave_nfse.to_string().parse());
+            prop_assert_eq!(Ok(oab), oab.to_string().parse());
+            prop_assert_eq!(Ok(crm), crm.to_string().parse());
+            prop_assert_eq!(Ok(crea), crea.to_string().parse());
+            prop_assert_eq!(Ok(pis), pis.to_string().parse());
+            prop_assert_eq!(Ok(titulo), titulo.to_string().parse());
+        }
+
+        #[test]
+        fn invalid_later_inputs_are_rejected(
+            codigo in invalid_codigo_municipio(),
+            cno in invalid_cno(),
+            caepf in invalid_caepf(),
+            cnes in invalid_cnes(),
+            cnpj in invalid_cnpj(),
+            chassi in invalid_chassi(),
+            rntrc in invalid_rntrc(),
+            renavam in invalid_renavam(),
+        ) {
+            prop_assert!(codigo.parse::<CodigoMunicipio>().is_err());
+            prop_assert!(cno.parse::<Cno>().is_err());
+            prop_assert!(cno.parse::<Cei>().is_err());
+            prop_assert!(caepf.parse::<Caepf>().is_err());
+            prop_assert!(cnes.parse::<Cnes>().is_err());
+            prop_assert!(cnpj.parse::<Cnpj>().is_err());
+            prop_assert!(chassi.parse::<Chassi>().is_err());
+            prop_assert!(rntrc.parse::<Rntrc>().is_err());
+            prop_assert!(renavam.parse::<Renavam>().is_err());
+        }
+
+        #[test]
+        fn invalid_keys_and_values_are_rejected(
+            chave_acesso in invalid_chave_acesso(),
+            chave_nfse in invalid_chave_nfse(),
+            registro in invalid_registro_profissional(),
+            competencia in invalid_competencia(),
+            real in invalid_real(),
+            pis in invalid_pis(),
+            titulo in invalid_titulo_eleitor(),
+        ) {
+            prop_assert!(chave_acesso.parse::<ChaveAcesso>().is_err());
+            prop_assert!(chave_nfse.parse::<ChaveNfse>().is_err());
+            prop_assert!(registro.parse::<Oab>().is_err());
+            prop_assert!(registro.parse::<Crm>().is_err());
+            prop_assert!(registro.parse::<Crea>().is_err());
+            prop_assert!(competencia.parse::<Competencia>().is_err());
+            prop_assert!(real.parse::<Real>().is_err());
+            prop_assert!(pis.parse::<Pis>().is_err());
+            prop_assert!(titulo.parse::<TituloEleitor>().is_err());
+        }
+    }
+
+    #[test]
+    fn arbitrary_from_unstructured() {
+        let data = [7u8; 64];
+        let mut u = arbitrary::Unstructured::new(&data);
+        let cpf: Cpf = u.arbitrary().unwrap();
+        assert_eq!(Ok(cpf), cpf.to_string().parse());
+        let cep: Cep = u.arbitrary().unwrap();
+        assert_eq!(Ok(cep), cep.to_string().parse());
+        let codigo: CodigoMunicipio = u.arbitrary().unwrap();
+        assert_eq!(Ok(codigo), codigo.to_string().parse());
+        let chave: ChaveNfse = u.arbitrary().unwrap();
+        assert_eq!(Ok(chave), chave.to_string().parse());
+        let oab: Oab = u.arbitrary().unwrap();
+        assert_eq!(Ok(oab), oab.to_string().parse());
+        let cnpj: Cnpj = u.arbitrary().unwrap();
+        assert_eq!(Ok(cnpj), cnpj.to_string().parse());
+    }
+}
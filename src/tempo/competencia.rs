@@ -0,0 +1,283 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+#[cfg(feature = "dates")]
+use chrono::{Datelike, NaiveDate};
+
+/// Month of reference (competência) used to index payroll and fiscal records, from 01/0000
+/// to 12/9999, the years that fit the four digits of its text formats.
+///
+/// # Examples
+/// ```
+/// use bras::Competencia;
+/// # use bras::ParseCompetenciaError;
+///
+/// let competencia: Competencia = "03/2024".parse()?;
+/// assert_eq!(competencia, "2024-03".parse()?);
+/// assert_eq!((2024, 3), (competencia.ano(), competencia.mes()));
+/// assert_eq!("03/2024", competencia.to_string());
+///
+/// assert_eq!("04/2024", competencia.next().to_string());
+/// assert_eq!("12/2023", Competencia::new(2024, 1)?.prev().to_string());
+/// assert!(competencia < competencia.next());
+///
+/// let trimestre: Vec<String> = competencia
+///     .until(Competencia::new(2024, 5)?)
+///     .map(|c| c.to_string())
+///     .collect();
+/// assert_eq!(vec!["03/2024", "04/2024", "05/2024"], trimestre);
+///
+/// # Ok::<(), ParseCompetenciaError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression))]
+#[cfg_attr(feature = "diesel", derive(diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
+pub struct Competencia {
+    ano: i32,
+    mes: u32,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseCompetenciaError {
+    Invalid,
+}
+
+impl Display for ParseCompetenciaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseCompetenciaError::Invalid => {
+                f.write_str("invalid competência, expected MM/YYYY or YYYY-MM")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseCompetenciaError {}
+
+impl Competencia {
+    pub const MIN: Competencia = Competencia { ano: 0, mes: 1 };
+    pub const MAX: Competencia = Competencia { ano: 9999, mes: 12 };
+
+    pub fn new(ano: i32, mes: u32) -> Result<Competencia, ParseCompetenciaError> {
+        if !(0..=9999).contains(&ano) || !(1..=12).contains(&mes) {
+            return Err(ParseCompetenciaError::Invalid);
+        }
+        Ok(Competencia { ano, mes })
+    }
+
+    pub fn ano(self) -> i32 {
+        self.ano
+    }
+
+    pub fn mes(self) -> u32 {
+        self.mes
+    }
+
+    /// The following month, or [`Competencia::MAX`] itself.
+    pub fn next(self) -> Competencia {
+        if self == Competencia::MAX {
+            self
+        } else if self.mes == 12 {
+            Competencia {
+                ano: self.ano + 1,
+                mes: 1,
+            }
+        } else {
+            Competencia {
+                ano: self.ano,
+                mes: self.mes + 1,
+            }
+        }
+    }
+
+    /// The previous month, or [`Competencia::MIN`] itself.
+    pub fn prev(self) -> Competencia {
+        if self == Competencia::MIN {
+            self
+        } else if self.mes == 1 {
+            Competencia {
+                ano: self.ano - 1,
+                mes: 12,
+            }
+        } else {
+            Competencia {
+                ano: self.ano,
+                mes: self.mes - 1,
+            }
+        }
+    }
+
+    /// Iterates from this competência up to `end`, inclusive.
+    pub fn until(self, end: Competencia) -> impl Iterator<Item = Competencia> {
+        let mut current = Some(self).filter(|c| *c <= end);
+        core::iter::from_fn(move || {
+            let item = current?;
+            current = Some(item).filter(|c| *c < end).map(Competencia::next);
+            Some(item)
+        })
+    }
+
+    /// ```
+    /// use bras::Competencia;
+    /// use chrono::NaiveDate;
+    /// # use bras::ParseCompetenciaError;
+    ///
+    /// let competencia = Competencia::new(2024, 2)?;
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(), competencia.primeiro_dia());
+    /// assert_eq!(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(), competencia.ultimo_dia());
+    ///
+    /// # Ok::<(), ParseCompetenciaError>(())
+    /// ```
+    #[cfg(feature = "dates")]
+    pub fn primeiro_dia(self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.ano, self.mes, 1).expect("valid date")
+    }
+
+    #[cfg(feature = "dates")]
+    pub fn ultimo_dia(self) -> NaiveDate {
+        let (ano, mes) = match self.mes {
+            12 => (self.ano + 1, 1),
+            mes => (self.ano, mes + 1),
+        };
+        NaiveDate::from_ymd_opt(ano, mes, 1)
+            .and_then(|date| date.pred_opt())
+            .expect("valid date")
+    }
+
+    #[cfg(feature = "dates")]
+    pub fn contains(self, date: NaiveDate) -> bool {
+        Competencia::try_from(date) == Ok(self)
+    }
+}
+
+#[cfg(feature = "dates")]
+impl TryFrom<NaiveDate> for Competencia {
+    type Error = ParseCompetenciaError;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        Competencia::new(date.year(), date.month())
+    }
+}
+
+impl FromStr for Competencia {
+    type Err = ParseCompetenciaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ano, mes) = match (s.split_once('/'), s.split_once('-')) {
+            (Some((mes, ano)), None) => (ano, mes),
+            (None, Some((ano, mes))) => (ano, mes),
+            _ => return Err(ParseCompetenciaError::Invalid),
+        };
+        if ano.len() != 4 || mes.len() != 2 {
+            return Err(ParseCompetenciaError::Invalid);
+        }
+        if !ano.bytes().chain(mes.bytes()).all(|b| b.is_ascii_digit()) {
+            return Err(ParseCompetenciaError::Invalid);
+        }
+        let ano = i32::from_str(ano).map_err(|_| ParseCompetenciaError::Invalid)?;
+        let mes = u32::from_str(mes).map_err(|_| ParseCompetenciaError::Invalid)?;
+        Competencia::new(ano, mes)
+    }
+}
+
+impl Display for Competencia {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:02}/{:04}", self.mes, self.ano)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Competencia {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Competencia {
+            ano: u.int_in_range(Competencia::MIN.ano..=Competencia::MAX.ano)?,
+            mes: u.int_in_range(1..=12)?,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl proptest::arbitrary::Arbitrary for Competencia {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Competencia>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        (Competencia::MIN.ano..=Competencia::MAX.ano, 1..=12u32)
+            .prop_map(|(ano, mes)| Competencia { ano, mes })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn return_error_on_invalid_str() {
+        let invalid = [
+            "13/2024", "00/2024", "3/2024", "03/24", "2024/03", "03-2024", "2024-3", "+3/2024", "",
+        ];
+        for competencia in invalid {
+            assert_eq!(
+                Competencia::from_str(competencia).unwrap_err(),
+                ParseCompetenciaError::Invalid
+            );
+        }
+    }
+
+    #[test]
+    fn next_and_prev_are_inverse() {
+        let mut competencia = Competencia::new(2023, 1).unwrap();
+        for _ in 0..30 {
+            assert_eq!(competencia, competencia.next().prev());
+            competencia = competencia.next();
+        }
+        assert_eq!(Competencia::new(2025, 7).unwrap(), competencia);
+    }
+
+    #[test]
+    fn empty_range() {
+        let start = Competencia::new(2024, 5).unwrap();
+        assert_eq!(0, start.until(start.prev()).count());
+        assert_eq!(1, start.until(start).count());
+        assert_eq!(13, start.until(Competencia::new(2025, 5).unwrap()).count());
+        assert_eq!(1, Competencia::MAX.until(Competencia::MAX).count());
+    }
+
+    #[test]
+    fn year_has_four_digits() {
+        assert_eq!(
+            Err(ParseCompetenciaError::Invalid),
+            Competencia::new(10000, 1)
+        );
+        assert_eq!(
+            Err(ParseCompetenciaError::Invalid),
+            Competencia::new(-1, 12)
+        );
+        assert_eq!(Ok(Competencia::MIN), "01/0000".parse());
+        assert_eq!(Ok(Competencia::MAX), "9999-12".parse());
+        assert_eq!(Competencia::MAX, Competencia::MAX.next());
+        assert_eq!(Competencia::MIN, Competencia::MIN.prev());
+    }
+
+    #[cfg(feature = "dates")]
+    #[test]
+    fn date_ranges() {
+        let competencia = Competencia::new(2023, 12).unwrap();
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            competencia.ultimo_dia()
+        );
+        assert!(competencia.contains(NaiveDate::from_ymd_opt(2023, 12, 15).unwrap()));
+        assert!(!competencia.contains(NaiveDate::from_ymd_opt(2024, 12, 15).unwrap()));
+        assert_eq!(
+            NaiveDate::from_ymd_opt(9999, 12, 31).unwrap(),
+            Competencia::MAX.ultimo_dia()
+        );
+        assert!(Competencia::try_from(NaiveDate::MAX).is_err());
+    }
+}
@@ -0,0 +1,93 @@
+//! Text helpers for systems that only accept ASCII.
+
+use alloc::borrow::Cow;
+
+/// Replaces letters with Portuguese (and other Latin-1) diacritics by their ASCII base
+/// letter, keeping the case. "º" and "ª" become "o" and "a". The combining marks of
+/// decomposed (NFD) text are dropped, typographic quotes, dashes and spaces become their
+/// ASCII counterparts and any other character outside ASCII, such as "€", is dropped, so
+/// the result is always ASCII. Borrows the input when there's nothing to replace.
+///
+/// ```
+/// use bras::texto::sem_acentos;
+///
+/// assert_eq!("Sao Joao da Boa Vista", sem_acentos("São João da Boa Vista"));
+/// assert_eq!("ACAO CONCEICAO", sem_acentos("AÇÃO CONCEIÇÃO"));
+/// assert_eq!("1o andar", sem_acentos("1º andar"));
+/// assert_eq!("Sao Paulo", sem_acentos("Sa\u{303}o Paulo"));
+/// assert_eq!(" 10,00", sem_acentos("€ 10,00"));
+/// ```
+pub fn sem_acentos(s: &str) -> Cow<'_, str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+    Cow::Owned(s.chars().filter_map(ascii).collect())
+}
+
+/// ASCII replacement of `c`, or `None` when it should be dropped.
+pub(crate) fn ascii(c: char) -> Option<char> {
+    match sem_acento(c) {
+        c if c.is_ascii() => Some(c),
+        '\u{a0}' | '\u{2000}'..='\u{200a}' | '\u{202f}' => Some(' '),
+        '\u{2010}'..='\u{2015}' | '\u{2212}' => Some('-'),
+        '\u{2018}' | '\u{2019}' | '\u{201a}' | '\u{2032}' => Some('\''),
+        '\u{201c}' | '\u{201d}' | '\u{201e}' | '\u{2033}' => Some('"'),
+        _ => None,
+    }
+}
+
+/// ASCII base letter of `c`, or `c` itself when it has no diacritic.
+fn sem_acento(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ã' | 'ä' | 'å' | 'ª' => 'a',
+        'Á' | 'À' | 'Â' | 'Ã' | 'Ä' | 'Å' => 'A',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'É' | 'È' | 'Ê' | 'Ë' => 'E',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'Í' | 'Ì' | 'Î' | 'Ï' => 'I',
+        'ó' | 'ò' | 'ô' | 'õ' | 'ö' | 'ø' | 'º' => 'o',
+        'Ó' | 'Ò' | 'Ô' | 'Õ' | 'Ö' | 'Ø' => 'O',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'Ú' | 'Ù' | 'Û' | 'Ü' => 'U',
+        'ç' => 'c',
+        'Ç' => 'C',
+        'ñ' => 'n',
+        'Ñ' => 'N',
+        'ý' | 'ÿ' => 'y',
+        'Ý' => 'Y',
+        c => c,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn portuguese_letters_become_ascii() {
+        let all = "áàâãéêíóôõúüçÁÀÂÃÉÊÍÓÔÕÚÜÇ";
+        let ascii = sem_acentos(all);
+        assert!(ascii.is_ascii());
+        assert_eq!("aaaaeeiooouucAAAAEEIOOOUUC", ascii);
+    }
+
+    #[test]
+    fn decomposed_letters_become_ascii() {
+        let nfd = "Sa\u{303}o Jose\u{301} da Conceic\u{327}a\u{303}o";
+        assert_eq!("Sao Jose da Conceicao", sem_acentos(nfd));
+    }
+
+    #[test]
+    fn other_characters_are_replaced_or_dropped() {
+        let texto =
+            "\u{201c}Olho-d\u{2019}\u{c1}gua\u{201d} \u{2013} R$\u{a0}5 \u{20ac} \u{4e2d}\u{1f600}";
+        let ascii = sem_acentos(texto);
+        assert!(ascii.is_ascii());
+        assert_eq!("\"Olho-d'Agua\" - R$ 5  ", ascii);
+    }
+
+    #[test]
+    fn borrow_ascii_input() {
+        assert!(matches!(sem_acentos("Rua Augusta"), Cow::Borrowed(_)));
+    }
+}
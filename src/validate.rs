@@ -0,0 +1,98 @@
+//! Validation functions for the `validator` crate's `custom` attribute.
+//!
+//! Each function checks a string with the same rules as the corresponding
+//! [`FromStr`](core::str::FromStr) implementation. The error code is the document name and
+//! the message is the parse error.
+//!
+//! # Examples
+//! ```
+//! use validator::Validate;
+//!
+//! #[derive(Validate)]
+//! struct Cadastro {
+//!     #[validate(custom(function = "bras::validate::cpf"))]
+//!     cpf: String,
+//!     #[validate(custom(function = "bras::validate::cep"))]
+//!     cep: String,
+//! }
+//!
+//! let cadastro = Cadastro {
+//!     cpf: "984.844.854-39".to_string(),
+//!     cep: "01310-100".to_string(),
+//! };
+//! assert!(cadastro.validate().is_ok());
+//!
+//! let cadastro = Cadastro {
+//!     cpf: "984.844.854-00".to_string(),
+//!     cep: "01310-100".to_string(),
+//! };
+//! let errors = cadastro.validate().unwrap_err();
+//! assert!(errors.field_errors().contains_key("cpf"));
+//! ```
+
+use core::str::FromStr;
+
+use validator::ValidationError;
+
+use crate::{Cep, Cnpj, CodigoMunicipio, Competencia, Cpf, Uf};
+
+fn parse<T>(value: &str, code: &'static str) -> Result<(), ValidationError>
+where
+    T: FromStr,
+    T::Err: ToString,
+{
+    value
+        .parse::<T>()
+        .map(|_| ())
+        .map_err(|err| ValidationError::new(code).with_message(err.to_string().into()))
+}
+
+pub fn cpf(value: &str) -> Result<(), ValidationError> {
+    parse::<Cpf>(value, "cpf")
+}
+
+pub fn cnpj(value: &str) -> Result<(), ValidationError> {
+    parse::<Cnpj>(value, "cnpj")
+}
+
+pub fn cep(value: &str) -> Result<(), ValidationError> {
+    parse::<Cep>(value, "cep")
+}
+
+pub fn codigo_municipio(value: &str) -> Result<(), ValidationError> {
+    parse::<CodigoMunicipio>(value, "codigo_municipio")
+}
+
+pub fn competencia(value: &str) -> Result<(), ValidationError> {
+    parse::<Competencia>(value, "competencia")
+}
+
+pub fn uf(value: &str) -> Result<(), ValidationError> {
+    parse::<Uf>(value, "uf")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn errors_carry_code_and_message() {
+        assert!(cpf("98484485439").is_ok());
+        let err = cpf("98484485401").unwrap_err();
+        assert_eq!("cpf", err.code);
+        assert_eq!(
+            Some("invalid CPF, the first check digit does not match".into()),
+            err.message
+        );
+
+        assert!(cnpj("12.ABC.345/01DE-35").is_ok());
+        assert_eq!(
+            Some("invalid CNPJ, the second check digit does not match".into()),
+            cnpj("12.ABC.345/01DE-36").unwrap_err().message
+        );
+
+        assert!(uf("sp").is_ok());
+        assert_eq!("uf", uf("XX").unwrap_err().code);
+        assert!(competencia("13/2024").is_err());
+    }
+}
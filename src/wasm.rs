@@ -0,0 +1,126 @@
+//! wasm-bindgen bindings, so browser forms run the same validation as the backend.
+//!
+//! Build the `bras-wasm` package in the `wasm` directory, which links these functions into a
+//! cdylib, with `wasm-pack build wasm` and import from JavaScript:
+//! ```js
+//! import { validate_cpf, format_cpf } from "bras-wasm";
+//!
+//! validate_cpf("984.844.854-39"); // true
+//! format_cpf("98484485439");      // "984.844.854-39"
+//! format_cpf("984.844.854-00");   // undefined
+//! ```
+//!
+//! The functions are plain Rust functions too:
+//! ```
+//! use bras::wasm::{format_cep, validate_cpf};
+//!
+//! assert!(validate_cpf("984.844.854-39"));
+//! assert_eq!(Some("01310-100".to_string()), format_cep("01.310-100"));
+//! ```
+
+use alloc::string::{String, ToString};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{Cep, Cnpj, CodigoMunicipio, Cpf};
+
+/// Whether `s` is a valid CPF, formatted or not. See [`Cpf::is_valid`].
+#[wasm_bindgen]
+pub fn validate_cpf(s: &str) -> bool {
+    Cpf::is_valid(s)
+}
+
+/// The CPF in `s` as "000.000.000-00", ignoring any other punctuation, or `undefined`
+/// when invalid. See [`Cpf::parse_lossy`].
+#[wasm_bindgen]
+pub fn format_cpf(s: &str) -> Option<String> {
+    Cpf::parse_lossy(s).ok().map(|cpf| cpf.to_string())
+}
+
+/// The CPF in `s` with the middle digits hidden, or `undefined` when invalid. See
+/// [`Cpf::masked`].
+#[wasm_bindgen]
+pub fn mask_cpf(s: &str) -> Option<String> {
+    Cpf::parse_lossy(s).ok().map(Cpf::masked)
+}
+
+/// Random valid CPF, formatted. See [`Cpf::generate`].
+#[wasm_bindgen]
+pub fn generate_cpf() -> String {
+    Cpf::generate().to_string()
+}
+
+/// Whether `s` is a valid CNPJ, numeric or alphanumeric, formatted or not. See
+/// [`Cnpj::is_valid`].
+#[wasm_bindgen]
+pub fn validate_cnpj(s: &str) -> bool {
+    Cnpj::is_valid(s)
+}
+
+/// The CNPJ in `s` as "00.000.000/0000-00", ignoring any other punctuation, or `undefined`
+/// when invalid. See [`Cnpj::parse_lossy`].
+#[wasm_bindgen]
+pub fn format_cnpj(s: &str) -> Option<String> {
+    Cnpj::parse_lossy(s).ok().map(|cnpj| cnpj.to_string())
+}
+
+/// The CNPJ in `s` with the establishment and check digits hidden, or `undefined` when
+/// invalid. See [`Cnpj::masked`].
+#[wasm_bindgen]
+pub fn mask_cnpj(s: &str) -> Option<String> {
+    Cnpj::parse_lossy(s).ok().map(|cnpj| cnpj.masked())
+}
+
+/// Random valid CNPJ, formatted. See [`Cnpj::generate`].
+#[wasm_bindgen]
+pub fn generate_cnpj() -> String {
+    Cnpj::generate().to_string()
+}
+
+/// Whether `s` is a valid CEP, formatted or not.
+#[wasm_bindgen]
+pub fn validate_cep(s: &str) -> bool {
+    Cep::is_valid(s)
+}
+
+/// The CEP in `s` as "00000-000", ignoring any other punctuation, or `undefined` when
+/// invalid. See [`Cep::parse_lossy`].
+#[wasm_bindgen]
+pub fn format_cep(s: &str) -> Option<String> {
+    Cep::parse_lossy(s).ok().map(|cep| cep.to_string())
+}
+
+/// Random valid CEP, formatted. See [`Cep::generate`].
+#[wasm_bindgen]
+pub fn generate_cep() -> String {
+    Cep::generate().to_string()
+}
+
+/// Whether `s` is a valid 7 digit IBGE municipality code.
+#[wasm_bindgen]
+pub fn validate_codigo_municipio(s: &str) -> bool {
+    CodigoMunicipio::is_valid(s)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn generated_documents_are_valid() {
+        assert!(validate_cpf(&generate_cpf()));
+        assert!(validate_cnpj(&generate_cnpj()));
+        assert!(validate_cep(&generate_cep()));
+        assert_eq!(None, format_cpf("984.844.854-00"));
+        assert_eq!(Some("***.844.854-**".to_string()), mask_cpf("98484485439"));
+        assert_eq!(
+            Some("12.ABC.345/01DE-35".to_string()),
+            format_cnpj("12 ABC 345 01DE 35")
+        );
+        assert_eq!(
+            Some("11.222.333/****-**".to_string()),
+            mask_cnpj("11222333000181")
+        );
+        assert!(validate_codigo_municipio("3550308"));
+    }
+}
@@ -0,0 +1,4 @@
+//! The cdylib wasm-pack builds from the bindings in [`bras::wasm`]. It lives in its own
+//! package because a cdylib links `std`, which would break the `no_std` builds of `bras`.
+
+pub use bras::wasm::*;